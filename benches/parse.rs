@@ -0,0 +1,100 @@
+// Parsing throughput in tags per second over synthetic headers with many short string elements,
+// where dictionary lookups and header decoding dominate over reading values.
+use std::hint::black_box;
+use std::io::Cursor;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+use dicomize::{dicom, tags, transfer_syntax, vr_type, DicomHandler, DicomTag, TransferSyntax};
+
+const ELEMENTS: usize = 20_000;
+const VALUE: &[u8] = b"VALUE1";
+
+#[derive(Default)]
+struct Counter(usize);
+
+impl DicomHandler for Counter {
+    fn element(&mut self, _tag: DicomTag) {
+        self.0 += 1;
+    }
+}
+
+// Dictionary tags with short string values, spread over the whole dictionary.
+fn header_tags() -> Vec<((u16, u16), u16)> {
+    let strings = [vr_type::CODE_STRING, vr_type::LONG_STRING, vr_type::SHORT_STRING, vr_type::PERSON_NAME];
+
+    tags::TAGS.iter()
+        .filter(|(tag, _, _, vr)| tag.0 > 0x0002 && tag.0 < 0x7FE0 && strings.contains(vr))
+        .map(|(tag, _, _, vr)| (*tag, *vr))
+        .cycle()
+        .take(ELEMENTS)
+        .collect()
+}
+
+fn dataset(implicit: bool) -> Vec<u8> {
+    header_tags().iter().flat_map(|((group, element), vr)| {
+        let length = VALUE.len() as u16;
+        let vr_and_length = match implicit {
+            true  => (length as u32).to_le_bytes().to_vec(),
+            false => [vr.to_le_bytes(), length.to_le_bytes()].concat()
+        };
+        [group.to_le_bytes().to_vec(), element.to_le_bytes().to_vec(), vr_and_length, VALUE.to_vec()].concat()
+    }).collect()
+}
+
+fn dictionary(c: &mut Criterion) {
+    let lookups = header_tags();
+    let mut group = c.benchmark_group("dictionary");
+    group.throughput(Throughput::Elements(lookups.len() as u64));
+
+    group.bench_function("tag_vr_type", |b| b.iter(|| {
+        lookups.iter().map(|((g, e), _)| tags::tag_vr_type(black_box(*g), black_box(*e)) as usize).sum::<usize>()
+    }));
+
+    group.bench_function("try_tag_name", |b| b.iter(|| {
+        lookups.iter().filter_map(|((g, e), _)| tags::try_tag_name(black_box(*g), black_box(*e))).count()
+    }));
+
+    group.finish();
+}
+
+fn parsers(c: &mut Criterion) {
+    let explicit = dataset(false);
+    let implicit = dataset(true);
+    let explicit_le = TransferSyntax::default();
+    let implicit_le = TransferSyntax::parse_str(transfer_syntax::IMPLICIT_LE);
+
+    let mut group = c.benchmark_group("parse");
+    group.throughput(Throughput::Elements(ELEMENTS as u64));
+
+    group.bench_function("explicit_le", |b| b.iter(|| {
+        let mut counter = Counter::default();
+        dicom::parse_dataset(&mut Cursor::new(&explicit), explicit_le, &mut counter).unwrap();
+        counter.0
+    }));
+
+    group.bench_function("implicit_le", |b| b.iter(|| {
+        let mut counter = Counter::default();
+        dicom::parse_dataset(&mut Cursor::new(&implicit), implicit_le, &mut counter).unwrap();
+        counter.0
+    }));
+
+    group.bench_function("stream", |b| b.iter(|| {
+        let mut counter = Counter::default();
+        dicom::parse_dataset_stream(&explicit[..], explicit_le, &mut counter).unwrap();
+        counter.0
+    }));
+
+    group.bench_function("tag_iter", |b| b.iter(|| {
+        dicom::dataset_tags(Cursor::new(&explicit), explicit_le).count()
+    }));
+
+    group.bench_function("slice", |b| b.iter(|| {
+        dicomize::dicom_slice::parse_dataset_slice(&explicit, explicit_le).count()
+    }));
+
+    group.finish();
+}
+
+criterion_group!(benches, dictionary, parsers);
+criterion_main!(benches);
@@ -0,0 +1,101 @@
+use std::env;
+use std::process;
+use std::time::Instant;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+
+use dicomize::{dicom, dicom_writer, transfer_syntax};
+use dicomize::dicom_handlers::*;
+use dicomize::transfer_syntax::TransferSyntax;
+
+const MIN_ARGUMENTS: usize = 2;
+const COMMAND_ARGUMENTS: usize = 3;
+const TRANSCODE_ARGUMENTS: usize = 5;
+
+// Short names accepted for the syntaxes files can be transcoded to, besides their UIDs.
+const SYNTAX_NAMES: [(&str, &str); 4] = [
+    ("implicit-le", transfer_syntax::IMPLICIT_LE),
+    ("explicit-le", transfer_syntax::EXPLICIT_LE),
+    ("explicit-be", transfer_syntax::EXPLICIT_BE),
+    ("deflated-le", transfer_syntax::DEFLATED_EXPLICIT_LE)
+];
+
+fn load_dcim(dcim_file_path: &str) -> Result<(), String> {
+
+    println!("LOADING DICOM TAGS IN {} ...", dcim_file_path);
+
+    let mut container = DicomContainer::new();
+
+    let mut reader = File::open(dcim_file_path).map_err(|err| format!("COULD NOT LOAD {}. {}", dcim_file_path, err))?;
+
+    dicom::parse(&mut reader, &mut container).map_err(|err| format!("COULD NOT PARSE {}. {}", dcim_file_path, err))?;
+
+    println!("Found {} dicom nodes", container.nodes.len());
+
+    Ok(())
+}
+
+fn dump_dcim(dcim_file_path: &str) -> Result<(), String> {
+
+    println!("DUMPING DICOM TAGS IN {} ...", dcim_file_path);
+
+    let mut container = DicomDumper::new();
+
+    let mut reader = File::open(dcim_file_path).map_err(|err| format!("COULD NOT LOAD {}. {}", dcim_file_path, err))?;
+
+    dicom::parse(&mut reader, &mut container).map_err(|err| format!("COULD NOT PARSE {}. {}", dcim_file_path, err))?;
+
+    println!("Found {} dicom nodes", container.tags_count());
+
+    Ok(())
+}
+
+// The file is written next to the output and renamed once complete, a failed transcode leaves no output behind.
+fn transcode_dcim(input_path: &str, output_path: &str, syntax_name: &str) -> Result<(), String> {
+
+    let uid = SYNTAX_NAMES.iter().find(|(name, _)| *name == syntax_name).map_or(syntax_name, |(_, uid)| uid);
+
+    let syntax = TransferSyntax::from_uid(uid).ok_or_else(|| format!("UNKNOWN TRANSFER SYNTAX {}", syntax_name))?;
+
+    println!("TRANSCODING {} TO {} IN {} ...", input_path, output_path, syntax.name);
+
+    let mut reader = File::open(input_path).map_err(|err| format!("COULD NOT LOAD {}. {}", input_path, err))?;
+
+    let partial_path = format!("{}.part", output_path);
+    let mut writer = File::create(&partial_path).map(BufWriter::new).map_err(|err| format!("COULD NOT CREATE {}. {}", partial_path, err))?;
+
+    let result = dicom_writer::transcode(&mut reader, &mut writer, syntax)
+        .map_err(|err| format!("COULD NOT TRANSCODE {}. {}", input_path, err))
+        .and_then(|_| writer.flush().map_err(|err| format!("COULD NOT WRITE {}. {}", partial_path, err)))
+        .and_then(|_| fs::rename(&partial_path, output_path).map_err(|err| format!("COULD NOT CREATE {}. {}", output_path, err)));
+
+    if result.is_err() {
+        let _ = fs::remove_file(&partial_path);
+    }
+
+    result
+}
+
+fn main() {
+    println!("DICOM COMMAND LINE PARSER");
+
+    let args: Vec<String> = env::args().collect();
+
+    let now = Instant::now();    
+
+    let result = match args.len() {
+        MIN_ARGUMENTS                                 => dump_dcim(&args[1]),
+        COMMAND_ARGUMENTS if args[1] == "load"        => load_dcim(&args[2]),
+        TRANSCODE_ARGUMENTS if args[1] == "transcode" => transcode_dcim(&args[2], &args[3], &args[4]),
+        COMMAND_ARGUMENTS | TRANSCODE_ARGUMENTS       => Err(format!("UNKNOWN COMMAND {}", args[1])),
+        _                                             => Err("UNEXPECTED NUMBER OF ARGUMENTS".to_string())
+    };
+
+    // Errors go to stderr and fail the process, so scripts can tell a bad file from a good one.
+    if let Err(message) = result {
+        eprintln!("ERROR: {}", message);
+        process::exit(1);
+    }
+
+    println!("FINISHED IN {}ms", now.elapsed().as_millis());    
+}
\ No newline at end of file
@@ -1,138 +1,111 @@
 use std::convert::TryFrom;
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{Read, Seek, SeekFrom, Result};
+
+use crate::transfer_syntax::EndianEncoding;
 
 pub trait RewindExtensions {
 
-    fn read_rewind_16<T>(&mut self, convert: fn([u8; 2]) -> T) -> T;
-    
-    fn read_rewind_u16(&mut self) -> u16;
+    fn read_rewind_16<T>(&mut self, convert: fn([u8; 2]) -> T) -> Result<T>;
+
+    fn read_rewind_u16(&mut self) -> Result<u16>;
+
+    fn is_at_end(&mut self) -> Result<bool>;
 }
 
 pub trait SeekExtensions {
 
-    fn len(&mut self) -> u64;
+    fn len(&mut self) -> Result<u64>;
 
-    fn pos(&mut self) -> u64;
+    fn pos(&mut self) -> Result<u64>;
 }
 
 pub trait BinaryReader {
 
-    fn read_64<T>(&mut self, convert: fn([u8; 8]) -> T) -> T;
-    
-    fn read_32<T>(&mut self, convert: fn([u8; 4]) -> T) -> T;
-    
-    fn read_16<T>(&mut self, convert: fn([u8; 2]) -> T) -> T;
-    
-    fn read_2(&mut self) -> [u8; 2];
-    
-    fn read_bytes(&mut self, length: usize) -> Vec<u8>;
-    
-    fn read_i32(&mut self) -> i32;
-    
-    fn read_i16(&mut self) -> i16;
-    
-    fn read_u16(&mut self) -> u16;
-    
-    fn read_u32(&mut self) -> u32;
-    
-    fn read_f32(&mut self) -> f32;
-    
-    fn read_f64(&mut self) -> f64;
-
-    fn read_string(&mut self, length: usize) -> String;
+    fn read_32<T>(&mut self, convert: fn([u8; 4]) -> T) -> Result<T>;
+
+    fn read_16<T>(&mut self, convert: fn([u8; 2]) -> T) -> Result<T>;
+
+    fn read_bytes(&mut self, length: usize) -> Result<Vec<u8>>;
+
+    fn read_u16(&mut self, endian: EndianEncoding) -> Result<u16>;
+
+    fn read_u32(&mut self, endian: EndianEncoding) -> Result<u32>;
 }
 
 impl <T: Seek> SeekExtensions for T {
 
-    fn len(&mut self) -> u64 {
-        let current = self.pos();
-        // Safe to unwrap, guaranteed by the stream impl.
-        let len = self.seek(SeekFrom::End(0)).unwrap();
-        self.seek(SeekFrom::Start(current)).unwrap();
-        len
+    fn len(&mut self) -> Result<u64> {
+        let current = self.pos()?;
+        let len = self.seek(SeekFrom::End(0))?;
+        self.seek(SeekFrom::Start(current))?;
+        Ok(len)
     }
 
-    fn pos(&mut self) -> u64 {
-        // Safe to unwrap, guaranteed by the stream impl.
-        self.seek(SeekFrom::Current(0)).unwrap()
-    }        
+    fn pos(&mut self) -> Result<u64> {
+        self.stream_position()
+    }
 }
 
 impl <T: Read+Seek> RewindExtensions for T {
 
-    fn read_rewind_16<T1>(&mut self, convert: fn([u8; 2]) -> T1) -> T1 {
+    fn read_rewind_16<T1>(&mut self, convert: fn([u8; 2]) -> T1) -> Result<T1> {
         let mut buffer = [0; 2];
-        let _ = self.read(&mut buffer[..]).unwrap();
+        self.read_exact(&mut buffer)?;
         let value = convert(buffer);
-        let _ = self.seek(SeekFrom::Current(-2)).unwrap(); // TODO: proper error propagation instead of unwrap.
-        value
+        self.seek(SeekFrom::Current(-2))?;
+        Ok(value)
+    }
+
+    fn read_rewind_u16(&mut self) -> Result<u16> {
+        self.read_rewind_16(u16::from_le_bytes)
     }
-    
-    fn read_rewind_u16(&mut self) -> u16 {
-        self.read_rewind_16(u16::from_ne_bytes)
+
+    // Works without knowing the length of the reader, by trying to read one more byte.
+    fn is_at_end(&mut self) -> Result<bool> {
+        let mut buffer = [0; 1];
+
+        match self.read(&mut buffer)? {
+            0 => Ok(true),
+            _ => {
+                self.seek(SeekFrom::Current(-1))?;
+                Ok(false)
+            }
+        }
     }
 }
 
 impl <T: Read> BinaryReader for T {
 
-    fn read_64<T1>(&mut self, convert: fn([u8; 8]) -> T1) -> T1 {
-        let mut buffer = [0; 8];
-        let _ = self.read(&mut buffer).unwrap(); // TODO: proper error propagation instead of unwrap.
-        convert(buffer)
-    }
-    
-    fn read_32<T1>(&mut self, convert: fn([u8; 4]) -> T1) -> T1 {
+    fn read_32<T1>(&mut self, convert: fn([u8; 4]) -> T1) -> Result<T1> {
         let mut buffer = [0; 4];
-        let _ = self.read(&mut buffer).unwrap(); // TODO: proper error propagation instead of unwrap.
-        convert(buffer)
+        self.read_exact(&mut buffer)?;
+        Ok(convert(buffer))
     }
-    
-    fn read_16<T1>(&mut self, convert: fn([u8; 2]) -> T1) -> T1 {
-        let mut buffer = [0; 2];
-        let _ = self.read(&mut buffer).unwrap(); // TODO: proper error propagation instead of unwrap.
-        convert(buffer)
-    }
-    
-    fn read_2(&mut self) -> [u8; 2] {
+
+    fn read_16<T1>(&mut self, convert: fn([u8; 2]) -> T1) -> Result<T1> {
         let mut buffer = [0; 2];
-        let _ = self.read(&mut buffer).unwrap(); // TODO: proper error propagation instead of unwrap.
-        buffer
-    }
-    
-    fn read_bytes(&mut self, length: usize) -> Vec<u8> {
-        let mut buffer = vec![0u8; length];
-        let _ = self.read(buffer.as_mut_slice()).unwrap(); // TODO: proper error propagation instead of unwrap.
-        buffer
-    }
-    
-    fn read_i32(&mut self) -> i32 {
-        self.read_32(i32::from_ne_bytes)
-    }
-    
-    fn read_i16(&mut self) -> i16 {
-        self.read_16(i16::from_ne_bytes)
+        self.read_exact(&mut buffer)?;
+        Ok(convert(buffer))
     }
-    
-    fn read_u16(&mut self) -> u16 {
-        self.read_16(u16::from_ne_bytes)
-    }
-    
-    fn read_u32(&mut self) -> u32 {
-        self.read_32(u32::from_ne_bytes)
-    }
-    
-    fn read_f32(&mut self) -> f32 {
-        self.read_32(f32::from_ne_bytes)
+
+    fn read_bytes(&mut self, length: usize) -> Result<Vec<u8>> {
+        // Grow the buffer as data arrives rather than trusting the declared length up front,
+        // a corrupt length field must not trigger a huge allocation.
+        let mut buffer = Vec::new();
+        let safe_length = u64::try_from(length).unwrap_or(u64::MAX);
+        self.take(safe_length).read_to_end(&mut buffer)?;
+
+        match buffer.len() == length {
+            true  => Ok(buffer),
+            false => Err(std::io::ErrorKind::UnexpectedEof.into())
+        }
     }
-    
-    fn read_f64(&mut self) -> f64 {
-        self.read_64(f64::from_ne_bytes)
+
+    fn read_u16(&mut self, endian: EndianEncoding) -> Result<u16> {
+        self.read_16(endian.convert(u16::from_le_bytes, u16::from_be_bytes))
     }
 
-    fn read_string(&mut self, length: usize) -> String {
-        let mut buffer = String::new();
-        let safe_length = u64::try_from(length).unwrap();
-        let _ = self.take(safe_length).read_to_string(&mut buffer).unwrap();
-        buffer
-    }    
-}
\ No newline at end of file
+    fn read_u32(&mut self, endian: EndianEncoding) -> Result<u32> {
+        self.read_32(endian.convert(u32::from_le_bytes, u32::from_be_bytes))
+    }
+}
@@ -0,0 +1,440 @@
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::vr_type;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SECOND;
+const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+
+// UTC offsets of DT values go from -12:00 to +14:00.
+const MIN_OFFSET: i16 = -12 * 60;
+const MAX_OFFSET: i16 = 14 * 60;
+
+// DA value, YYYYMMDD where month and day may be left out.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DicomDate {
+    pub year: u16,
+    pub month: Option<u8>,
+    pub day: Option<u8>
+}
+
+// TM value, HHMMSS.FFFFFF where minutes, seconds and the fraction may be left out. The fraction is held in
+// microseconds along with the number of digits it was written with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DicomTime {
+    pub hour: u8,
+    pub minute: Option<u8>,
+    pub second: Option<u8>,
+    pub micros: Option<u32>,
+    pub fraction_digits: u8
+}
+
+// DT value, a date, a time when the date is complete and an offset from UTC in minutes, &ZZXX.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DicomDateTime {
+    pub date: DicomDate,
+    pub time: Option<DicomTime>,
+    pub offset: Option<i16>
+}
+
+// Range matching values of query datasets, "start-end" where either end may be open, a single value matching itself.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DicomRange<T> {
+    pub start: Option<T>,
+    pub end: Option<T>
+}
+
+// Dates and times, ordered by the span of time they cover.
+pub trait Temporal: Copy + PartialEq + fmt::Display {
+    const VR: u16;
+
+    fn parse(value: &str) -> Option<Self>;
+
+    // First and last microsecond covered, a value of partial precision covering every value it is a prefix of.
+    // Dates and date times count from the Unix epoch, times from midnight.
+    fn bounds(&self) -> (i64, i64);
+}
+
+impl DicomDate {
+    pub fn parse(value: &str) -> Option<DicomDate> {
+        if !value.is_ascii() {
+            return None;
+        }
+
+        let (year, month, day) = match value.len() {
+            4 => (number(value)?, None, None),
+            6 => (number(&value[..4])?, Some(number(&value[4..])?), None),
+            8 => (number(&value[..4])?, Some(number(&value[4..6])?), Some(number(&value[6..])?)),
+            _ => return None
+        };
+
+        let date = DicomDate { year: year as u16, month: month.map(|m| m as u8), day: day.map(|d| d as u8) };
+        match date.month.is_none_or(|m| (1..=12).contains(&m)) && date.day.is_none_or(|d| (1..=date.days_in_month()).contains(&d)) {
+            true  => Some(date),
+            false => None
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.day.is_some()
+    }
+
+    // Midnight UTC of the first day covered, None outside of the range of SystemTime.
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        system_time(self.bounds().0)
+    }
+
+    fn days_in_month(&self) -> u8 {
+        let leap = self.year.is_multiple_of(4) && (!self.year.is_multiple_of(100) || self.year.is_multiple_of(400));
+        match self.month.unwrap_or(12) {
+            2 if leap      => 29,
+            2              => 28,
+            4 | 6 | 9 | 11 => 30,
+            _              => 31
+        }
+    }
+
+    fn first_day(&self) -> i64 {
+        days_from_civil(i64::from(self.year), i64::from(self.month.unwrap_or(1)), i64::from(self.day.unwrap_or(1)))
+    }
+
+    fn last_day(&self) -> i64 {
+        days_from_civil(i64::from(self.year), i64::from(self.month.unwrap_or(12)), i64::from(self.day.unwrap_or_else(|| self.days_in_month())))
+    }
+}
+
+impl DicomTime {
+    pub fn parse(value: &str) -> Option<DicomTime> {
+        if !value.is_ascii() {
+            return None;
+        }
+
+        let (time, fraction) = match value.find('.') {
+            Some(dot) => (&value[..dot], Some(&value[dot + 1..])),
+            None      => (value, None)
+        };
+
+        let (hour, minute, second) = match time.len() {
+            2 => (number(time)?, None, None),
+            4 => (number(&time[..2])?, Some(number(&time[2..])?), None),
+            6 => (number(&time[..2])?, Some(number(&time[2..4])?), Some(number(&time[4..])?)),
+            _ => return None
+        };
+
+        // A fraction only follows seconds and has 1 to 6 digits.
+        let micros = match fraction {
+            Some(f) if second.is_some() && f.len() <= 6 => Some(number(f)? * 10u32.pow(6 - f.len() as u32)),
+            Some(_)                                     => return None,
+            None                                        => None
+        };
+
+        let valid = hour <= 23 && minute.is_none_or(|m| m <= 59) && second.is_none_or(|s| s <= 60);
+        match valid {
+            true  => Some(DicomTime {
+                hour: hour as u8,
+                minute: minute.map(|m| m as u8),
+                second: second.map(|s| s as u8),
+                micros,
+                fraction_digits: fraction.map_or(0, |f| f.len() as u8)
+            }),
+            false => None
+        }
+    }
+
+    // Time elapsed since midnight at the start of the time.
+    pub fn to_duration(&self) -> Duration {
+        Duration::from_micros(self.bounds().0 as u64)
+    }
+
+    // Microseconds from midnight to the start and the end of the time.
+    fn span(&self) -> (i64, i64) {
+        let start = i64::from(self.hour) * MICROS_PER_HOUR
+            + i64::from(self.minute.unwrap_or(0)) * MICROS_PER_MINUTE
+            + i64::from(self.second.unwrap_or(0)) * MICROS_PER_SECOND
+            + i64::from(self.micros.unwrap_or(0));
+
+        let length = match (self.minute, self.second, self.micros) {
+            (None, _, _)          => MICROS_PER_HOUR,
+            (Some(_), None, _)    => MICROS_PER_MINUTE,
+            (_, Some(_), None)    => MICROS_PER_SECOND,
+            (_, _, Some(_))       => 10i64.pow(6 - u32::from(self.fraction_digits))
+        };
+
+        (start, start + length - 1)
+    }
+}
+
+impl DicomDateTime {
+    pub fn parse(value: &str) -> Option<DicomDateTime> {
+        if !value.is_ascii() {
+            return None;
+        }
+
+        let (value, offset) = match value.len().checked_sub(5).map(|sign| (sign, &value[sign..sign + 1])) {
+            Some((sign, "+")) | Some((sign, "-")) => (&value[..sign], Some(utc_offset(&value[sign..])?)),
+            _                                   => (value, None)
+        };
+
+        // Time only follows a complete date.
+        let (date, time) = match value.len() > 8 {
+            true  => (DicomDate::parse(&value[..8])?, Some(DicomTime::parse(&value[8..])?)),
+            false => (DicomDate::parse(value)?, None)
+        };
+
+        Some(DicomDateTime { date, time, offset })
+    }
+
+    // Start of the date time, taken as UTC when it has no offset. None outside of the range of SystemTime.
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        system_time(self.bounds().0)
+    }
+}
+
+impl<T: Temporal> DicomRange<T> {
+    // A single value first, the hyphen of a negative UTC offset not being a range, then each hyphen in turn.
+    pub fn parse(value: &str) -> Option<DicomRange<T>> {
+        if let Some(v) = T::parse(value) {
+            return Some(DicomRange { start: Some(v), end: Some(v) });
+        }
+
+        value.match_indices('-').find_map(|(hyphen, _)| {
+            let start = bound(&value[..hyphen])?;
+            let end = bound(&value[hyphen + 1..])?;
+            match start.is_some() || end.is_some() {
+                true  => Some(DicomRange { start, end }),
+                false => None
+            }
+        })
+    }
+
+    // Values fully inside the range, "2020" covering every date of the year.
+    pub fn contains(&self, value: &T) -> bool {
+        let (first, last) = value.bounds();
+        self.start.is_none_or(|start| start.bounds().0 <= first) && self.end.is_none_or(|end| last <= end.bounds().1)
+    }
+}
+
+// Empty for an open end, None when the string is not a value.
+fn bound<T: Temporal>(value: &str) -> Option<Option<T>> {
+    match value.is_empty() {
+        true  => Some(None),
+        false => T::parse(value).map(Some)
+    }
+}
+
+impl Temporal for DicomDate {
+    const VR: u16 = vr_type::DATE;
+
+    fn parse(value: &str) -> Option<DicomDate> {
+        DicomDate::parse(value)
+    }
+
+    fn bounds(&self) -> (i64, i64) {
+        (self.first_day() * MICROS_PER_DAY, (self.last_day() + 1) * MICROS_PER_DAY - 1)
+    }
+}
+
+impl Temporal for DicomTime {
+    const VR: u16 = vr_type::TIME;
+
+    fn parse(value: &str) -> Option<DicomTime> {
+        DicomTime::parse(value)
+    }
+
+    fn bounds(&self) -> (i64, i64) {
+        self.span()
+    }
+}
+
+impl Temporal for DicomDateTime {
+    const VR: u16 = vr_type::DATE_TIME;
+
+    fn parse(value: &str) -> Option<DicomDateTime> {
+        DicomDateTime::parse(value)
+    }
+
+    fn bounds(&self) -> (i64, i64) {
+        let (first, last) = match self.time {
+            Some(time) => {
+                let midnight = self.date.first_day() * MICROS_PER_DAY;
+                let (start, end) = time.span();
+                (midnight + start, midnight + end)
+            },
+            None       => self.date.bounds()
+        };
+
+        let offset = i64::from(self.offset.unwrap_or(0)) * MICROS_PER_MINUTE;
+        (first - offset, last - offset)
+    }
+}
+
+// Values are formatted back to DICOM with the precision they were read with.
+impl fmt::Display for DicomDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DicomTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}", self.hour)?;
+        if let Some(minute) = self.minute {
+            write!(f, "{:02}", minute)?;
+        }
+        if let Some(second) = self.second {
+            write!(f, "{:02}", second)?;
+        }
+        if let Some(micros) = self.micros {
+            let digits = usize::from(self.fraction_digits);
+            write!(f, ".{:0width$}", micros / 10u32.pow(6 - digits as u32), width = digits)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DicomDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.date)?;
+        if let Some(time) = self.time {
+            write!(f, "{}", time)?;
+        }
+        if let Some(offset) = self.offset {
+            let sign = if offset < 0 { '-' } else { '+' };
+            write!(f, "{}{:02}{:02}", sign, offset.abs() / 60, offset.abs() % 60)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Temporal> fmt::Display for DicomRange<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.start, &self.end) {
+            (Some(start), Some(end)) if start == end => write!(f, "{}", start),
+            (start, end)                             => {
+                if let Some(start) = start {
+                    write!(f, "{}", start)?;
+                }
+                write!(f, "-")?;
+                if let Some(end) = end {
+                    write!(f, "{}", end)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_conversions {
+    use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveTime, TimeZone, Timelike};
+
+    use super::{DicomDate, DicomDateTime, DicomTime};
+
+    impl DicomDate {
+        // First day covered, None for years chrono does not represent.
+        pub fn to_naive_date(&self) -> Option<NaiveDate> {
+            NaiveDate::from_ymd_opt(i32::from(self.year), u32::from(self.month.unwrap_or(1)), u32::from(self.day.unwrap_or(1)))
+        }
+    }
+
+    impl DicomTime {
+        // Start of the time, a leap second being the 59th second past its first million microseconds as in chrono.
+        pub fn to_naive_time(&self) -> Option<NaiveTime> {
+            let (second, micros) = match self.second.unwrap_or(0) {
+                60     => (59, self.micros.unwrap_or(0) + 1_000_000),
+                second => (second, self.micros.unwrap_or(0))
+            };
+            NaiveTime::from_hms_micro_opt(u32::from(self.hour), u32::from(self.minute.unwrap_or(0)), u32::from(second), micros)
+        }
+    }
+
+    impl DicomDateTime {
+        // Start of the date time, taken as UTC when it has no offset.
+        pub fn to_chrono(&self) -> Option<DateTime<FixedOffset>> {
+            let time = match self.time {
+                Some(time) => time.to_naive_time()?,
+                None       => NaiveTime::from_hms_opt(0, 0, 0)?
+            };
+            let offset = FixedOffset::east_opt(i32::from(self.offset.unwrap_or(0)) * 60)?;
+            offset.from_local_datetime(&self.date.to_naive_date()?.and_time(time)).single()
+        }
+    }
+
+    impl From<NaiveDate> for DicomDate {
+        fn from(date: NaiveDate) -> DicomDate {
+            DicomDate { year: date.year() as u16, month: Some(date.month() as u8), day: Some(date.day() as u8) }
+        }
+    }
+
+    // Seconds always, the fraction only when there is one.
+    impl From<NaiveTime> for DicomTime {
+        fn from(time: NaiveTime) -> DicomTime {
+            let (second, micros) = match time.nanosecond() / 1_000 {
+                micros if micros >= 1_000_000 => (60, micros - 1_000_000),
+                micros                        => (time.second() as u8, micros)
+            };
+            DicomTime {
+                hour: time.hour() as u8,
+                minute: Some(time.minute() as u8),
+                second: Some(second),
+                micros: if micros > 0 { Some(micros) } else { None },
+                fraction_digits: if micros > 0 { 6 } else { 0 }
+            }
+        }
+    }
+
+    impl From<DateTime<FixedOffset>> for DicomDateTime {
+        fn from(date_time: DateTime<FixedOffset>) -> DicomDateTime {
+            DicomDateTime {
+                date: date_time.date_naive().into(),
+                time: Some(date_time.time().into()),
+                offset: Some((date_time.offset().local_minus_utc() / 60) as i16)
+            }
+        }
+    }
+}
+
+// Fixed width run of ASCII digits, without the sign parse would accept.
+fn number(digits: &str) -> Option<u32> {
+    match !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        true  => digits.parse().ok(),
+        false => None
+    }
+}
+
+// &ZZXX, a sign then hours and minutes.
+fn utc_offset(value: &str) -> Option<i16> {
+    let minutes = (number(&value[1..3])? * 60 + number(&value[3..])?) as i16;
+    let offset = if value.starts_with('-') { -minutes } else { minutes };
+
+    match number(&value[3..])? < 60 && (MIN_OFFSET..=MAX_OFFSET).contains(&offset) {
+        true  => Some(offset),
+        false => None
+    }
+}
+
+// Days from 1970-01-01 to a date of the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn system_time(micros: i64) -> Option<SystemTime> {
+    match micros >= 0 {
+        true  => UNIX_EPOCH.checked_add(Duration::from_micros(micros as u64)),
+        false => UNIX_EPOCH.checked_sub(Duration::from_micros(micros.unsigned_abs()))
+    }
+}
@@ -0,0 +1,105 @@
+use crate::dicom_tag::VrValue;
+use crate::vr_type;
+use crate::transfer_syntax::EndianEncoding;
+
+// Decoding of values from their raw bytes, shared by every parser front end.
+// Values whose length is not a multiple of the size of one value are kept as raw bytes, along with their VR.
+pub fn decode_value(vr_code: u16, bytes: Vec<u8>, endian: EndianEncoding) -> VrValue {
+    if !bytes.len().is_multiple_of(value_size(vr_code)) {
+        return VrValue::Raw { vr_code, bytes };
+    }
+
+    match vr_code {
+        vr_type::DELIMITER           => VrValue::Delimiter,
+        vr_type::SEQUENCE_OF_ITEMS   => VrValue::SequenceOfItems,
+        vr_type::ATTRIBUTE           => VrValue::Attribute(attributes(&bytes, endian)),
+
+        vr_type::UNSIGNED_SHORT      => VrValue::UnsignedShort(vm_16(&bytes, endian.convert(u16::from_le_bytes, u16::from_be_bytes))),
+        vr_type::SIGNED_SHORT        => VrValue::SignedShort(vm_16(&bytes, endian.convert(i16::from_le_bytes, i16::from_be_bytes))),
+        vr_type::UNSIGNED_LONG       => VrValue::UnsignedLong(vm_32(&bytes, endian.convert(u32::from_le_bytes, u32::from_be_bytes))),
+        vr_type::SIGNED_LONG         => VrValue::SignedLong(vm_32(&bytes, endian.convert(i32::from_le_bytes, i32::from_be_bytes))),
+        vr_type::FLOAT               => VrValue::Float(vm_32(&bytes, endian.convert(f32::from_le_bytes, f32::from_be_bytes))),
+        vr_type::DOUBLE              => VrValue::Double(vm_64(&bytes, endian.convert(f64::from_le_bytes, f64::from_be_bytes))),
+        vr_type::SIGNED_VERY_LONG    => VrValue::SignedVeryLong(vm_64(&bytes, endian.convert(i64::from_le_bytes, i64::from_be_bytes))),
+        vr_type::UNSIGNED_VERY_LONG  => VrValue::UnsignedVeryLong(vm_64(&bytes, endian.convert(u64::from_le_bytes, u64::from_be_bytes))),
+
+        vr_type::APPLICATION_ENTITY  => VrValue::ApplicationEntity(strings(&bytes)),
+        vr_type::AGE_STRING          => VrValue::AgeString(strings(&bytes)),
+        vr_type::CODE_STRING         => VrValue::CodeString(strings(&bytes)),
+        vr_type::LONG_TEXT           => VrValue::LongText(string(&bytes)),
+        vr_type::PERSON_NAME         => VrValue::PersonName(strings(&bytes)),
+        vr_type::SHORT_STRING        => VrValue::ShortString(strings(&bytes)),
+        vr_type::SHORT_TEXT          => VrValue::ShortText(string(&bytes)),
+        vr_type::UNLIMITED_TEXT      => VrValue::UnlimitedText(string(&bytes)),
+        vr_type::UNLIMITED_CHARACTERS => VrValue::UnlimitedCharacters(strings(&bytes)),
+        vr_type::UNIVERSAL_RESOURCE  => VrValue::UniversalResource(string(&bytes)),
+
+        vr_type::DATE                => VrValue::Date(strings(&bytes)),
+        vr_type::DATE_TIME           => VrValue::DateTime(strings(&bytes)),
+        vr_type::TIME                => VrValue::Time(strings(&bytes)),
+        vr_type::DECIMAL_STRING      => VrValue::DecimalString(strings(&bytes)),
+        vr_type::INTEGER_STRING      => VrValue::IntegerString(strings(&bytes)),
+        vr_type::LONG_STRING         => VrValue::LongString(strings(&bytes)),
+        vr_type::UID                 => VrValue::Uid(strings(&bytes)),
+
+        vr_type::OTHER_BYTE          => VrValue::OtherByte(bytes),
+        vr_type::OTHER_FLOAT         => VrValue::OtherFloat(other(bytes, 4, endian)),
+        vr_type::OTHER_WORD          => VrValue::OtherWord(other(bytes, 2, endian)),
+        vr_type::OTHER_DOUBLE        => VrValue::OtherDouble(vm_64(&bytes, endian.convert(f64::from_le_bytes, f64::from_be_bytes))),
+        vr_type::OTHER_LONG          => VrValue::OtherLong(vm_32(&bytes, endian.convert(u32::from_le_bytes, u32::from_be_bytes))),
+        vr_type::OTHER_VERY_LONG     => VrValue::OtherVeryLong(vm_64(&bytes, endian.convert(u64::from_le_bytes, u64::from_be_bytes))),
+        vr_type::UNKNOWN             => VrValue::Unknown(bytes),
+        _                            => VrValue::Unknown(bytes)
+    }
+}
+
+// Size of one value, lengths are a multiple of it.
+pub fn value_size(vr_code: u16) -> usize {
+    match vr_code {
+        vr_type::UNSIGNED_SHORT | vr_type::SIGNED_SHORT                      => 2,
+        vr_type::UNSIGNED_LONG | vr_type::SIGNED_LONG | vr_type::FLOAT       => 4,
+        vr_type::ATTRIBUTE                                                   => 4,
+        vr_type::DOUBLE | vr_type::OTHER_DOUBLE                              => 8,
+        vr_type::SIGNED_VERY_LONG | vr_type::UNSIGNED_VERY_LONG              => 8,
+        vr_type::OTHER_VERY_LONG                                             => 8,
+        vr_type::OTHER_LONG                                                  => 4,
+        _                                                                    => 1
+    }
+}
+
+fn vm_16<T>(bytes: &[u8], convert: fn([u8; 2]) -> T) -> Vec<T> {
+    bytes.chunks_exact(2).map(|b| convert([b[0], b[1]])).collect()
+}
+
+fn vm_32<T>(bytes: &[u8], convert: fn([u8; 4]) -> T) -> Vec<T> {
+    bytes.chunks_exact(4).map(|b| convert([b[0], b[1], b[2], b[3]])).collect()
+}
+
+fn vm_64<T>(bytes: &[u8], convert: fn([u8; 8]) -> T) -> Vec<T> {
+    bytes.chunks_exact(8).map(|b| convert([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]])).collect()
+}
+
+fn attributes(bytes: &[u8], endian: EndianEncoding) -> Vec<(u16, u16)> {
+    let words = vm_16(bytes, endian.convert(u16::from_le_bytes, u16::from_be_bytes));
+    words.chunks_exact(2).map(|w| (w[0], w[1])).collect()
+}
+
+fn string(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+fn strings(bytes: &[u8]) -> Vec<String> {
+    match bytes.is_empty() {
+        true  => Vec::new(),
+        false => string(bytes).split('\\').map(String::from).collect()
+    }
+}
+
+// OW/OF style values are kept as raw bytes in little endian order whatever the source encoding.
+fn other(mut bytes: Vec<u8>, word_size: usize, endian: EndianEncoding) -> Vec<u8> {
+    if let EndianEncoding::BigEndian = endian {
+        bytes.chunks_exact_mut(word_size).for_each(|word| word.reverse());
+    }
+
+    bytes
+}
@@ -1,140 +1,556 @@
 use std::convert::TryFrom;
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{self, Cursor, ErrorKind, Read, Seek, SeekFrom};
+
+use flate2::read::DeflateDecoder;
 
 use crate::binary_reader::*;
 use crate::dicom_reader::DicomReader;
+use crate::decoder;
+use crate::stream_reader::StreamReader;
+use crate::tag_iter::TagIter;
 use crate::dicom_handlers::*;
 use crate::dicom_tag::*;
+use crate::dicom_error::{DicomError, DicomResult};
+use crate::encapsulated::{EncapsulatedPixelData, Fragment};
 use crate::vr_type;
 use crate::tags;
-use crate::transfer_syntax::{VrEncoding, EndianEncoding, TransferSyntax};
+use crate::transfer_syntax::{self, VrEncoding, EndianEncoding, Compression, TransferSyntax};
 
-const STANDARD_PREAMBLE: &str = "DICM";
+pub(crate) const STANDARD_PREAMBLE: &str = "DICM";
+pub const PREAMBLE_LENGTH: usize = 128;
+const FILE_META_GROUP: u16 = 0x0002;
 
-fn endian_reader<T: Read+Seek>(reader: &mut T, syntax: TransferSyntax) -> &mut T {
-    // TODO: handle big endian reading, for now assuming little endian by default.
-    match syntax.endian_encoding {
-        EndianEncoding::LittleEndian => reader,
-        EndianEncoding::BigEndian    => reader
-    }
+// Kind of node holding the tags being parsed, it determines which tags may appear and how the node ends.
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Container {
+    Dataset,
+    Sequence,
+    Item
 }
 
-fn next_tag(reader: &mut (impl Read + Seek), syntax: TransferSyntax) -> DicomTag {
+// State carried across tags while parsing a dataset.
+#[derive(Default)]
+pub(crate) struct ParseState {
+    // Offsets in an inflated dataset do not point into the source, values cannot be deferred.
+    pub(crate) inflated: bool,
+    // Values of a stream are behind the reader once skipped, they cannot be read back.
+    pub(crate) streamed: bool,
+    extended_offset_table: Option<Vec<u64>>,
+    extended_offset_table_lengths: Option<Vec<u64>>
+}
 
-    let endian_reader = endian_reader(reader, syntax);
+impl ParseState {
+    pub(crate) fn streamed() -> ParseState {
+        ParseState { streamed: true, ..ParseState::default() }
+    }
+}
 
-    let group = endian_reader.read_u16();
-    let element = endian_reader.read_u16();
+// Reads the tag, VR and length of the element at offset, where the reader is, leaving it at the start of its value.
+pub(crate) fn read_header(reader: &mut (impl Read + Seek), offset: u64, syntax: TransferSyntax) -> DicomResult<TagHeader> {
+    let endian = syntax.endian_encoding;
 
-    let vr_code = endian_reader.read_vr_code(group, element, syntax.vr_encoding);
+    let group = reader.read_u16(endian).map_err(DicomError::io(offset, None))?;
+    let element = reader.read_u16(endian).map_err(DicomError::io(offset, None))?;
+    let tag = (group, element);
+
+    let vr_code = reader.read_vr_code(group, element, syntax.vr_encoding).map_err(DicomError::io(offset, Some(tag)))?;
 
     let test_length = match syntax.vr_encoding {
-        VrEncoding::Implicit => endian_reader.read_i32(),
-        VrEncoding::Explicit => vr_type::get_explicit_vr(vr_code, endian_reader)
-    };
+        VrEncoding::Implicit => reader.read_u32(endian),
+        VrEncoding::Explicit if vr_type::is_valid_code(vr_code) => vr_type::get_explicit_vr(vr_code, reader, endian),
+        VrEncoding::Explicit => return Err(DicomError::InvalidVr { offset, tag, vr_code })
+    }.map_err(DicomError::io(offset, Some(tag)))?;
 
-    let value_length = match test_length > 0 {
-        true  => usize::try_from(test_length).unwrap(),
-        false => 0
-    };
+    let value_length = usize::try_from(test_length).map_err(|_| DicomError::BadLength { offset, tag, length: test_length })?;
+    let header = TagHeader { group, element, vr_code, value_length, offset };
 
-    let tag_value = match vr_code {
-        vr_type::DELIMITER           => VrValue::Delimiter,
-        vr_type::SEQUENCE_OF_ITEMS   => VrValue::SequenceOfItems,
-        vr_type::ATTRIBUTE           => VrValue::Attribute(endian_reader.read_u16(), endian_reader.read_u16()),
-
-        vr_type::UNSIGNED_SHORT      => VrValue::UnsignedShort(endian_reader.read_vm_16(value_length, u16::from_ne_bytes)),
-        vr_type::SIGNED_SHORT        => VrValue::SignedShort(endian_reader.read_vm_16(value_length, i16::from_ne_bytes)),
-        vr_type::UNSIGNED_LONG       => VrValue::UnsignedLong(endian_reader.read_vm_32(value_length, u32::from_ne_bytes)),
-        vr_type::SIGNED_LONG         => VrValue::SignedLong(endian_reader.read_vm_32(value_length, i32::from_ne_bytes)),
-        vr_type::FLOAT               => VrValue::Float(endian_reader.read_vm_32(value_length, f32::from_ne_bytes)),
-        vr_type::DOUBLE              => VrValue::Double(endian_reader.read_vm_64(value_length, f64::from_ne_bytes)),
-       
-        vr_type::APPLICATION_ENTITY  => VrValue::ApplicationEntity(endian_reader.read_string(value_length)),
-        vr_type::AGE_STRING          => VrValue::AgeString(endian_reader.read_string(value_length)),
-        vr_type::CODE_STRING         => VrValue::CodeString(endian_reader.read_string(value_length)),
-        vr_type::LONG_TEXT           => VrValue::LongText(endian_reader.read_string(value_length)),
-        vr_type::PERSON_NAME         => VrValue::PersonName(endian_reader.read_string(value_length)),
-        vr_type::SHORT_STRING        => VrValue::ShortString(endian_reader.read_string(value_length)),
-        vr_type::SHORT_TEXT          => VrValue::ShortText(endian_reader.read_string(value_length)),
-        vr_type::UNLIMITED_TEXT      => VrValue::UnlimitedText(endian_reader.read_string(value_length)),
-
-        vr_type::DATE                => VrValue::Date(endian_reader.read_string(value_length)),
-        vr_type::DATE_TIME           => VrValue::DateTime(endian_reader.read_string(value_length)),
-        vr_type::TIME                => VrValue::Time(endian_reader.read_string(value_length)),
-        vr_type::DECIMAL_STRING      => VrValue::DecimalString(endian_reader.read_string(value_length)),
-        vr_type::INTEGER_STRING      => VrValue::IntegerString(endian_reader.read_string(value_length)),
-        vr_type::LONG_STRING         => VrValue::LongString(endian_reader.read_string(value_length)),
-        vr_type::UID                 => VrValue::Uid(endian_reader.read_string(value_length)),
-
-        vr_type::OTHER_BYTE          => VrValue::OtherByte(endian_reader.read_bytes(value_length)),
-        vr_type::OTHER_FLOAT         => VrValue::OtherFloat(endian_reader.read_bytes(value_length)),
-        vr_type::OTHER_WORD          => VrValue::OtherWord(endian_reader.read_bytes(value_length)),
-        vr_type::UNKNOWN             => VrValue::Unknown(endian_reader.read_bytes(value_length)),
-        _                            => VrValue::Unknown(endian_reader.read_bytes(value_length))
+    // Only sequences, UN values parsed as sequences, items and encapsulated pixel data may be delimited instead of declaring their length.
+    if value_length == UNDEFINED_LENGTH && !header.is_encapsulated() && !header.is_sequence() && vr_code != vr_type::DELIMITER {
+        return Err(DicomError::BadLength { offset, tag, length: test_length });
+    }
+
+    Ok(header)
+}
+
+// Loads a value deferred while parsing from the source it was parsed from, None when the value was not deferred.
+// Only encapsulated pixel data is deferred with an undefined length, its fragments are read up to the sequence delimiter.
+pub fn load_value(reader: &mut (impl Read + Seek), tag: &DicomTag) -> DicomResult<Option<VrValue>> {
+    let (vr_code, value_offset, length) = match tag.value {
+        VrValue::Deferred { vr_code, offset, length } => (vr_code, offset, length),
+        _                                             => return Ok(None)
     };
+    let tag_id = (tag.group, tag.element);
+
+    reader.seek(SeekFrom::Start(value_offset)).map_err(DicomError::io(tag.offset, Some(tag_id)))?;
+
+    if tag.has_undefined_length() {
+        return read_encapsulated(reader, value_offset, tag.syntax.endian_encoding).map(|pixel_data| Some(VrValue::Encapsulated(pixel_data)));
+    }
+
+    read_value(reader, vr_code, length, tag.syntax.endian_encoding).map(Some).map_err(|err| match err.kind() {
+        ErrorKind::UnexpectedEof => DicomError::TruncatedValue { offset: tag.offset, tag: tag_id, length },
+        _                        => DicomError::Io { offset: tag.offset, tag: Some(tag_id), source: err }
+    })
+}
 
-    DicomTag {
-        group: group,
-        element: element,
-        syntax: syntax,
+// Reads the value following a header read with read_header.
+pub(crate) fn read_tag(reader: &mut (impl Read + Seek), header: TagHeader, syntax: TransferSyntax) -> DicomResult<DicomTag> {
+    let TagHeader { group, element, vr_code, value_length, offset } = header;
+    let tag = header.tag();
+    let endian = syntax.endian_encoding;
+
+    if header.is_encapsulated() {
+        let value_pos = reader.pos().map_err(DicomError::io(offset, Some(tag)))?;
+        let value = VrValue::Encapsulated(read_encapsulated(reader, value_pos, endian)?);
+        return Ok(DicomTag { group, element, syntax, value, value_length, offset });
+    }
+
+    let tag_value = match tag {
+        tags::ITEM                => Ok(VrValue::Item),
+        _ if header.is_sequence() => Ok(VrValue::SequenceOfItems),
+        _                         => read_value(reader, vr_code, value_length, endian)
+    }.map_err(|err| match err.kind() {
+        ErrorKind::UnexpectedEof => DicomError::TruncatedValue { offset, tag, length: value_length },
+        _                        => DicomError::Io { offset, tag: Some(tag), source: err }
+    })?;
+
+    Ok(DicomTag {
+        group,
+        element,
+        syntax,
         value: tag_value,
-        value_length: value_length
+        value_length,
+        offset
+    })
+}
+
+fn next_tag(reader: &mut (impl Read + Seek), offset: u64, syntax: TransferSyntax) -> DicomResult<DicomTag> {
+    let header = read_header(reader, offset, syntax)?;
+    read_tag(reader, header, syntax)
+}
+
+// Seeks past the value of a header, sequences, items and encapsulated pixel data included.
+// Nodes of undefined length have to be scanned for their delimitation item, only headers are read on the way.
+pub(crate) fn skip_value(reader: &mut (impl Read + Seek), header: TagHeader, syntax: TransferSyntax) -> DicomResult<()> {
+    match header.has_undefined_length() {
+        true  => skip_to_delimiter(reader, &header, header.items_syntax(syntax)),
+        false => reader.seek(SeekFrom::Current(header.value_length as i64)).map(|_| ()).map_err(DicomError::io(header.offset, Some(header.tag())))
     }
 }
 
-fn parse_tags(reader: &mut (impl Read + Seek), parent_index: usize, syntax: TransferSyntax, limit_pos: u64, dicom_handler: &mut impl DicomHandler) {
+// Skips to the end of the delimiter closing the node the reader is in, nested undefined length nodes included.
+// Their syntaxes are stacked, the items of an explicit UN sequence being read with implicit VR.
+// Errors locating a header are reported at the header read before it, the one of the node at first.
+fn skip_to_delimiter(reader: &mut (impl Read + Seek), node: &TagHeader, syntax: TransferSyntax) -> DicomResult<()> {
+    let mut syntaxes = vec![syntax];
+    let mut last = (node.offset, node.tag());
 
-    let tag_syntax = reader.peek_syntax(syntax);
+    while let Some(syntax) = syntaxes.last().copied() {
+        let offset = reader.pos().map_err(DicomError::io(last.0, Some(last.1)))?;
+        let header = read_header(reader, offset, syntax)?;
+        last = (header.offset, header.tag());
 
-    let tag = next_tag(reader, tag_syntax);
-    let value_length = tag.value_length;
+        match (header.tag(), header.has_undefined_length()) {
+            (tags::SEQUENCE_DELIMITER, _) | (tags::ITEM_DELIMITER, _) => { syntaxes.pop(); },
+            (_, true)                                                 => syntaxes.push(header.items_syntax(syntax)),
+            (_, false)                                                => skip_value(reader, header, syntax)?
+        }
+    }
 
-    let is_sequence = match tag.value {
-        VrValue::SequenceOfItems => true,
-        _                        => false
-    };
-    
-    let not_a_sequence_delimiter = match (tag.group, tag.element) {
-        tags::SEQUENCE_DELIMITER => false,
-        _                        => true
+    Ok(())
+}
+
+// Encapsulated pixel data is a sequence of items, the first one holding the Basic Offset Table
+// and the rest the fragments of compressed frames, closed by a sequence delimiter. The reader is at value_pos,
+// the offsets of the items follow from their lengths.
+pub(crate) fn read_encapsulated(reader: &mut (impl Read + Seek), value_pos: u64, endian: EndianEncoding) -> DicomResult<EncapsulatedPixelData> {
+    let mut basic_offset_table = None;
+    let mut fragments = Vec::new();
+    let mut offset = value_pos;
+
+    loop {
+        let group = reader.read_u16(endian).map_err(DicomError::io(offset, Some(tags::PIXEL_DATA)))?;
+        let element = reader.read_u16(endian).map_err(DicomError::io(offset, Some(tags::PIXEL_DATA)))?;
+        let length = reader.read_u32(endian).map_err(DicomError::io(offset, Some(tags::PIXEL_DATA)))?;
+        let tag = (group, element);
+
+        let item_length = match (tag, usize::try_from(length)) {
+            (tags::SEQUENCE_DELIMITER, _)                => break,
+            (tags::ITEM, Ok(l)) if l != UNDEFINED_LENGTH => l,
+            (tags::ITEM, _)                              => return Err(DicomError::BadLength { offset, tag, length }),
+            (_, _)                                       => return Err(DicomError::UnexpectedElement { offset, tag })
+        };
+
+        let data_offset = offset + 8;
+        let data = reader.read_bytes(item_length).map_err(|err| match err.kind() {
+            ErrorKind::UnexpectedEof => DicomError::TruncatedValue { offset, tag, length: item_length },
+            _                        => DicomError::Io { offset, tag: Some(tag), source: err }
+        })?;
+
+        let convert: fn([u8; 4]) -> u32 = endian.convert(u32::from_le_bytes, u32::from_be_bytes);
+
+        match basic_offset_table {
+            None if !data.len().is_multiple_of(4) => return Err(DicomError::BadLength { offset, tag, length }),
+            None                                  => basic_offset_table = Some(data.chunks_exact(4).map(|b| convert([b[0], b[1], b[2], b[3]])).collect()),
+            Some(_)                               => fragments.push(Fragment { offset: data_offset, data })
+        }
+
+        offset = data_offset + item_length as u64;
+    }
+
+    Ok(EncapsulatedPixelData::new(basic_offset_table.unwrap_or_default(), fragments))
+}
+
+// Sequences hold no value of their own, their items follow as separate tags.
+fn read_value(reader: &mut (impl Read + Seek), vr_code: u16, value_length: usize, endian: EndianEncoding) -> io::Result<VrValue> {
+    let bytes = match vr_code {
+        vr_type::SEQUENCE_OF_ITEMS | vr_type::DELIMITER => Vec::new(),
+        _                                               => reader.read_bytes(value_length)?
     };
 
-    let child_syntax = match tag.try_transfer_syntax() {
-        Some(s) => s,
-        None    => syntax
+    Ok(decoder::decode_value(vr_code, bytes, endian))
+}
+
+// Sequence or item whose tags are being parsed, with the position where it ends when its length is defined
+// and the syntax of its tags.
+#[derive(Copy, Clone)]
+pub(crate) struct OpenNode {
+    pub(crate) container: Container,
+    pub(crate) limit_pos: Option<u64>,
+    pub(crate) syntax: TransferSyntax
+}
+
+// Checks a tag may appear in the node it was read in, true when it is the delimiter closing the node.
+pub(crate) fn check_placement(header: &TagHeader, node: OpenNode) -> DicomResult<bool> {
+    let tag_id = header.tag();
+    let offset = header.offset;
+    let is_item = tag_id == tags::ITEM;
+    let is_delimiter = tag_id == tags::SEQUENCE_DELIMITER || tag_id == tags::ITEM_DELIMITER;
+
+    match (tag_id, node.container, node.limit_pos) {
+        (tags::SEQUENCE_DELIMITER, Container::Sequence, None) => Ok(true),
+        (tags::ITEM_DELIMITER, Container::Item, None)         => Ok(true),
+        (tags::ITEM, Container::Sequence, _)                  => Ok(false),
+        (_, _, _) if is_delimiter || is_item                  => Err(DicomError::UnexpectedDelimiter { offset, tag: tag_id }),
+        (_, Container::Sequence, _)                           => Err(DicomError::UnexpectedElement { offset, tag: tag_id }),
+        (_, _, _)                                             => Ok(false)
+    }
+}
+
+// Values can be deferred unless they are nodes, read from a stream or from an inflated copy of the source. Encapsulated pixel data
+// is the only delimited value that can, its byte range ends with the sequence delimiter.
+pub(crate) fn can_defer(header: &TagHeader, state: &ParseState) -> bool {
+    let is_node = header.is_sequence() || header.tag() == tags::ITEM;
+    !state.inflated && !state.streamed && !is_node && (!header.has_undefined_length() || header.is_encapsulated())
+}
+
+// The Extended Offset Table precedes pixel data, keep it to locate the encapsulated frames.
+pub(crate) fn keep_offset_tables(tag: &mut DicomTag, state: &mut ParseState) {
+    match ((tag.group, tag.element), &mut tag.value) {
+        (tags::EXTENDED_OFFSET_TABLE, VrValue::OtherVeryLong(table))         => state.extended_offset_table = Some(table.clone()),
+        (tags::EXTENDED_OFFSET_TABLE_LENGTHS, VrValue::OtherVeryLong(table)) => state.extended_offset_table_lengths = Some(table.clone()),
+        (_, VrValue::Encapsulated(pixel_data))                               => {
+            pixel_data.extended_offset_table = state.extended_offset_table.take();
+            pixel_data.extended_offset_table_lengths = state.extended_offset_table_lengths.take();
+        },
+        (_, _)                                                               => ()
+    }
+}
+
+// Node opened by a sequence or item tag for its children to be parsed into, value_pos being where its value starts
+// and syntax the one of its children.
+pub(crate) fn child_node(tag: &DicomTag, value_pos: u64, syntax: TransferSyntax) -> Option<OpenNode> {
+    let limit_pos = match tag.value_length {
+        UNDEFINED_LENGTH => None,
+        length           => Some(value_pos + length as u64)
     };
 
-    let child_index = dicom_handler.handle_tag(parent_index, tag);
-    let stream_pos = reader.pos();
+    match tag.value {
+        VrValue::Item             => Some(OpenNode { container: Container::Item, limit_pos, syntax }),
+        VrValue::SequenceOfItems  => Some(OpenNode { container: Container::Sequence, limit_pos, syntax }),
+        _                         => None
+    }
+}
+
+// Reports a tag read in full, returning the node it opens, if any.
+pub(crate) fn open_node(tag: DicomTag, value_pos: u64, syntax: TransferSyntax, dicom_handler: &mut impl DicomHandler) -> Option<OpenNode> {
+    let child = child_node(&tag, value_pos, syntax);
 
-    if stream_pos < limit_pos && not_a_sequence_delimiter {
-        let next_limit = match (is_sequence, value_length > 0) {
-            (true, false) => Some(reader.len()),
-            (true, true)  => Some(stream_pos + u64::try_from(value_length).unwrap()),
-            (_, _)        => None
-        };
-    
-        if let Some(l) = next_limit {            
-            parse_tags(reader, child_index, child_syntax, l, dicom_handler);
+    match child.map(|node| node.container) {
+        Some(Container::Item)     => dicom_handler.start_item(tag),
+        Some(Container::Sequence) => dicom_handler.start_sequence(tag),
+        _                         => dicom_handler.element(tag)
+    }
+
+    child
+}
+
+// Parses tags in a loop, keeping open sequences and items on an explicit stack so that neither the number
+// of elements nor their nesting depth grows the call stack.
+// Without a limit the dataset ends with the reader.
+// Errors locating a tag are reported at the one read before it, at dataset_pos before the first.
+fn parse_tags(reader: &mut (impl Read + Seek), syntax: TransferSyntax, dataset_pos: u64, limit_pos: Option<u64>, state: &mut ParseState, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+
+    let mut open_nodes = vec![OpenNode { container: Container::Dataset, limit_pos, syntax }];
+    let mut last = (dataset_pos, None);
+
+    while let Some(node) = open_nodes.last().copied() {
+        let start_pos = reader.pos().map_err(DicomError::io(last.0, last.1))?;
+
+        // Defined length nodes end at their limit, undefined length ones at their delimitation item.
+        let is_closed = match (node.container, node.limit_pos) {
+            (_, Some(limit))          => start_pos >= limit,
+            (Container::Dataset, None) => reader.is_at_end().map_err(DicomError::io(start_pos, None))?,
+            (_, None)                 => false
         };
 
-        parse_tags(reader, parent_index, child_syntax, limit_pos, dicom_handler);
+        if is_closed {
+            close_node(node.container, dicom_handler);
+            open_nodes.pop();
+            continue;
+        }
+
+        let syntax = node.syntax;
+        let header = read_header(reader, start_pos, syntax)?;
+        last = (header.offset, Some(header.tag()));
+
+        if check_placement(&header, node)? {
+            close_node(node.container, dicom_handler);
+            open_nodes.pop();
+            continue;
+        }
+
+        match dicom_handler.tag_header(&header) {
+            ParseAction::Continue     => (),
+            ParseAction::SkipValue    => {
+                skip_value(reader, header, syntax)?;
+                continue;
+            },
+            ParseAction::SkipSequence => {
+                skip_value(reader, header, syntax)?;
+                skip_sequence(reader, &header, &mut open_nodes, dicom_handler)?;
+                continue;
+            },
+            ParseAction::DeferValue if can_defer(&header, state) => {
+                let value_offset = reader.pos().map_err(DicomError::io(header.offset, Some(header.tag())))?;
+                skip_value(reader, header, syntax)?;
+                let end_pos = reader.pos().map_err(DicomError::io(header.offset, Some(header.tag())))?;
+                dicom_handler.element(header.deferred(syntax, value_offset, end_pos - value_offset));
+                continue;
+            },
+            ParseAction::DeferValue   => (),
+            ParseAction::Stop         => return Ok(())
+        }
+
+        let value_pos = reader.pos().map_err(DicomError::io(header.offset, Some(header.tag())))?;
+        let mut tag = read_tag(reader, header, syntax)?;
+        keep_offset_tables(&mut tag, state);
+
+        if let Some(child) = open_node(tag, value_pos, header.items_syntax(syntax), dicom_handler) {
+            open_nodes.push(child);
+        }
+    }
+
+    dicom_handler.end_of_dataset();
+
+    Ok(())
+}
+
+// Skips to the end of the innermost open sequence, closing it and the items opened within it, from the value of header.
+// Outside any sequence there is nothing left to skip.
+pub(crate) fn skip_sequence(reader: &mut (impl Read + Seek), header: &TagHeader, open_nodes: &mut Vec<OpenNode>, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    if !open_nodes.iter().any(|n| n.container == Container::Sequence) {
+        return Ok(());
+    }
+
+    while let Some(node) = open_nodes.pop() {
+        match node.limit_pos {
+            Some(limit) => reader.seek(SeekFrom::Start(limit)).map(|_| ()).map_err(DicomError::io(limit, None))?,
+            None        => skip_to_delimiter(reader, header, node.syntax)?
+        }
+
+        close_node(node.container, dicom_handler);
+
+        if node.container == Container::Sequence {
+            return Ok(());
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn close_node(container: Container, dicom_handler: &mut impl DicomHandler) {
+    match container {
+        Container::Sequence => dicom_handler.end_sequence(),
+        Container::Item     => dicom_handler.end_item(),
+        Container::Dataset  => ()
+    }
+}
+
+// File Meta Information, group 0002, is always Explicit VR Little Endian and declares the syntax of the rest of the file.
+// None when the group is absent or does not carry a Transfer Syntax UID.
+pub fn parse_file_meta(reader: &mut (impl Read + Seek), on_tag: &mut impl FnMut(DicomTag)) -> DicomResult<Option<TransferSyntax>> {
+    let meta_pos = reader.pos().map_err(DicomError::io(0, None))?;
+    read_file_meta(reader, meta_pos, on_tag).map(|(syntax, _)| syntax)
+}
+
+// Same as parse_file_meta from meta_pos, where the reader is, also returning the position of the dataset following the group.
+fn read_file_meta(reader: &mut (impl Read + Seek), meta_pos: u64, on_tag: &mut impl FnMut(DicomTag)) -> DicomResult<(Option<TransferSyntax>, u64)> {
+    let meta_syntax = TransferSyntax::default();
+    let mut syntax = None;
+    let mut pos = meta_pos;
+
+    while in_file_meta(reader, pos)? {
+        let tag = next_tag(reader, pos, meta_syntax)?;
+        syntax = tag.try_transfer_syntax()?.or(syntax);
+        pos = reader.pos().map_err(DicomError::io(tag.offset, Some((tag.group, tag.element))))?;
+        on_tag(tag);
     }
+
+    Ok((syntax, pos))
 }
 
-pub fn parse(reader: &mut (impl Read + Seek), dicom_handler: &mut impl DicomHandler) {
-    let (preamble_length, dicm_mark_length) = (128, 4);
-    let _ = reader.seek(SeekFrom::Start(preamble_length)).unwrap();
+// True when the tag at pos, where the reader is, belongs to the File Meta Information group.
+pub(crate) fn in_file_meta(reader: &mut (impl Read + Seek), pos: u64) -> DicomResult<bool> {
+    match reader.is_at_end().map_err(DicomError::io(pos, None))? {
+        true  => Ok(false),
+        false => reader.read_rewind_u16().map(|group| group == FILE_META_GROUP).map_err(DicomError::io(pos, None))
+    }
+}
 
-    let dicm_mark = reader.read_string(dicm_mark_length);
+// Reads the preamble and checks the DICM mark following it. Files without it are rewound to the start,
+// some writers omit the preamble and begin straight with the File Meta Information or the dataset.
+pub fn read_preamble(reader: &mut (impl Read + Seek)) -> DicomResult<Option<[u8; PREAMBLE_LENGTH]>> {
+    let mut head = Vec::with_capacity(PREAMBLE_LENGTH + STANDARD_PREAMBLE.len());
 
-    if dicm_mark != STANDARD_PREAMBLE {
-        let _ = reader.seek(SeekFrom::Start(0)).unwrap();
+    reader.seek(SeekFrom::Start(0)).map_err(DicomError::io(0, None))?;
+    reader.take(head.capacity() as u64).read_to_end(&mut head).map_err(|err| DicomError::Io { offset: head.len() as u64, tag: None, source: err })?;
+
+    match head.get(PREAMBLE_LENGTH..) {
+        Some(mark) if mark == STANDARD_PREAMBLE.as_bytes() => {
+            let mut preamble = [0; PREAMBLE_LENGTH];
+            preamble.copy_from_slice(&head[..PREAMBLE_LENGTH]);
+            Ok(Some(preamble))
+        },
+        _                                                  => {
+            reader.seek(SeekFrom::Start(0)).map_err(DicomError::io(0, None))?;
+            Ok(None)
+        }
     }
+}
+
+// Deflated datasets cannot be seeked, inflate them into memory and parse from there.
+// Offsets of tags and errors are then relative to the start of the inflated dataset, the reader being at offset.
+pub(crate) fn inflate(reader: &mut (impl Read + Seek), offset: u64) -> DicomResult<Cursor<Vec<u8>>> {
+    let mut inflated = Vec::new();
+
+    DeflateDecoder::new(reader).read_to_end(&mut inflated).map_err(DicomError::io(offset, None))?;
+
+    Ok(Cursor::new(inflated))
+}
+
+// Guesses the syntax of a dataset from the bytes of its first element. An upper case VR code after the tag means
+// explicit VR, in which case a group number that reads smaller as big endian means big endian. Anything else is
+// taken for Implicit VR Little Endian, the default syntax of raw datasets. The reader is left where it was.
+pub fn detect_syntax(reader: &mut (impl Read + Seek)) -> DicomResult<TransferSyntax> {
+    let pos = reader.pos().map_err(DicomError::io(0, None))?;
+    syntax_at(reader, pos)
+}
+
+// Same as detect_syntax for the element at pos, where the reader is.
+fn syntax_at(reader: &mut (impl Read + Seek), pos: u64) -> DicomResult<TransferSyntax> {
+    let mut header = Vec::with_capacity(6);
+
+    reader.take(6).read_to_end(&mut header).map_err(DicomError::io(pos, None))?;
+    reader.seek(SeekFrom::Start(pos)).map_err(DicomError::io(pos, None))?;
 
-    let limit_pos = reader.len();
-    let initial_syntax = TransferSyntax::default();
- 
-    parse_tags(reader, 0, initial_syntax, limit_pos, dicom_handler);
-}
\ No newline at end of file
+    if header.len() < 6 {
+        return Ok(TransferSyntax::default());
+    }
+
+    let group = [header[0], header[1]];
+    let vr_code = u16::from_le_bytes([header[4], header[5]]);
+
+    let syntax = match vr_type::is_known_code(vr_code) {
+        true if u16::from_be_bytes(group) < u16::from_le_bytes(group) => transfer_syntax::EXPLICIT_BE,
+        true                                                           => transfer_syntax::EXPLICIT_LE,
+        false                                                          => transfer_syntax::IMPLICIT_LE
+    };
+
+    Ok(TransferSyntax::parse_str(syntax))
+}
+
+// Parses the dataset starting at dataset_pos, where the reader is, up to limit_pos, or up to the end of the stream without a limit.
+pub(crate) fn parse_dataset_until(reader: &mut (impl Read + Seek), syntax: TransferSyntax, dataset_pos: u64, limit_pos: Option<u64>, mut state: ParseState, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+
+    match syntax.compression {
+        Compression::Deflate => {
+            let mut dataset = inflate(reader, dataset_pos)?;
+            let dataset_limit = dataset.get_ref().len() as u64;
+            state.inflated = true;
+            parse_tags(&mut dataset, syntax, 0, Some(dataset_limit), &mut state, dicom_handler)
+        },
+        _                    => parse_tags(reader, syntax, dataset_pos, limit_pos, &mut state, dicom_handler)
+    }
+}
+
+// Files without File Meta Information, or without a Transfer Syntax UID in it, get their syntax detected.
+// Streams end with their last byte, seekable readers are parsed up to their length.
+fn parse_file(reader: &mut (impl Read + Seek), state: ParseState, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    let meta_pos = match read_preamble(reader)? {
+        Some(_) => (PREAMBLE_LENGTH + STANDARD_PREAMBLE.len()) as u64,
+        None    => 0
+    };
+
+    let limit_pos = match state.streamed {
+        true  => None,
+        false => Some(reader.len().map_err(DicomError::io(meta_pos, None))?)
+    };
+
+    let (syntax, dataset_pos) = read_file_meta(reader, meta_pos, &mut |tag| dicom_handler.element(tag))?;
+    let syntax = match syntax {
+        Some(syntax) => syntax,
+        None         => syntax_at(reader, dataset_pos)?
+    };
+
+    dicom_handler.file_meta_done(syntax);
+
+    parse_dataset_until(reader, syntax, dataset_pos, limit_pos, state, dicom_handler)
+}
+
+// Parses a dataset with no preamble nor File Meta Information, from the current position to the end of the reader.
+pub fn parse_dataset(reader: &mut (impl Read + Seek), syntax: TransferSyntax, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    let dataset_pos = reader.pos().map_err(DicomError::io(0, None))?;
+    let limit_pos = reader.len().map_err(DicomError::io(dataset_pos, None))?;
+    parse_dataset_until(reader, syntax, dataset_pos, Some(limit_pos), ParseState::default(), dicom_handler)
+}
+
+// Same as parse_dataset, guessing the syntax with detect_syntax.
+pub fn parse_dataset_detect(reader: &mut (impl Read + Seek), dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    let syntax = detect_syntax(reader)?;
+    parse_dataset(reader, syntax, dicom_handler)
+}
+
+pub fn parse(reader: &mut (impl Read + Seek), dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    parse_file(reader, ParseState::default(), dicom_handler)
+}
+
+// Same as parse over a reader that cannot seek, such as a pipe or a socket. The dataset ends with the stream.
+// Values skipped by the handler are read and discarded, values it defers are read as usual since they could not be read back.
+pub fn parse_stream(reader: impl Read, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    parse_file(&mut StreamReader::new(reader), ParseState::streamed(), dicom_handler)
+}
+
+pub fn parse_dataset_stream(reader: impl Read, syntax: TransferSyntax, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    parse_dataset_until(&mut StreamReader::new(reader), syntax, 0, None, ParseState::streamed(), dicom_handler)
+}
+
+// Pulls the tags of a file one at a time instead of pushing them to a handler, see TagIter.
+pub fn tags<R: Read + Seek>(reader: R) -> TagIter<R> {
+    TagIter::new(reader)
+}
+
+// Same as tags over a dataset with no preamble nor File Meta Information.
+pub fn dataset_tags<R: Read + Seek>(reader: R, syntax: TransferSyntax) -> TagIter<R> {
+    TagIter::dataset(reader, syntax)
+}
@@ -0,0 +1,416 @@
+use std::convert::TryFrom;
+use std::io::{self, Cursor, ErrorKind, Read, Seek, SeekFrom};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, BufReader, ReadBuf};
+
+use crate::decoder;
+use crate::dicom::{self, Container, OpenNode, ParseState, PREAMBLE_LENGTH};
+use crate::dicom_handlers::*;
+use crate::dicom_tag::*;
+use crate::dicom_error::{DicomError, DicomResult};
+use crate::vr_type;
+use crate::tags;
+use crate::transfer_syntax::{Compression, EndianEncoding, TransferSyntax};
+
+const FILE_META_GROUP: u16 = 0x0002;
+// Explicit VR headers with a reserved field and a 4 byte length are the longest.
+const MAX_HEADER_LENGTH: usize = 12;
+
+// Bytes read ahead from the stream, seen as Read + Seek at their position in the stream so that the sync
+// decoding in the dicom module runs over them unchanged and reports the same offsets.
+struct Window {
+    base: u64,
+    cursor: Cursor<Vec<u8>>
+}
+
+impl Window {
+    fn new(base: u64, bytes: Vec<u8>) -> Window {
+        Window { base, cursor: Cursor::new(bytes) }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        self.cursor.get_ref()
+    }
+
+    fn consumed(&self) -> usize {
+        self.cursor.position() as usize
+    }
+}
+
+impl Read for Window {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Read::read(&mut self.cursor, buf)
+    }
+}
+
+impl Seek for Window {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(p) => SeekFrom::Start(p.checked_sub(self.base).ok_or_else(|| io::Error::from(ErrorKind::InvalidInput))?),
+            relative           => relative
+        };
+
+        Seek::seek(&mut self.cursor, pos).map(|p| self.base + p)
+    }
+}
+
+// How a reader moves past the bytes the parser does not need.
+trait Skip: AsyncRead + Unpin {
+    // Skipped values can be read back with load_deferred only when the source seeks.
+    const SEEKABLE: bool;
+
+    async fn skip(&mut self, length: u64) -> io::Result<()>;
+}
+
+// Reader that can only be read, skipped bytes are read and discarded.
+struct Forward<R>(BufReader<R>);
+
+// Reader that can also seek, skipped bytes are seeked past.
+struct Seeking<R>(BufReader<R>);
+
+impl<R: AsyncRead + Unpin> AsyncRead for Forward<R> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for Seeking<R> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl<R: AsyncRead + Unpin> Skip for Forward<R> {
+    const SEEKABLE: bool = false;
+
+    async fn skip(&mut self, length: u64) -> io::Result<()> {
+        let skipped = tokio::io::copy(&mut (&mut self.0).take(length), &mut tokio::io::sink()).await?;
+
+        match skipped == length {
+            true  => Ok(()),
+            false => Err(ErrorKind::UnexpectedEof.into())
+        }
+    }
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> Skip for Seeking<R> {
+    const SEEKABLE: bool = true;
+
+    async fn skip(&mut self, length: u64) -> io::Result<()> {
+        let length = i64::try_from(length).map_err(|_| io::Error::from(ErrorKind::InvalidInput))?;
+        self.0.seek(SeekFrom::Current(length)).await.map(|_| ())
+    }
+}
+
+// Stream being parsed, with the bytes peeked at and not consumed yet and the position reached in it.
+struct AsyncSource<S> {
+    reader: S,
+    pos: u64,
+    peeked: Vec<u8>
+}
+
+impl<S: Skip> AsyncSource<S> {
+    fn new(reader: S, pos: u64) -> AsyncSource<S> {
+        AsyncSource { reader, pos, peeked: Vec::new() }
+    }
+
+    // Looks at the next length bytes without consuming them, fewer when the stream ends before.
+    async fn peek(&mut self, length: usize) -> io::Result<Window> {
+        while self.peeked.len() < length {
+            let start = self.peeked.len();
+            self.peeked.resize(length, 0);
+
+            let read = self.reader.read(&mut self.peeked[start..]).await;
+            self.peeked.truncate(start + read.as_ref().map_or(0, |n| *n));
+
+            if read? == 0 {
+                break;
+            }
+        }
+
+        Ok(Window::new(self.pos, self.peeked[..length.min(self.peeked.len())].to_vec()))
+    }
+
+    fn consume(&mut self, length: usize) {
+        self.peeked.drain(..length);
+        self.pos += length as u64;
+    }
+
+    async fn is_at_end(&mut self) -> io::Result<bool> {
+        Ok(self.peek(1).await?.as_slice().is_empty())
+    }
+
+    // Reads the next length bytes, fewer when the stream ends before.
+    async fn read_up_to(&mut self, length: usize) -> io::Result<Vec<u8>> {
+        let from_peeked = length.min(self.peeked.len());
+        let mut bytes: Vec<u8> = self.peeked.drain(..from_peeked).collect();
+
+        (&mut self.reader).take((length - from_peeked) as u64).read_to_end(&mut bytes).await?;
+        self.pos += bytes.len() as u64;
+
+        Ok(bytes)
+    }
+
+    async fn read_to_end(&mut self) -> io::Result<Vec<u8>> {
+        let mut bytes = std::mem::take(&mut self.peeked);
+
+        self.reader.read_to_end(&mut bytes).await?;
+        self.pos += bytes.len() as u64;
+
+        Ok(bytes)
+    }
+
+    async fn skip(&mut self, length: usize) -> io::Result<()> {
+        let from_peeked = length.min(self.peeked.len());
+        let rest = (length - from_peeked) as u64;
+
+        self.consume(from_peeked);
+        self.reader.skip(rest).await?;
+        self.pos += rest;
+
+        Ok(())
+    }
+}
+
+// Peeks at as many bytes as the longest header takes and decodes them with the sync read_header.
+async fn read_header(source: &mut AsyncSource<impl Skip>, syntax: TransferSyntax) -> DicomResult<TagHeader> {
+    let mut window = source.peek(MAX_HEADER_LENGTH).await.map_err(DicomError::io(source.pos, None))?;
+    let header = dicom::read_header(&mut window, source.pos, syntax)?;
+
+    source.consume(window.consumed());
+
+    Ok(header)
+}
+
+// Reads the value following a header and decodes it in place, as the sync read_tag does. Values cut short
+// by the end of the stream are reported as truncated, encapsulated fragments keep their offsets in the stream.
+async fn read_tag(source: &mut AsyncSource<impl Skip>, header: TagHeader, syntax: TransferSyntax) -> DicomResult<DicomTag> {
+    let TagHeader { group, element, vr_code, value_length, offset } = header;
+    let tag = header.tag();
+    let endian = syntax.endian_encoding;
+    let value_pos = source.pos;
+
+    let value = match tag {
+        _ if header.is_encapsulated()      => {
+            let bytes = read_fragments(source, endian).await.map_err(DicomError::io(offset, Some(tag)))?;
+            VrValue::Encapsulated(dicom::read_encapsulated(&mut Window::new(value_pos, bytes), value_pos, endian)?)
+        },
+        tags::ITEM                         => VrValue::Item,
+        _ if header.is_sequence()          => VrValue::SequenceOfItems,
+        _ if vr_code == vr_type::DELIMITER => decoder::decode_value(vr_code, Vec::new(), endian),
+        _                                  => {
+            let bytes = source.read_up_to(value_length).await.map_err(DicomError::io(offset, Some(tag)))?;
+
+            if bytes.len() < value_length {
+                return Err(DicomError::TruncatedValue { offset, tag, length: value_length });
+            }
+
+            decoder::decode_value(vr_code, bytes, endian)
+        }
+    };
+
+    Ok(DicomTag { group, element, syntax, value, value_length, offset })
+}
+
+// Collects the raw items of encapsulated pixel data up to the sequence delimiter, for read_encapsulated to decode.
+async fn read_fragments(source: &mut AsyncSource<impl Skip>, endian: EndianEncoding) -> io::Result<Vec<u8>> {
+    let convert_16: fn([u8; 2]) -> u16 = endian.convert(u16::from_le_bytes, u16::from_be_bytes);
+    let convert_32: fn([u8; 4]) -> u32 = endian.convert(u32::from_le_bytes, u32::from_be_bytes);
+    let mut bytes = Vec::new();
+
+    loop {
+        let item = source.read_up_to(8).await?;
+        bytes.extend_from_slice(&item);
+
+        if item.len() < 8 {
+            return Ok(bytes);
+        }
+
+        let tag = (convert_16([item[0], item[1]]), convert_16([item[2], item[3]]));
+        let length = convert_32([item[4], item[5], item[6], item[7]]);
+
+        match tag {
+            tags::ITEM if length as usize != UNDEFINED_LENGTH => bytes.extend(source.read_up_to(length as usize).await?),
+            _                                                 => return Ok(bytes)
+        }
+    }
+}
+
+async fn skip_value(source: &mut AsyncSource<impl Skip>, header: TagHeader, syntax: TransferSyntax) -> DicomResult<()> {
+    match header.has_undefined_length() {
+        true  => skip_to_delimiter(source, header.items_syntax(syntax)).await,
+        false => source.skip(header.value_length).await.map_err(DicomError::io(header.offset, Some(header.tag())))
+    }
+}
+
+async fn skip_to_delimiter(source: &mut AsyncSource<impl Skip>, syntax: TransferSyntax) -> DicomResult<()> {
+    let mut syntaxes = vec![syntax];
+
+    while let Some(syntax) = syntaxes.last().copied() {
+        let header = read_header(source, syntax).await?;
+
+        match (header.tag(), header.has_undefined_length()) {
+            (tags::SEQUENCE_DELIMITER, _) | (tags::ITEM_DELIMITER, _) => { syntaxes.pop(); },
+            (_, true)                                                 => syntaxes.push(header.items_syntax(syntax)),
+            (_, false)                                                => source.skip(header.value_length).await.map_err(DicomError::io(header.offset, Some(header.tag())))?
+        }
+    }
+
+    Ok(())
+}
+
+async fn skip_sequence(source: &mut AsyncSource<impl Skip>, open_nodes: &mut Vec<OpenNode>, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    if !open_nodes.iter().any(|n| n.container == Container::Sequence) {
+        return Ok(());
+    }
+
+    while let Some(node) = open_nodes.pop() {
+        match node.limit_pos {
+            Some(limit) => source.skip(limit.saturating_sub(source.pos) as usize).await.map_err(DicomError::io(limit, None))?,
+            None        => skip_to_delimiter(source, node.syntax).await?
+        }
+
+        dicom::close_node(node.container, dicom_handler);
+
+        if node.container == Container::Sequence {
+            return Ok(());
+        }
+    }
+
+    Ok(())
+}
+
+// Same loop as the sync parse_tags, the dataset ends with the stream.
+async fn parse_tags<S: Skip>(source: &mut AsyncSource<S>, syntax: TransferSyntax, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    let mut state = match S::SEEKABLE {
+        true  => ParseState::default(),
+        false => ParseState::streamed()
+    };
+    let mut open_nodes = vec![OpenNode { container: Container::Dataset, limit_pos: None, syntax }];
+
+    while let Some(node) = open_nodes.last().copied() {
+        let is_closed = match (node.container, node.limit_pos) {
+            (_, Some(limit))          => source.pos >= limit,
+            (Container::Dataset, None) => source.is_at_end().await.map_err(DicomError::io(source.pos, None))?,
+            (_, None)                 => false
+        };
+
+        if is_closed {
+            dicom::close_node(node.container, dicom_handler);
+            open_nodes.pop();
+            continue;
+        }
+
+        let syntax = node.syntax;
+        let header = read_header(source, syntax).await?;
+
+        if dicom::check_placement(&header, node)? {
+            dicom::close_node(node.container, dicom_handler);
+            open_nodes.pop();
+            continue;
+        }
+
+        match dicom_handler.tag_header(&header) {
+            ParseAction::Continue     => (),
+            ParseAction::SkipValue    => {
+                skip_value(source, header, syntax).await?;
+                continue;
+            },
+            ParseAction::SkipSequence => {
+                skip_value(source, header, syntax).await?;
+                skip_sequence(source, &mut open_nodes, dicom_handler).await?;
+                continue;
+            },
+            ParseAction::DeferValue if dicom::can_defer(&header, &state) => {
+                let value_offset = source.pos;
+                skip_value(source, header, syntax).await?;
+                dicom_handler.element(header.deferred(syntax, value_offset, source.pos - value_offset));
+                continue;
+            },
+            ParseAction::DeferValue   => (),
+            ParseAction::Stop         => return Ok(())
+        }
+
+        let value_pos = source.pos;
+        let mut tag = read_tag(source, header, syntax).await?;
+        dicom::keep_offset_tables(&mut tag, &mut state);
+
+        if let Some(child) = dicom::open_node(tag, value_pos, header.items_syntax(syntax), dicom_handler) {
+            open_nodes.push(child);
+        }
+    }
+
+    dicom_handler.end_of_dataset();
+
+    Ok(())
+}
+
+async fn parse_file_meta(source: &mut AsyncSource<impl Skip>, dicom_handler: &mut impl DicomHandler) -> DicomResult<Option<TransferSyntax>> {
+    let meta_syntax = TransferSyntax::default();
+    let mut syntax = None;
+
+    loop {
+        let group = source.peek(2).await.map_err(DicomError::io(source.pos, None))?;
+
+        if group.as_slice() != FILE_META_GROUP.to_le_bytes() {
+            return Ok(syntax);
+        }
+
+        let header = read_header(source, meta_syntax).await?;
+        let tag = read_tag(source, header, meta_syntax).await?;
+        syntax = tag.try_transfer_syntax()?.or(syntax);
+        dicom_handler.element(tag);
+    }
+}
+
+async fn parse_dataset_from(source: &mut AsyncSource<impl Skip>, syntax: TransferSyntax, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    match syntax.compression {
+        // Inflating takes the whole dataset anyway, the sync parser takes over once it is read.
+        Compression::Deflate => {
+            let offset = source.pos;
+            let deflated = source.read_to_end().await.map_err(DicomError::io(offset, None))?;
+            dicom::parse_dataset_until(&mut Cursor::new(deflated), syntax, offset, None, ParseState::default(), dicom_handler)
+        },
+        _                    => parse_tags(source, syntax, dicom_handler).await
+    }
+}
+
+async fn parse_file(source: &mut AsyncSource<impl Skip>, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    let mut head = source.peek(PREAMBLE_LENGTH + 4).await.map_err(DicomError::io(0, None))?;
+    dicom::read_preamble(&mut head)?;
+    source.consume(head.consumed());
+
+    let syntax = match parse_file_meta(source, dicom_handler).await? {
+        Some(syntax) => syntax,
+        None         => dicom::detect_syntax(&mut source.peek(6).await.map_err(DicomError::io(source.pos, None))?)?
+    };
+
+    dicom_handler.file_meta_done(syntax);
+
+    parse_dataset_from(source, syntax, dicom_handler).await
+}
+
+// Same as dicom::parse over a tokio AsyncRead, raising the same events as the stream is read.
+// Values skipped or deferred by the handler are read and discarded.
+pub async fn parse(reader: impl AsyncRead + Unpin, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    parse_file(&mut AsyncSource::new(Forward(BufReader::new(reader)), 0), dicom_handler).await
+}
+
+pub async fn parse_dataset(reader: impl AsyncRead + Unpin, syntax: TransferSyntax, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    parse_dataset_from(&mut AsyncSource::new(Forward(BufReader::new(reader)), 0), syntax, dicom_handler).await
+}
+
+// Same as parse over a reader that can also seek, values skipped or deferred by the handler are seeked past
+// and deferred values can be loaded later from the same source. Parsing starts at the beginning of the reader.
+pub async fn parse_seekable(mut reader: impl AsyncRead + AsyncSeek + Unpin, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    reader.seek(SeekFrom::Start(0)).await.map_err(DicomError::io(0, None))?;
+    parse_file(&mut AsyncSource::new(Seeking(BufReader::new(reader)), 0), dicom_handler).await
+}
+
+// Same as parse_dataset over a reader that can also seek, parsing from its current position.
+pub async fn parse_dataset_seekable(mut reader: impl AsyncRead + AsyncSeek + Unpin, syntax: TransferSyntax, dicom_handler: &mut impl DicomHandler) -> DicomResult<()> {
+    let pos = reader.stream_position().await.map_err(DicomError::io(0, None))?;
+    parse_dataset_from(&mut AsyncSource::new(Seeking(BufReader::new(reader)), pos), syntax, dicom_handler).await
+}
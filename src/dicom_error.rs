@@ -0,0 +1,110 @@
+use std::{error, fmt, io};
+
+pub type DicomResult<T> = Result<T, DicomError>;
+
+#[derive(Debug)]
+pub enum DicomError {
+    Io { offset: u64, tag: Option<(u16, u16)>, source: io::Error },
+    TruncatedValue { offset: u64, tag: (u16, u16), length: usize },
+    BadLength { offset: u64, tag: (u16, u16), length: u32 },
+    InvalidVr { offset: u64, tag: (u16, u16), vr_code: u16 },
+    UnexpectedDelimiter { offset: u64, tag: (u16, u16) },
+    UnexpectedElement { offset: u64, tag: (u16, u16) },
+    InvalidTransferSyntax { offset: u64, tag: (u16, u16) },
+    MissingPreamble { offset: u64 },
+    MissingElement { offset: u64, tag: (u16, u16) },
+    UnsupportedTransferSyntax { offset: u64, uid: &'static str },
+    InvalidValue { offset: u64, tag: (u16, u16), expected: &'static str },
+    // Raised when editing, the value was not read from a source and has no offset.
+    IncompatibleValue { tag: (u16, u16), vr_code: u16 }
+}
+
+impl DicomError {
+    pub fn io(offset: u64, tag: Option<(u16, u16)>) -> impl FnOnce(io::Error) -> DicomError {
+        move |source| DicomError::Io { offset, tag, source }
+    }
+
+    pub fn offset(&self) -> u64 {
+        match self {
+            DicomError::Io { offset, .. }                    => *offset,
+            DicomError::TruncatedValue { offset, .. }        => *offset,
+            DicomError::BadLength { offset, .. }             => *offset,
+            DicomError::InvalidVr { offset, .. }             => *offset,
+            DicomError::UnexpectedDelimiter { offset, .. }   => *offset,
+            DicomError::UnexpectedElement { offset, .. }     => *offset,
+            DicomError::InvalidTransferSyntax { offset, .. } => *offset,
+            DicomError::MissingPreamble { offset }           => *offset,
+            DicomError::MissingElement { offset, .. }        => *offset,
+            DicomError::UnsupportedTransferSyntax { offset, .. } => *offset,
+            DicomError::InvalidValue { offset, .. }          => *offset,
+            DicomError::IncompatibleValue { .. }             => 0
+        }
+    }
+
+    pub fn tag(&self) -> Option<(u16, u16)> {
+        match self {
+            DicomError::Io { tag, .. }                    => *tag,
+            DicomError::TruncatedValue { tag, .. }        => Some(*tag),
+            DicomError::BadLength { tag, .. }             => Some(*tag),
+            DicomError::InvalidVr { tag, .. }             => Some(*tag),
+            DicomError::UnexpectedDelimiter { tag, .. }   => Some(*tag),
+            DicomError::UnexpectedElement { tag, .. }     => Some(*tag),
+            DicomError::InvalidTransferSyntax { tag, .. } => Some(*tag),
+            DicomError::MissingPreamble { .. }            => None,
+            DicomError::MissingElement { tag, .. }        => Some(*tag),
+            DicomError::UnsupportedTransferSyntax { .. }  => None,
+            DicomError::InvalidValue { tag, .. }          => Some(*tag),
+            DicomError::IncompatibleValue { tag, .. }     => Some(*tag)
+        }
+    }
+}
+
+pub struct TagId(pub (u16, u16));
+
+impl fmt::Display for TagId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:04X},{:04X})", (self.0).0, (self.0).1)
+    }
+}
+
+impl fmt::Display for DicomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DicomError::Io { offset, tag: Some(tag), source } =>
+                write!(f, "I/O error reading tag {} at offset {}: {}", TagId(*tag), offset, source),
+            DicomError::Io { offset, tag: None, source } =>
+                write!(f, "I/O error at offset {}: {}", offset, source),
+            DicomError::TruncatedValue { offset, tag, length } =>
+                write!(f, "Truncated value in tag {} at offset {}, expected {} bytes", TagId(*tag), offset, length),
+            DicomError::BadLength { offset, tag, length } =>
+                write!(f, "Bad value length {} in tag {} at offset {}", length, TagId(*tag), offset),
+            DicomError::InvalidVr { offset, tag, vr_code } =>
+                write!(f, "Invalid VR code 0x{:04X} in tag {} at offset {}", vr_code, TagId(*tag), offset),
+            DicomError::UnexpectedDelimiter { offset, tag } =>
+                write!(f, "Unexpected delimiter {} at offset {}", TagId(*tag), offset),
+            DicomError::UnexpectedElement { offset, tag } =>
+                write!(f, "Unexpected element {} at offset {}, sequences may only contain items", TagId(*tag), offset),
+            DicomError::InvalidTransferSyntax { offset, tag } =>
+                write!(f, "Transfer syntax in tag {} at offset {} is not encoded as a UID", TagId(*tag), offset),
+            DicomError::MissingPreamble { offset } =>
+                write!(f, "Missing DICM marker after the preamble at offset {}", offset),
+            DicomError::MissingElement { offset, tag } =>
+                write!(f, "Missing mandatory element {} in File Meta Information ending at offset {}", TagId(*tag), offset),
+            DicomError::UnsupportedTransferSyntax { offset, uid } =>
+                write!(f, "Transfer syntax {} of the dataset at offset {} is not supported by this parser", uid, offset),
+            DicomError::InvalidValue { offset, tag, expected } =>
+                write!(f, "Value of tag {} at offset {} cannot be read as {}", TagId(*tag), offset, expected),
+            DicomError::IncompatibleValue { tag, vr_code } =>
+                write!(f, "Value of VR {} does not fit tag {}", String::from_utf8_lossy(&vr_code.to_le_bytes()), TagId(*tag))
+        }
+    }
+}
+
+impl error::Error for DicomError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            DicomError::Io { source, .. } => Some(source),
+            _                             => None
+        }
+    }
+}
@@ -1,76 +1,322 @@
+use std::io::{Read, Seek};
+
+use crate::dicom;
+use crate::dicom_error::DicomResult;
 use crate::tags;
-use crate::dicom_tag::DicomTag;
+use crate::vr_type;
+use crate::dicom_tag::{DicomTag, TagHeader};
+use crate::date_time::{DicomDate, DicomDateTime, DicomTime};
+use crate::transfer_syntax::TransferSyntax;
+
+// What the parser does with a tag once its header is read.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ParseAction {
+    Continue,
+    // Seek past the value without reading it, or past the whole node for sequences and items. The tag is not reported.
+    SkipValue,
+    // Skip the rest of the sequence holding the tag, the tag included, closing the open items and the sequence.
+    // Outside any sequence it is the same as SkipValue.
+    SkipSequence,
+    // Seek past the value and report the tag with a VrValue::Deferred holding where the value is, to load it later.
+    // Sequences, items and values in deflated datasets are read as usual. Encapsulated pixel data is deferred
+    // with the byte range of its fragments, up to and including the sequence delimiter.
+    DeferValue,
+    // Stop parsing, no further events are raised.
+    Stop
+}
 
+// Events raised while parsing, every method defaults to doing nothing so handlers only implement what they need.
+// Sequences and items are always closed, by their delimitation item when their length is undefined or when
+// their declared length is consumed otherwise. Delimitation items themselves are not reported.
 pub trait DicomHandler {
-    fn handle_tag(&mut self, parent_index: usize, tag: DicomTag) -> usize;
+    // Consulted for every tag of the dataset other than delimitation items, before its value is read.
+    fn tag_header(&mut self, _header: &TagHeader) -> ParseAction {
+        ParseAction::Continue
+    }
+
+    fn start_sequence(&mut self, _tag: DicomTag) {}
+
+    fn end_sequence(&mut self) {}
+
+    fn start_item(&mut self, _tag: DicomTag) {}
+
+    fn end_item(&mut self) {}
+
+    fn element(&mut self, _tag: DicomTag) {}
+
+    // Raised after the File Meta Information group, with the syntax of the dataset that follows.
+    fn file_meta_done(&mut self, _syntax: TransferSyntax) {}
+
+    fn end_of_dataset(&mut self) {}
+}
+
+fn print_tag(depth: usize, tag: &DicomTag) {
+    let tag_name = tags::try_tag_name(tag.group, tag.element).unwrap_or("UNKNOWN");
+
+    let id = format!("({}, {})", tag.group, tag.element);
+
+    println!("TAG | {}{:<14} | {:<38} | {}", "  ".repeat(depth), id, tag_name, tag.value);
 }
 
 pub struct DicomNode {
-    tag: Option<DicomTag>, 
-    children: Vec<usize>
+    pub(crate) tag: Option<DicomTag>, 
+    pub(crate) children: Vec<usize>
+}
+
+impl DicomNode {
+    pub fn tag(&self) -> Option<&DicomTag> {
+        self.tag.as_ref()
+    }
+
+    pub fn children(&self) -> &[usize] {
+        &self.children
+    }
 }
 
 pub struct DicomContainer {
-    pub nodes: Vec<DicomNode>
+    pub nodes: Vec<DicomNode>,
+    // Indices of the open sequences and items, the root node is always at the bottom.
+    open_nodes: Vec<usize>,
+    // Binary values longer than this are left in the source, see load_deferred.
+    deferred_threshold: Option<usize>
 }
 
 impl DicomContainer {
     pub fn new() -> DicomContainer {
-        DicomContainer { nodes: vec! [ DicomNode { tag: None, children: Vec::new() } ] }
+        DicomContainer { 
+            nodes: vec! [ DicomNode { tag: None, children: Vec::new() } ],
+            open_nodes: vec![0],
+            deferred_threshold: None
+        }
     }
-}
 
-impl DicomHandler for DicomContainer {
-    fn handle_tag(&mut self, parent_index: usize, tag: DicomTag) -> usize {
-        let tag_name = match tags::try_tag_name(tag.group, tag.element) {
-            Some(name) => name,
-            None       => "UNKNOWN"
+    pub fn with_deferred_threshold(threshold: usize) -> DicomContainer {
+        DicomContainer { deferred_threshold: Some(threshold), ..DicomContainer::new() }
+    }
+
+    // Loads a deferred value from the source the container was parsed from, replacing it in the node.
+    pub fn load_deferred(&mut self, index: usize, source: &mut (impl Read + Seek)) -> DicomResult<()> {
+        let tag = match self.nodes.get_mut(index).and_then(|n| n.tag.as_mut()) {
+            Some(tag) => tag,
+            None      => return Ok(())
         };
 
-        let id = format!("({}, {})", tag.group, tag.element);
+        if let Some(value) = dicom::load_value(source, tag)? {
+            tag.value = value;
+        }
 
-        println!("TAG | {:<14} | {:<38} | {}", id, tag_name, tag.value);
+        Ok(())
+    }
+
+    // Tag of the dataset, sequences and items are not searched, see get_path.
+    pub fn get(&self, tag: (u16, u16)) -> Option<&DicomTag> {
+        self.find(0, tag).and_then(|index| self.nodes[index].tag())
+    }
+
+    pub fn get_by_keyword(&self, keyword: &str) -> Option<&DicomTag> {
+        tags::try_keyword_tag(keyword).and_then(|tag| self.get(tag))
+    }
+
+    // Tag at a path of keywords or (gggg,eeee) tags separated by dots, the index of the item within each sequence
+    // in brackets, e.g. ReferencedSeriesSequence[0].SeriesInstanceUID. A bracketed index last gives the item itself.
+    pub fn get_path(&self, path: &str) -> Option<&DicomTag> {
+        self.find_path(path).and_then(|index| self.nodes[index].tag())
+    }
 
+    // Index of the child of a node with the given tag, 0 being the index of the dataset.
+    pub fn find(&self, parent: usize, tag: (u16, u16)) -> Option<usize> {
+        self.children(parent).find(|(_, child)| (child.group, child.element) == tag).map(|(index, _)| index)
+    }
+
+    // Index of the node at a path, see get_path.
+    pub fn find_path(&self, path: &str) -> Option<usize> {
+        let mut steps = path.split('.').peekable();
+        let mut index = 0;
+
+        while let Some(step) = steps.next() {
+            let (name, item) = match step.find('[') {
+                Some(open) if step.ends_with(']') => (&step[..open], Some(step[open + 1..step.len() - 1].parse::<usize>().ok()?)),
+                Some(_)                           => return None,
+                None                              => (step, None)
+            };
+
+            index = self.find(index, parse_tag(name)?)?;
+
+            match item {
+                Some(item)                      => index = *self.nodes[index].children.get(item)?,
+                None if steps.peek().is_some()  => return None,
+                None                            => ()
+            }
+        }
+
+        Some(index)
+    }
+
+    // Tags held by a node, with their indices: the tags of the dataset for index 0, the items of a sequence
+    // or the tags of an item.
+    pub fn children(&self, index: usize) -> impl Iterator<Item = (usize, &DicomTag)> + '_ {
+        self.nodes.get(index).into_iter()
+            .flat_map(|node| node.children.iter())
+            .filter_map(move |child| self.nodes[*child].tag().map(|tag| (*child, tag)))
+    }
+
+    // Typed values of dataset tags, None when the tag is absent and an error when its value cannot be converted.
+    pub fn get_str(&self, tag: (u16, u16)) -> DicomResult<Option<&str>> {
+        self.get(tag).map(DicomTag::to_str).transpose()
+    }
+
+    pub fn get_strs(&self, tag: (u16, u16)) -> DicomResult<Option<Vec<&str>>> {
+        self.get(tag).map(DicomTag::to_strs).transpose()
+    }
+
+    pub fn get_u16(&self, tag: (u16, u16)) -> DicomResult<Option<u16>> {
+        self.get_u16s(tag).map(|values| values.and_then(|v| v.first().copied()))
+    }
+
+    pub fn get_u16s(&self, tag: (u16, u16)) -> DicomResult<Option<Vec<u16>>> {
+        self.get(tag).map(DicomTag::to_u16s).transpose()
+    }
+
+    pub fn get_f64(&self, tag: (u16, u16)) -> DicomResult<Option<f64>> {
+        self.get_f64s(tag).map(|values| values.and_then(|v| v.first().copied()))
+    }
+
+    pub fn get_f64s(&self, tag: (u16, u16)) -> DicomResult<Option<Vec<f64>>> {
+        self.get(tag).map(DicomTag::to_f64s).transpose()
+    }
+
+    pub fn get_date(&self, tag: (u16, u16)) -> DicomResult<Option<DicomDate>> {
+        self.get(tag).map(DicomTag::to_dates).transpose().map(|values| values.and_then(|v| v.first().copied()))
+    }
+
+    pub fn get_time(&self, tag: (u16, u16)) -> DicomResult<Option<DicomTime>> {
+        self.get(tag).map(DicomTag::to_times).transpose().map(|values| values.and_then(|v| v.first().copied()))
+    }
+
+    pub fn get_date_time(&self, tag: (u16, u16)) -> DicomResult<Option<DicomDateTime>> {
+        self.get(tag).map(DicomTag::to_date_times).transpose().map(|values| values.and_then(|v| v.first().copied()))
+    }
+
+    fn push(&mut self, tag: DicomTag) -> usize {
         let child = DicomNode { tag: Some(tag), children: Vec::new() };
         self.nodes.push(child);
 
         let child_index = self.nodes.len() - 1;
+        let parent_index = self.open_nodes[self.open_nodes.len() - 1];
         self.nodes[parent_index].children.push(child_index);
 
         child_index
     }
+
+    fn close(&mut self) {
+        // The root is never closed, unbalanced events cannot detach the rest of the tree.
+        if self.open_nodes.len() > 1 {
+            self.open_nodes.pop();
+        }
+    }
+}
+
+// Keyword or (gggg,eeee) tag in hexadecimal.
+fn parse_tag(name: &str) -> Option<(u16, u16)> {
+    match name.strip_prefix('(').and_then(|n| n.strip_suffix(')')) {
+        Some(id) => {
+            let (group, element) = id.split_once(',')?;
+            Some((u16::from_str_radix(group.trim(), 16).ok()?, u16::from_str_radix(element.trim(), 16).ok()?))
+        },
+        None     => tags::try_keyword_tag(name)
+    }
+}
+
+impl Default for DicomContainer {
+    fn default() -> DicomContainer {
+        DicomContainer::new()
+    }
+}
+
+impl DicomHandler for DicomContainer {
+    // OV is left out, it holds the Extended Offset Table needed to locate encapsulated frames.
+    fn tag_header(&mut self, header: &TagHeader) -> ParseAction {
+        let is_binary = matches!(header.vr_code,
+            vr_type::OTHER_BYTE | vr_type::OTHER_WORD | vr_type::OTHER_FLOAT | vr_type::OTHER_DOUBLE | vr_type::OTHER_LONG | vr_type::UNKNOWN);
+
+        match self.deferred_threshold {
+            Some(threshold) if is_binary && header.value_length > threshold => ParseAction::DeferValue,
+            _                                                               => ParseAction::Continue
+        }
+    }
+
+    fn start_sequence(&mut self, tag: DicomTag) {
+        let index = self.push(tag);
+        self.open_nodes.push(index);
+    }
+
+    fn end_sequence(&mut self) {
+        self.close();
+    }
+
+    fn start_item(&mut self, tag: DicomTag) {
+        let index = self.push(tag);
+        self.open_nodes.push(index);
+    }
+
+    fn end_item(&mut self) {
+        self.close();
+    }
+
+    fn element(&mut self, tag: DicomTag) {
+        self.push(tag);
+    }
 }
 
 pub struct DicomDumper {
-    tags_count: usize
+    tags_count: usize,
+    depth: usize
 }
 
 impl DicomDumper {
     pub fn new() -> DicomDumper {
         DicomDumper { 
-            tags_count: 0
+            tags_count: 0,
+            depth: 0
         }
     }
 
-    pub fn len(&self) -> usize {
+    pub fn tags_count(&self) -> usize {
         self.tags_count
     }
+
+    fn dump(&mut self, tag: DicomTag) {
+        print_tag(self.depth, &tag);
+        self.tags_count += 1;
+    }
 }
 
+impl Default for DicomDumper {
+    fn default() -> DicomDumper {
+        DicomDumper::new()
+    }
+}
 
 impl DicomHandler for DicomDumper {
-    fn handle_tag(&mut self, _: usize, tag: DicomTag) -> usize {
-        let tag_name = match tags::try_tag_name(tag.group, tag.element) {
-            Some(name) => name, 
-            None       => "UNKNOWN"
-        };
+    fn start_sequence(&mut self, tag: DicomTag) {
+        self.dump(tag);
+        self.depth += 1;
+    }
+
+    fn end_sequence(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
 
-        let id = format!("({}, {})", tag.group, tag.element);
-        
-        println!("TAG | {:<14} | {:<38} | {}", id, tag_name, tag.value);
+    fn start_item(&mut self, tag: DicomTag) {
+        self.dump(tag);
+        self.depth += 1;
+    }
 
-        self.tags_count += 1;
+    fn end_item(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
 
-        self.tags_count
+    fn element(&mut self, tag: DicomTag) {
+        self.dump(tag);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,345 @@
+use std::io::{Read, Seek};
+
+use crate::dicom;
+use crate::encoder;
+use crate::tags;
+use crate::vr_type;
+use crate::dicom_error::{DicomError, DicomResult};
+use crate::dicom_handlers::{DicomContainer, DicomHandler, DicomNode};
+use crate::dicom_tag::{DicomTag, VrValue, UNDEFINED_LENGTH};
+use crate::transfer_syntax::{TransferSyntax, VrEncoding};
+
+// Element of a DicomObject, sequences hold their items.
+pub struct DicomElement {
+    pub(crate) tag: DicomTag,
+    pub(crate) items: Vec<DicomObject>
+}
+
+impl DicomElement {
+    // The tag as parsed or inserted. For sequences value_length only tells whether the length is undefined,
+    // the defined length depends on the items, see value_length.
+    pub fn tag(&self) -> &DicomTag {
+        &self.tag
+    }
+
+    pub fn id(&self) -> (u16, u16) {
+        (self.tag.group, self.tag.element)
+    }
+
+    pub fn value(&self) -> &VrValue {
+        &self.tag.value
+    }
+
+    pub fn is_sequence(&self) -> bool {
+        matches!(self.tag.value, VrValue::SequenceOfItems)
+    }
+
+    pub fn items(&self) -> &[DicomObject] {
+        &self.items
+    }
+
+    // Length of the value as declared in its header, UNDEFINED_LENGTH for delimited sequences and encapsulated pixel data.
+    pub fn value_length(&self, vr_encoding: VrEncoding) -> usize {
+        match self.is_sequence() && !self.tag.has_undefined_length() {
+            true  => self.items.iter().map(|item| item.item_length(vr_encoding)).sum(),
+            false => self.tag.value_length
+        }
+    }
+
+    // Bytes taken by the whole element, header and delimitation items included.
+    pub fn encoded_length(&self, vr_encoding: VrEncoding) -> usize {
+        let vr_code = self.tag.value.vr_code().unwrap_or(vr_type::UNKNOWN);
+        let header = encoder::header_length(vr_code, vr_encoding);
+
+        let value = match &self.tag.value {
+            VrValue::Encapsulated(pixels)                              => encoder::encapsulated_length(pixels),
+            VrValue::SequenceOfItems if self.tag.has_undefined_length() =>
+                self.items.iter().map(|item| item.item_length(vr_encoding)).sum::<usize>() + encoder::ITEM_HEADER_LENGTH,
+            _                                                          => self.value_length(vr_encoding)
+        };
+
+        header + value
+    }
+}
+
+// Mutable dataset, elements are kept sorted by tag whatever the order they are inserted in. Values are checked
+// against the VR the dictionary gives to their tag, tags missing from the dictionary take any value.
+pub struct DicomObject {
+    pub(crate) elements: Vec<DicomElement>,
+    // As an item, whether it is closed by an item delimitation item instead of declaring its length.
+    undefined_length: bool,
+    // Syntax of the dataset the object was parsed from, given to the tags inserted.
+    syntax: TransferSyntax
+}
+
+impl DicomObject {
+    pub fn new() -> DicomObject {
+        DicomObject { elements: Vec::new(), undefined_length: false, syntax: TransferSyntax::default() }
+    }
+
+    pub fn syntax(&self) -> TransferSyntax {
+        self.syntax
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    // Elements in tag order.
+    pub fn elements(&self) -> &[DicomElement] {
+        &self.elements
+    }
+
+    pub fn get(&self, tag: (u16, u16)) -> Option<&DicomElement> {
+        self.position(tag).ok().map(|index| &self.elements[index])
+    }
+
+    // Inserts the value in place of the element with the same tag if any, returning it.
+    pub fn insert(&mut self, tag: (u16, u16), value: VrValue) -> DicomResult<Option<DicomElement>> {
+        check_vr(tag, &value)?;
+        let tag = self.new_tag(tag, value);
+        Ok(self.put(DicomElement { tag, items: Vec::new() }))
+    }
+
+    // Replaces the value of an existing element, returning the element replaced. Nothing is inserted when the tag is missing.
+    pub fn replace(&mut self, tag: (u16, u16), value: VrValue) -> DicomResult<Option<DicomElement>> {
+        match self.position(tag) {
+            Ok(_)  => self.insert(tag, value),
+            Err(_) => Ok(None)
+        }
+    }
+
+    pub fn remove(&mut self, tag: (u16, u16)) -> Option<DicomElement> {
+        self.position(tag).ok().map(|index| self.elements.remove(index))
+    }
+
+    // Inserts a sequence of undefined length holding the items, in place of the element with the same tag if any.
+    pub fn insert_sequence(&mut self, tag: (u16, u16), items: Vec<DicomObject>) -> DicomResult<Option<DicomElement>> {
+        check_vr(tag, &VrValue::SequenceOfItems)?;
+        let mut tag = self.new_tag(tag, VrValue::SequenceOfItems);
+        tag.value_length = UNDEFINED_LENGTH;
+        Ok(self.put(DicomElement { tag, items }))
+    }
+
+    // Items of a sequence, None when the tag is missing or not a sequence.
+    pub fn items(&self, tag: (u16, u16)) -> Option<&[DicomObject]> {
+        self.get(tag).filter(|element| element.is_sequence()).map(DicomElement::items)
+    }
+
+    pub fn item_mut(&mut self, tag: (u16, u16), index: usize) -> Option<&mut DicomObject> {
+        let position = self.position(tag).ok()?;
+        self.elements[position].items.get_mut(index)
+    }
+
+    // Inserts an item at index, appending it past the last item. A missing sequence is inserted with the item,
+    // an element other than a sequence fails with IncompatibleValue.
+    pub fn insert_item(&mut self, tag: (u16, u16), index: usize, item: DicomObject) -> DicomResult<()> {
+        let position = match self.position(tag) {
+            Ok(position) => position,
+            Err(_)       => return self.insert_sequence(tag, vec![item]).map(|_| ())
+        };
+
+        let element = &mut self.elements[position];
+        if !element.is_sequence() {
+            return Err(DicomError::IncompatibleValue { tag, vr_code: vr_type::SEQUENCE_OF_ITEMS });
+        }
+
+        element.items.insert(index.min(element.items.len()), item);
+        Ok(())
+    }
+
+    pub fn remove_item(&mut self, tag: (u16, u16), index: usize) -> Option<DicomObject> {
+        let position = self.position(tag).ok()?;
+        let items = &mut self.elements[position].items;
+
+        match index < items.len() {
+            true  => Some(items.remove(index)),
+            false => None
+        }
+    }
+
+    pub fn has_undefined_length(&self) -> bool {
+        self.undefined_length
+    }
+
+    // Bytes taken by the elements, as the content of an item or a dataset.
+    pub fn encoded_length(&self, vr_encoding: VrEncoding) -> usize {
+        self.elements.iter().map(|element| element.encoded_length(vr_encoding)).sum()
+    }
+
+    // Bytes taken by the object as an item of a sequence, its header and delimitation item included.
+    fn item_length(&self, vr_encoding: VrEncoding) -> usize {
+        let delimiter = match self.undefined_length {
+            true  => encoder::ITEM_HEADER_LENGTH,
+            false => 0
+        };
+
+        encoder::ITEM_HEADER_LENGTH + self.encoded_length(vr_encoding) + delimiter
+    }
+
+    fn position(&self, tag: (u16, u16)) -> Result<usize, usize> {
+        self.elements.binary_search_by_key(&tag, DicomElement::id)
+    }
+
+    fn put(&mut self, element: DicomElement) -> Option<DicomElement> {
+        match self.position(element.id()) {
+            Ok(index)  => Some(std::mem::replace(&mut self.elements[index], element)),
+            Err(index) => {
+                self.elements.insert(index, element);
+                None
+            }
+        }
+    }
+
+    // Tags inserted were not read from a source, their offset is 0.
+    fn new_tag(&self, tag: (u16, u16), value: VrValue) -> DicomTag {
+        let value_length = encoder::value_length(&value);
+        DicomTag { group: tag.0, element: tag.1, syntax: self.syntax, value, value_length, offset: 0 }
+    }
+}
+
+// The dictionary gives a single VR where the standard allows OB or OW and US or SS, either is accepted.
+// Values of unknown VR fit any tag, as they do when read in implicit VR.
+fn check_vr(tag: (u16, u16), value: &VrValue) -> DicomResult<()> {
+    let vr_code = value.vr_code().unwrap_or(vr_type::DELIMITER);
+
+    let fits = match (tags::tag_vr_type(tag.0, tag.1), vr_code) {
+        (_, vr_type::DELIMITER)                                  => false,
+        (vr_type::UNKNOWN, _) | (_, vr_type::UNKNOWN)            => true,
+        (vr_type::OTHER_BYTE, vr_type::OTHER_WORD)               => true,
+        (vr_type::OTHER_WORD, vr_type::OTHER_BYTE)               => true,
+        (vr_type::UNSIGNED_SHORT, vr_type::SIGNED_SHORT)         => true,
+        (vr_type::SIGNED_SHORT, vr_type::UNSIGNED_SHORT)         => true,
+        (expected, vr_code)                                      => expected == vr_code
+    };
+
+    match fits {
+        true  => Ok(()),
+        false => Err(DicomError::IncompatibleValue { tag, vr_code })
+    }
+}
+
+impl Default for DicomObject {
+    fn default() -> DicomObject {
+        DicomObject::new()
+    }
+}
+
+// Builds a DicomObject from the parser events, parsed values are kept as they are without checking their VR.
+pub struct DicomObjectBuilder {
+    // The dataset at the bottom, then each open item.
+    objects: Vec<DicomObject>,
+    // Open sequences, holding the items already closed.
+    sequences: Vec<DicomElement>
+}
+
+impl DicomObjectBuilder {
+    pub fn new() -> DicomObjectBuilder {
+        DicomObjectBuilder { objects: vec![DicomObject::new()], sequences: Vec::new() }
+    }
+
+    // Closes the sequences and items left open when parsing stopped early.
+    pub fn into_object(mut self) -> DicomObject {
+        while self.objects.len() > 1 || !self.sequences.is_empty() {
+            match self.sequences.len() >= self.objects.len() {
+                true  => self.end_sequence(),
+                false => self.end_item()
+            }
+        }
+
+        self.objects.pop().unwrap_or_default()
+    }
+
+    fn current(&mut self) -> &mut DicomObject {
+        self.objects.last_mut().expect("the dataset is never closed")
+    }
+}
+
+impl Default for DicomObjectBuilder {
+    fn default() -> DicomObjectBuilder {
+        DicomObjectBuilder::new()
+    }
+}
+
+impl DicomHandler for DicomObjectBuilder {
+    fn start_sequence(&mut self, tag: DicomTag) {
+        self.sequences.push(DicomElement { tag, items: Vec::new() });
+    }
+
+    fn end_sequence(&mut self) {
+        if let Some(sequence) = self.sequences.pop() {
+            self.current().put(sequence);
+        }
+    }
+
+    fn start_item(&mut self, tag: DicomTag) {
+        self.objects.push(DicomObject { elements: Vec::new(), undefined_length: tag.has_undefined_length(), syntax: tag.syntax });
+    }
+
+    fn end_item(&mut self) {
+        // The dataset is never closed, unbalanced events cannot drop the rest of the tree.
+        if self.objects.len() < 2 {
+            return;
+        }
+
+        let item = self.objects.pop().expect("an item is open");
+        if let Some(sequence) = self.sequences.last_mut() {
+            sequence.items.push(item);
+        }
+    }
+
+    fn element(&mut self, tag: DicomTag) {
+        self.current().put(DicomElement { tag, items: Vec::new() });
+    }
+
+    fn file_meta_done(&mut self, syntax: TransferSyntax) {
+        self.objects[0].syntax = syntax;
+    }
+}
+
+// Replays the tree of the container as parser events, tags are moved out of the container.
+impl From<DicomContainer> for DicomObject {
+    fn from(mut container: DicomContainer) -> DicomObject {
+        let mut builder = DicomObjectBuilder::new();
+
+        let syntax = container.nodes.iter().filter_map(DicomNode::tag).find(|tag| tag.group != 0x0002).map(|tag| tag.syntax);
+        builder.objects[0].syntax = syntax.unwrap_or_default();
+
+        replay(&mut container.nodes, 0, &mut builder);
+        builder.into_object()
+    }
+}
+
+fn replay(nodes: &mut [DicomNode], index: usize, builder: &mut DicomObjectBuilder) {
+    for child in std::mem::take(&mut nodes[index].children) {
+        let tag = match nodes[child].tag.take() {
+            Some(tag) => tag,
+            None      => continue
+        };
+
+        match tag.value {
+            VrValue::SequenceOfItems => {
+                builder.start_sequence(tag);
+                replay(nodes, child, builder);
+                builder.end_sequence();
+            },
+            VrValue::Item            => {
+                builder.start_item(tag);
+                replay(nodes, child, builder);
+                builder.end_item();
+            },
+            _                        => builder.element(tag)
+        }
+    }
+}
+
+// Parses a Dicom file into a DicomObject, the File Meta Information elements included.
+pub fn parse(reader: &mut (impl Read + Seek)) -> DicomResult<DicomObject> {
+    let mut builder = DicomObjectBuilder::new();
+    dicom::parse(reader, &mut builder)?;
+    Ok(builder.into_object())
+}
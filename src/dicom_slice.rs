@@ -0,0 +1,188 @@
+use std::io::Cursor;
+use std::str;
+
+use crate::decoder;
+use crate::dicom;
+use crate::dicom_error::{DicomError, DicomResult};
+use crate::dicom_tag::{DicomTag, TagHeader, VrValue};
+use crate::tags;
+use crate::vr_type;
+use crate::transfer_syntax::{Compression, TransferSyntax};
+
+const FILE_META_GROUP: u16 = 0x0002;
+const PREAMBLE: &[u8] = b"DICM";
+
+// Tag borrowing its value from the parsed bytes. Values are kept as encoded, in the byte order of their syntax,
+// sequences, items and delimiters have an empty value and encapsulated pixel data spans its items and delimiter.
+pub struct DicomTagRef<'a> {
+    pub group: u16,
+    pub element: u16,
+    pub vr_code: u16,
+    pub syntax: TransferSyntax,
+    pub value_length: usize,
+    pub offset: u64,
+    pub bytes: &'a [u8],
+    value_offset: u64
+}
+
+impl<'a> DicomTagRef<'a> {
+    pub fn tag(&self) -> (u16, u16) {
+        (self.group, self.element)
+    }
+
+    fn header(&self) -> TagHeader {
+        TagHeader { group: self.group, element: self.element, vr_code: self.vr_code, value_length: self.value_length, offset: self.offset }
+    }
+
+    // Value of string VRs, padding included. None for other VRs and for values that are not valid UTF-8.
+    pub fn as_str(&self) -> Option<&'a str> {
+        match vr_type::is_string(self.vr_code) {
+            true  => str::from_utf8(self.bytes).ok(),
+            false => None
+        }
+    }
+
+    pub fn strings(&self) -> impl Iterator<Item = &'a str> {
+        self.as_str().filter(|s| !s.is_empty()).into_iter().flat_map(|s| s.split('\\'))
+    }
+
+    // Decodes the value into an owned VrValue, the same the streaming parser would produce.
+    // Encapsulated pixel data is decoded without the Extended Offset Table, which is a separate tag.
+    pub fn to_value(&self) -> DicomResult<VrValue> {
+        let endian = self.syntax.endian_encoding;
+
+        if self.header().is_encapsulated() {
+            let pixel_data = dicom::read_encapsulated(&mut Cursor::new(self.bytes), self.value_offset, endian)?;
+            return Ok(VrValue::Encapsulated(pixel_data));
+        }
+
+        match self.tag() {
+            tags::ITEM                       => Ok(VrValue::Item),
+            _ if self.header().is_sequence() => Ok(VrValue::SequenceOfItems),
+            _                                => Ok(decoder::decode_value(self.vr_code, self.bytes.to_vec(), endian))
+        }
+    }
+
+    pub fn to_tag(&self) -> DicomResult<DicomTag> {
+        Ok(DicomTag {
+            group: self.group,
+            element: self.element,
+            syntax: self.syntax,
+            value: self.to_value()?,
+            value_length: self.value_length,
+            offset: self.offset
+        })
+    }
+}
+
+// Walks the tags of a Dicom file held in memory without copying their values. Tags come in file order,
+// sequences and items are followed by the tags they contain and closed by their delimiters, if any.
+pub struct SliceParser<'a> {
+    data: &'a [u8],
+    cursor: Cursor<&'a [u8]>,
+    syntax: TransferSyntax,
+    in_file_meta: bool,
+    dataset_syntax: Option<TransferSyntax>,
+    // Syntax of the open nodes of undefined length, the items of an explicit UN sequence are read with implicit VR.
+    nested_syntaxes: Vec<TransferSyntax>,
+    done: bool
+}
+
+impl<'a> SliceParser<'a> {
+    pub fn syntax(&self) -> TransferSyntax {
+        self.syntax
+    }
+
+    fn next_tag(&mut self) -> DicomResult<DicomTagRef<'a>> {
+        let pos = self.cursor.position();
+
+        if self.in_file_meta && self.data[pos as usize..].get(..2) != Some(&FILE_META_GROUP.to_le_bytes()[..]) {
+            self.in_file_meta = false;
+            self.syntax = match self.dataset_syntax {
+                Some(syntax) => syntax,
+                None         => dicom::detect_syntax(&mut self.cursor)?
+            };
+        }
+
+        // Deflated datasets have to be inflated into a buffer of their own, there is nothing to borrow from.
+        if self.syntax.compression == Compression::Deflate {
+            return Err(DicomError::UnsupportedTransferSyntax { offset: pos, uid: self.syntax.uid });
+        }
+
+        let syntax = self.nested_syntaxes.last().copied().unwrap_or(self.syntax);
+        let header = dicom::read_header(&mut self.cursor, pos, syntax)?;
+        let tag = header.tag();
+        let value_offset = self.cursor.position();
+
+        let is_delimiter = tag == tags::ITEM_DELIMITER || tag == tags::SEQUENCE_DELIMITER;
+        let is_node = header.is_sequence() || tag == tags::ITEM || is_delimiter;
+
+        match (is_node && header.has_undefined_length(), is_delimiter) {
+            (true, _)      => self.nested_syntaxes.push(header.items_syntax(syntax)),
+            (false, true)  => { self.nested_syntaxes.pop(); },
+            (false, false) => ()
+        }
+
+        let bytes = match is_node {
+            true  => &self.data[..0],
+            false => {
+                dicom::skip_value(&mut self.cursor, header, syntax)?;
+                self.data.get(value_offset as usize..self.cursor.position() as usize)
+                    .ok_or(DicomError::TruncatedValue { offset: header.offset, tag, length: header.value_length })?
+            }
+        };
+
+        let tag_ref = DicomTagRef {
+            group: header.group,
+            element: header.element,
+            vr_code: header.vr_code,
+            syntax,
+            value_length: header.value_length,
+            offset: header.offset,
+            bytes,
+            value_offset
+        };
+
+        if self.in_file_meta && tag == tags::TRANSFER_SYNTAX_UID {
+            self.dataset_syntax = tag_ref.as_str().map(TransferSyntax::parse_str);
+        }
+
+        Ok(tag_ref)
+    }
+}
+
+impl<'a> Iterator for SliceParser<'a> {
+    type Item = DicomResult<DicomTagRef<'a>>;
+
+    // Parsing stops at the first error, which is the last item returned.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.cursor.position() >= self.data.len() as u64 {
+            return None;
+        }
+
+        let tag = self.next_tag();
+        self.done = tag.is_err();
+
+        Some(tag)
+    }
+}
+
+// Parses a Part 10 file, the File Meta Information is reported as tags and gives the syntax of the dataset.
+pub fn parse_slice(data: &[u8]) -> SliceParser<'_> {
+    let preamble_end = dicom::PREAMBLE_LENGTH + PREAMBLE.len();
+
+    let start = match data.get(dicom::PREAMBLE_LENGTH..preamble_end) {
+        Some(PREAMBLE) => preamble_end,
+        _              => 0
+    };
+
+    let mut cursor = Cursor::new(data);
+    cursor.set_position(start as u64);
+
+    SliceParser { data, cursor, syntax: TransferSyntax::default(), in_file_meta: true, dataset_syntax: None, nested_syntaxes: Vec::new(), done: false }
+}
+
+// Parses a dataset with no preamble nor File Meta Information.
+pub fn parse_dataset_slice(data: &[u8], syntax: TransferSyntax) -> SliceParser<'_> {
+    SliceParser { data, cursor: Cursor::new(data), syntax, in_file_meta: false, dataset_syntax: None, nested_syntaxes: Vec::new(), done: false }
+}
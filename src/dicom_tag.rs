@@ -1,41 +1,88 @@
-use std::{fmt};
+use std::convert::TryFrom;
+use std::fmt;
 
 use crate::tags;
-use crate::transfer_syntax::TransferSyntax;
+use crate::utils;
+use crate::vr_type;
+use crate::dicom_error::{DicomError, DicomResult};
+use crate::date_time::{DicomDate, DicomDateTime, DicomRange, DicomTime, Temporal};
+use crate::encapsulated::EncapsulatedPixelData;
+use crate::transfer_syntax::{self, TransferSyntax, VrEncoding};
+
+// Value length marking sequences and items terminated by a delimitation item.
+pub const UNDEFINED_LENGTH: usize = 0xFFFF_FFFF;
 
 pub enum VrValue {
     Delimiter,
     SequenceOfItems,
-    Attribute(u16, u16),
-
-    UnsignedShort(u16),
-    SignedShort(i16),
-    UnsignedLong(u32),
-    SignedLong(i32),
-    Float(f32),
-    Double(f64),
-   
-    ApplicationEntity(String),
-    AgeString(String),
-    CodeString(String),
+    Item,
+    Attribute(Vec<(u16, u16)>),
+
+    UnsignedShort(Vec<u16>),
+    SignedShort(Vec<i16>),
+    UnsignedLong(Vec<u32>),
+    SignedLong(Vec<i32>),
+    Float(Vec<f32>),
+    Double(Vec<f64>),
+    SignedVeryLong(Vec<i64>),
+    UnsignedVeryLong(Vec<u64>),
+
+    ApplicationEntity(Vec<String>),
+    AgeString(Vec<String>),
+    CodeString(Vec<String>),
     LongText(String),
-    PersonName(String),
-    ShortString(String),
+    PersonName(Vec<String>),
+    ShortString(Vec<String>),
     ShortText(String),
     UnlimitedText(String),
+    UnlimitedCharacters(Vec<String>),
+    UniversalResource(String),
 
-    Date(String),
-    DateTime(String),
-    Time(String),
-    DecimalString(String),
-    IntegerString(String),
-    LongString(String),
-    Uid(String),
+    Date(Vec<String>),
+    DateTime(Vec<String>),
+    Time(Vec<String>),
+    DecimalString(Vec<String>),
+    IntegerString(Vec<String>),
+    LongString(Vec<String>),
+    Uid(Vec<String>),
 
     OtherByte(Vec<u8>),
     OtherFloat(Vec<u8>),
     OtherWord(Vec<u8>),
-    Unknown(Vec<u8>)
+    OtherDouble(Vec<f64>),
+    OtherLong(Vec<u32>),
+    OtherVeryLong(Vec<u64>),
+    Encapsulated(EncapsulatedPixelData),
+    Unknown(Vec<u8>),
+
+    // Value that could not be decoded as its VR, its length not being a multiple of the size of one value.
+    Raw { vr_code: u16, bytes: Vec<u8> },
+
+    // Value left in the source, offset is the position of its first byte and length the number of bytes it spans.
+    Deferred { vr_code: u16, offset: u64, length: usize }
+}
+
+// Displays multiple values separated by backslash, as they are encoded in Dicom.
+struct Multi<'a, T>(&'a [T]);
+
+impl <T: fmt::Display> fmt::Display for Multi<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "\\")?;
+            }
+            write!(f, "{}", v)?;
+        }
+        Ok(())
+    }
+}
+
+struct AttributeTag((u16, u16));
+
+impl fmt::Display for AttributeTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", (self.0).0, (self.0).1)
+    }
 }
 
 impl fmt::Display for VrValue {
@@ -43,58 +90,356 @@ impl fmt::Display for VrValue {
         match self {
             VrValue::Delimiter               => write!(f, "Delimiter"),
             VrValue::SequenceOfItems         => write!(f, "SequenceOfItems"),
-            VrValue::Attribute(group, value) => write!(f, "Attribute: ({}, {})", group, value),
-
-            VrValue::UnsignedShort(v)        => write!(f, "UnsignedShort: {}", v),
-            VrValue::SignedShort(v)          => write!(f, "SignedShort: {}", v),
-            VrValue::UnsignedLong(v)         => write!(f, "UnsignedLong: {}", v),
-            VrValue::SignedLong(v)           => write!(f, "SignedLong: {}", v),            
-            VrValue::Float(v)                => write!(f, "Float: {}", v),
-            VrValue::Double(v)               => write!(f, "Double: {}", v),
-           
-            VrValue::ApplicationEntity(s)    => write!(f, "ApplicationEntity: {}", s),
-            VrValue::AgeString(s)            => write!(f, "AgeString: {}", s),
-            VrValue::CodeString(s)           => write!(f, "CodeString: {}", s),
+            VrValue::Item                    => write!(f, "Item"),
+            VrValue::Attribute(v)            => {
+                let tags: Vec<AttributeTag> = v.iter().map(|t| AttributeTag(*t)).collect();
+                write!(f, "Attribute: {}", Multi(&tags))
+            },
+
+            VrValue::UnsignedShort(v)        => write!(f, "UnsignedShort: {}", Multi(v)),
+            VrValue::SignedShort(v)          => write!(f, "SignedShort: {}", Multi(v)),
+            VrValue::UnsignedLong(v)         => write!(f, "UnsignedLong: {}", Multi(v)),
+            VrValue::SignedLong(v)           => write!(f, "SignedLong: {}", Multi(v)),
+            VrValue::Float(v)                => write!(f, "Float: {}", Multi(v)),
+            VrValue::Double(v)               => write!(f, "Double: {}", Multi(v)),
+            VrValue::SignedVeryLong(v)       => write!(f, "SignedVeryLong: {}", Multi(v)),
+            VrValue::UnsignedVeryLong(v)     => write!(f, "UnsignedVeryLong: {}", Multi(v)),
+
+            VrValue::ApplicationEntity(s)    => write!(f, "ApplicationEntity: {}", Multi(s)),
+            VrValue::AgeString(s)            => write!(f, "AgeString: {}", Multi(s)),
+            VrValue::CodeString(s)           => write!(f, "CodeString: {}", Multi(s)),
             VrValue::LongText(s)             => write!(f, "LongText: {}", s),
-            VrValue::PersonName(s)           => write!(f, "PersonName: {}", s),
-            VrValue::ShortString(s)          => write!(f, "ShortString: {}", s),
+            VrValue::PersonName(s)           => write!(f, "PersonName: {}", Multi(s)),
+            VrValue::ShortString(s)          => write!(f, "ShortString: {}", Multi(s)),
             VrValue::ShortText(s)            => write!(f, "ShortText: {}", s),
             VrValue::UnlimitedText(s)        => write!(f, "UnlimitedText: {}", s),
- 
-            VrValue::Date(s)                 => write!(f, "Date: {}", s),
-            VrValue::DateTime(s)             => write!(f, "DateTime: {}", s),
-            VrValue::Time(s)                 => write!(f, "Time: {}", s),
-            VrValue::DecimalString(s)        => write!(f, "DecimalString: {}", s),
-            VrValue::IntegerString(s)        => write!(f, "IntegerString: {}", s),
-            VrValue::LongString(s)           => write!(f, "LongString: {}", s),
-            VrValue::Uid(s)                  => write!(f, "Uid: {}", s),
- 
+            VrValue::UnlimitedCharacters(s)  => write!(f, "UnlimitedCharacters: {}", Multi(s)),
+            VrValue::UniversalResource(s)    => write!(f, "UniversalResource: {}", s),
+
+            VrValue::Date(s)                 => write!(f, "Date: {}", Multi(s)),
+            VrValue::DateTime(s)             => write!(f, "DateTime: {}", Multi(s)),
+            VrValue::Time(s)                 => write!(f, "Time: {}", Multi(s)),
+            VrValue::DecimalString(s)        => write!(f, "DecimalString: {}", Multi(s)),
+            VrValue::IntegerString(s)        => write!(f, "IntegerString: {}", Multi(s)),
+            VrValue::LongString(s)           => write!(f, "LongString: {}", Multi(s)),
+            VrValue::Uid(s)                  => write!(f, "Uid: {}", Multi(s)),
+
             VrValue::OtherByte(bytes)        => write!(f, "OtherByte: {} bytes", bytes.len()),
             VrValue::OtherFloat(bytes)       => write!(f, "OtherFloat: {} bytes", bytes.len()),
             VrValue::OtherWord(bytes)        => write!(f, "OtherWord: {} bytes", bytes.len()),
-            VrValue::Unknown(bytes)          => write!(f, "Unknown: {} bytes", bytes.len())
+            VrValue::OtherDouble(v)          => write!(f, "OtherDouble: {} values", v.len()),
+            VrValue::OtherLong(v)            => write!(f, "OtherLong: {} values", v.len()),
+            VrValue::OtherVeryLong(v)        => write!(f, "OtherVeryLong: {} values", v.len()),
+            VrValue::Encapsulated(pixel_data) => write!(f, "Encapsulated: {} fragments", pixel_data.fragments.len()),
+            VrValue::Unknown(bytes)          => write!(f, "Unknown: {} bytes", bytes.len()),
+            VrValue::Raw { bytes, .. }       => write!(f, "Raw: {} bytes", bytes.len()),
+            VrValue::Deferred { offset, length, .. } => write!(f, "Deferred: {} bytes at offset {}", length, offset)
+        }
+    }
+}
+
+impl VrValue {
+    // Value multiplicity, the number of values encoded in the element.
+    pub fn vm(&self) -> usize {
+        match self {
+            VrValue::Delimiter               => 0,
+            VrValue::SequenceOfItems         => 1,
+            VrValue::Item                    => 1,
+            VrValue::Attribute(v)            => v.len(),
+
+            VrValue::UnsignedShort(v)        => v.len(),
+            VrValue::SignedShort(v)          => v.len(),
+            VrValue::UnsignedLong(v)         => v.len(),
+            VrValue::SignedLong(v)           => v.len(),
+            VrValue::Float(v)                => v.len(),
+            VrValue::Double(v)               => v.len(),
+            VrValue::SignedVeryLong(v)       => v.len(),
+            VrValue::UnsignedVeryLong(v)     => v.len(),
+
+            VrValue::ApplicationEntity(s)    => s.len(),
+            VrValue::AgeString(s)            => s.len(),
+            VrValue::CodeString(s)           => s.len(),
+            VrValue::PersonName(s)           => s.len(),
+            VrValue::ShortString(s)          => s.len(),
+            VrValue::Date(s)                 => s.len(),
+            VrValue::DateTime(s)             => s.len(),
+            VrValue::Time(s)                 => s.len(),
+            VrValue::DecimalString(s)        => s.len(),
+            VrValue::IntegerString(s)        => s.len(),
+            VrValue::LongString(s)           => s.len(),
+            VrValue::Uid(s)                  => s.len(),
+            VrValue::UnlimitedCharacters(s)  => s.len(),
+
+            // Text and binary values are single valued by definition.
+            VrValue::LongText(s)             => usize::from(!s.is_empty()),
+            VrValue::ShortText(s)            => usize::from(!s.is_empty()),
+            VrValue::UnlimitedText(s)        => usize::from(!s.is_empty()),
+            VrValue::UniversalResource(s)    => usize::from(!s.is_empty()),
+            VrValue::OtherByte(bytes)        => usize::from(!bytes.is_empty()),
+            VrValue::OtherFloat(bytes)       => usize::from(!bytes.is_empty()),
+            VrValue::OtherWord(bytes)        => usize::from(!bytes.is_empty()),
+            VrValue::OtherDouble(v)          => usize::from(!v.is_empty()),
+            VrValue::OtherLong(v)            => usize::from(!v.is_empty()),
+            VrValue::OtherVeryLong(v)        => usize::from(!v.is_empty()),
+            VrValue::Encapsulated(_)         => 1,
+            VrValue::Unknown(bytes)          => usize::from(!bytes.is_empty()),
+            VrValue::Raw { bytes, .. }       => usize::from(!bytes.is_empty()),
+            VrValue::Deferred { length, .. } => usize::from(*length > 0)
+        }
+    }
+}
+
+impl VrValue {
+    // VR the value is encoded with, None for items and delimiters which have none. Encapsulated pixel data is taken as OB.
+    pub fn vr_code(&self) -> Option<u16> {
+        let vr_code = match self {
+            VrValue::Delimiter | VrValue::Item => return None,
+            VrValue::SequenceOfItems         => vr_type::SEQUENCE_OF_ITEMS,
+            VrValue::Attribute(_)            => vr_type::ATTRIBUTE,
+
+            VrValue::UnsignedShort(_)        => vr_type::UNSIGNED_SHORT,
+            VrValue::SignedShort(_)          => vr_type::SIGNED_SHORT,
+            VrValue::UnsignedLong(_)         => vr_type::UNSIGNED_LONG,
+            VrValue::SignedLong(_)           => vr_type::SIGNED_LONG,
+            VrValue::Float(_)                => vr_type::FLOAT,
+            VrValue::Double(_)               => vr_type::DOUBLE,
+            VrValue::SignedVeryLong(_)       => vr_type::SIGNED_VERY_LONG,
+            VrValue::UnsignedVeryLong(_)     => vr_type::UNSIGNED_VERY_LONG,
+
+            VrValue::ApplicationEntity(_)    => vr_type::APPLICATION_ENTITY,
+            VrValue::AgeString(_)            => vr_type::AGE_STRING,
+            VrValue::CodeString(_)           => vr_type::CODE_STRING,
+            VrValue::LongText(_)             => vr_type::LONG_TEXT,
+            VrValue::PersonName(_)           => vr_type::PERSON_NAME,
+            VrValue::ShortString(_)          => vr_type::SHORT_STRING,
+            VrValue::ShortText(_)            => vr_type::SHORT_TEXT,
+            VrValue::UnlimitedText(_)        => vr_type::UNLIMITED_TEXT,
+            VrValue::UnlimitedCharacters(_)  => vr_type::UNLIMITED_CHARACTERS,
+            VrValue::UniversalResource(_)    => vr_type::UNIVERSAL_RESOURCE,
+
+            VrValue::Date(_)                 => vr_type::DATE,
+            VrValue::DateTime(_)             => vr_type::DATE_TIME,
+            VrValue::Time(_)                 => vr_type::TIME,
+            VrValue::DecimalString(_)        => vr_type::DECIMAL_STRING,
+            VrValue::IntegerString(_)        => vr_type::INTEGER_STRING,
+            VrValue::LongString(_)           => vr_type::LONG_STRING,
+            VrValue::Uid(_)                  => vr_type::UID,
+
+            VrValue::OtherByte(_)            => vr_type::OTHER_BYTE,
+            VrValue::OtherFloat(_)           => vr_type::OTHER_FLOAT,
+            VrValue::OtherWord(_)            => vr_type::OTHER_WORD,
+            VrValue::OtherDouble(_)          => vr_type::OTHER_DOUBLE,
+            VrValue::OtherLong(_)            => vr_type::OTHER_LONG,
+            VrValue::OtherVeryLong(_)        => vr_type::OTHER_VERY_LONG,
+            VrValue::Encapsulated(_)         => vr_type::OTHER_BYTE,
+            VrValue::Unknown(_)              => vr_type::UNKNOWN,
+            VrValue::Raw { vr_code, .. }     => *vr_code,
+            VrValue::Deferred { vr_code, .. } => *vr_code
+        };
+
+        Some(vr_code)
+    }
+}
+
+impl VrValue {
+    // Values of string VRs without their padding, None for other VRs.
+    pub fn strings(&self) -> Option<Vec<&str>> {
+        let values: Vec<&str> = match self {
+            VrValue::ApplicationEntity(s) | VrValue::AgeString(s) | VrValue::CodeString(s) | VrValue::PersonName(s) |
+            VrValue::ShortString(s) | VrValue::UnlimitedCharacters(s) | VrValue::Date(s) | VrValue::DateTime(s) |
+            VrValue::Time(s) | VrValue::DecimalString(s) | VrValue::IntegerString(s) | VrValue::LongString(s) |
+            VrValue::Uid(s)                  => s.iter().map(String::as_str).collect(),
+            VrValue::LongText(s) | VrValue::ShortText(s) | VrValue::UnlimitedText(s) |
+            VrValue::UniversalResource(s)    => vec![s.as_str()],
+            _                                => return None
+        };
+
+        Some(values.into_iter().map(utils::trim_padding).collect())
+    }
+
+    // Values of numeric VRs as f64, decimal and integer strings parsed. None for other VRs and for strings that do not parse.
+    pub fn numbers(&self) -> Option<Vec<f64>> {
+        match self {
+            VrValue::UnsignedShort(v)        => Some(v.iter().map(|n| f64::from(*n)).collect()),
+            VrValue::SignedShort(v)          => Some(v.iter().map(|n| f64::from(*n)).collect()),
+            VrValue::UnsignedLong(v)         => Some(v.iter().map(|n| f64::from(*n)).collect()),
+            VrValue::SignedLong(v)           => Some(v.iter().map(|n| f64::from(*n)).collect()),
+            VrValue::Float(v)                => Some(v.iter().map(|n| f64::from(*n)).collect()),
+            VrValue::Double(v)               => Some(v.clone()),
+            VrValue::OtherDouble(v)          => Some(v.clone()),
+            VrValue::SignedVeryLong(v)       => Some(v.iter().map(|n| *n as f64).collect()),
+            VrValue::UnsignedVeryLong(v)     => Some(v.iter().map(|n| *n as f64).collect()),
+            VrValue::DecimalString(s)        => s.iter().map(|v| v.trim_matches(['\0', ' ']).parse().ok()).collect(),
+            VrValue::IntegerString(s)        => s.iter().map(|v| v.trim_matches(['\0', ' ']).parse().ok()).collect(),
+            _                                => None
+        }
+    }
+
+    // Values of integer VRs, integer strings parsed. None for other VRs and for strings that do not parse.
+    pub fn integers(&self) -> Option<Vec<i64>> {
+        match self {
+            VrValue::UnsignedShort(v)        => Some(v.iter().map(|n| i64::from(*n)).collect()),
+            VrValue::SignedShort(v)          => Some(v.iter().map(|n| i64::from(*n)).collect()),
+            VrValue::UnsignedLong(v)         => Some(v.iter().map(|n| i64::from(*n)).collect()),
+            VrValue::SignedLong(v)           => Some(v.iter().map(|n| i64::from(*n)).collect()),
+            VrValue::SignedVeryLong(v)       => Some(v.clone()),
+            VrValue::UnsignedVeryLong(v)     => v.iter().map(|n| i64::try_from(*n).ok()).collect(),
+            VrValue::IntegerString(s)        => s.iter().map(|v| v.trim_matches(['\0', ' ']).parse().ok()).collect(),
+            _                                => None
+        }
+    }
+
+    // Values of DA, TM and DT VRs parsed. None for other VRs and for strings that are not dates or times.
+    pub fn dates(&self) -> Option<Vec<DicomDate>> {
+        self.temporals()
+    }
+
+    pub fn times(&self) -> Option<Vec<DicomTime>> {
+        self.temporals()
+    }
+
+    pub fn date_times(&self) -> Option<Vec<DicomDateTime>> {
+        self.temporals()
+    }
+
+    // Values of DA, TM and DT VRs parsed as the range matching of query datasets.
+    pub fn ranges<T: Temporal>(&self) -> Option<Vec<DicomRange<T>>> {
+        self.temporal_strings::<T>()?.into_iter().map(DicomRange::parse).collect()
+    }
+
+    fn temporals<T: Temporal>(&self) -> Option<Vec<T>> {
+        self.temporal_strings::<T>()?.into_iter().map(T::parse).collect()
+    }
+
+    fn temporal_strings<T: Temporal>(&self) -> Option<Vec<&str>> {
+        match self {
+            VrValue::Date(_) | VrValue::Time(_) | VrValue::DateTime(_) if self.vr_code() == Some(T::VR) => self.strings(),
+            _                                                                                        => None
         }
     }
 }
 
+// Tag, VR and length of an element, read before its value so handlers can decide whether the value is worth reading.
+#[derive(Copy, Clone, Debug)]
+pub struct TagHeader {
+    pub group: u16,
+    pub element: u16,
+    pub vr_code: u16,
+    pub value_length: usize,
+    pub offset: u64
+}
+
+impl TagHeader {
+    pub fn tag(&self) -> (u16, u16) {
+        (self.group, self.element)
+    }
+
+    pub fn has_undefined_length(&self) -> bool {
+        self.value_length == UNDEFINED_LENGTH
+    }
+
+    // Pixel data of undefined length holds compressed frames split in fragment items.
+    pub fn is_encapsulated(&self) -> bool {
+        match (self.tag(), self.vr_code) {
+            (tags::PIXEL_DATA, vr_type::OTHER_BYTE) => self.has_undefined_length(),
+            (tags::PIXEL_DATA, vr_type::OTHER_WORD) => self.has_undefined_length(),
+            (tags::PIXEL_DATA, vr_type::UNKNOWN)    => self.has_undefined_length(),
+            (_, _)                                  => false
+        }
+    }
+
+    // Values of unknown VR and undefined length are sequences, such as private sequences read with implicit VR.
+    pub fn is_sequence(&self) -> bool {
+        match self.vr_code {
+            vr_type::SEQUENCE_OF_ITEMS => true,
+            vr_type::UNKNOWN           => self.has_undefined_length() && !self.is_encapsulated(),
+            _                          => false
+        }
+    }
+
+    // Syntax of the items of a node, those of an explicit UN sequence are encoded in Implicit VR Little Endian (PS3.5 6.2.2).
+    pub(crate) fn items_syntax(&self, syntax: TransferSyntax) -> TransferSyntax {
+        match self.vr_code == vr_type::UNKNOWN && syntax.vr_encoding == VrEncoding::Explicit {
+            true  => TransferSyntax::parse_str(transfer_syntax::IMPLICIT_LE),
+            false => syntax
+        }
+    }
+
+    // The tag reported in place of a value left unread in the source, value_offset being where the value starts and length
+    // the bytes it spans, fragments and delimiter included for encapsulated pixel data.
+    pub(crate) fn deferred(&self, syntax: TransferSyntax, value_offset: u64, length: u64) -> DicomTag {
+        let value = VrValue::Deferred { vr_code: self.vr_code, offset: value_offset, length: length as usize };
+        DicomTag { group: self.group, element: self.element, syntax, value, value_length: self.value_length, offset: self.offset }
+    }
+}
+
 pub struct DicomTag {
     pub group: u16,
     pub element: u16,
     pub syntax: TransferSyntax,
     pub value: VrValue,
-    pub value_length: usize
+    pub value_length: usize,
+    pub offset: u64
 }
 
 impl DicomTag {
-    pub fn try_transfer_syntax(&self) -> Option<TransferSyntax> {
+    pub fn vm(&self) -> usize {
+        self.value.vm()
+    }
+
+    pub fn has_undefined_length(&self) -> bool {
+        self.value_length == UNDEFINED_LENGTH
+    }
+
+    // Typed values, failing with InvalidValue when the VR holds another kind of value or the strings do not parse.
+    pub fn to_strs(&self) -> DicomResult<Vec<&str>> {
+        self.value.strings().ok_or_else(|| self.invalid_value("strings"))
+    }
+
+    // First string value, empty when the element has none.
+    pub fn to_str(&self) -> DicomResult<&str> {
+        self.to_strs().map(|values| values.first().copied().unwrap_or_default())
+    }
+
+    pub fn to_u16s(&self) -> DicomResult<Vec<u16>> {
+        self.value.integers()
+            .and_then(|values| values.into_iter().map(|v| u16::try_from(v).ok()).collect())
+            .ok_or_else(|| self.invalid_value("u16 values"))
+    }
+
+    pub fn to_f64s(&self) -> DicomResult<Vec<f64>> {
+        self.value.numbers().ok_or_else(|| self.invalid_value("f64 values"))
+    }
+
+    pub fn to_dates(&self) -> DicomResult<Vec<DicomDate>> {
+        self.value.dates().ok_or_else(|| self.invalid_value("dates"))
+    }
+
+    pub fn to_times(&self) -> DicomResult<Vec<DicomTime>> {
+        self.value.times().ok_or_else(|| self.invalid_value("times"))
+    }
+
+    pub fn to_date_times(&self) -> DicomResult<Vec<DicomDateTime>> {
+        self.value.date_times().ok_or_else(|| self.invalid_value("date times"))
+    }
+
+    pub fn to_ranges<T: Temporal>(&self) -> DicomResult<Vec<DicomRange<T>>> {
+        self.value.ranges().ok_or_else(|| self.invalid_value("date or time ranges"))
+    }
+
+    pub(crate) fn invalid_value(&self, expected: &'static str) -> DicomError {
+        DicomError::InvalidValue { offset: self.offset, tag: (self.group, self.element), expected }
+    }
+
+    pub fn try_transfer_syntax(&self) -> DicomResult<Option<TransferSyntax>> {
         match ((self.group, self.element), &self.value) {
-            (tags::TRANSFER_SYNTAX_UID, VrValue::Uid(syntax)) => {
-                //  TODO: tags representing child syntax should have their own type.
-                //let syntax = str::from_utf8(value).unwrap();
-                Some(TransferSyntax::parse_str(&syntax))
-            },
-            (tags::TRANSFER_SYNTAX_UID, _)           => panic!("Transfer syntax cannot be encoded in a numeric value"),
-            (_, _)                                   => None
+            //  TODO: tags representing child syntax should have their own type.
+            (tags::TRANSFER_SYNTAX_UID, VrValue::Uid(uids)) if uids.len() == 1 => Ok(Some(TransferSyntax::parse_str(&uids[0]))),
+            (tags::TRANSFER_SYNTAX_UID, _)                                     => Err(DicomError::InvalidTransferSyntax {
+                offset: self.offset,
+                tag: tags::TRANSFER_SYNTAX_UID
+            }),
+            (_, _)                                                             => Ok(None)
         }
     }
 }
\ No newline at end of file
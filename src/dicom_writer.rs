@@ -0,0 +1,309 @@
+use std::io::{Read, Seek, Write};
+
+use flate2::write::DeflateEncoder;
+
+use crate::dicom::{self, PREAMBLE_LENGTH};
+use crate::decoder;
+use crate::encoder;
+use crate::dicom_object;
+use crate::tags;
+use crate::vr_type;
+use crate::dicom_error::{DicomError, DicomResult};
+use crate::dicom_handlers::DicomContainer;
+use crate::dicom_object::DicomObject;
+use crate::dicom_tag::{DicomTag, VrValue, UNDEFINED_LENGTH};
+use crate::transfer_syntax::{Compression, EndianEncoding, TransferSyntax, VrEncoding};
+
+// How the lengths of sequences and items are written.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SequenceLength {
+    // Undefined when they were read delimited or were inserted with insert_sequence, defined otherwise.
+    AsRead,
+    Defined,
+    Undefined
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct WriteOptions {
+    // Syntax of the dataset, the File Meta Information is always Explicit VR Little Endian.
+    pub syntax: TransferSyntax,
+    pub sequence_length: SequenceLength,
+    pub preamble: [u8; PREAMBLE_LENGTH]
+}
+
+impl WriteOptions {
+    pub fn new(syntax: TransferSyntax) -> WriteOptions {
+        WriteOptions { syntax, sequence_length: SequenceLength::AsRead, preamble: [0; PREAMBLE_LENGTH] }
+    }
+}
+
+impl Default for WriteOptions {
+    fn default() -> WriteOptions {
+        WriteOptions::new(TransferSyntax::default())
+    }
+}
+
+// Dataset to write, borrowed from a DicomObject or a DicomContainer.
+enum Node<'a> {
+    Element(&'a DicomTag),
+    Sequence(&'a DicomTag, Vec<Item<'a>>)
+}
+
+struct Item<'a> {
+    undefined_length: bool,
+    nodes: Vec<Node<'a>>
+}
+
+impl Node<'_> {
+    fn tag(&self) -> &DicomTag {
+        match self {
+            Node::Element(tag) | Node::Sequence(tag, _) => tag
+        }
+    }
+}
+
+fn object_nodes(object: &DicomObject) -> Vec<Node<'_>> {
+    object.elements().iter().map(|element| match element.is_sequence() {
+        true  => Node::Sequence(element.tag(), element.items().iter()
+            .map(|item| Item { undefined_length: item.has_undefined_length(), nodes: object_nodes(item) })
+            .collect()),
+        false => Node::Element(element.tag())
+    }).collect()
+}
+
+fn container_nodes(container: &DicomContainer, index: usize) -> Vec<Node<'_>> {
+    container.nodes[index].children().iter().filter_map(tag_of(container)).map(|(child, tag)| match tag.value {
+        VrValue::SequenceOfItems => Node::Sequence(tag, container.nodes[child].children().iter().filter_map(tag_of(container))
+            .map(|(item, item_tag)| Item { undefined_length: item_tag.has_undefined_length(), nodes: container_nodes(container, item) })
+            .collect()),
+        _                        => Node::Element(tag)
+    }).collect()
+}
+
+fn tag_of<'a>(container: &'a DicomContainer) -> impl Fn(&usize) -> Option<(usize, &'a DicomTag)> {
+    move |index| container.nodes[*index].tag().map(|tag| (*index, tag))
+}
+
+// Bytes written so far are counted to report where writing failed.
+struct Output<W> {
+    writer: W,
+    pos: u64,
+    syntax: TransferSyntax,
+    sequence_length: SequenceLength
+}
+
+impl<W: Write> Output<W> {
+    fn bytes(&mut self, bytes: &[u8], tag: Option<(u16, u16)>) -> DicomResult<()> {
+        self.writer.write_all(bytes).map_err(DicomError::io(self.pos, tag))?;
+        self.pos += bytes.len() as u64;
+        Ok(())
+    }
+
+    fn header(&mut self, tag: (u16, u16), vr_code: u16, length: usize) -> DicomResult<()> {
+        match encoder::element_header(tag, vr_code, length, self.syntax) {
+            Some(header) => self.bytes(&header, Some(tag)),
+            None         => Err(DicomError::BadLength { offset: self.pos, tag, length: length as u32 })
+        }
+    }
+
+    fn node(&mut self, node: &Node) -> DicomResult<()> {
+        match node {
+            Node::Element(tag)         => self.element(tag),
+            Node::Sequence(tag, items) => self.sequence(tag, items)
+        }
+    }
+
+    fn element(&mut self, tag: &DicomTag) -> DicomResult<()> {
+        let id = (tag.group, tag.element);
+        let vr_code = tag.value.vr_code().unwrap_or(vr_type::UNKNOWN);
+        let value = encoder::encode_value(&tag.value, self.syntax.endian_encoding).ok_or_else(|| tag.invalid_value("a value held in memory"))?;
+
+        let length = match tag.value {
+            VrValue::Encapsulated(_) => UNDEFINED_LENGTH,
+            _                        => value.len()
+        };
+
+        self.header(id, vr_code, length)?;
+        self.bytes(&value, Some(id))
+    }
+
+    fn sequence(&mut self, tag: &DicomTag, items: &[Item]) -> DicomResult<()> {
+        let id = (tag.group, tag.element);
+
+        match self.is_undefined(tag.has_undefined_length()) {
+            true  => {
+                self.header(id, vr_type::SEQUENCE_OF_ITEMS, UNDEFINED_LENGTH)?;
+                items.iter().try_for_each(|item| self.item(item))?;
+                self.bytes(&encoder::item_header(tags::SEQUENCE_DELIMITER, 0, self.syntax.endian_encoding), Some(id))
+            },
+            false => {
+                let header_length = encoder::header_length(vr_type::SEQUENCE_OF_ITEMS, self.syntax.vr_encoding);
+                let content = self.buffered(header_length, |out| items.iter().try_for_each(|item| out.item(item)))?;
+                self.header(id, vr_type::SEQUENCE_OF_ITEMS, content.len())?;
+                self.bytes(&content, Some(id))
+            }
+        }
+    }
+
+    fn item(&mut self, item: &Item) -> DicomResult<()> {
+        let endian = self.syntax.endian_encoding;
+
+        match self.is_undefined(item.undefined_length) {
+            true  => {
+                self.bytes(&encoder::item_header(tags::ITEM, UNDEFINED_LENGTH, endian), Some(tags::ITEM))?;
+                item.nodes.iter().try_for_each(|node| self.node(node))?;
+                self.bytes(&encoder::item_header(tags::ITEM_DELIMITER, 0, endian), Some(tags::ITEM))
+            },
+            false => {
+                let content = self.buffered(encoder::ITEM_HEADER_LENGTH, |out| item.nodes.iter().try_for_each(|node| out.node(node)))?;
+                self.bytes(&encoder::item_header(tags::ITEM, content.len(), endian), Some(tags::ITEM))?;
+                self.bytes(&content, Some(tags::ITEM))
+            }
+        }
+    }
+
+    fn is_undefined(&self, as_read: bool) -> bool {
+        match self.sequence_length {
+            SequenceLength::AsRead    => as_read,
+            SequenceLength::Defined   => false,
+            SequenceLength::Undefined => true
+        }
+    }
+
+    // Content whose length goes in a header written before it, offsets start after that header.
+    fn buffered(&self, header_length: usize, write: impl FnOnce(&mut Output<Vec<u8>>) -> DicomResult<()>) -> DicomResult<Vec<u8>> {
+        let mut out = Output { writer: Vec::new(), pos: self.pos + header_length as u64, syntax: self.syntax, sequence_length: self.sequence_length };
+        write(&mut out)?;
+        Ok(out.writer)
+    }
+}
+
+// Writes a Part 10 file: the preamble, the DICM mark, the File Meta Information with its group length recomputed
+// and the dataset. The Transfer Syntax UID written is the one of options.syntax, other File Meta Information
+// elements are written as they are. Values are padded to even length, deferred values cannot be written.
+pub fn write(writer: &mut impl Write, object: &DicomObject, options: &WriteOptions) -> DicomResult<()> {
+    write_file(writer, object_nodes(object), options)
+}
+
+// Same as write, tags are written in the order they were parsed.
+pub fn write_container(writer: &mut impl Write, container: &DicomContainer, options: &WriteOptions) -> DicomResult<()> {
+    write_file(writer, container_nodes(container, 0), options)
+}
+
+fn write_file(writer: &mut impl Write, nodes: Vec<Node>, options: &WriteOptions) -> DicomResult<()> {
+    let syntax = options.syntax;
+    if syntax.uid.is_empty() {
+        return Err(DicomError::UnsupportedTransferSyntax { offset: 0, uid: syntax.name });
+    }
+
+    let syntax_uid = new_tag(tags::TRANSFER_SYNTAX_UID, VrValue::Uid(vec![syntax.uid.to_string()]));
+    let (meta, dataset): (Vec<Node>, Vec<Node>) = nodes.into_iter().partition(|node| node.tag().group == 0x0002);
+    let meta = with_syntax_uid(meta, &syntax_uid);
+
+    let mut out = Output { writer, pos: 0, syntax: TransferSyntax::default(), sequence_length: options.sequence_length };
+    out.bytes(&options.preamble, None)?;
+    out.bytes(dicom::STANDARD_PREAMBLE.as_bytes(), None)?;
+
+    let group_length_size = encoder::header_length(vr_type::UNSIGNED_LONG, out.syntax.vr_encoding) + 4;
+    let group = out.buffered(group_length_size, |meta_out| meta.iter().try_for_each(|node| meta_out.node(node)))?;
+    out.element(&new_tag(tags::FILE_META_GROUP_LENGTH, VrValue::UnsignedLong(vec![group.len() as u32])))?;
+    out.bytes(&group, None)?;
+
+    let pos = out.pos;
+    let sequence_length = out.sequence_length;
+
+    match syntax.compression {
+        Compression::Deflate => {
+            let mut deflated = Output { writer: DeflateEncoder::new(out.writer, flate2::Compression::default()), pos: 0, syntax, sequence_length };
+            dataset.iter().try_for_each(|node| deflated.node(node))?;
+            deflated.writer.finish().and_then(|writer| writer.flush()).map_err(DicomError::io(pos, None))
+        },
+        _                    => {
+            out.syntax = syntax;
+            dataset.iter().try_for_each(|node| out.node(node))?;
+            out.writer.flush().map_err(DicomError::io(out.pos, None))
+        }
+    }
+}
+
+// Replaces the Transfer Syntax UID, or inserts it in tag order. A UID naming the same syntax is kept with its padding.
+fn with_syntax_uid<'a>(meta: Vec<Node<'a>>, syntax_uid: &'a DicomTag) -> Vec<Node<'a>> {
+    let same_syntax = |tag: &DicomTag| tag.try_transfer_syntax().ok().flatten().map(|syntax| syntax.uid) == syntax_uid.to_str().ok();
+
+    let mut meta: Vec<Node> = meta.into_iter()
+        .filter(|node| (node.tag().group, node.tag().element) != tags::FILE_META_GROUP_LENGTH)
+        .map(|node| match (node.tag().group, node.tag().element) == tags::TRANSFER_SYNTAX_UID && !same_syntax(node.tag()) {
+            true  => Node::Element(syntax_uid),
+            false => node
+        })
+        .collect();
+
+    if !meta.iter().any(|node| (node.tag().group, node.tag().element) == tags::TRANSFER_SYNTAX_UID) {
+        let index = meta.iter().position(|node| node.tag().element > tags::TRANSFER_SYNTAX_UID.1).unwrap_or(meta.len());
+        meta.insert(index, Node::Element(syntax_uid));
+    }
+
+    meta
+}
+
+// File Meta Information elements made by the writer, not read from a source.
+fn new_tag(tag: (u16, u16), value: VrValue) -> DicomTag {
+    let value_length = encoder::value_length(&value);
+    DicomTag { group: tag.0, element: tag.1, syntax: TransferSyntax::default(), value, value_length, offset: 0 }
+}
+
+// Rewrites a file in another syntax, Implicit VR Little Endian, Explicit VR Little or Big Endian, or deflated.
+// The preamble and File Meta Information are kept, the Transfer Syntax UID updated. Values of unknown VR are
+// given the VR of the dictionary, so their bytes are swapped as they should when changing the byte order.
+pub fn transcode(input: &mut (impl Read + Seek), output: &mut impl Write, syntax: TransferSyntax) -> DicomResult<()> {
+    check_uncompressed(syntax)?;
+
+    let preamble = dicom::read_preamble(input)?;
+    let mut object = dicom_object::parse(input)?;
+
+    let source = object.syntax();
+    check_uncompressed(source)?;
+    resolve_vrs(&mut object, source);
+
+    let options = WriteOptions { preamble: preamble.unwrap_or([0; PREAMBLE_LENGTH]), ..WriteOptions::new(syntax) };
+    write(output, &object, &options)
+}
+
+// Syntaxes whose pixel data is not encapsulated, the only ones values can be converted between.
+fn check_uncompressed(syntax: TransferSyntax) -> DicomResult<()> {
+    match !syntax.encapsulated && matches!(syntax.compression, Compression::None | Compression::Deflate) {
+        true  => Ok(()),
+        false => Err(DicomError::UnsupportedTransferSyntax { offset: 0, uid: syntax.uid })
+    }
+}
+
+// Implicit VR gives pixel data the OB of the dictionary where the standard makes it OW for samples of more than 8 bits,
+// or when Bits Allocated is missing (PS3.5 A.1), words are then swapped when writing big endian. Unknown values of tags in the dictionary are decoded with the byte order of the source,
+// the one of their value whether they were read as explicit UN or converted from Implicit VR Little Endian.
+fn resolve_vrs(object: &mut DicomObject, source: TransferSyntax) {
+    let pixel_words = source.vr_encoding == VrEncoding::Implicit && bits_allocated(object).is_none_or(|bits| bits > 8);
+
+    for element in object.elements.iter_mut() {
+        element.items.iter_mut().for_each(|item| resolve_vrs(item, source));
+
+        let tag = &mut element.tag;
+        let id = (tag.group, tag.element);
+
+        tag.value = match std::mem::replace(&mut tag.value, VrValue::Delimiter) {
+            VrValue::OtherByte(bytes) if pixel_words && id == tags::PIXEL_DATA => VrValue::OtherWord(bytes),
+            VrValue::Unknown(bytes)                                         => resolve_unknown(id, bytes, source.endian_encoding),
+            value                                                           => value
+        };
+    }
+}
+
+fn bits_allocated(object: &DicomObject) -> Option<u16> {
+    object.get(tags::BITS_ALLOCATED)?.tag().to_u16s().ok()?.first().copied()
+}
+
+fn resolve_unknown(tag: (u16, u16), bytes: Vec<u8>, endian: EndianEncoding) -> VrValue {
+    match tags::tag_vr_type(tag.0, tag.1) {
+        vr_type::UNKNOWN | vr_type::SEQUENCE_OF_ITEMS | vr_type::DELIMITER => VrValue::Unknown(bytes),
+        vr_code                                                           => decoder::decode_value(vr_code, bytes, endian)
+    }
+}
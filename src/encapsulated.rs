@@ -0,0 +1,77 @@
+use std::ops::Range;
+
+// Item header preceding each fragment: tag and length, 4 bytes each.
+const ITEM_HEADER_LENGTH: u64 = 8;
+
+pub struct Fragment {
+    pub offset: u64,
+    pub data: Vec<u8>
+}
+
+pub struct EncapsulatedPixelData {
+    pub basic_offset_table: Vec<u32>,
+    pub extended_offset_table: Option<Vec<u64>>,
+    pub extended_offset_table_lengths: Option<Vec<u64>>,
+    pub fragments: Vec<Fragment>
+}
+
+impl EncapsulatedPixelData {
+    pub fn new(basic_offset_table: Vec<u32>, fragments: Vec<Fragment>) -> EncapsulatedPixelData {
+        EncapsulatedPixelData {
+            basic_offset_table,
+            extended_offset_table: None,
+            extended_offset_table_lengths: None,
+            fragments
+        }
+    }
+
+    // Offset of each fragment item relative to the first one, as used by the offset tables.
+    fn relative_offset(&self, fragment: &Fragment) -> u64 {
+        match self.fragments.first() {
+            Some(first) => fragment.offset - first.offset,
+            None        => 0
+        }
+    }
+
+    fn fragment_index(&self, relative_offset: u64) -> Option<usize> {
+        self.fragments.iter().position(|f| self.relative_offset(f) == relative_offset)
+    }
+
+    pub fn frame_count(&self) -> Option<usize> {
+        match (&self.extended_offset_table, self.basic_offset_table.len()) {
+            (Some(table), _) => Some(table.len()),
+            (None, 0)        => None,
+            (None, n)        => Some(n)
+        }
+    }
+
+    // Indices of the fragments holding a frame. Frames can only be located through an offset table,
+    // with an empty Basic Offset Table and no Extended Offset Table the frame boundaries are unknown.
+    pub fn frame_fragments(&self, frame: usize) -> Option<Range<usize>> {
+        let (start, end) = match &self.extended_offset_table {
+            Some(table) => {
+                let start = *table.get(frame)?;
+                let end = match &self.extended_offset_table_lengths {
+                    Some(lengths) => lengths.get(frame).map(|l| start + l + ITEM_HEADER_LENGTH),
+                    None          => table.get(frame + 1).copied()
+                };
+                (start, end)
+            },
+            None => {
+                let start = u64::from(*self.basic_offset_table.get(frame)?);
+                (start, self.basic_offset_table.get(frame + 1).map(|o| u64::from(*o)))
+            }
+        };
+
+        let first = self.fragment_index(start)?;
+        let last = match end {
+            Some(end) => self.fragments.iter().rposition(|f| self.relative_offset(f) < end)?,
+            None      => self.fragments.len() - 1
+        };
+
+        match first <= last {
+            true  => Some(first..last + 1),
+            false => None
+        }
+    }
+}
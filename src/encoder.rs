@@ -0,0 +1,171 @@
+use std::convert::TryFrom;
+
+use crate::dicom_tag::{VrValue, UNDEFINED_LENGTH};
+use crate::encapsulated::EncapsulatedPixelData;
+use crate::tags;
+use crate::vr_type;
+use crate::transfer_syntax::{EndianEncoding, TransferSyntax, VrEncoding};
+
+// Tag and length of an item or a delimitation item, 4 bytes each.
+pub const ITEM_HEADER_LENGTH: usize = 8;
+
+// Encoding of values to their raw bytes, the reverse of the decoder. Values are padded to even length,
+// None for deferred values which are not held in memory.
+pub fn encode_value(value: &VrValue, endian: EndianEncoding) -> Option<Vec<u8>> {
+    let mut bytes = match value {
+        VrValue::Delimiter | VrValue::SequenceOfItems | VrValue::Item => Vec::new(),
+        VrValue::Attribute(v)            => v.iter().flat_map(|(g, e)| [words(*g, endian), words(*e, endian)].concat()).collect(),
+
+        VrValue::UnsignedShort(v)        => v.iter().flat_map(|n| endian.convert(n.to_le_bytes(), n.to_be_bytes())).collect(),
+        VrValue::SignedShort(v)          => v.iter().flat_map(|n| endian.convert(n.to_le_bytes(), n.to_be_bytes())).collect(),
+        VrValue::UnsignedLong(v)         => v.iter().flat_map(|n| endian.convert(n.to_le_bytes(), n.to_be_bytes())).collect(),
+        VrValue::SignedLong(v)           => v.iter().flat_map(|n| endian.convert(n.to_le_bytes(), n.to_be_bytes())).collect(),
+        VrValue::Float(v)                => v.iter().flat_map(|n| endian.convert(n.to_le_bytes(), n.to_be_bytes())).collect(),
+        VrValue::Double(v)               => v.iter().flat_map(|n| endian.convert(n.to_le_bytes(), n.to_be_bytes())).collect(),
+        VrValue::SignedVeryLong(v)       => v.iter().flat_map(|n| endian.convert(n.to_le_bytes(), n.to_be_bytes())).collect(),
+        VrValue::UnsignedVeryLong(v)     => v.iter().flat_map(|n| endian.convert(n.to_le_bytes(), n.to_be_bytes())).collect(),
+
+        VrValue::ApplicationEntity(s) | VrValue::AgeString(s) | VrValue::CodeString(s) | VrValue::PersonName(s) |
+        VrValue::ShortString(s) | VrValue::UnlimitedCharacters(s) | VrValue::Date(s) | VrValue::DateTime(s) |
+        VrValue::Time(s) | VrValue::DecimalString(s) | VrValue::IntegerString(s) | VrValue::LongString(s) |
+        VrValue::Uid(s)                  => s.join("\\").into_bytes(),
+        VrValue::LongText(s) | VrValue::ShortText(s) | VrValue::UnlimitedText(s) |
+        VrValue::UniversalResource(s)    => s.as_bytes().to_vec(),
+
+        VrValue::OtherByte(bytes)        => bytes.clone(),
+        VrValue::OtherFloat(bytes)       => other(bytes, 4, endian),
+        VrValue::OtherWord(bytes)        => other(bytes, 2, endian),
+        VrValue::OtherDouble(v)          => v.iter().flat_map(|n| endian.convert(n.to_le_bytes(), n.to_be_bytes())).collect(),
+        VrValue::OtherLong(v)            => v.iter().flat_map(|n| endian.convert(n.to_le_bytes(), n.to_be_bytes())).collect(),
+        VrValue::OtherVeryLong(v)        => v.iter().flat_map(|n| endian.convert(n.to_le_bytes(), n.to_be_bytes())).collect(),
+        VrValue::Encapsulated(pixels)    => return Some(encapsulated(pixels, endian)),
+        VrValue::Unknown(bytes)          => bytes.clone(),
+        VrValue::Raw { bytes, .. }       => bytes.clone(),
+        VrValue::Deferred { .. }         => return None
+    };
+
+    if bytes.len() % 2 == 1 {
+        bytes.push(padding(value));
+    }
+
+    Some(bytes)
+}
+
+// Length encode_value produces, without encoding. Encapsulated pixel data has an undefined length.
+pub fn value_length(value: &VrValue) -> usize {
+    let length = match value {
+        VrValue::Delimiter | VrValue::SequenceOfItems | VrValue::Item => 0,
+        VrValue::Attribute(v)            => v.len() * 4,
+
+        VrValue::UnsignedShort(v)        => v.len() * 2,
+        VrValue::SignedShort(v)          => v.len() * 2,
+        VrValue::UnsignedLong(v)         => v.len() * 4,
+        VrValue::SignedLong(v)           => v.len() * 4,
+        VrValue::Float(v)                => v.len() * 4,
+        VrValue::Double(v)               => v.len() * 8,
+        VrValue::SignedVeryLong(v)       => v.len() * 8,
+        VrValue::UnsignedVeryLong(v)     => v.len() * 8,
+
+        VrValue::ApplicationEntity(s) | VrValue::AgeString(s) | VrValue::CodeString(s) | VrValue::PersonName(s) |
+        VrValue::ShortString(s) | VrValue::UnlimitedCharacters(s) | VrValue::Date(s) | VrValue::DateTime(s) |
+        VrValue::Time(s) | VrValue::DecimalString(s) | VrValue::IntegerString(s) | VrValue::LongString(s) |
+        VrValue::Uid(s)                  => s.iter().map(String::len).sum::<usize>() + s.len().saturating_sub(1),
+        VrValue::LongText(s) | VrValue::ShortText(s) | VrValue::UnlimitedText(s) |
+        VrValue::UniversalResource(s)    => s.len(),
+
+        VrValue::OtherByte(bytes)        => bytes.len(),
+        VrValue::OtherFloat(bytes)       => bytes.len(),
+        VrValue::OtherWord(bytes)        => bytes.len(),
+        VrValue::OtherDouble(v)          => v.len() * 8,
+        VrValue::OtherLong(v)            => v.len() * 4,
+        VrValue::OtherVeryLong(v)        => v.len() * 8,
+        VrValue::Encapsulated(_)         => return UNDEFINED_LENGTH,
+        VrValue::Unknown(bytes)          => bytes.len(),
+        VrValue::Raw { bytes, .. }       => bytes.len(),
+        VrValue::Deferred { length, .. } => return *length
+    };
+
+    length + length % 2
+}
+
+// Bytes following the header of encapsulated pixel data: the Basic Offset Table item, an item
+// per fragment and the sequence delimitation item. Fragments of odd length are padded with a zero byte.
+pub fn encapsulated(pixels: &EncapsulatedPixelData, endian: EndianEncoding) -> Vec<u8> {
+    let table: Vec<u8> = pixels.basic_offset_table.iter().flat_map(|o| endian.convert(o.to_le_bytes(), o.to_be_bytes())).collect();
+
+    let mut bytes = item_header(tags::ITEM, table.len(), endian);
+    bytes.extend(table);
+
+    for fragment in &pixels.fragments {
+        let length = fragment.data.len();
+        bytes.extend(item_header(tags::ITEM, length + length % 2, endian));
+        bytes.extend(&fragment.data);
+        bytes.extend(vec![0; length % 2]);
+    }
+
+    bytes.extend(item_header(tags::SEQUENCE_DELIMITER, 0, endian));
+    bytes
+}
+
+// Number of bytes encapsulated writes.
+pub fn encapsulated_length(pixels: &EncapsulatedPixelData) -> usize {
+    let fragments: usize = pixels.fragments.iter().map(|f| ITEM_HEADER_LENGTH + f.data.len() + f.data.len() % 2).sum();
+    ITEM_HEADER_LENGTH + pixels.basic_offset_table.len() * 4 + fragments + ITEM_HEADER_LENGTH
+}
+
+// Header of items and delimitation items, they have no VR whatever the syntax.
+pub fn item_header(tag: (u16, u16), length: usize, endian: EndianEncoding) -> Vec<u8> {
+    let length = length as u32;
+    [words(tag.0, endian), words(tag.1, endian), endian.convert(length.to_le_bytes(), length.to_be_bytes()).to_vec()].concat()
+}
+
+// Header of an element, None when the length does not fit the length field of the VR.
+pub fn element_header(tag: (u16, u16), vr_code: u16, length: usize, syntax: TransferSyntax) -> Option<Vec<u8>> {
+    let endian = syntax.endian_encoding;
+    let id = [words(tag.0, endian), words(tag.1, endian)].concat();
+
+    let vr_and_length = match syntax.vr_encoding {
+        VrEncoding::Implicit                                      => long_length(length, endian)?,
+        VrEncoding::Explicit if vr_type::has_long_length(vr_code) => [vr_code.to_le_bytes().to_vec(), vec![0, 0], long_length(length, endian)?].concat(),
+        VrEncoding::Explicit                                      => [vr_code.to_le_bytes().to_vec(), words(u16::try_from(length).ok()?, endian)].concat()
+    };
+
+    Some([id, vr_and_length].concat())
+}
+
+// Tag, VR and length of an element. VRs with a reserved field have a 4 bytes length, the others 2 bytes.
+pub fn header_length(vr_code: u16, vr_encoding: VrEncoding) -> usize {
+    match vr_encoding {
+        VrEncoding::Implicit                                      => 8,
+        VrEncoding::Explicit if vr_type::has_long_length(vr_code) => 12,
+        VrEncoding::Explicit                                      => 8
+    }
+}
+
+fn long_length(length: usize, endian: EndianEncoding) -> Option<Vec<u8>> {
+    let length = u32::try_from(length).ok()?;
+    Some(endian.convert(length.to_le_bytes(), length.to_be_bytes()).to_vec())
+}
+
+fn words(word: u16, endian: EndianEncoding) -> Vec<u8> {
+    endian.convert(word.to_le_bytes(), word.to_be_bytes()).to_vec()
+}
+
+// OW/OF style values are held in little endian order, see the decoder.
+fn other(bytes: &[u8], word_size: usize, endian: EndianEncoding) -> Vec<u8> {
+    let mut bytes = bytes.to_vec();
+    if let EndianEncoding::BigEndian = endian {
+        bytes.chunks_exact_mut(word_size).for_each(|word| word.reverse());
+    }
+
+    bytes
+}
+
+// UIDs are padded with NUL, other strings with a space and binary values with a zero byte.
+fn padding(value: &VrValue) -> u8 {
+    match value {
+        VrValue::Uid(_)                => 0,
+        _ if value.strings().is_some() => b' ',
+        _                              => 0
+    }
+}
@@ -0,0 +1,81 @@
+use std::io::{Read, Seek};
+
+use crate::binary_reader::*;
+use crate::dicom;
+use crate::dicom_error::{DicomError, DicomResult};
+use crate::dicom_tag::{DicomTag, VrValue};
+use crate::tags;
+use crate::transfer_syntax::TransferSyntax;
+use crate::utils;
+
+#[derive(Debug, Clone)]
+pub struct FileMetaInformation {
+    pub preamble: [u8; dicom::PREAMBLE_LENGTH],
+    pub group_length: u32,
+    pub version: Vec<u8>,
+    pub media_storage_sop_class_uid: String,
+    pub media_storage_sop_instance_uid: String,
+    pub transfer_syntax_uid: String,
+    pub implementation_class_uid: String,
+    pub implementation_version_name: Option<String>,
+    pub source_application_entity_title: Option<String>,
+    pub private_information_creator_uid: Option<String>,
+    pub private_information: Option<Vec<u8>>
+}
+
+impl FileMetaInformation {
+    pub fn transfer_syntax(&self) -> TransferSyntax {
+        TransferSyntax::parse_str(&self.transfer_syntax_uid)
+    }
+}
+
+fn find(meta_tags: &[DicomTag], tag: (u16, u16)) -> Option<&DicomTag> {
+    meta_tags.iter().find(|t| (t.group, t.element) == tag)
+}
+
+fn string_value(meta_tags: &[DicomTag], tag: (u16, u16)) -> Option<String> {
+    match &find(meta_tags, tag)?.value {
+        VrValue::Uid(v) | VrValue::ShortString(v) | VrValue::ApplicationEntity(v) => v.first().map(|s| utils::trim_padding(s).trim_start().to_string()),
+        _                                                                         => None
+    }
+}
+
+fn bytes_value(meta_tags: &[DicomTag], tag: (u16, u16)) -> Option<Vec<u8>> {
+    match &find(meta_tags, tag)?.value {
+        VrValue::OtherByte(v) | VrValue::Unknown(v) => Some(v.clone()),
+        _                                           => None
+    }
+}
+
+fn group_length_value(meta_tags: &[DicomTag]) -> Option<u32> {
+    match &find(meta_tags, tags::FILE_META_GROUP_LENGTH)?.value {
+        VrValue::UnsignedLong(v) => v.first().copied(),
+        _                        => None
+    }
+}
+
+// Reads the preamble, the DICM mark and the File Meta Information group, leaving the reader at the start of the dataset.
+pub fn read_file_meta(reader: &mut (impl Read + Seek)) -> DicomResult<FileMetaInformation> {
+    let preamble = dicom::read_preamble(reader)?.ok_or(DicomError::MissingPreamble { offset: dicom::PREAMBLE_LENGTH as u64 })?;
+
+    let mut meta_tags = Vec::new();
+    dicom::parse_file_meta(reader, &mut |tag| meta_tags.push(tag))?;
+
+    let end_pos = reader.pos().map_err(DicomError::io(0, None))?;
+    let missing = |tag| DicomError::MissingElement { offset: end_pos, tag };
+
+    // Present but wrongly encoded mandatory elements are reported as missing, they cannot be used either way.
+    Ok(FileMetaInformation {
+        preamble,
+        group_length: group_length_value(&meta_tags).ok_or_else(|| missing(tags::FILE_META_GROUP_LENGTH))?,
+        version: bytes_value(&meta_tags, tags::FILE_META_INFORMATION_VERSION).ok_or_else(|| missing(tags::FILE_META_INFORMATION_VERSION))?,
+        media_storage_sop_class_uid: string_value(&meta_tags, tags::MEDIA_STORAGE_SOP_CLASS_UID).ok_or_else(|| missing(tags::MEDIA_STORAGE_SOP_CLASS_UID))?,
+        media_storage_sop_instance_uid: string_value(&meta_tags, tags::MEDIA_STORAGE_SOP_INSTANCE_UID).ok_or_else(|| missing(tags::MEDIA_STORAGE_SOP_INSTANCE_UID))?,
+        transfer_syntax_uid: string_value(&meta_tags, tags::TRANSFER_SYNTAX_UID).ok_or_else(|| missing(tags::TRANSFER_SYNTAX_UID))?,
+        implementation_class_uid: string_value(&meta_tags, tags::IMPLEMENTATION_CLASS_UID).ok_or_else(|| missing(tags::IMPLEMENTATION_CLASS_UID))?,
+        implementation_version_name: string_value(&meta_tags, tags::IMPLEMENTATION_VERSION_NAME),
+        source_application_entity_title: string_value(&meta_tags, tags::SOURCE_APPLICATION_ENTITY_TITLE),
+        private_information_creator_uid: string_value(&meta_tags, tags::PRIVATE_INFORMATION_CREATOR_UID),
+        private_information: bytes_value(&meta_tags, tags::PRIVATE_INFORMATION)
+    })
+}
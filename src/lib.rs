@@ -0,0 +1,36 @@
+mod utils;
+mod binary_reader;
+mod dicom_reader;
+mod decoder;
+mod encoder;
+mod stream_reader;
+
+pub mod dicom_error;
+pub mod vr_type;
+pub mod sop_class;
+pub mod transfer_syntax;
+pub mod tags;
+pub mod encapsulated;
+pub mod date_time;
+pub mod dicom_tag;
+pub mod dicom_handlers;
+pub mod dicom;
+pub mod file_meta;
+pub mod dicom_slice;
+pub mod tag_iter;
+pub mod dicom_object;
+pub mod dicom_writer;
+#[cfg(feature = "tokio")]
+pub mod dicom_async;
+
+pub use crate::dicom_error::{DicomError, DicomResult};
+pub use crate::dicom_tag::{DicomTag, TagHeader, VrValue, UNDEFINED_LENGTH};
+pub use crate::date_time::{DicomDate, DicomTime, DicomDateTime, DicomRange, Temporal};
+pub use crate::dicom_handlers::{DicomHandler, ParseAction, DicomContainer, DicomNode, DicomDumper};
+pub use crate::encapsulated::{EncapsulatedPixelData, Fragment};
+pub use crate::file_meta::{FileMetaInformation, read_file_meta};
+pub use crate::transfer_syntax::{TransferSyntax, VrEncoding, EndianEncoding, Compression};
+pub use crate::dicom_slice::{DicomTagRef, SliceParser, parse_slice};
+pub use crate::tag_iter::{TagIter, PathNode};
+pub use crate::dicom_object::{DicomObject, DicomElement, DicomObjectBuilder};
+pub use crate::dicom_writer::{WriteOptions, SequenceLength};
@@ -1,4 +1,4 @@
-const CAT_UID: &str = "1.2.840.10008.5.1.4.1.1.2";
+pub const CAT_UID: &str = "1.2.840.10008.5.1.4.1.1.2";
 
 const SOP_CLASSES: [(&str, &str); 140] = [
     ("1.2.840.10008.5.1.4.1.1.9.1.3", "Ambulatory Electrocardiogram Waveform"),
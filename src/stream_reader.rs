@@ -0,0 +1,84 @@
+use std::collections::VecDeque;
+use std::io::{self, ErrorKind, Read, Seek, SeekFrom};
+
+// Bytes kept to seek back over, enough for the preamble check which reads the most before rewinding.
+const LOOK_BEHIND: usize = 132;
+
+// Adapts a plain Read to the Seek the parser needs. Seeking forward reads and discards the bytes in between,
+// seeking back is limited to the last LOOK_BEHIND bytes read and seeking from the end is not supported.
+pub struct StreamReader<R: Read> {
+    inner: R,
+    pos: u64,
+    // Last bytes read, oldest first.
+    history: VecDeque<u8>,
+    // Bytes seeked back over, read again before reading from inner.
+    pending: VecDeque<u8>
+}
+
+impl<R: Read> StreamReader<R> {
+    pub fn new(inner: R) -> StreamReader<R> {
+        StreamReader { inner, pos: 0, history: VecDeque::with_capacity(LOOK_BEHIND), pending: VecDeque::new() }
+    }
+
+    fn remember(&mut self, bytes: &[u8]) {
+        let kept = &bytes[bytes.len().saturating_sub(LOOK_BEHIND)..];
+        let overflow = (self.history.len() + kept.len()).saturating_sub(LOOK_BEHIND);
+
+        self.history.drain(..overflow);
+        self.history.extend(kept);
+    }
+}
+
+impl<R: Read> Read for StreamReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = match self.pending.is_empty() {
+            true  => self.inner.read(buf)?,
+            false => {
+                let n = buf.len().min(self.pending.len());
+                buf.iter_mut().zip(self.pending.drain(..n)).for_each(|(b, p)| *b = p);
+                n
+            }
+        };
+
+        self.remember(&buf[..read]);
+        self.pos += read as u64;
+
+        Ok(read)
+    }
+}
+
+impl<R: Read> Seek for StreamReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(p)   => Some(p),
+            SeekFrom::Current(d) => self.pos.checked_add_signed(d),
+            SeekFrom::End(_)     => return Err(io::Error::new(ErrorKind::Unsupported, "cannot seek from the end of a stream"))
+        }.ok_or_else(|| io::Error::from(ErrorKind::InvalidInput))?;
+
+        match target >= self.pos {
+            true  => {
+                let skip = target - self.pos;
+                let skipped = io::copy(&mut self.by_ref().take(skip), &mut io::sink())?;
+
+                if skipped < skip {
+                    return Err(ErrorKind::UnexpectedEof.into());
+                }
+            },
+            false => {
+                let back = (self.pos - target) as usize;
+
+                if back > self.history.len() {
+                    return Err(io::Error::new(ErrorKind::Unsupported, "cannot seek back further than the look-behind buffer"));
+                }
+
+                for _ in 0..back {
+                    // Safe to unwrap, history holds at least back bytes.
+                    self.pending.push_front(self.history.pop_back().unwrap());
+                }
+                self.pos = target;
+            }
+        }
+
+        Ok(self.pos)
+    }
+}
@@ -0,0 +1,341 @@
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+
+use crate::binary_reader::RewindExtensions;
+use crate::dicom::{self, Container, OpenNode, ParseState};
+use crate::dicom_handlers::{DicomHandler, ParseAction};
+use crate::dicom_tag::*;
+use crate::dicom_error::{DicomError, DicomResult};
+use crate::tags;
+use crate::transfer_syntax::{Compression, TransferSyntax};
+
+// Step of the path from the dataset down to a tag, the sequence holding it and the index of the item within it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PathNode {
+    Sequence((u16, u16)),
+    Item(usize)
+}
+
+// Reader tags are pulled from, replaced by the inflated dataset when it is deflated.
+enum Source<R> {
+    Plain(R),
+    Inflated(Cursor<Vec<u8>>)
+}
+
+impl<R: Read> Read for Source<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Source::Plain(reader)    => reader.read(buf),
+            Source::Inflated(cursor) => cursor.read(buf)
+        }
+    }
+}
+
+impl<R: Seek> Seek for Source<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match self {
+            Source::Plain(reader)    => reader.seek(pos),
+            Source::Inflated(cursor) => cursor.seek(pos)
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Phase {
+    Preamble,
+    // Syntax of the dataset, once the Transfer Syntax UID has been read.
+    FileMeta(Option<TransferSyntax>),
+    DatasetStart(TransferSyntax),
+    Dataset(TransferSyntax),
+    Done
+}
+
+// Handler for the node events raised while skipping a sequence, the iterator keeps its own path.
+struct Ignore;
+
+impl DicomHandler for Ignore {}
+
+// Pulls tags one at a time, in file order. Sequences and items come before the tags they contain,
+// delimiters are not reported. Iteration ends at the end of the reader or at the first error.
+pub struct TagIter<R> {
+    source: Source<R>,
+    phase: Phase,
+    state: ParseState,
+    open_nodes: Vec<OpenNode>,
+    path: Vec<PathNode>,
+    // Items seen so far in each open node, only counted for sequences.
+    item_counts: Vec<usize>,
+    // Depth of the last tag returned.
+    depth: usize,
+    // Offset and tag of the last header read, errors locating the next one are reported there.
+    last: (u64, Option<(u16, u16)>)
+}
+
+impl<R: Read + Seek> TagIter<R> {
+    pub fn new(reader: R) -> TagIter<R> {
+        TagIter::starting_at(reader, Phase::Preamble)
+    }
+
+    pub fn dataset(reader: R, syntax: TransferSyntax) -> TagIter<R> {
+        TagIter::starting_at(reader, Phase::DatasetStart(syntax))
+    }
+
+    fn starting_at(reader: R, phase: Phase) -> TagIter<R> {
+        TagIter {
+            source: Source::Plain(reader),
+            phase,
+            state: ParseState::default(),
+            open_nodes: Vec::new(),
+            path: Vec::new(),
+            item_counts: Vec::new(),
+            depth: 0,
+            last: (0, None)
+        }
+    }
+
+    // Number of sequences and items holding the last tag returned, 0 for tags of the dataset itself.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    // Sequences and items holding the last tag returned, outermost first.
+    pub fn path(&self) -> &[PathNode] {
+        &self.path[..self.depth]
+    }
+
+    // Syntax of the dataset, None while reading the File Meta Information.
+    pub fn syntax(&self) -> Option<TransferSyntax> {
+        match self.phase {
+            Phase::Dataset(syntax) => Some(syntax),
+            _                      => None
+        }
+    }
+
+    // Yields the tags of the dataset while predicate holds for their group. Tags nested in sequences
+    // follow their sequence, the iterator is left before the first tag failing the predicate.
+    pub fn take_while_group<F: FnMut(u16) -> bool>(&mut self, predicate: F) -> TakeWhileGroup<'_, R, F> {
+        TakeWhileGroup { tags: self, predicate, done: false }
+    }
+
+    // Reads up to the first tag with the given id, at any depth, skipping the values of other tags without reading them.
+    pub fn find_tag(&mut self, tag: (u16, u16)) -> Option<DicomResult<DicomTag>> {
+        loop {
+            let next = self.next_with(|header, _| match header.tag() == tag || is_node(header) {
+                true  => ParseAction::Continue,
+                false => ParseAction::SkipValue
+            });
+
+            match next {
+                Some(Ok(found)) if (found.group, found.element) != tag => continue,
+                next                                                   => return next
+            }
+        }
+    }
+
+    // Returns the next tag, consulting decide with its header and path before reading its value. SkipValue and
+    // SkipSequence move on to the following tag, Stop returns None leaving the reader before the header.
+    // Values are always read, DeferValue is taken for Continue.
+    pub fn next_with(&mut self, decide: impl FnMut(&TagHeader, &[PathNode]) -> ParseAction) -> Option<DicomResult<DicomTag>> {
+        match self.advance(decide) {
+            Ok(tag) => tag.map(Ok),
+            Err(err) => {
+                self.phase = Phase::Done;
+                Some(Err(err))
+            }
+        }
+    }
+
+    fn advance(&mut self, mut decide: impl FnMut(&TagHeader, &[PathNode]) -> ParseAction) -> DicomResult<Option<DicomTag>> {
+        loop {
+            let syntax = match self.phase {
+                Phase::Done                  => return Ok(None),
+                Phase::Preamble              => {
+                    dicom::read_preamble(&mut self.source)?;
+                    self.phase = Phase::FileMeta(None);
+                    continue;
+                },
+                Phase::FileMeta(syntax)      => match self.in_file_meta()? {
+                    true  => TransferSyntax::default(),
+                    false => {
+                        let syntax = match syntax {
+                            Some(syntax) => syntax,
+                            None         => dicom::detect_syntax(&mut self.source)?
+                        };
+                        self.phase = Phase::DatasetStart(syntax);
+                        continue;
+                    }
+                },
+                Phase::DatasetStart(syntax)  => {
+                    self.start_dataset(syntax)?;
+                    continue;
+                },
+                // Tags are read with the syntax of the node holding them.
+                Phase::Dataset(syntax)       => self.open_nodes.last().map_or(syntax, |node| node.syntax)
+            };
+
+            if let Phase::Dataset(_) = self.phase {
+                if self.close_ended_node()? {
+                    continue;
+                }
+            }
+
+            let offset = self.pos()?;
+            let header = dicom::read_header(&mut self.source, offset, syntax)?;
+            self.last = (header.offset, Some(header.tag()));
+
+            if let Some(node) = self.open_nodes.last().copied() {
+                if dicom::check_placement(&header, node)? {
+                    self.close_node();
+                    continue;
+                }
+            }
+
+            match decide(&header, &self.path) {
+                ParseAction::Continue | ParseAction::DeferValue => (),
+                ParseAction::SkipValue                          => {
+                    dicom::skip_value(&mut self.source, header, syntax)?;
+                    continue;
+                },
+                ParseAction::SkipSequence                       => {
+                    dicom::skip_value(&mut self.source, header, syntax)?;
+                    dicom::skip_sequence(&mut self.source, &header, &mut self.open_nodes, &mut Ignore)?;
+                    self.path.truncate(self.open_nodes.len().saturating_sub(1));
+                    self.item_counts.truncate(self.open_nodes.len());
+                    self.depth = self.depth.min(self.path.len());
+                    continue;
+                },
+                ParseAction::Stop                               => {
+                    self.source.seek(SeekFrom::Start(header.offset)).map_err(DicomError::io(header.offset, Some(header.tag())))?;
+                    return Ok(None);
+                }
+            }
+
+            let value_pos = self.source.stream_position().map_err(DicomError::io(header.offset, Some(header.tag())))?;
+            let mut tag = dicom::read_tag(&mut self.source, header, syntax)?;
+
+            match self.phase {
+                Phase::FileMeta(syntax) => self.phase = Phase::FileMeta(tag.try_transfer_syntax()?.or(syntax)),
+                _                       => dicom::keep_offset_tables(&mut tag, &mut self.state)
+            }
+
+            self.depth = self.path.len();
+            self.open_child(&tag, value_pos, header.items_syntax(syntax));
+
+            return Ok(Some(tag));
+        }
+    }
+
+    // Deflated datasets are inflated into memory, offsets are then relative to the inflated dataset.
+    fn start_dataset(&mut self, syntax: TransferSyntax) -> DicomResult<()> {
+        if syntax.compression == Compression::Deflate {
+            let offset = self.pos()?;
+            self.source = Source::Inflated(dicom::inflate(&mut self.source, offset)?);
+            self.state.inflated = true;
+            self.last = (0, None);
+        }
+
+        self.phase = Phase::Dataset(syntax);
+        self.open_nodes = vec![OpenNode { container: Container::Dataset, limit_pos: None, syntax }];
+        self.item_counts = vec![0];
+
+        Ok(())
+    }
+
+    // Closes the innermost node when its length is reached, or the dataset at the end of the reader.
+    fn close_ended_node(&mut self) -> DicomResult<bool> {
+        let node = match self.open_nodes.last() {
+            Some(node) => *node,
+            None       => {
+                self.phase = Phase::Done;
+                return Ok(true);
+            }
+        };
+
+        let pos = self.pos()?;
+
+        let is_closed = match (node.container, node.limit_pos) {
+            (_, Some(limit))          => pos >= limit,
+            (Container::Dataset, None) => self.source.is_at_end().map_err(DicomError::io(pos, None))?,
+            (_, None)                 => false
+        };
+
+        if is_closed {
+            self.close_node();
+        }
+
+        Ok(is_closed)
+    }
+
+    fn pos(&mut self) -> DicomResult<u64> {
+        self.source.stream_position().map_err(DicomError::io(self.last.0, self.last.1))
+    }
+
+    fn in_file_meta(&mut self) -> DicomResult<bool> {
+        let pos = self.pos()?;
+        dicom::in_file_meta(&mut self.source, pos)
+    }
+
+    fn close_node(&mut self) {
+        self.open_nodes.pop();
+        self.item_counts.pop();
+        self.path.truncate(self.open_nodes.len().saturating_sub(1));
+        self.depth = self.depth.min(self.path.len());
+    }
+
+    fn open_child(&mut self, tag: &DicomTag, value_pos: u64, syntax: TransferSyntax) {
+        let node = match dicom::child_node(tag, value_pos, syntax) {
+            Some(node) => node,
+            None       => return
+        };
+
+        let step = match node.container {
+            Container::Item => {
+                let count = self.item_counts.last_mut().expect("items are only read within a sequence");
+                *count += 1;
+                PathNode::Item(*count - 1)
+            },
+            _               => PathNode::Sequence((tag.group, tag.element))
+        };
+
+        self.open_nodes.push(node);
+        self.item_counts.push(0);
+        self.path.push(step);
+    }
+}
+
+fn is_node(header: &TagHeader) -> bool {
+    header.is_sequence() || header.tag() == tags::ITEM
+}
+
+impl<R: Read + Seek> Iterator for TagIter<R> {
+    type Item = DicomResult<DicomTag>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with(|_, _| ParseAction::Continue)
+    }
+}
+
+// Iterator returned by TagIter::take_while_group.
+pub struct TakeWhileGroup<'a, R, F> {
+    tags: &'a mut TagIter<R>,
+    predicate: F,
+    done: bool
+}
+
+impl<'a, R: Read + Seek, F: FnMut(u16) -> bool> Iterator for TakeWhileGroup<'a, R, F> {
+    type Item = DicomResult<DicomTag>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let predicate = &mut self.predicate;
+        let next = self.tags.next_with(|header, path| match path.is_empty() && !predicate(header.group) {
+            true  => ParseAction::Stop,
+            false => ParseAction::Continue
+        });
+
+        self.done = next.is_none();
+        next
+    }
+}
@@ -1,3042 +1,3090 @@
+use std::sync::OnceLock;
+
 use crate::vr_type;
 
-const POSITION: (u16, u16) = (0x0020u16, 0x0032u16);
-const LOCATION: (u16, u16) = (0x0020u16, 0x1041u16);
-const SOP_CLASS_UID: (u16, u16) = (0x0008u16, 0x0016u16);
-const SOP_INSTANCE_UID: (u16, u16) = (0x0008u16, 0x0018u16);
-const STUDY_INSTANCE_UID: (u16, u16) = (0x0020u16, 0x000Du16);
-const SERIES_INSTANCE_UID: (u16, u16) = (0x0020u16, 0x000Eu16);
+pub const POSITION: (u16, u16) = (0x0020u16, 0x0032u16);
+pub const LOCATION: (u16, u16) = (0x0020u16, 0x1041u16);
+pub const SOP_CLASS_UID: (u16, u16) = (0x0008u16, 0x0016u16);
+pub const SOP_INSTANCE_UID: (u16, u16) = (0x0008u16, 0x0018u16);
+pub const STUDY_INSTANCE_UID: (u16, u16) = (0x0020u16, 0x000Du16);
+pub const SERIES_INSTANCE_UID: (u16, u16) = (0x0020u16, 0x000Eu16);
+pub const FILE_META_GROUP_LENGTH: (u16, u16) = (0x0002u16, 0x0000u16);
+pub const FILE_META_INFORMATION_VERSION: (u16, u16) = (0x0002u16, 0x0001u16);
+pub const MEDIA_STORAGE_SOP_CLASS_UID: (u16, u16) = (0x0002u16, 0x0002u16);
+pub const MEDIA_STORAGE_SOP_INSTANCE_UID: (u16, u16) = (0x0002u16, 0x0003u16);
+pub const IMPLEMENTATION_CLASS_UID: (u16, u16) = (0x0002u16, 0x0012u16);
+pub const IMPLEMENTATION_VERSION_NAME: (u16, u16) = (0x0002u16, 0x0013u16);
+pub const SOURCE_APPLICATION_ENTITY_TITLE: (u16, u16) = (0x0002u16, 0x0016u16);
+pub const PRIVATE_INFORMATION_CREATOR_UID: (u16, u16) = (0x0002u16, 0x0100u16);
+pub const PRIVATE_INFORMATION: (u16, u16) = (0x0002u16, 0x0102u16);
 pub const TRANSFER_SYNTAX_UID: (u16, u16) = (0x0002u16, 0x0010u16);
-const PATIENT_NAME: (u16, u16) = (0x0010u16, 0x0010u16);
+pub const PATIENT_NAME: (u16, u16) = (0x0010u16, 0x0010u16);
 pub const PIXEL_DATA: (u16, u16) = (0x7FE0u16, 0x0010u16);
-const PIXEL_SPACING: (u16, u16) = (0x0028u16, 0x0030u16);
-const ROWS: (u16, u16) = (0x0028u16, 0x0010u16);
-const COLUMNS: (u16, u16) = (0x0028u16, 0x0011u16);
-const WINDOW_CENTER: (u16, u16) = (0x0028u16, 0x1050u16);
-const WINDOW_WIDTH: (u16, u16) = (0x0028u16, 0x1051u16);
-const RESCALE_INTERCEPT: (u16, u16) = (0x0028u16, 0x1052u16);
-const RESCALE_SLOPE: (u16, u16) = (0x0028u16, 0x1053u16);
-const PIXEL_PADDING: (u16, u16) = (0x0028u16, 0x0120u16);
-const PIXEL_PADDING_RANGE_LIMIT: (u16, u16) = (0x0028u16, 0x0121u16);
-const PIXEL_REPRESENTATION: (u16, u16) = (0x0028u16, 0x0103u16);
+pub const EXTENDED_OFFSET_TABLE: (u16, u16) = (0x7FE0u16, 0x0001u16);
+pub const EXTENDED_OFFSET_TABLE_LENGTHS: (u16, u16) = (0x7FE0u16, 0x0002u16);
+pub const PIXEL_SPACING: (u16, u16) = (0x0028u16, 0x0030u16);
+pub const ROWS: (u16, u16) = (0x0028u16, 0x0010u16);
+pub const COLUMNS: (u16, u16) = (0x0028u16, 0x0011u16);
+pub const WINDOW_CENTER: (u16, u16) = (0x0028u16, 0x1050u16);
+pub const WINDOW_WIDTH: (u16, u16) = (0x0028u16, 0x1051u16);
+pub const RESCALE_INTERCEPT: (u16, u16) = (0x0028u16, 0x1052u16);
+pub const RESCALE_SLOPE: (u16, u16) = (0x0028u16, 0x1053u16);
+pub const PIXEL_PADDING: (u16, u16) = (0x0028u16, 0x0120u16);
+pub const PIXEL_PADDING_RANGE_LIMIT: (u16, u16) = (0x0028u16, 0x0121u16);
+pub const PIXEL_REPRESENTATION: (u16, u16) = (0x0028u16, 0x0103u16);
+pub const BITS_ALLOCATED: (u16, u16) = (0x0028u16, 0x0100u16);
+pub const ITEM: (u16, u16) = (0xFFFEu16, 0xE000u16);
+pub const ITEM_DELIMITER: (u16, u16) = (0xFFFEu16, 0xE00Du16);
 pub const SEQUENCE_DELIMITER: (u16, u16) = (0xFFFEu16, 0xE0DDu16);
 
-pub const TAGS: [((u16,u16), &str, u16); 2999] = [
-    ((0xFFFEu16, 0xE000u16), "Item", vr_type::DELIMITER),
-    ((0xFFFEu16, 0xE00Du16), "Item Delimitation Item", vr_type::DELIMITER),
-    (SEQUENCE_DELIMITER,     "Sequence Delimitation Item", vr_type::DELIMITER),
-    ((0x0004u16, 0x1130u16), "File-set ID", vr_type::CODE_STRING),
-    ((0x0004u16, 0x1141u16), "File-set Descriptor File ID", vr_type::CODE_STRING),
-    ((0x0004u16, 0x1142u16), "Specific Character Set of File-set Descriptor File", vr_type::CODE_STRING),
-    ((0x0004u16, 0x1200u16), "Offset of the First Directory Record of the Root Directory Entity", vr_type::UNSIGNED_LONG),
-    ((0x0004u16, 0x1202u16), "Offset of the Last Directory Record of the Root Directory Entity", vr_type::UNSIGNED_LONG),
-    ((0x0004u16, 0x1212u16), "File-set Consistency Flag", vr_type::UNSIGNED_SHORT),
-    ((0x0004u16, 0x1220u16), "Directory Record Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0004u16, 0x1400u16), "Offset of the Next Directory Record", vr_type::UNSIGNED_LONG),
-    ((0x0004u16, 0x1410u16), "Record In-use Flag", vr_type::UNSIGNED_SHORT),
-    ((0x0004u16, 0x1420u16), "Offset of Referenced Lower-Level Directory Entity", vr_type::UNSIGNED_LONG),
-    ((0x0004u16, 0x1430u16), "Directory Record Type", vr_type::CODE_STRING),
-    ((0x0004u16, 0x1432u16), "Private Record Uid", vr_type::UID),
-    ((0x0004u16, 0x1500u16), "Referenced File ID", vr_type::CODE_STRING),
-    ((0x0004u16, 0x1504u16), "MRDR Directory Record Offset", vr_type::UNSIGNED_LONG),
-    ((0x0004u16, 0x1510u16), "Referenced SOP Class Uid in File", vr_type::UID),
-    ((0x0004u16, 0x1511u16), "Referenced SOP Instance Uid in File", vr_type::UID),
-    ((0x0004u16, 0x1512u16), "Referenced Transfer Syntax Uid in File", vr_type::UID),
-    ((0x0004u16, 0x151Au16), "Referenced Related General SOP Class Uid in File", vr_type::UID),
-    ((0x0004u16, 0x1600u16), "Number of References", vr_type::UNSIGNED_LONG),
-    ((0x0002u16, 0x0000u16), "Group 0002 Length", vr_type::UNSIGNED_LONG),
-    ((0x0002u16, 0x0001u16), "File Meta Information Version", vr_type::OTHER_BYTE),
-    ((0x0002u16, 0x0002u16), "Media Storage SOP Class Uid", vr_type::UID),
-    ((0x0002u16, 0x0003u16), "Media Storage SOP Instance Uid", vr_type::UID),
-    (TRANSFER_SYNTAX_UID,    "Transfer Syntax Uid", vr_type::UID),
-    ((0x0002u16, 0x0012u16), "Implementation Class Uid", vr_type::UID),
-    ((0x0002u16, 0x0013u16), "Implementation Version Name", vr_type::SHORT_STRING),
-    ((0x0002u16, 0x0016u16), "Source Application Entity Title", vr_type::APPLICATION_ENTITY),
-    ((0x0002u16, 0x0100u16), "Private Information Creator Uid", vr_type::UID),
-    ((0x0002u16, 0x0102u16), "Private Information", vr_type::OTHER_BYTE),
-    ((0x0000u16, 0x0000u16), "Group 0000 Length", vr_type::UNSIGNED_LONG),
-    ((0x0000u16, 0x0002u16), "Affected SOP Class Uid", vr_type::UID),
-    ((0x0000u16, 0x0003u16), "Requested SOP Class Uid", vr_type::UID),
-    ((0x0000u16, 0x0100u16), "Command Field", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x0110u16), "Message ID", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x0120u16), "Message ID Being Responded To", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x0600u16), "Move Destination", vr_type::APPLICATION_ENTITY),
-    ((0x0000u16, 0x0700u16), "Priority", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x0800u16), "Data Set Type", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x0900u16), "Status", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x0901u16), "Offending Element", vr_type::ATTRIBUTE),
-    ((0x0000u16, 0x0902u16), "Error Comment", vr_type::LONG_STRING),
-    ((0x0000u16, 0x0903u16), "Error ID", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x1000u16), "Affected SOP Instance Uid", vr_type::UID),
-    ((0x0000u16, 0x1001u16), "Requested SOP Instance Uid", vr_type::UID),
-    ((0x0000u16, 0x1002u16), "Event Type ID", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x1005u16), "Attribute Identifier List", vr_type::ATTRIBUTE),
-    ((0x0000u16, 0x1008u16), "Action Type ID", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x1020u16), "Number of Remaining Suboperations", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x1021u16), "Number of Completed Suboperations", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x1022u16), "Number of Failed Suboperations", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x1023u16), "Number of Warning Suboperations", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x1030u16), "Move Originator Application Entity Title", vr_type::APPLICATION_ENTITY),
-    ((0x0000u16, 0x1031u16), "Move Originator Message ID", vr_type::UNSIGNED_SHORT),
-    ((0x0008u16, 0x0005u16), "Specific Character Set", vr_type::CODE_STRING),
-    ((0x0008u16, 0x0008u16), "Image Type", vr_type::CODE_STRING),
-    ((0x0008u16, 0x0012u16), "Instance Creation Date", vr_type::DATE),
-    ((0x0008u16, 0x0013u16), "Instance Creation Time", vr_type::TIME),
-    ((0x0008u16, 0x0014u16), "Instance Creator Uid", vr_type::UID),
-    (SOP_CLASS_UID,          "SOP Class Uid", vr_type::UID),
-    (SOP_INSTANCE_UID,       "SOP Instance Uid", vr_type::UID),
-    ((0x0008u16, 0x001Au16), "Related General SOP Class Uid", vr_type::UID),
-    ((0x0008u16, 0x001Bu16), "Original Specialized SOP Class Uid", vr_type::UID),
-    ((0x0008u16, 0x0020u16), "Study Date", vr_type::DATE),
-    ((0x0008u16, 0x0021u16), "Series Date", vr_type::DATE),
-    ((0x0008u16, 0x0022u16), "Acquisition Date", vr_type::DATE),
-    ((0x0008u16, 0x0023u16), "Content Date", vr_type::DATE),
-    ((0x0008u16, 0x0024u16), "Overlay Date", vr_type::DATE),
-    ((0x0008u16, 0x0025u16), "Curve Date", vr_type::DATE),
-    ((0x0008u16, 0x0030u16), "Study Time", vr_type::TIME),
-    ((0x0008u16, 0x0031u16), "Series Time", vr_type::TIME),
-    ((0x0008u16, 0x0032u16), "Acquisition Time", vr_type::TIME),
-    ((0x0008u16, 0x0033u16), "Content Time", vr_type::TIME),
-    ((0x0008u16, 0x0034u16), "Overlay Time", vr_type::TIME),
-    ((0x0008u16, 0x0035u16), "Curve Time", vr_type::TIME),
-    ((0x0008u16, 0x0050u16), "Accession Number", vr_type::SHORT_STRING),
-    ((0x0008u16, 0x0052u16), "Query/Retrieve Level", vr_type::CODE_STRING),
-    ((0x0008u16, 0x0054u16), "Retrieve AE Title", vr_type::APPLICATION_ENTITY),
-    ((0x0008u16, 0x0056u16), "Instance Availability", vr_type::CODE_STRING),
-    ((0x0008u16, 0x0058u16), "Failed SOP Instance Uid List", vr_type::UID),
-    ((0x0008u16, 0x0060u16), "Modality", vr_type::CODE_STRING),
-    ((0x0008u16, 0x0061u16), "Modalities in Study", vr_type::CODE_STRING),
-    ((0x0008u16, 0x0062u16), "SOP Classes in Study", vr_type::UID),
-    ((0x0008u16, 0x0064u16), "Conversion Type", vr_type::CODE_STRING),
-    ((0x0008u16, 0x0068u16), "Presentation Intent Type", vr_type::CODE_STRING),
-    ((0x0008u16, 0x0070u16), "Manufacturer", vr_type::LONG_STRING),
-    ((0x0008u16, 0x0080u16), "Institution Name", vr_type::LONG_STRING),
-    ((0x0008u16, 0x0081u16), "Institution Address", vr_type::SHORT_TEXT),
-    ((0x0008u16, 0x0082u16), "Institution Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x0090u16), "Referring Physician's Name", vr_type::PERSON_NAME),
-    ((0x0008u16, 0x0092u16), "Referring Physician's Address", vr_type::SHORT_TEXT),
-    ((0x0008u16, 0x0094u16), "Referring Physician's Telephone Numbers", vr_type::SHORT_STRING),
-    ((0x0008u16, 0x0100u16), "Code Value", vr_type::SHORT_STRING),
-    ((0x0008u16, 0x0102u16), "Coding Scheme Designator", vr_type::SHORT_STRING),
-    ((0x0008u16, 0x0103u16), "Coding Scheme Version", vr_type::SHORT_STRING),
-    ((0x0008u16, 0x0104u16), "Code Meaning", vr_type::LONG_STRING),
-    ((0x0008u16, 0x0105u16), "Mapping Resource", vr_type::CODE_STRING),
-    ((0x0008u16, 0x0106u16), "Context Group Version", vr_type::DATE_TIME),
-    ((0x0008u16, 0x0107u16), "Context Group Local Version", vr_type::DATE_TIME),
-    ((0x0008u16, 0x010Bu16), "Context Group Extension Flag", vr_type::CODE_STRING),
-    ((0x0008u16, 0x010Cu16), "Coding Scheme Uid", vr_type::UID),
-    ((0x0008u16, 0x010Du16), "Context Group Extension Creator Uid", vr_type::UID),
-    ((0x0008u16, 0x010Fu16), "Context Identifier", vr_type::CODE_STRING),
-    ((0x0008u16, 0x0110u16), "Coding Scheme Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x0112u16), "Coding Scheme Registry", vr_type::LONG_STRING),
-    ((0x0008u16, 0x0114u16), "Coding Scheme External ID", vr_type::SHORT_TEXT),
-    ((0x0008u16, 0x0115u16), "Coding Scheme Name", vr_type::SHORT_TEXT),
-    ((0x0008u16, 0x0116u16), "Coding Scheme Responsible Organization", vr_type::SHORT_TEXT),
-    ((0x0008u16, 0x0201u16), "Timezone Offset From UTC", vr_type::SHORT_STRING),
-    ((0x0008u16, 0x1010u16), "Station Name", vr_type::SHORT_STRING),
-    ((0x0008u16, 0x1030u16), "Study Description", vr_type::LONG_STRING),
-    ((0x0008u16, 0x1032u16), "Procedure Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x103Eu16), "Series Description", vr_type::LONG_STRING),
-    ((0x0008u16, 0x1040u16), "Institutional Department Name", vr_type::LONG_STRING),
-    ((0x0008u16, 0x1048u16), "Physician(s) of Record", vr_type::PERSON_NAME),
-    ((0x0008u16, 0x1050u16), "Performing Physician's Name", vr_type::PERSON_NAME),
-    ((0x0008u16, 0x1060u16), "Name of Physician(s) Reading Study", vr_type::PERSON_NAME),
-    ((0x0008u16, 0x1070u16), "Operators' Name", vr_type::PERSON_NAME),
-    ((0x0008u16, 0x1080u16), "Admitting Diagnoses Description", vr_type::LONG_STRING),
-    ((0x0008u16, 0x1084u16), "Admitting Diagnoses Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1090u16), "Manufacturer's Model Name", vr_type::LONG_STRING),
-    ((0x0008u16, 0x1100u16), "Referenced Results Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1110u16), "Referenced Study Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1111u16), "Referenced Performed Procedure Step Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1115u16), "Referenced Series Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1120u16), "Referenced Patient Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1125u16), "Referenced Visit Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1130u16), "Referenced Overlay Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1140u16), "Referenced Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x114Bu16), "Referenced Real World Value Mapping Instance Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1150u16), "Referenced SOP Class Uid", vr_type::UID),
-    ((0x0008u16, 0x1155u16), "Referenced SOP Instance Uid", vr_type::UID),
-    ((0x0008u16, 0x115Au16), "SOP Classes Supported", vr_type::UID),
-    ((0x0008u16, 0x1160u16), "Referenced Frame Number", vr_type::INTEGER_STRING),
-    ((0x0008u16, 0x1195u16), "Transaction Uid", vr_type::UID),
-    ((0x0008u16, 0x1197u16), "Failure Reason", vr_type::UNSIGNED_SHORT),
-    ((0x0008u16, 0x1198u16), "Failed SOP Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1199u16), "Referenced SOP Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1250u16), "Related Series Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2111u16), "Derivation Description", vr_type::SHORT_TEXT),
-    ((0x0008u16, 0x2112u16), "Source Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2120u16), "Stage Name", vr_type::SHORT_STRING),
-    ((0x0008u16, 0x2122u16), "Stage Number", vr_type::INTEGER_STRING),
-    ((0x0008u16, 0x2124u16), "Number of Stages", vr_type::INTEGER_STRING),
-    ((0x0008u16, 0x2127u16), "View Name", vr_type::SHORT_STRING),
-    ((0x0008u16, 0x2128u16), "View Number", vr_type::INTEGER_STRING),
-    ((0x0008u16, 0x2129u16), "Number of Event Timers", vr_type::INTEGER_STRING),
-    ((0x0008u16, 0x212Au16), "Number of Views in Stage", vr_type::INTEGER_STRING),
-    ((0x0008u16, 0x2130u16), "Event Elapsed Time(s)", vr_type::DECIMAL_STRING),
-    ((0x0008u16, 0x2132u16), "Event Timer Name(s)", vr_type::LONG_STRING),
-    ((0x0008u16, 0x2142u16), "Start Trim", vr_type::INTEGER_STRING),
-    ((0x0008u16, 0x2143u16), "Stop Trim", vr_type::INTEGER_STRING),
-    ((0x0008u16, 0x2144u16), "Recommended Display Frame Rate", vr_type::INTEGER_STRING),
-    ((0x0008u16, 0x2218u16), "Anatomic Region Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2220u16), "Anatomic Region Modifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2228u16), "Primary Anatomic Structure Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2229u16), "Anatomic Structure, Space or Region Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2230u16), "Primary Anatomic Structure Modifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x3001u16), "Alternate Representation Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    (PATIENT_NAME,           "Patient's Name", vr_type::PERSON_NAME),
-    ((0x0010u16, 0x0020u16), "Patient ID", vr_type::LONG_STRING),
-    ((0x0010u16, 0x0021u16), "Issuer of Patient ID", vr_type::LONG_STRING),
-    ((0x0010u16, 0x0030u16), "Patient's Birth Date", vr_type::DATE),
-    ((0x0010u16, 0x0032u16), "Patient's Birth Time", vr_type::TIME),
-    ((0x0010u16, 0x0040u16), "Patient's Sex", vr_type::CODE_STRING),
-    ((0x0010u16, 0x0050u16), "Patient's Insurance Plan Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0010u16, 0x0101u16), "Patient's Primary Language Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0010u16, 0x0102u16), "Patient's Primary Language Modifier Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0010u16, 0x1000u16), "Other Patient IDs", vr_type::LONG_STRING),
-    ((0x0010u16, 0x1001u16), "Other Patient Names", vr_type::PERSON_NAME),
-    ((0x0010u16, 0x1005u16), "Patient's Birth Name", vr_type::PERSON_NAME),
-    ((0x0010u16, 0x1010u16), "Patient's Age", vr_type::AGE_STRING),
-    ((0x0010u16, 0x1020u16), "Patient's Size", vr_type::DECIMAL_STRING),
-    ((0x0010u16, 0x1030u16), "Patient's Weight", vr_type::DECIMAL_STRING),
-    ((0x0010u16, 0x1040u16), "Patient's Address", vr_type::LONG_STRING),
-    ((0x0010u16, 0x1060u16), "Patient's Mother's Birth Name", vr_type::PERSON_NAME),
-    ((0x0010u16, 0x1080u16), "Military Rank", vr_type::LONG_STRING),
-    ((0x0010u16, 0x1081u16), "Branch of Service", vr_type::LONG_STRING),
-    ((0x0010u16, 0x1090u16), "Medical Record Locator", vr_type::LONG_STRING),
-    ((0x0010u16, 0x2000u16), "Medical Alerts", vr_type::LONG_STRING),
-    ((0x0010u16, 0x2110u16), "Allergies", vr_type::LONG_STRING),
-    ((0x0010u16, 0x2150u16), "Country of Residence", vr_type::LONG_STRING),
-    ((0x0010u16, 0x2152u16), "Region of Residence", vr_type::LONG_STRING),
-    ((0x0010u16, 0x2154u16), "Patient's Telephone Numbers", vr_type::SHORT_STRING),
-    ((0x0010u16, 0x2160u16), "Ethnic Group", vr_type::SHORT_STRING),
-    ((0x0010u16, 0x2180u16), "Occupation", vr_type::SHORT_STRING),
-    ((0x0010u16, 0x21A0u16), "Smoking Status", vr_type::CODE_STRING),
-    ((0x0010u16, 0x21B0u16), "Additional Patient History", vr_type::LONG_TEXT),
-    ((0x0010u16, 0x21C0u16), "Pregnancy Status", vr_type::UNSIGNED_SHORT),
-    ((0x0010u16, 0x21D0u16), "Last Menstrual Date", vr_type::DATE),
-    ((0x0010u16, 0x21F0u16), "Patient's Religious Preference", vr_type::LONG_STRING),
-    ((0x0010u16, 0x4000u16), "Patient Comments", vr_type::LONG_TEXT),
-    ((0x0018u16, 0x0010u16), "Contrast/Bolus Agent", vr_type::LONG_STRING),
-    ((0x0018u16, 0x0012u16), "Contrast/Bolus Agent Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x0014u16), "Contrast/Bolus Administration Route Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x0015u16), "Body Part Examined", vr_type::CODE_STRING),
-    ((0x0018u16, 0x0020u16), "Scanning Sequence", vr_type::CODE_STRING),
-    ((0x0018u16, 0x0021u16), "Sequence Variant", vr_type::CODE_STRING),
-    ((0x0018u16, 0x0022u16), "Scan Options", vr_type::CODE_STRING),
-    ((0x0018u16, 0x0023u16), "MR Acquisition Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x0024u16), "Sequence Name", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x0025u16), "Angio Flag", vr_type::CODE_STRING),
-    ((0x0018u16, 0x0026u16), "Intervention Drug Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x0027u16), "Intervention Drug Stop Time", vr_type::TIME),
-    ((0x0018u16, 0x0028u16), "Intervention Drug Dose", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0029u16), "Intervention Drug Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x002Au16), "Additional Drug Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x0031u16), "Radiopharmaceutical", vr_type::LONG_STRING),
-    ((0x0018u16, 0x0034u16), "Intervention Drug Name", vr_type::LONG_STRING),
-    ((0x0018u16, 0x0035u16), "Intervention Drug Start Time", vr_type::TIME),
-    ((0x0018u16, 0x0036u16), "Intervention Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x0038u16), "Intervention Status", vr_type::CODE_STRING),
-    ((0x0018u16, 0x003Au16), "Intervention Description", vr_type::SHORT_TEXT),
-    ((0x0018u16, 0x0040u16), "Cine Rate", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x0050u16), "Slice Thickness", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0060u16), "KVP", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0070u16), "Counts Accumulated", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x0071u16), "Acquisition Termination Condition", vr_type::CODE_STRING),
-    ((0x0018u16, 0x0072u16), "Effective Duration", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0073u16), "Acquisition Start Condition", vr_type::CODE_STRING),
-    ((0x0018u16, 0x0074u16), "Acquisition Start Condition Data", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x0075u16), "Acquisition Termination Condition Data", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x0080u16), "Repetition Time", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0081u16), "Echo Time", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0082u16), "Inversion Time", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0083u16), "Number of Averages", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0084u16), "Imaging Frequency", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0085u16), "Imaged Nucleus", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x0086u16), "Echo Number(s)", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x0087u16), "Magnetic Field Strength", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0088u16), "Spacing Between Slices", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0089u16), "Number of Phase Encoding Steps", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x0090u16), "Data Collection Diameter", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0091u16), "Echo Train Length", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x0093u16), "Percent Sampling", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0094u16), "Percent Phase Field of View", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0095u16), "Pixel Bandwidth", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1000u16), "Device Serial Number", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1002u16), "Device Uid", vr_type::UID),
-    ((0x0018u16, 0x1003u16), "Device ID", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1004u16), "Plate ID", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1010u16), "Secondary Capture Device ID", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1011u16), "Hardcopy Creation Device ID", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1012u16), "Date of Secondary Capture", vr_type::DATE),
-    ((0x0018u16, 0x1014u16), "Time of Secondary Capture", vr_type::TIME),
-    ((0x0018u16, 0x1016u16), "Secondary Capture Device Manufacturer", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1017u16), "Hardcopy Device Manufacturer", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1018u16), "Secondary Capture Device Manufacturer's Model Name", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1019u16), "Secondary Capture Device Software Version(s)", vr_type::LONG_STRING),
-    ((0x0018u16, 0x101Au16), "Hardcopy Device Software Version", vr_type::LONG_STRING),
-    ((0x0018u16, 0x101Bu16), "Hardcopy Device Manufacturer's Model Name", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1020u16), "Software Version(s)", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1022u16), "Video Image Format Acquired", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x1023u16), "Digital Image Format Acquired", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1030u16), "Protocol Name", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1040u16), "Contrast/Bolus Route", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1041u16), "Contrast/Bolus Volume", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1042u16), "Contrast/Bolus Start Time", vr_type::TIME),
-    ((0x0018u16, 0x1043u16), "Contrast/Bolus Stop Time", vr_type::TIME),
-    ((0x0018u16, 0x1044u16), "Contrast/Bolus Total Dose", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1045u16), "Syringe Counts", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1046u16), "Contrast Flow Rate", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1047u16), "Contrast Flow Duration", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1048u16), "Contrast/Bolus Ingredient", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1049u16), "Contrast/Bolus Ingredient Concentration", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1050u16), "Spatial Resolution", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1060u16), "Trigger Time", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1061u16), "Trigger Source or Type", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1062u16), "Nominal Interval", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1063u16), "Frame Time", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1064u16), "Cardiac Framing Type", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1065u16), "Frame Time Vector", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1066u16), "Frame Delay", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1070u16), "Radiopharmaceutical Route", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1071u16), "Radiopharmaceutical Volume", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1072u16), "Radiopharmaceutical Start Time", vr_type::TIME),
-    ((0x0018u16, 0x1073u16), "Radiopharmaceutical Stop Time", vr_type::TIME),
-    ((0x0018u16, 0x1074u16), "Radionuclide Total Dose", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1075u16), "Radionuclide Half Life", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1076u16), "Radionuclide Positron Fraction", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1077u16), "Radiopharmaceutical Specific Activity", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1078u16), "Radiopharmaceutical Start DateTime", vr_type::DATE_TIME),
-    ((0x0018u16, 0x1079u16), "Radiopharmaceutical Stop DateTime", vr_type::DATE_TIME),
-    ((0x0018u16, 0x1080u16), "Beat Rejection Flag", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1081u16), "Low R-R Value", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1082u16), "High R-R Value", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1083u16), "Intervals Acquired", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1084u16), "Intervals Rejected", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1085u16), "PVC Rejection", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1086u16), "Skip Beats", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1088u16), "Heart Rate", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1090u16), "Cardiac Number of Images", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1094u16), "Trigger Window", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1100u16), "Reconstruction Diameter", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1110u16), "Distance Source to Detector", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1111u16), "Distance Source to Patient", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1114u16), "Estimated Radiographic Magnification Factor", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1120u16), "Gantry/Detector Tilt", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1121u16), "Gantry/Detector Slew", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1130u16), "Table Height", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1131u16), "Table Traverse", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1134u16), "Table Motion", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1135u16), "Table Vertical Increment", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1136u16), "Table Lateral Increment", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1137u16), "Table Longitudinal Increment", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1138u16), "Table Angle", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x113Au16), "Table Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1140u16), "Rotation Direction", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1141u16), "Angular Position", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1142u16), "Radial Position", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1143u16), "Scan Arc", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1144u16), "Angular Step", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1145u16), "Center of Rotation Offset", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1147u16), "Field of View Shape", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1149u16), "Field of View Dimension(s)", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1150u16), "Exposure Time", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1151u16), "X-Ray Tube Current", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1152u16), "Exposure", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1153u16), "Exposure in uAs", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1154u16), "Average Pulse Width", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1155u16), "Radiation Setting", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1156u16), "Rectification Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x115Au16), "Radiation Mode", vr_type::CODE_STRING),
-    ((0x0018u16, 0x115Eu16), "Image and Fluoroscopy Area Dose Product", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1160u16), "Filter Type", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x1161u16), "Type of Filters", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1162u16), "Intensifier Size", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1164u16), "Imager Pixel Spacing", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1166u16), "Grid", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1170u16), "Generator Power", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1180u16), "Collimator/Grid Name", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x1181u16), "Collimator Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1182u16), "Focal Distance", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1183u16), "X Focus Center", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1184u16), "Y Focus Center", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1190u16), "Focal Spot(s)", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1191u16), "Anode Target Material", vr_type::CODE_STRING),
-    ((0x0018u16, 0x11A0u16), "Body Part Thickness", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x11A2u16), "Compression Force", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1200u16), "Date of Last Calibration", vr_type::DATE),
-    ((0x0018u16, 0x1201u16), "Time of Last Calibration", vr_type::TIME),
-    ((0x0018u16, 0x1210u16), "Convolution Kernel", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x1242u16), "Actual Frame Duration", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1243u16), "Count Rate", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1244u16), "Preferred Playback Sequencing", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x1250u16), "Receive Coil Name", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x1251u16), "Transmit Coil Name", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x1260u16), "Plate Type", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x1261u16), "Phosphor Type", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1300u16), "Scan Velocity", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1301u16), "Whole Body Technique", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1302u16), "Scan Length", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1310u16), "Acquisition Matrix", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x1312u16), "In-Plane Phase Encoding Direction", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1314u16), "Flip Angle", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1315u16), "Variable Flip Angle Flag", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1316u16), "SAR", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1318u16), "dB/dt", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1400u16), "Acquisition Device Processing Description", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1401u16), "Acquisition Device Processing Code", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1402u16), "Cassette Orientation", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1403u16), "Cassette Size", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1404u16), "Exposures on Plate", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x1405u16), "Relative X-Ray Exposure", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1450u16), "Column Angulation", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1460u16), "Tomo Layer Height", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1470u16), "Tomo Angle", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1480u16), "Tomo Time", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1490u16), "Tomo Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1491u16), "Tomo Class", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1495u16), "Number of Tomosynthesis Source Images", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1500u16), "Positioner Motion", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1508u16), "Positioner Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1510u16), "Positioner Primary Angle", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1511u16), "Positioner Secondary Angle", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1520u16), "Positioner Primary Angle Increment", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1521u16), "Positioner Secondary Angle Increment", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1530u16), "Detector Primary Angle", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1531u16), "Detector Secondary Angle", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1600u16), "Shutter Shape", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1602u16), "Shutter Left Vertical Edge", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1604u16), "Shutter Right Vertical Edge", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1606u16), "Shutter Upper Horizontal Edge", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1608u16), "Shutter Lower Horizontal Edge", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1610u16), "Center of Circular Shutter", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1612u16), "Radius of Circular Shutter", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1620u16), "Vertices of the Polygonal Shutter", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1624u16), "Shutter Presentation Color CIELab Value", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x1700u16), "Collimator Shape", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1702u16), "Collimator Left Vertical Edge", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1704u16), "Collimator Right Vertical Edge", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1706u16), "Collimator Upper Horizontal Edge", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1708u16), "Collimator Lower Horizontal Edge", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1710u16), "Center of Circular Collimator", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1712u16), "Radius of Circular Collimator", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x1720u16), "Vertices of the Polygonal Collimator", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x5000u16), "Output Power", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x5010u16), "Transducer Data", vr_type::LONG_STRING),
-    ((0x0018u16, 0x5012u16), "Focus Depth", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x5020u16), "Processing Function", vr_type::LONG_STRING),
-    ((0x0018u16, 0x5021u16), "Postprocessing Function", vr_type::LONG_STRING),
-    ((0x0018u16, 0x5022u16), "Mechanical Index", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x5024u16), "Bone Thermal Index", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x5026u16), "Cranial Thermal Index", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x5027u16), "Soft Tissue Thermal Index", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x5028u16), "Soft Tissue-focus Thermal Index", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x5029u16), "Soft Tissue-surface Thermal Index", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x5050u16), "Depth of Scan Field", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x5100u16), "Patient Position", vr_type::CODE_STRING),
-    ((0x0018u16, 0x5101u16), "View Position", vr_type::CODE_STRING),
-    ((0x0018u16, 0x5104u16), "Projection Eponymous Name Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x6000u16), "Sensitivity", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x6011u16), "Sequence of Ultrasound Regions", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x6012u16), "Region Spatial Format", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x6014u16), "Region Data Type", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x6016u16), "Region Flags", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x6018u16), "Region Location Min X0", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x601Au16), "Region Location Min Y0", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x601Cu16), "Region Location Max X1", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x601Eu16), "Region Location Max Y1", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x6020u16), "Reference Pixel X0", vr_type::SIGNED_LONG),
-    ((0x0018u16, 0x6022u16), "Reference Pixel Y0", vr_type::SIGNED_LONG),
-    ((0x0018u16, 0x6024u16), "Physical Units X Direction", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x6026u16), "Physical Units Y Direction", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x6028u16), "Reference Pixel Physical Value X", vr_type::DOUBLE),
-    ((0x0018u16, 0x602Au16), "Reference Pixel Physical Value Y", vr_type::DOUBLE),
-    ((0x0018u16, 0x602Cu16), "Physical Delta X", vr_type::DOUBLE),
-    ((0x0018u16, 0x602Eu16), "Physical Delta Y", vr_type::DOUBLE),
-    ((0x0018u16, 0x6030u16), "Transducer Frequency", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x6031u16), "Transducer Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x6032u16), "Pulse Repetition Frequency", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x6034u16), "Doppler Correction Angle", vr_type::DOUBLE),
-    ((0x0018u16, 0x6036u16), "Steering Angle", vr_type::DOUBLE),
-    ((0x0018u16, 0x6039u16), "Doppler Sample Volume X Position", vr_type::SIGNED_LONG),
-    ((0x0018u16, 0x603Bu16), "Doppler Sample Volume Y Position", vr_type::SIGNED_LONG),
-    ((0x0018u16, 0x603Du16), "TM-Line Position X0", vr_type::SIGNED_LONG),
-    ((0x0018u16, 0x603Fu16), "TM-Line Position Y0", vr_type::SIGNED_LONG),
-    ((0x0018u16, 0x6041u16), "TM-Line Position X1", vr_type::SIGNED_LONG),
-    ((0x0018u16, 0x6043u16), "TM-Line Position Y1", vr_type::SIGNED_LONG),
-    ((0x0018u16, 0x6044u16), "Pixel Component Organization", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x6046u16), "Pixel Component Mask", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x6048u16), "Pixel Component Range Start", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x604Au16), "Pixel Component Range Stop", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x604Cu16), "Pixel Component Physical Units", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x604Eu16), "Pixel Component Data Type", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x6050u16), "Number of Table Break Points", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x6052u16), "Table of X Break Points", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x6054u16), "Table of Y Break Points", vr_type::DOUBLE),
-    ((0x0018u16, 0x6056u16), "Number of Table Entries", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x6058u16), "Table of Pixel Values", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x605Au16), "Table of Parameter Values", vr_type::FLOAT),
-    ((0x0018u16, 0x6060u16), "R Wave Time Vector", vr_type::FLOAT),
-    ((0x0018u16, 0x7000u16), "Detector Conditions Nominal Flag", vr_type::CODE_STRING),
-    ((0x0018u16, 0x7001u16), "Detector Temperature", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7004u16), "Detector Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x7005u16), "Detector Configuration", vr_type::CODE_STRING),
-    ((0x0018u16, 0x7006u16), "Detector Description", vr_type::LONG_TEXT),
-    ((0x0018u16, 0x7008u16), "Detector Mode", vr_type::LONG_TEXT),
-    ((0x0018u16, 0x700Au16), "Detector ID", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x700Cu16), "Date of Last Detector Calibration", vr_type::DATE),
-    ((0x0018u16, 0x700Eu16), "Time of Last Detector Calibration", vr_type::TIME),
-    ((0x0018u16, 0x7010u16), "Exposures on Detector Since Last Calibration", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x7011u16), "Exposures on Detector Since Manufactured", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x7012u16), "Detector Time Since Last Exposure", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7014u16), "Detector Active Time", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7016u16), "Detector Activation Offset From Exposure", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x701Au16), "Detector Binning", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7020u16), "Detector Element Physical Size", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7022u16), "Detector Element Spacing", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7024u16), "Detector Active Shape", vr_type::CODE_STRING),
-    ((0x0018u16, 0x7026u16), "Detector Active Dimension(s)", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7028u16), "Detector Active Origin", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x702Au16), "Detector Manufacturer Name", vr_type::LONG_STRING),
-    ((0x0018u16, 0x702Bu16), "Detector Manufacturer's Model Name", vr_type::LONG_STRING),
-    ((0x0018u16, 0x7030u16), "Field of View Origin", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7032u16), "Field of View Rotation", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7034u16), "Field of View Horizontal Flip", vr_type::CODE_STRING),
-    ((0x0018u16, 0x7040u16), "Grid Absorbing Material", vr_type::LONG_TEXT),
-    ((0x0018u16, 0x7041u16), "Grid Spacing Material", vr_type::LONG_TEXT),
-    ((0x0018u16, 0x7042u16), "Grid Thickness", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7044u16), "Grid Pitch", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7046u16), "Grid Aspect Ratio", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x7048u16), "Grid Period", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x704Cu16), "Grid Focal Distance", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7050u16), "Filter Material", vr_type::CODE_STRING),
-    ((0x0018u16, 0x7052u16), "Filter Thickness Minimum", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7054u16), "Filter Thickness Maximum", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x7060u16), "Exposure Control Mode", vr_type::CODE_STRING),
-    ((0x0018u16, 0x7062u16), "Exposure Control Mode Description", vr_type::LONG_TEXT),
-    ((0x0018u16, 0x7064u16), "Exposure Status", vr_type::CODE_STRING),
-    ((0x0018u16, 0x7065u16), "PhotoTimer Setting", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x8150u16), "Exposure Time in uS", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x8151u16), "X-Ray Tube Current in uA", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0xA001u16), "Contributing Equipment Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0xA002u16), "Contribution DateTime", vr_type::DATE_TIME),
-    ((0x0018u16, 0xA003u16), "Contribution Description", vr_type::SHORT_TEXT),
-    (STUDY_INSTANCE_UID,     "Study Instance Uid", vr_type::UID),
-    (SERIES_INSTANCE_UID,    "Series Instance Uid", vr_type::UID),
-    ((0x0020u16, 0x0010u16), "Study ID", vr_type::SHORT_STRING),
-    ((0x0020u16, 0x0011u16), "Series Number", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0012u16), "Acquisition Number", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0013u16), "Instance Number", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0019u16), "Item Number", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0020u16), "Patient Orientation", vr_type::CODE_STRING),
-    ((0x0020u16, 0x0022u16), "Overlay Number", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0024u16), "Curve Number", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0026u16), "LUT Number", vr_type::INTEGER_STRING),
-    (POSITION,               "Image Position (Patient)", vr_type::DECIMAL_STRING),
-    ((0x0020u16, 0x0037u16), "Image Orientation (Patient)", vr_type::DECIMAL_STRING),
-    ((0x0020u16, 0x0052u16), "Frame of Reference Uid", vr_type::UID),
-    ((0x0020u16, 0x0060u16), "Laterality", vr_type::CODE_STRING),
-    ((0x0020u16, 0x0062u16), "Image Laterality", vr_type::CODE_STRING),
-    ((0x0020u16, 0x0100u16), "Temporal Position Identifier", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0105u16), "Number of Temporal Positions", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0110u16), "Temporal Resolution", vr_type::DECIMAL_STRING),
-    ((0x0020u16, 0x1000u16), "Series in Study", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x1002u16), "Images in Acquisition", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x1040u16), "Position Reference Indicator", vr_type::LONG_STRING),
-    (LOCATION,               "Slice Location", vr_type::DECIMAL_STRING),
-    ((0x0020u16, 0x1070u16), "Other Study Numbers", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x1200u16), "Number of Patient Related Studies", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x1202u16), "Number of Patient Related Series", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x1204u16), "Number of Patient Related Instances", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x1206u16), "Number of Study Related Series", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x1208u16), "Number of Study Related Instances", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x1209u16), "Number of Series Related Instances", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x4000u16), "Image Comments", vr_type::LONG_TEXT),
-    ((0x0028u16, 0x0002u16), "Samples per Pixel", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0004u16), "Photometric Interpretation", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0006u16), "Planar Configuration", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0008u16), "Number of Frames", vr_type::INTEGER_STRING),
-    ((0x0028u16, 0x0009u16), "Frame Increment Pointer", vr_type::ATTRIBUTE),
-    ((0x0028u16, 0x000Au16), "Frame Dimension Pointer", vr_type::ATTRIBUTE),
-    (ROWS,                   "Rows", vr_type::UNSIGNED_SHORT),
-    (COLUMNS,                "Columns", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0012u16), "Planes", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0014u16), "Ultrasound Color Data Present", vr_type::UNSIGNED_SHORT),
-    (PIXEL_SPACING,          "Pixel Spacing", vr_type::DECIMAL_STRING),
-    ((0x0028u16, 0x0031u16), "Zoom Factor", vr_type::DECIMAL_STRING),
-    ((0x0028u16, 0x0032u16), "Zoom Center", vr_type::DECIMAL_STRING),
-    ((0x0028u16, 0x0034u16), "Pixel Aspect Ratio", vr_type::INTEGER_STRING),
-    ((0x0028u16, 0x0051u16), "Corrected Image", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0100u16), "Bits Allocated", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0101u16), "Bits Stored", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0102u16), "High Bit", vr_type::UNSIGNED_SHORT),
-    (PIXEL_REPRESENTATION,   "Pixel Representation", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0106u16), "Smallest Image Pixel Value", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0107u16), "Largest Image Pixel Value", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0108u16), "Smallest Pixel Value in Series", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0109u16), "Largest Pixel Value in Series", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0110u16), "Smallest Image Pixel Value in Plane", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0111u16), "Largest Image Pixel Value in Plane", vr_type::UNSIGNED_SHORT),
-    (PIXEL_PADDING,          "Pixel Padding Value", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0300u16), "Quality Control Image", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0301u16), "Burned In Annotation", vr_type::CODE_STRING),
-    ((0x0028u16, 0x1040u16), "Pixel Intensity Relationship", vr_type::CODE_STRING),
-    ((0x0028u16, 0x1041u16), "Pixel Intensity Relationship Sign", vr_type::SIGNED_SHORT),
-    (WINDOW_CENTER,          "Window Center", vr_type::DECIMAL_STRING),
-    (WINDOW_WIDTH,           "Window Width", vr_type::DECIMAL_STRING),
-    (RESCALE_INTERCEPT,      "Rescale Intercept", vr_type::DECIMAL_STRING),
-    (RESCALE_SLOPE,          "Rescale Slope", vr_type::DECIMAL_STRING),
-    ((0x0028u16, 0x1054u16), "Rescale Type", vr_type::LONG_STRING),
-    ((0x0028u16, 0x1055u16), "Window Center and Width Explanation", vr_type::LONG_STRING),
-    ((0x0028u16, 0x1056u16), "VOI LUT Function", vr_type::CODE_STRING),
-    ((0x0028u16, 0x1090u16), "Recommended Viewing Mode", vr_type::CODE_STRING),
-    ((0x0028u16, 0x1101u16), "Red Palette Color Lookup Table Descriptor", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x1102u16), "Green Palette Color Lookup Table Descriptor", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x1103u16), "Blue Palette Color Lookup Table Descriptor", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x1199u16), "Palette Color Lookup Table Uid", vr_type::UID),
-    ((0x0028u16, 0x1201u16), "Red Palette Color Lookup Table Data", vr_type::OTHER_WORD),
-    ((0x0028u16, 0x1202u16), "Green Palette Color Lookup Table Data", vr_type::OTHER_WORD),
-    ((0x0028u16, 0x1203u16), "Blue Palette Color Lookup Table Data", vr_type::OTHER_WORD),
-    ((0x0028u16, 0x1221u16), "Segmented Red Palette Color Lookup Table Data", vr_type::OTHER_WORD),
-    ((0x0028u16, 0x1222u16), "Segmented Green Palette Color Lookup Table Data", vr_type::OTHER_WORD),
-    ((0x0028u16, 0x1223u16), "Segmented Blue Palette Color Lookup Table Data", vr_type::OTHER_WORD),
-    ((0x0028u16, 0x1300u16), "Breast Implant Present", vr_type::CODE_STRING),
-    ((0x0028u16, 0x1350u16), "Partial View", vr_type::CODE_STRING),
-    ((0x0028u16, 0x135Au16), "Spatial Locations Preserved", vr_type::CODE_STRING),
-    ((0x0028u16, 0x1351u16), "Partial View Description", vr_type::SHORT_TEXT),
-    ((0x0028u16, 0x1352u16), "Partial View Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x2110u16), "Lossy Image Compression", vr_type::CODE_STRING),
-    ((0x0028u16, 0x2112u16), "Lossy Image Compression Ratio", vr_type::DECIMAL_STRING),
-    ((0x0028u16, 0x2114u16), "Lossy Image Compression Method", vr_type::CODE_STRING),
-    ((0x0028u16, 0x3000u16), "Modality LUT Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x3002u16), "LUT Descriptor", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x3003u16), "LUT Explanation", vr_type::LONG_STRING),
-    ((0x0028u16, 0x3004u16), "Modality LUT Type", vr_type::LONG_STRING),
-    ((0x0028u16, 0x3006u16), "LUT Data", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x3010u16), "VOI LUT Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x5000u16), "Bi-Plane Acquisition Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x6010u16), "Representative Frame Number", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x6020u16), "Frame Numbers of Interest (FOI)", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x6022u16), "Frame of Interest Description", vr_type::LONG_STRING),
-    ((0x0028u16, 0x6023u16), "Frame of Interest Type", vr_type::CODE_STRING),
-    ((0x0028u16, 0x6040u16), "R Wave Pointer", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x6100u16), "Mask Subtraction Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x6101u16), "Mask Operation", vr_type::CODE_STRING),
-    ((0x0028u16, 0x6102u16), "Applicable Frame Range", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x6110u16), "Mask Frame Numbers", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x6112u16), "Contrast Frame Averaging", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x6114u16), "Mask Sub-pixel Shift", vr_type::FLOAT),
-    ((0x0028u16, 0x6120u16), "TID Offset", vr_type::SIGNED_SHORT),
-    ((0x0028u16, 0x6190u16), "Mask Operation Explanation", vr_type::SHORT_TEXT),
-    ((0x0028u16, 0x7FE0u16), "Pixel Data Provider URL", vr_type::UNLIMITED_TEXT),
-    ((0x0032u16, 0x1032u16), "Requesting Physician", vr_type::PERSON_NAME),
-    ((0x0032u16, 0x1033u16), "Requesting Service", vr_type::LONG_STRING),
-    ((0x0032u16, 0x1060u16), "Requested Procedure Description", vr_type::LONG_STRING),
-    ((0x0032u16, 0x1064u16), "Requested Procedure Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0032u16, 0x1070u16), "Requested Contrast Agent", vr_type::LONG_STRING),
-    ((0x0032u16, 0x4000u16), "Study Comments", vr_type::LONG_TEXT),
-    ((0x0038u16, 0x0004u16), "Referenced Patient Alias Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0038u16, 0x0008u16), "Visit Status ID", vr_type::CODE_STRING),
-    ((0x0038u16, 0x0010u16), "Admission ID", vr_type::LONG_STRING),
-    ((0x0038u16, 0x0016u16), "Route of Admissions", vr_type::LONG_STRING),
-    ((0x0038u16, 0x0020u16), "Admitting Date", vr_type::DATE),
-    ((0x0038u16, 0x0021u16), "Admitting Time", vr_type::TIME),
-    ((0x0038u16, 0x0050u16), "Special Needs", vr_type::LONG_STRING),
-    ((0x0038u16, 0x0300u16), "Current Patient Location", vr_type::LONG_STRING),
-    ((0x0038u16, 0x0400u16), "Patient's Institution Residence", vr_type::LONG_STRING),
-    ((0x0038u16, 0x0500u16), "Patient State", vr_type::LONG_STRING),
-    ((0x0038u16, 0x0502u16), "Patient Clinical Trial Participation Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0038u16, 0x0100u16), "Pertinent Documents Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0038u16, 0x4000u16), "Visit Comments", vr_type::LONG_TEXT),
-    ((0x0040u16, 0x0001u16), "Scheduled Station AE Title", vr_type::APPLICATION_ENTITY),
-    ((0x0040u16, 0x0002u16), "Scheduled Procedure Step Start Date", vr_type::DATE),
-    ((0x0040u16, 0x0003u16), "Scheduled Procedure Step Start Time", vr_type::TIME),
-    ((0x0040u16, 0x0004u16), "Scheduled Procedure Step End Date", vr_type::DATE),
-    ((0x0040u16, 0x0005u16), "Scheduled Procedure Step End Time", vr_type::TIME),
-    ((0x0040u16, 0x0006u16), "Scheduled Performing Physician's Name", vr_type::PERSON_NAME),
-    ((0x0040u16, 0x0007u16), "Scheduled Procedure Step Description", vr_type::LONG_STRING),
-    ((0x0040u16, 0x0008u16), "Scheduled Protocol Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0009u16), "Scheduled Procedure Step ID", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x000Au16), "Stage Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0010u16), "Scheduled Station Name", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x0011u16), "Scheduled Procedure Step Location", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x0012u16), "Pre-Medication", vr_type::LONG_STRING),
-    ((0x0040u16, 0x0020u16), "Scheduled Procedure Step Status", vr_type::CODE_STRING),
-    ((0x0040u16, 0x0100u16), "Scheduled Procedure Step Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0220u16), "Referenced Non-Image Composite SOP Instance Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0241u16), "Performed Station AE Title", vr_type::APPLICATION_ENTITY),
-    ((0x0040u16, 0x0242u16), "Performed Station Name", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x0243u16), "Performed Location", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x0244u16), "Performed Procedure Step Start Date", vr_type::DATE),
-    ((0x0040u16, 0x0245u16), "Performed Procedure Step Start Time", vr_type::TIME),
-    ((0x0040u16, 0x0250u16), "Performed Procedure Step End Date", vr_type::DATE),
-    ((0x0040u16, 0x0251u16), "Performed Procedure Step End Time", vr_type::TIME),
-    ((0x0040u16, 0x0252u16), "Performed Procedure Step Status", vr_type::CODE_STRING),
-    ((0x0040u16, 0x0253u16), "Performed Procedure Step ID", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x0254u16), "Performed Procedure Step Description", vr_type::LONG_STRING),
-    ((0x0040u16, 0x0255u16), "Performed Procedure Type Description", vr_type::LONG_STRING),
-    ((0x0040u16, 0x0260u16), "Performed Protocol Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0270u16), "Scheduled Step Attributes Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0275u16), "Request Attributes Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0280u16), "Comments on the Performed Procedure Step", vr_type::SHORT_TEXT),
-    ((0x0040u16, 0x0281u16), "Performed Procedure Step Discontinuation Reason Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0293u16), "Quantity Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0294u16), "Quantity", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0x0295u16), "Measuring Units Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0296u16), "Billing Item Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0300u16), "Total Time of Fluoroscopy", vr_type::UNSIGNED_SHORT),
-    ((0x0040u16, 0x0301u16), "Total Number of Exposures", vr_type::UNSIGNED_SHORT),
-    ((0x0040u16, 0x0302u16), "Entrance Dose", vr_type::UNSIGNED_SHORT),
-    ((0x0040u16, 0x0303u16), "Exposed Area", vr_type::UNSIGNED_SHORT),
-    ((0x0040u16, 0x0306u16), "Distance Source to Entrance", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0x030Eu16), "Exposure Dose Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0310u16), "Comments on Radiation Dose", vr_type::SHORT_TEXT),
-    ((0x0040u16, 0x0312u16), "X-Ray Output", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0x0314u16), "Half Value Layer", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0x0316u16), "Organ Dose", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0x0318u16), "Organ Exposed", vr_type::CODE_STRING),
-    ((0x0040u16, 0x0320u16), "Billing Procedure Step Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0321u16), "Film Consumption Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0324u16), "Billing Supplies and Devices Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0340u16), "Performed Series Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0400u16), "Comments on the Scheduled Procedure Step", vr_type::LONG_TEXT),
-    ((0x0040u16, 0x0440u16), "Protocol Context Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0441u16), "Content Item Modifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0551u16), "Specimen Identifier", vr_type::LONG_STRING),
-    ((0x0040u16, 0x059Au16), "Specimen Type Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0555u16), "Acquisition Context Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0556u16), "Acquisition Context Description", vr_type::SHORT_TEXT),
-    ((0x0040u16, 0x071Au16), "Image Center Point Coordinates Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x072Au16), "X offset in Slide Coordinate System", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0x073Au16), "Y offset in Slide Coordinate System", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0x074Au16), "Z offset in Slide Coordinate System", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0x08D8u16), "Pixel Spacing Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x08DAu16), "Coordinate System Axis Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x08EAu16), "Measurement Units Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x1001u16), "Requested Procedure ID", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x1002u16), "Reason for the Requested Procedure", vr_type::LONG_STRING),
-    ((0x0040u16, 0x1003u16), "Requested Procedure Priority", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x1004u16), "Patient Transport Arrangements", vr_type::LONG_STRING),
-    ((0x0040u16, 0x1005u16), "Requested Procedure Location", vr_type::LONG_STRING),
-    ((0x0040u16, 0x1008u16), "Confidentiality Code", vr_type::LONG_STRING),
-    ((0x0040u16, 0x1009u16), "Reporting Priority", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x100Au16), "Reason for Requested Procedure Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x1010u16), "Names of Intended Recipients of Results", vr_type::PERSON_NAME),
-    ((0x0040u16, 0x1400u16), "Requested Procedure Comments", vr_type::LONG_TEXT),
-    ((0x0040u16, 0x2004u16), "Issue Date of Imaging Service Request", vr_type::DATE),
-    ((0x0040u16, 0x2005u16), "Issue Time of Imaging Service Request", vr_type::TIME),
-    ((0x0040u16, 0x2008u16), "Order Entered By", vr_type::PERSON_NAME),
-    ((0x0040u16, 0x2009u16), "Order Enterer's Location", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x2010u16), "Order Callback Phone Number", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x2016u16), "Placer Order Number/Imaging Service Request", vr_type::LONG_STRING),
-    ((0x0040u16, 0x2017u16), "Filler Order Number/Imaging Service Request", vr_type::LONG_STRING),
-    ((0x0040u16, 0x2400u16), "Imaging Service Request Comments", vr_type::LONG_TEXT),
-    ((0x0040u16, 0x3001u16), "Confidentiality Constraint on Patient Data Description", vr_type::LONG_STRING),
-    ((0x0040u16, 0x8302u16), "Entrance Dose in mGy", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0x9094u16), "Referenced Image Real World Value Mapping Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x9098u16), "Pixel Value Mapping Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA043u16), "Concept Name Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA078u16), "Author Observer Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA07Au16), "Participant Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA07Cu16), "Custodial Organization Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA080u16), "Participation Type", vr_type::CODE_STRING),
-    ((0x0040u16, 0xA082u16), "Participation DateTime", vr_type::DATE_TIME),
-    ((0x0040u16, 0xA084u16), "Observer Type", vr_type::CODE_STRING),
-    ((0x0040u16, 0xA121u16), "Date", vr_type::DATE),
-    ((0x0040u16, 0xA122u16), "Time", vr_type::TIME),
-    ((0x0040u16, 0xA123u16), "Person Name", vr_type::PERSON_NAME),
-    ((0x0040u16, 0xA136u16), "Referenced Frame Numbers", vr_type::UNSIGNED_SHORT),
-    ((0x0040u16, 0xA160u16), "Text Value", vr_type::UNLIMITED_TEXT),
-    ((0x0040u16, 0xA168u16), "Concept Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA30Au16), "Numeric Value", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0xA390u16), "HL7 Structured Document Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xE001u16), "HL7 Instance Identifier", vr_type::SHORT_TEXT),
-    ((0x0040u16, 0xE004u16), "HL7 Document Effective Time", vr_type::DATE_TIME),
-    ((0x0040u16, 0xE006u16), "HL7 Document Type Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xE010u16), "Retrieve URI", vr_type::UNLIMITED_TEXT),
-    ((0x0042u16, 0x0010u16), "Document Title", vr_type::SHORT_TEXT),
-    ((0x0042u16, 0x0011u16), "Encapsulated Document", vr_type::OTHER_BYTE),
-    ((0x0042u16, 0x0012u16), "MIME Type of Encapsulated Document", vr_type::LONG_STRING),
-    ((0x0042u16, 0x0013u16), "Source Instance Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0050u16, 0x0004u16), "Calibration Image", vr_type::CODE_STRING),
-    ((0x0050u16, 0x0010u16), "Device Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0050u16, 0x0014u16), "Device Length", vr_type::DECIMAL_STRING),
-    ((0x0050u16, 0x0016u16), "Device Diameter", vr_type::DECIMAL_STRING),
-    ((0x0050u16, 0x0017u16), "Device Diameter Units", vr_type::CODE_STRING),
-    ((0x0050u16, 0x0018u16), "Device Volume", vr_type::DECIMAL_STRING),
-    ((0x0050u16, 0x0019u16), "Inter-Marker Distance", vr_type::DECIMAL_STRING),
-    ((0x0050u16, 0x0020u16), "Device Description", vr_type::LONG_STRING),
-    ((0x0054u16, 0x0010u16), "Energy Window Vector", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0011u16), "Number of Energy Windows", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0012u16), "Energy Window Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0013u16), "Energy Window Range Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0014u16), "Energy Window Lower Limit", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x0015u16), "Energy Window Upper Limit", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x0016u16), "Radiopharmaceutical Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0017u16), "Residual Syringe Counts", vr_type::INTEGER_STRING),
-    ((0x0054u16, 0x0018u16), "Energy Window Name", vr_type::SHORT_STRING),
-    ((0x0054u16, 0x0020u16), "Detector Vector", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0021u16), "Number of Detectors", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0022u16), "Detector Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0030u16), "Phase Vector", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0031u16), "Number of Phases", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0032u16), "Phase Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0033u16), "Number of Frames in Phase", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0036u16), "Phase Delay", vr_type::INTEGER_STRING),
-    ((0x0054u16, 0x0038u16), "Pause Between Frames", vr_type::INTEGER_STRING),
-    ((0x0054u16, 0x0039u16), "Phase Description", vr_type::CODE_STRING),
-    ((0x0054u16, 0x0050u16), "Rotation Vector", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0051u16), "Number of Rotations", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0052u16), "Rotation Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0053u16), "Number of Frames in Rotation", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0060u16), "R-R Interval Vector", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0061u16), "Number of R-R Intervals", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0062u16), "Gated Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0063u16), "Data Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0070u16), "Time Slot Vector", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0071u16), "Number of Time Slots", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0072u16), "Time Slot Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0073u16), "Time Slot Time", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x0080u16), "Slice Vector", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0081u16), "Number of Slices", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0090u16), "Angular View Vector", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0100u16), "Time Slice Vector", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0101u16), "Number of Time Slices", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0200u16), "Start Angle", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x0202u16), "Type of Detector Motion", vr_type::CODE_STRING),
-    ((0x0054u16, 0x0210u16), "Trigger Vector", vr_type::INTEGER_STRING),
-    ((0x0054u16, 0x0211u16), "Number of Triggers in Phase", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0220u16), "View Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0222u16), "View Modifier Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0300u16), "Radionuclide Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0302u16), "Administration Route Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0304u16), "Radiopharmaceutical Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0306u16), "Calibration Data Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0308u16), "Energy Window Number", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x0400u16), "Image ID", vr_type::SHORT_STRING),
-    ((0x0054u16, 0x0410u16), "Patient Orientation Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0412u16), "Patient Orientation Modifier Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0414u16), "Patient Gantry Relationship Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0054u16, 0x0500u16), "Slice Progression Direction", vr_type::CODE_STRING),
-    ((0x0054u16, 0x1000u16), "Series Type", vr_type::CODE_STRING),
-    ((0x0054u16, 0x1001u16), "Units", vr_type::CODE_STRING),
-    ((0x0054u16, 0x1002u16), "Counts Source", vr_type::CODE_STRING),
-    ((0x0054u16, 0x1004u16), "Reprojection Method", vr_type::CODE_STRING),
-    ((0x0054u16, 0x1100u16), "Randoms Correction Method", vr_type::CODE_STRING),
-    ((0x0054u16, 0x1101u16), "Attenuation Correction Method", vr_type::LONG_STRING),
-    ((0x0054u16, 0x1102u16), "Decay Correction", vr_type::CODE_STRING),
-    ((0x0054u16, 0x1103u16), "Reconstruction Method", vr_type::LONG_STRING),
-    ((0x0054u16, 0x1104u16), "Detector Lines of Response Used", vr_type::LONG_STRING),
-    ((0x0054u16, 0x1105u16), "Scatter Correction Method", vr_type::LONG_STRING),
-    ((0x0054u16, 0x1200u16), "Axial Acceptance", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x1201u16), "Axial Mash", vr_type::INTEGER_STRING),
-    ((0x0054u16, 0x1202u16), "Transverse Mash", vr_type::INTEGER_STRING),
-    ((0x0054u16, 0x1203u16), "Detector Element Size", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x1210u16), "Coincidence Window Width", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x1220u16), "Secondary Counts Type", vr_type::CODE_STRING),
-    ((0x0054u16, 0x1300u16), "Frame Reference Time", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x1310u16), "Primary (Prompts) Counts Accumulated", vr_type::INTEGER_STRING),
-    ((0x0054u16, 0x1311u16), "Secondary Counts Accumulated", vr_type::INTEGER_STRING),
-    ((0x0054u16, 0x1320u16), "Slice Sensitivity Factor", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x1321u16), "Decay Factor", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x1322u16), "Dose Calibration Factor", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x1323u16), "Scatter Fraction Factor", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x1324u16), "Dead Time Factor", vr_type::DECIMAL_STRING),
-    ((0x0054u16, 0x1330u16), "Image Index", vr_type::UNSIGNED_SHORT),
-    ((0x0054u16, 0x1400u16), "Counts Included", vr_type::CODE_STRING),
-    ((0x0054u16, 0x1401u16), "Dead Time Correction Flag", vr_type::CODE_STRING),
-    ((0x0060u16, 0x3000u16), "Histogram Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0060u16, 0x3002u16), "Histogram Number of Bins", vr_type::UNSIGNED_SHORT),
-    ((0x0060u16, 0x3004u16), "Histogram First Bin Value", vr_type::UNSIGNED_SHORT),
-    ((0x0060u16, 0x3006u16), "Histogram Last Bin Value", vr_type::UNSIGNED_SHORT),
-    ((0x0060u16, 0x3008u16), "Histogram Bin Width", vr_type::UNSIGNED_SHORT),
-    ((0x0060u16, 0x3010u16), "Histogram Explanation", vr_type::LONG_STRING),
-    ((0x0060u16, 0x3020u16), "Histogram Data", vr_type::UNSIGNED_LONG),
-    ((0x0070u16, 0x0022u16), "Graphic Data", vr_type::FLOAT),
-    ((0x0070u16, 0x0023u16), "Graphic Type", vr_type::CODE_STRING),
-    ((0x0088u16, 0x0130u16), "Storage Media File-set ID", vr_type::SHORT_STRING),
-    ((0x0088u16, 0x0140u16), "Storage Media File-set Uid", vr_type::UID),
-    ((0x0088u16, 0x0200u16), "Icon Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0088u16, 0x0904u16), "Topic Title", vr_type::LONG_STRING),
-    ((0x0088u16, 0x0906u16), "Topic Subject", vr_type::SHORT_TEXT),
-    ((0x0088u16, 0x0910u16), "Topic Author", vr_type::LONG_STRING),
-    ((0x0088u16, 0x0912u16), "Topic Keywords", vr_type::LONG_STRING),
-    ((0x2000u16, 0x0010u16), "Number of Copies", vr_type::INTEGER_STRING),
-    ((0x2000u16, 0x001Eu16), "Printer Configuration Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2000u16, 0x0020u16), "Print Priority", vr_type::CODE_STRING),
-    ((0x2000u16, 0x0030u16), "Medium Type", vr_type::CODE_STRING),
-    ((0x2000u16, 0x0040u16), "Film Destination", vr_type::CODE_STRING),
-    ((0x2000u16, 0x0050u16), "Film Session Label", vr_type::LONG_STRING),
-    ((0x2000u16, 0x0060u16), "Memory Allocation", vr_type::INTEGER_STRING),
-    ((0x2000u16, 0x0061u16), "Maximum Memory Allocation", vr_type::INTEGER_STRING),
-    ((0x2000u16, 0x0062u16), "Color Image Printing Flag", vr_type::CODE_STRING),
-    ((0x2000u16, 0x0063u16), "Collation Flag", vr_type::CODE_STRING),
-    ((0x2000u16, 0x0065u16), "Annotation Flag", vr_type::CODE_STRING),
-    ((0x2000u16, 0x0067u16), "Image Overlay Flag", vr_type::CODE_STRING),
-    ((0x2000u16, 0x0069u16), "Presentation LUT Flag", vr_type::CODE_STRING),
-    ((0x2000u16, 0x006Au16), "Image Box Presentation LUT Flag", vr_type::CODE_STRING),
-    ((0x2000u16, 0x00A0u16), "Memory Bit Depth", vr_type::UNSIGNED_SHORT),
-    ((0x2000u16, 0x00A1u16), "Printing Bit Depth", vr_type::UNSIGNED_SHORT),
-    ((0x2000u16, 0x00A2u16), "Media Installed Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2000u16, 0x00A4u16), "Other Media Available Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2000u16, 0x00A8u16), "Supported Image Display Formats Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2000u16, 0x0500u16), "Referenced Film Box Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2000u16, 0x0510u16), "Referenced Stored Print Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2010u16, 0x0010u16), "Image Display Format", vr_type::SHORT_TEXT),
-    ((0x2010u16, 0x0030u16), "Annotation Display Format ID", vr_type::CODE_STRING),
-    ((0x2010u16, 0x0040u16), "Film Orientation", vr_type::CODE_STRING),
-    ((0x2010u16, 0x0050u16), "Film Size ID", vr_type::CODE_STRING),
-    ((0x2010u16, 0x0052u16), "Printer Resolution ID", vr_type::CODE_STRING),
-    ((0x2010u16, 0x0054u16), "Default Printer Resolution ID", vr_type::CODE_STRING),
-    ((0x2010u16, 0x0060u16), "Magnification Type", vr_type::CODE_STRING),
-    ((0x2010u16, 0x0080u16), "Smoothing Type", vr_type::CODE_STRING),
-    ((0x2010u16, 0x00A6u16), "Default Magnification Type", vr_type::CODE_STRING),
-    ((0x2010u16, 0x00A7u16), "Other Magnification Types Available", vr_type::CODE_STRING),
-    ((0x2010u16, 0x00A8u16), "Default Smoothing Type", vr_type::CODE_STRING),
-    ((0x2010u16, 0x00A9u16), "Other Smoothing Types Available", vr_type::CODE_STRING),
-    ((0x2010u16, 0x0100u16), "Border Density", vr_type::CODE_STRING),
-    ((0x2010u16, 0x0110u16), "Empty Image Density", vr_type::CODE_STRING),
-    ((0x2010u16, 0x0120u16), "Min Density", vr_type::UNSIGNED_SHORT),
-    ((0x2010u16, 0x0130u16), "Max Density", vr_type::UNSIGNED_SHORT),
-    ((0x2010u16, 0x0140u16), "Trim", vr_type::CODE_STRING),
-    ((0x2010u16, 0x0150u16), "Configuration Information", vr_type::SHORT_TEXT),
-    ((0x2010u16, 0x0152u16), "Configuration Information Description", vr_type::LONG_TEXT),
-    ((0x2010u16, 0x0154u16), "Maximum Collated Films", vr_type::INTEGER_STRING),
-    ((0x2010u16, 0x015Eu16), "Illumination", vr_type::UNSIGNED_SHORT),
-    ((0x2010u16, 0x0160u16), "Reflected Ambient Light", vr_type::UNSIGNED_SHORT),
-    ((0x2010u16, 0x0376u16), "Printer Pixel Spacing", vr_type::DECIMAL_STRING),
-    ((0x2010u16, 0x0500u16), "Referenced Film Session Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2010u16, 0x0510u16), "Referenced Image Box Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2010u16, 0x0520u16), "Referenced Basic Annotation Box Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2020u16, 0x0010u16), "Image Box Position", vr_type::UNSIGNED_SHORT),
-    ((0x2020u16, 0x0020u16), "Polarity", vr_type::CODE_STRING),
-    ((0x2020u16, 0x0030u16), "Requested Image Size", vr_type::DECIMAL_STRING),
-    ((0x2020u16, 0x0040u16), "Requested Decimate/Crop Behavior", vr_type::CODE_STRING),
-    ((0x2020u16, 0x0050u16), "Requested Resolution ID", vr_type::CODE_STRING),
-    ((0x2020u16, 0x00A0u16), "Requested Image Size Flag", vr_type::CODE_STRING),
-    ((0x2020u16, 0x00A2u16), "Decimate/Crop Result", vr_type::CODE_STRING),
-    ((0x2020u16, 0x0110u16), "Basic Grayscale Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2020u16, 0x0111u16), "Basic Color Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2030u16, 0x0010u16), "Annotation Position", vr_type::UNSIGNED_SHORT),
-    ((0x2030u16, 0x0020u16), "Text String", vr_type::LONG_STRING),
-    ((0x2050u16, 0x0010u16), "Presentation LUT Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2050u16, 0x0020u16), "Presentation LUT Shape", vr_type::CODE_STRING),
-    ((0x2050u16, 0x0500u16), "Referenced Presentation LUT Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2100u16, 0x0010u16), "Print Job ID", vr_type::SHORT_STRING),
-    ((0x2100u16, 0x0020u16), "Execution Status", vr_type::CODE_STRING),
-    ((0x2100u16, 0x0030u16), "Execution Status Info", vr_type::CODE_STRING),
-    ((0x2100u16, 0x0040u16), "Creation Date", vr_type::DATE),
-    ((0x2100u16, 0x0050u16), "Creation Time", vr_type::TIME),
-    ((0x2100u16, 0x0070u16), "Originator", vr_type::APPLICATION_ENTITY),
-    ((0x2100u16, 0x0140u16), "Destination AE", vr_type::APPLICATION_ENTITY),
-    ((0x2100u16, 0x0160u16), "Owner ID", vr_type::SHORT_STRING),
-    ((0x2100u16, 0x0170u16), "Number of Films", vr_type::INTEGER_STRING),
-    ((0x2100u16, 0x0500u16), "Referenced Print Job Sequence (Pull Stored Print)", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2110u16, 0x0010u16), "Printer Status", vr_type::CODE_STRING),
-    ((0x2110u16, 0x0020u16), "Printer Status Info", vr_type::CODE_STRING),
-    ((0x2110u16, 0x0030u16), "Printer Name", vr_type::LONG_STRING),
-    ((0x2110u16, 0x0099u16), "Print Queue ID", vr_type::SHORT_STRING),
-    ((0x3002u16, 0x0002u16), "RT Image Label", vr_type::SHORT_STRING),
-    ((0x3002u16, 0x0003u16), "RT Image Name", vr_type::LONG_STRING),
-    ((0x3002u16, 0x0004u16), "RT Image Description", vr_type::SHORT_TEXT),
-    ((0x3002u16, 0x000Au16), "Reported Values Origin", vr_type::CODE_STRING),
-    ((0x3002u16, 0x000Cu16), "RT Image Plane", vr_type::CODE_STRING),
-    ((0x3002u16, 0x000Du16), "X-Ray Image Receptor Translation", vr_type::DECIMAL_STRING),
-    ((0x3002u16, 0x000Eu16), "X-Ray Image Receptor Angle", vr_type::DECIMAL_STRING),
-    ((0x3002u16, 0x0010u16), "RT Image Orientation", vr_type::DECIMAL_STRING),
-    ((0x3002u16, 0x0011u16), "Image Plane Pixel Spacing", vr_type::DECIMAL_STRING),
-    ((0x3002u16, 0x0012u16), "RT Image Position", vr_type::DECIMAL_STRING),
-    ((0x3002u16, 0x0020u16), "Radiation Machine Name", vr_type::SHORT_STRING),
-    ((0x3002u16, 0x0022u16), "Radiation Machine SAD", vr_type::DECIMAL_STRING),
-    ((0x3002u16, 0x0024u16), "Radiation Machine SSD", vr_type::DECIMAL_STRING),
-    ((0x3002u16, 0x0026u16), "RT Image SID", vr_type::DECIMAL_STRING),
-    ((0x3002u16, 0x0028u16), "Source to Reference Object Distance", vr_type::DECIMAL_STRING),
-    ((0x3002u16, 0x0029u16), "Fraction Number", vr_type::INTEGER_STRING),
-    ((0x3002u16, 0x0030u16), "Exposure Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3002u16, 0x0032u16), "Meterset Exposure", vr_type::DECIMAL_STRING),
-    ((0x3002u16, 0x0034u16), "Diaphragm Position", vr_type::DECIMAL_STRING),
-    ((0x3002u16, 0x0040u16), "Fluence Map Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3002u16, 0x0041u16), "Fluence Data Source", vr_type::CODE_STRING),
-    ((0x3002u16, 0x0042u16), "Fluence Data Scale", vr_type::DECIMAL_STRING),
-    ((0x3004u16, 0x0001u16), "DVH Type", vr_type::CODE_STRING),
-    ((0x3004u16, 0x0002u16), "Dose Units", vr_type::CODE_STRING),
-    ((0x3004u16, 0x0004u16), "Dose Type", vr_type::CODE_STRING),
-    ((0x3004u16, 0x0006u16), "Dose Comment", vr_type::LONG_STRING),
-    ((0x3004u16, 0x0008u16), "Normalization Point", vr_type::DECIMAL_STRING),
-    ((0x3004u16, 0x000Au16), "Dose Summation Type", vr_type::CODE_STRING),
-    ((0x3004u16, 0x000Cu16), "Grid Frame Offset Vector", vr_type::DECIMAL_STRING),
-    ((0x3004u16, 0x000Eu16), "Dose Grid Scaling", vr_type::DECIMAL_STRING),
-    ((0x3004u16, 0x0010u16), "RT Dose ROI Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3004u16, 0x0012u16), "Dose Value", vr_type::DECIMAL_STRING),
-    ((0x3004u16, 0x0014u16), "Tissue Heterogeneity Correction", vr_type::CODE_STRING),
-    ((0x3004u16, 0x0040u16), "DVH Normalization Point", vr_type::DECIMAL_STRING),
-    ((0x3004u16, 0x0042u16), "DVH Normalization Dose Value", vr_type::DECIMAL_STRING),
-    ((0x3004u16, 0x0050u16), "DVH Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3004u16, 0x0052u16), "DVH Dose Scaling", vr_type::DECIMAL_STRING),
-    ((0x3004u16, 0x0054u16), "DVH Volume Units", vr_type::CODE_STRING),
-    ((0x3004u16, 0x0056u16), "DVH Number of Bins", vr_type::INTEGER_STRING),
-    ((0x3004u16, 0x0058u16), "DVH Data", vr_type::DECIMAL_STRING),
-    ((0x3004u16, 0x0060u16), "DVH Referenced ROI Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3004u16, 0x0062u16), "DVH ROI Contribution Type", vr_type::CODE_STRING),
-    ((0x3004u16, 0x0070u16), "DVH Minimum Dose", vr_type::DECIMAL_STRING),
-    ((0x3004u16, 0x0072u16), "DVH Maximum Dose", vr_type::DECIMAL_STRING),
-    ((0x3004u16, 0x0074u16), "DVH Mean Dose", vr_type::DECIMAL_STRING),
-    ((0x3006u16, 0x0002u16), "Structure Set Label", vr_type::SHORT_STRING),
-    ((0x3006u16, 0x0004u16), "Structure Set Name", vr_type::LONG_STRING),
-    ((0x3006u16, 0x0006u16), "Structure Set Description", vr_type::SHORT_TEXT),
-    ((0x3006u16, 0x0008u16), "Structure Set Date", vr_type::DATE),
-    ((0x3006u16, 0x0009u16), "Structure Set Time", vr_type::TIME),
-    ((0x3006u16, 0x0010u16), "Referenced Frame of Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x0012u16), "RT Referenced Study Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x0014u16), "RT Referenced Series Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x0016u16), "Contour Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x0020u16), "Structure Set ROI Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x0022u16), "ROI Number", vr_type::INTEGER_STRING),
-    ((0x3006u16, 0x0024u16), "Referenced Frame of Reference Uid", vr_type::UID),
-    ((0x3006u16, 0x0026u16), "ROI Name", vr_type::LONG_STRING),
-    ((0x3006u16, 0x0028u16), "ROI Description", vr_type::SHORT_TEXT),
-    ((0x3006u16, 0x002Au16), "ROI Display Color", vr_type::INTEGER_STRING),
-    ((0x3006u16, 0x002Cu16), "ROI Volume", vr_type::DECIMAL_STRING),
-    ((0x3006u16, 0x0030u16), "RT Related ROI Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x0033u16), "RT ROI Relationship", vr_type::CODE_STRING),
-    ((0x3006u16, 0x0036u16), "ROI Generation Algorithm", vr_type::CODE_STRING),
-    ((0x3006u16, 0x0038u16), "ROI Generation Description", vr_type::LONG_STRING),
-    ((0x3006u16, 0x0039u16), "ROI Contour Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x0040u16), "Contour Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x0042u16), "Contour Geometric Type", vr_type::CODE_STRING),
-    ((0x3006u16, 0x0044u16), "Contour Slab Thickness", vr_type::DECIMAL_STRING),
-    ((0x3006u16, 0x0045u16), "Contour Offset Vector", vr_type::DECIMAL_STRING),
-    ((0x3006u16, 0x0046u16), "Number of Contour Points", vr_type::INTEGER_STRING),
-    ((0x3006u16, 0x0048u16), "Contour Number", vr_type::INTEGER_STRING),
-    ((0x3006u16, 0x0049u16), "Attached Contours", vr_type::INTEGER_STRING),
-    ((0x3006u16, 0x0050u16), "Contour Data", vr_type::DECIMAL_STRING),
-    ((0x3006u16, 0x0080u16), "RT ROI Observations Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x0082u16), "Observation Number", vr_type::INTEGER_STRING),
-    ((0x3006u16, 0x0084u16), "Referenced ROI Number", vr_type::INTEGER_STRING),
-    ((0x3006u16, 0x0085u16), "ROI Observation Label", vr_type::SHORT_STRING),
-    ((0x3006u16, 0x0086u16), "RT ROI Identification Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x0088u16), "ROI Observation Description", vr_type::SHORT_TEXT),
-    ((0x3006u16, 0x00A0u16), "Related RT ROI Observations Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x00A4u16), "RT ROI Interpreted Type", vr_type::CODE_STRING),
-    ((0x3006u16, 0x00A6u16), "ROI Interpreter", vr_type::PERSON_NAME),
-    ((0x3006u16, 0x00B0u16), "ROI Physical Properties Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x00B2u16), "ROI Physical Property", vr_type::CODE_STRING),
-    ((0x3006u16, 0x00B4u16), "ROI Physical Property Value", vr_type::DECIMAL_STRING),
-    ((0x3006u16, 0x00C0u16), "Frame of Reference Relationship Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x00C2u16), "Related Frame of Reference Uid", vr_type::UID),
-    ((0x3006u16, 0x00C4u16), "Frame of Reference Transformation Type", vr_type::CODE_STRING),
-    ((0x3006u16, 0x00C6u16), "Frame of Reference Transformation Matrix", vr_type::DECIMAL_STRING),
-    ((0x3006u16, 0x00C8u16), "Frame of Reference Transformation Comment", vr_type::LONG_STRING),
-    ((0x3008u16, 0x0010u16), "Measured Dose Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0012u16), "Measured Dose Description", vr_type::SHORT_TEXT),
-    ((0x3008u16, 0x0014u16), "Measured Dose Type", vr_type::CODE_STRING),
-    ((0x3008u16, 0x0016u16), "Measured Dose Value", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0020u16), "Treatment Session Beam Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0022u16), "Current Fraction Number", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x0024u16), "Treatment Control Point Date", vr_type::DATE),
-    ((0x3008u16, 0x0025u16), "Treatment Control Point Time", vr_type::TIME),
-    ((0x3008u16, 0x002Au16), "Treatment Termination Status", vr_type::CODE_STRING),
-    ((0x3008u16, 0x002Bu16), "Treatment Termination Code", vr_type::SHORT_STRING),
-    ((0x3008u16, 0x002Cu16), "Treatment Verification Status", vr_type::CODE_STRING),
-    ((0x3008u16, 0x0030u16), "Referenced Treatment Record Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0032u16), "Specified Primary Meterset", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0033u16), "Specified Secondary Meterset", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0036u16), "Delivered Primary Meterset", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0037u16), "Delivered Secondary Meterset", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x003Au16), "Specified Treatment Time", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x003Bu16), "Delivered Treatment Time", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0040u16), "Control Point Delivery Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0042u16), "Specified Meterset", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0044u16), "Delivered Meterset", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0048u16), "Dose Rate Delivered", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0050u16), "Treatment Summary Calculated Dose Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0052u16), "Cumulative Dose to Dose Reference", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0054u16), "First Treatment Date", vr_type::DATE),
-    ((0x3008u16, 0x0056u16), "Most Recent Treatment Date", vr_type::DATE),
-    ((0x3008u16, 0x005Au16), "Number of Fractions Delivered", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x0060u16), "Override Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0062u16), "Override Parameter Pointer", vr_type::ATTRIBUTE),
-    ((0x3008u16, 0x0064u16), "Measured Dose Reference Number", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x0066u16), "Override Reason", vr_type::SHORT_TEXT),
-    ((0x3008u16, 0x0070u16), "Calculated Dose Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0072u16), "Calculated Dose Reference Number", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x0074u16), "Calculated Dose Reference Description", vr_type::SHORT_TEXT),
-    ((0x3008u16, 0x0076u16), "Calculated Dose Reference Dose Value", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0078u16), "Start Meterset", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x007Au16), "End Meterset", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0080u16), "Referenced Measured Dose Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0082u16), "Referenced Measured Dose Reference Number", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x0090u16), "Referenced Calculated Dose Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0092u16), "Referenced Calculated Dose Reference Number", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x00A0u16), "Beam Limiting Device Leaf Pairs Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x00B0u16), "Recorded Wedge Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x00C0u16), "Recorded Compensator Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x00D0u16), "Recorded Block Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x00E0u16), "Treatment Summary Measured Dose Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0100u16), "Recorded Source Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0105u16), "Source Serial Number", vr_type::LONG_STRING),
-    ((0x3008u16, 0x0110u16), "Treatment Session Application Setup Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0116u16), "Application Setup Check", vr_type::CODE_STRING),
-    ((0x3008u16, 0x0120u16), "Recorded Brachy Accessory Device Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0122u16), "Referenced Brachy Accessory Device Number", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x0130u16), "Recorded Channel Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0132u16), "Specified Channel Total Time", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0134u16), "Delivered Channel Total Time", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0136u16), "Specified Number of Pulses", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x0138u16), "Delivered Number of Pulses", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x013Au16), "Specified Pulse Repetition Interval", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x013Cu16), "Delivered Pulse Repetition Interval", vr_type::DECIMAL_STRING),
-    ((0x3008u16, 0x0140u16), "Recorded Source Applicator Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0142u16), "Referenced Source Applicator Number", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x0150u16), "Recorded Channel Shield Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0152u16), "Referenced Channel Shield Number", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x0160u16), "Brachy Control Point Delivered Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0162u16), "Safe Position Exit Date", vr_type::DATE),
-    ((0x3008u16, 0x0164u16), "Safe Position Exit Time", vr_type::TIME),
-    ((0x3008u16, 0x0166u16), "Safe Position Return Date", vr_type::DATE),
-    ((0x3008u16, 0x0168u16), "Safe Position Return Time", vr_type::TIME),
-    ((0x3008u16, 0x0200u16), "Current Treatment Status", vr_type::CODE_STRING),
-    ((0x3008u16, 0x0202u16), "Treatment Status Comment", vr_type::SHORT_TEXT),
-    ((0x3008u16, 0x0220u16), "Fraction Group Summary Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0223u16), "Referenced Fraction Number", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x0224u16), "Fraction Group Type", vr_type::CODE_STRING),
-    ((0x3008u16, 0x0230u16), "Beam Stopper Position", vr_type::CODE_STRING),
-    ((0x3008u16, 0x0240u16), "Fraction Status Summary Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0250u16), "Treatment Date", vr_type::DATE),
-    ((0x3008u16, 0x0251u16), "Treatment Time", vr_type::TIME),
-    ((0x300Au16, 0x0002u16), "RT Plan Label", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0003u16), "RT Plan Name", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0004u16), "RT Plan Description", vr_type::SHORT_TEXT),
-    ((0x300Au16, 0x0006u16), "RT Plan Date", vr_type::DATE),
-    ((0x300Au16, 0x0007u16), "RT Plan Time", vr_type::TIME),
-    ((0x300Au16, 0x0009u16), "Treatment Protocols", vr_type::LONG_STRING),
-    ((0x300Au16, 0x000Au16), "Plan Intent", vr_type::CODE_STRING),
-    ((0x300Au16, 0x000Bu16), "Treatment Sites", vr_type::LONG_STRING),
-    ((0x300Au16, 0x000Cu16), "RT Plan Geometry", vr_type::CODE_STRING),
-    ((0x300Au16, 0x000Eu16), "Prescription Description", vr_type::SHORT_TEXT),
-    ((0x300Au16, 0x0010u16), "Dose Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0012u16), "Dose Reference Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0013u16), "Dose Reference Uid", vr_type::UID),
-    ((0x300Au16, 0x0014u16), "Dose Reference Structure Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0015u16), "Nominal Beam Energy Unit", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0016u16), "Dose Reference Description", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0018u16), "Dose Reference Point Coordinates", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x001Au16), "Nominal Prior Dose", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0020u16), "Dose Reference Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0021u16), "Constraint Weight", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0022u16), "Delivery Warning Dose", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0023u16), "Delivery Maximum Dose", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0025u16), "Target Minimum Dose", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0026u16), "Target Prescription Dose", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0027u16), "Target Maximum Dose", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0028u16), "Target Underdose Volume Fraction", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x002Au16), "Organ at Risk Full-volume Dose", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x002Bu16), "Organ at Risk Limit Dose", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x002Cu16), "Organ at Risk Maximum Dose", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x002Du16), "Organ at Risk Overdose Volume Fraction", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0040u16), "Tolerance Table Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0042u16), "Tolerance Table Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0043u16), "Tolerance Table Label", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0044u16), "Gantry Angle Tolerance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0046u16), "Beam Limiting Device Angle Tolerance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0048u16), "Beam Limiting Device Tolerance Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x004Au16), "Beam Limiting Device Position Tolerance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x004Cu16), "Patient Support Angle Tolerance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x004Eu16), "Table Top Eccentric Angle Tolerance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0051u16), "Table Top Vertical Position Tolerance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0052u16), "Table Top Longitudinal Position Tolerance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0053u16), "Table Top Lateral Position Tolerance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0055u16), "RT Plan Relationship", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0070u16), "Fraction Group Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0071u16), "Fraction Group Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0072u16), "Fraction Group Description", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0078u16), "Number of Fractions Planned", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0079u16), "Number of Fraction Pattern Digits Per Day", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x007Au16), "Repeat Fraction Cycle Length", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x007Bu16), "Fraction Pattern", vr_type::LONG_TEXT),
-    ((0x300Au16, 0x0080u16), "Number of Beams", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0082u16), "Beam Dose Specification Point", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0084u16), "Beam Dose", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0086u16), "Beam Meterset", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0088u16), "Beam Dose Point Depth", vr_type::FLOAT),
-    ((0x300Au16, 0x0089u16), "Beam Dose Point Equivalent Depth", vr_type::FLOAT),
-    ((0x300Au16, 0x008Au16), "Beam Dose Point SSD", vr_type::FLOAT),
-    ((0x300Au16, 0x00A0u16), "Number of Brachy Application Setups", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00A2u16), "Brachy Application Setup Dose Specification Point", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00A4u16), "Brachy Application Setup Dose", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00B0u16), "Beam Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x00B2u16), "Treatment Machine Name", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x00B3u16), "Primary Dosimeter Unit", vr_type::CODE_STRING),
-    ((0x300Au16, 0x00B4u16), "Source-Axis Distance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00B6u16), "Beam Limiting Device Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x00B8u16), "RT Beam Limiting Device Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x00BAu16), "Source to Beam Limiting Device Distance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00BCu16), "Number of Leaf/Jaw Pairs", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00BEu16), "Leaf Position Boundaries", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00C0u16), "Beam Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00C2u16), "Beam Name", vr_type::LONG_STRING),
-    ((0x300Au16, 0x00C3u16), "Beam Description", vr_type::SHORT_TEXT),
-    ((0x300Au16, 0x00C4u16), "Beam Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x00C6u16), "Radiation Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x00C7u16), "High-Dose Technique Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x00C8u16), "Reference Image Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00CAu16), "Planned Verification Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x00CCu16), "Imaging Device-Specific Acquisition Parameters", vr_type::LONG_STRING),
-    ((0x300Au16, 0x00CEu16), "Treatment Delivery Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x00D0u16), "Number of Wedges", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00D1u16), "Wedge Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x00D2u16), "Wedge Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00D3u16), "Wedge Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x00D4u16), "Wedge ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x00D5u16), "Wedge Angle", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00D6u16), "Wedge Factor", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00D8u16), "Wedge Orientation", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00DAu16), "Source to Wedge Tray Distance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00DCu16), "Bolus ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x00DDu16), "Bolus Description", vr_type::SHORT_TEXT),
-    ((0x300Au16, 0x00E0u16), "Number of Compensators", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00E1u16), "Material ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x00E2u16), "Total Compensator Tray Factor", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00E3u16), "Compensator Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x00E4u16), "Compensator Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00E5u16), "Compensator ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x00E6u16), "Source to Compensator Tray Distance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00E7u16), "Compensator Rows", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00E8u16), "Compensator Columns", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00E9u16), "Compensator Pixel Spacing", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00EAu16), "Compensator Position", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00EBu16), "Compensator Transmission Data", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00ECu16), "Compensator Thickness Data", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00EDu16), "Number of Boli", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00EEu16), "Compensator Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x00F0u16), "Number of Blocks", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00F2u16), "Total Block Tray Factor", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00F4u16), "Block Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x00F5u16), "Block Tray ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x00F6u16), "Source to Block Tray Distance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x00F8u16), "Block Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x00F9u16), "Accessory Code", vr_type::LONG_STRING),
-    ((0x300Au16, 0x00FAu16), "Block Divergence", vr_type::CODE_STRING),
-    ((0x300Au16, 0x00FBu16), "Block Mounting Position", vr_type::CODE_STRING),
-    ((0x300Au16, 0x00FCu16), "Block Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x00FEu16), "Block Name", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0100u16), "Block Thickness", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0102u16), "Block Transmission", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0104u16), "Block Number of Points", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0106u16), "Block Data", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0107u16), "Applicator Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0108u16), "Applicator ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0109u16), "Applicator Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x010Au16), "Applicator Description", vr_type::LONG_STRING),
-    ((0x300Au16, 0x010Cu16), "Cumulative Dose Reference Coefficient", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x010Eu16), "Final Cumulative Meterset Weight", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0110u16), "Number of Control Points", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0111u16), "Control Point Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0112u16), "Control Point Index", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0114u16), "Nominal Beam Energy", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0115u16), "Dose Rate Set", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0116u16), "Wedge Position Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0118u16), "Wedge Position", vr_type::CODE_STRING),
-    ((0x300Au16, 0x011Au16), "Beam Limiting Device Position Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x011Cu16), "Leaf/Jaw Positions", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x011Eu16), "Gantry Angle", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x011Fu16), "Gantry Rotation Direction", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0120u16), "Beam Limiting Device Angle", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0121u16), "Beam Limiting Device Rotation Direction", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0122u16), "Patient Support Angle", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0123u16), "Patient Support Rotation Direction", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0124u16), "Table Top Eccentric Axis Distance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0125u16), "Table Top Eccentric Angle", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0126u16), "Table Top Eccentric Rotation Direction", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0128u16), "Table Top Vertical Position", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0129u16), "Table Top Longitudinal Position", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x012Au16), "Table Top Lateral Position", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x012Cu16), "Isocenter Position", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x012Eu16), "Surface Entry Point", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0130u16), "Source to Surface Distance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0134u16), "Cumulative Meterset Weight", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0180u16), "Patient Setup Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0182u16), "Patient Setup Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0183u16), "Patient Setup Label", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0184u16), "Patient Additional Position", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0190u16), "Fixation Device Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0192u16), "Fixation Device Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0194u16), "Fixation Device Label", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0196u16), "Fixation Device Description", vr_type::SHORT_TEXT),
-    ((0x300Au16, 0x0198u16), "Fixation Device Position", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0199u16), "Fixation Device Pitch Angle", vr_type::FLOAT),
-    ((0x300Au16, 0x019Au16), "Fixation Device Roll Angle", vr_type::FLOAT),
-    ((0x300Au16, 0x01A0u16), "Shielding Device Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x01A2u16), "Shielding Device Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x01A4u16), "Shielding Device Label", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x01A6u16), "Shielding Device Description", vr_type::SHORT_TEXT),
-    ((0x300Au16, 0x01A8u16), "Shielding Device Position", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x01B0u16), "Setup Technique", vr_type::CODE_STRING),
-    ((0x300Au16, 0x01B2u16), "Setup Technique Description", vr_type::SHORT_TEXT),
-    ((0x300Au16, 0x01B4u16), "Setup Device Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x01B6u16), "Setup Device Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x01B8u16), "Setup Device Label", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x01BAu16), "Setup Device Description", vr_type::SHORT_TEXT),
-    ((0x300Au16, 0x01BCu16), "Setup Device Parameter", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x01D0u16), "Setup Reference Description", vr_type::SHORT_TEXT),
-    ((0x300Au16, 0x01D2u16), "Table Top Vertical Setup Displacement", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x01D4u16), "Table Top Longitudinal Setup Displacement", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x01D6u16), "Table Top Lateral Setup Displacement", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0200u16), "Brachy Treatment Technique", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0202u16), "Brachy Treatment Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0206u16), "Treatment Machine Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0210u16), "Source Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0212u16), "Source Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0214u16), "Source Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0216u16), "Source Manufacturer", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0218u16), "Active Source Diameter", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x021Au16), "Active Source Length", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0222u16), "Source Encapsulation Nominal Thickness", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0224u16), "Source Encapsulation Nominal Transmission", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0226u16), "Source Isotope Name", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0228u16), "Source Isotope Half Life", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0229u16), "Source Strength Units", vr_type::CODE_STRING),
-    ((0x300Au16, 0x022Au16), "Reference Air Kerma Rate", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x022Bu16), "Source Strength", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x022Cu16), "Source Strength Reference Date", vr_type::DATE),
-    ((0x300Au16, 0x022Eu16), "Source Strength Reference Time", vr_type::TIME),
-    ((0x300Au16, 0x0230u16), "Application Setup Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0232u16), "Application Setup Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0234u16), "Application Setup Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0236u16), "Application Setup Name", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0238u16), "Application Setup Manufacturer", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0240u16), "Template Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0242u16), "Template Type", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0244u16), "Template Name", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0250u16), "Total Reference Air Kerma", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0260u16), "Brachy Accessory Device Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0262u16), "Brachy Accessory Device Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0263u16), "Brachy Accessory Device ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0264u16), "Brachy Accessory Device Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0266u16), "Brachy Accessory Device Name", vr_type::LONG_STRING),
-    ((0x300Au16, 0x026Au16), "Brachy Accessory Device Nominal Thickness", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x026Cu16), "Brachy Accessory Device Nominal Transmission", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0280u16), "Channel Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0282u16), "Channel Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0284u16), "Channel Length", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0286u16), "Channel Total Time", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0288u16), "Source Movement Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x028Au16), "Number of Pulses", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x028Cu16), "Pulse Repetition Interval", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0290u16), "Source Applicator Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0291u16), "Source Applicator ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0292u16), "Source Applicator Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0294u16), "Source Applicator Name", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0296u16), "Source Applicator Length", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0298u16), "Source Applicator Manufacturer", vr_type::LONG_STRING),
-    ((0x300Au16, 0x029Cu16), "Source Applicator Wall Nominal Thickness", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x029Eu16), "Source Applicator Wall Nominal Transmission", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x02A0u16), "Source Applicator Step Size", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x02A2u16), "Transfer Tube Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x02A4u16), "Transfer Tube Length", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x02B0u16), "Channel Shield Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x02B2u16), "Channel Shield Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x02B3u16), "Channel Shield ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x02B4u16), "Channel Shield Name", vr_type::LONG_STRING),
-    ((0x300Au16, 0x02B8u16), "Channel Shield Nominal Thickness", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x02BAu16), "Channel Shield Nominal Transmission", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x02C8u16), "Final Cumulative Time Weight", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x02D0u16), "Brachy Control Point Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x02D2u16), "Control Point Relative Position", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x02D4u16), "Control Point 3D Position", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x02D6u16), "Cumulative Time Weight", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x02E0u16), "Compensator Divergence", vr_type::CODE_STRING),
-    ((0x300Au16, 0x02E1u16), "Compensator Mounting Position", vr_type::CODE_STRING),
-    ((0x300Au16, 0x02E2u16), "Source to Compensator Distance", vr_type::DECIMAL_STRING),
-    ((0x300Au16, 0x0401u16), "Referenced Setup Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0410u16), "Motion Synchronization Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0402u16), "Setup Image Comment", vr_type::SHORT_TEXT),
-    ((0x300Cu16, 0x0002u16), "Referenced RT Plan Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x0004u16), "Referenced Beam Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x0006u16), "Referenced Beam Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x0007u16), "Referenced Reference Image Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x0008u16), "Start Cumulative Meterset Weight", vr_type::DECIMAL_STRING),
-    ((0x300Cu16, 0x0009u16), "End Cumulative Meterset Weight", vr_type::DECIMAL_STRING),
-    ((0x300Cu16, 0x000Au16), "Referenced Brachy Application Setup Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x000Cu16), "Referenced Brachy Application Setup Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x000Eu16), "Referenced Source Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x0020u16), "Referenced Fraction Group Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x0022u16), "Referenced Fraction Group Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x0040u16), "Referenced Verification Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x0042u16), "Referenced Reference Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x0050u16), "Referenced Dose Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x0051u16), "Referenced Dose Reference Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x0055u16), "Brachy Referenced Dose Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x0060u16), "Referenced Structure Set Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x006Au16), "Referenced Patient Setup Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x0080u16), "Referenced Dose Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x00A0u16), "Referenced Tolerance Table Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x00B0u16), "Referenced Bolus Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x00C0u16), "Referenced Wedge Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x00D0u16), "Referenced Compensator Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x00E0u16), "Referenced Block Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x00F0u16), "Referenced Control Point Index", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x00F2u16), "Referenced Control Point Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x00F4u16), "Referenced Start Control Point Index", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x00F6u16), "Referenced Stop Control Point Index", vr_type::INTEGER_STRING),
-    ((0x300Eu16, 0x0002u16), "Approval Status", vr_type::CODE_STRING),
-    ((0x300Eu16, 0x0004u16), "Review Date", vr_type::DATE),
-    ((0x300Eu16, 0x0005u16), "Review Time", vr_type::TIME),
-    ((0x300Eu16, 0x0008u16), "Reviewer Name", vr_type::PERSON_NAME),
-    (PIXEL_DATA,            "Pixel Data", vr_type::OTHER_BYTE),
-    ((0xFFFCu16, 0xFFFCu16), "Data Set Trailing Padding", vr_type::OTHER_BYTE),
-    ((0x0008u16, 0x002Au16), "Acquisition DateTime", vr_type::DATE_TIME),
-    ((0x0008u16, 0x114Au16), "Referenced Instance Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x1067u16), "Image Trigger Delay", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1068u16), "Multiplex Group Time Offset", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1069u16), "Trigger Time Offset", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x106Au16), "Synchronization Trigger", vr_type::CODE_STRING),
-    ((0x0018u16, 0x106Cu16), "Synchronization Channel", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x106Eu16), "Trigger Sample Position", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x1800u16), "Acquisition Time Synchronized", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1801u16), "Time Source", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x1802u16), "Time Distribution Protocol", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1803u16), "NTP Source Address", vr_type::LONG_STRING),
-    ((0x0020u16, 0x0200u16), "Synchronization Frame of Reference Uid", vr_type::UID),
-    ((0x003Au16, 0x0004u16), "Waveform Originality", vr_type::CODE_STRING),
-    ((0x003Au16, 0x0005u16), "Number of Waveform Channels", vr_type::UNSIGNED_SHORT),
-    ((0x003Au16, 0x0010u16), "Number of Waveform Samples", vr_type::UNSIGNED_LONG),
-    ((0x003Au16, 0x001Au16), "Sampling Frequency", vr_type::DECIMAL_STRING),
-    ((0x003Au16, 0x0020u16), "Multiplex Group Label", vr_type::SHORT_STRING),
-    ((0x003Au16, 0x0200u16), "Channel Definition Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x003Au16, 0x0202u16), "Waveform Channel Number", vr_type::INTEGER_STRING),
-    ((0x003Au16, 0x0203u16), "Channel Label", vr_type::SHORT_STRING),
-    ((0x003Au16, 0x0205u16), "Channel Status", vr_type::CODE_STRING),
-    ((0x003Au16, 0x0208u16), "Channel Source Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x003Au16, 0x0209u16), "Channel Source Modifiers Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x003Au16, 0x020Au16), "Source Waveform Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x003Au16, 0x020Cu16), "Channel Derivation Description", vr_type::LONG_STRING),
-    ((0x003Au16, 0x0210u16), "Channel Sensitivity", vr_type::DECIMAL_STRING),
-    ((0x003Au16, 0x0211u16), "Channel Sensitivity Units Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x003Au16, 0x0212u16), "Channel Sensitivity Correction Factor", vr_type::DECIMAL_STRING),
-    ((0x003Au16, 0x0213u16), "Channel Baseline", vr_type::DECIMAL_STRING),
-    ((0x003Au16, 0x0214u16), "Channel Time Skew", vr_type::DECIMAL_STRING),
-    ((0x003Au16, 0x0215u16), "Channel Sample Skew", vr_type::DECIMAL_STRING),
-    ((0x003Au16, 0x0218u16), "Channel Offset", vr_type::DECIMAL_STRING),
-    ((0x003Au16, 0x021Au16), "Waveform Bits Stored", vr_type::UNSIGNED_SHORT),
-    ((0x003Au16, 0x0220u16), "Filter Low Frequency", vr_type::DECIMAL_STRING),
-    ((0x003Au16, 0x0221u16), "Filter High Frequency", vr_type::DECIMAL_STRING),
-    ((0x003Au16, 0x0222u16), "Notch Filter Frequency", vr_type::DECIMAL_STRING),
-    ((0x003Au16, 0x0223u16), "Notch Filter Bandwidth", vr_type::DECIMAL_STRING),
-    ((0x003Au16, 0x0300u16), "Multiplexed Audio Channels Description Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x003Au16, 0x0301u16), "Channel Identification Code", vr_type::INTEGER_STRING),
-    ((0x003Au16, 0x0302u16), "Channel Mode", vr_type::CODE_STRING),
-    ((0x0040u16, 0xA0B0u16), "Referenced Waveform Channels", vr_type::UNSIGNED_SHORT),
-    ((0x0040u16, 0xA130u16), "Temporal Range Type", vr_type::CODE_STRING),
-    ((0x0040u16, 0xA132u16), "Referenced Sample Positions", vr_type::UNSIGNED_LONG),
-    ((0x0040u16, 0xA138u16), "Referenced Time Offsets", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0xA13Au16), "Referenced DateTime", vr_type::DATE_TIME),
-    ((0x0040u16, 0xA180u16), "Annotation Group Number", vr_type::UNSIGNED_SHORT),
-    ((0x0040u16, 0xA195u16), "Modifier Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xB020u16), "Waveform Annotation Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0006u16), "Unformatted Text Value", vr_type::SHORT_TEXT),
-    ((0x5400u16, 0x0100u16), "Waveform Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x5400u16, 0x0110u16), "Channel Minimum Value", vr_type::OTHER_BYTE),
-    ((0x5400u16, 0x0112u16), "Channel Maximum Value", vr_type::OTHER_BYTE),
-    ((0x5400u16, 0x1004u16), "Waveform Bits Allocated", vr_type::UNSIGNED_SHORT),
-    ((0x5400u16, 0x1006u16), "Waveform Sample Interpretation", vr_type::CODE_STRING),
-    ((0x5400u16, 0x100Au16), "Waveform Padding Value", vr_type::OTHER_BYTE),
-    ((0x5400u16, 0x1010u16), "Waveform Data", vr_type::OTHER_BYTE),
-    ((0x0040u16, 0xA010u16), "Relationship Type", vr_type::CODE_STRING),
-    ((0x0040u16, 0xA027u16), "Verifying Organization", vr_type::LONG_STRING),
-    ((0x0040u16, 0xA030u16), "Verification DateTime", vr_type::DATE_TIME),
-    ((0x0040u16, 0xA032u16), "Observation DateTime", vr_type::DATE_TIME),
-    ((0x0040u16, 0xA040u16), "Value Type", vr_type::CODE_STRING),
-    ((0x0040u16, 0xA050u16), "Continuity of Content", vr_type::CODE_STRING),
-    ((0x0040u16, 0xA073u16), "Verifying Observer Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA075u16), "Verifying Observer Name", vr_type::PERSON_NAME),
-    ((0x0040u16, 0xA088u16), "Verifying Observer Identification Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA120u16), "DateTime", vr_type::DATE_TIME),
-    ((0x0040u16, 0xA124u16), "Uid", vr_type::UID),
-    ((0x0040u16, 0xA300u16), "Measured Value Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA301u16), "Numeric Value Qualifier Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA360u16), "Predecessor Documents Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA370u16), "Referenced Request Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA372u16), "Performed Procedure Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA375u16), "Current Requested Procedure Evidence Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA385u16), "Pertinent Other Evidence Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA491u16), "Completion Flag", vr_type::CODE_STRING),
-    ((0x0040u16, 0xA492u16), "Completion Flag Description", vr_type::LONG_STRING),
-    ((0x0040u16, 0xA493u16), "Verification Flag", vr_type::CODE_STRING),
-    ((0x0040u16, 0xA504u16), "Content Template Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA525u16), "Identical Documents Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA730u16), "Content Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xDB00u16), "Template Identifier", vr_type::CODE_STRING),
-    ((0x0040u16, 0xDB73u16), "Referenced Content Item Identifier", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x1622u16), "Shutter Presentation Value", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x1623u16), "Shutter Overlay Group", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x3110u16), "Softcopy VOI LUT Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0001u16), "Graphic Annotation Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0002u16), "Graphic Layer", vr_type::CODE_STRING),
-    ((0x0070u16, 0x0003u16), "Bounding Box Annotation Units", vr_type::CODE_STRING),
-    ((0x0070u16, 0x0004u16), "Anchor Point Annotation Units", vr_type::CODE_STRING),
-    ((0x0070u16, 0x0005u16), "Graphic Annotation Units", vr_type::CODE_STRING),
-    ((0x0070u16, 0x0008u16), "Text Object Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0009u16), "Graphic Object Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0010u16), "Bounding Box Top Left Hand Corner", vr_type::FLOAT),
-    ((0x0070u16, 0x0011u16), "Bounding Box Bottom Right Hand Corner", vr_type::FLOAT),
-    ((0x0070u16, 0x0012u16), "Bounding Box Text Horizontal Justification", vr_type::CODE_STRING),
-    ((0x0070u16, 0x0014u16), "Anchor Point", vr_type::FLOAT),
-    ((0x0070u16, 0x0015u16), "Anchor Point Visibility", vr_type::CODE_STRING),
-    ((0x0070u16, 0x0020u16), "Graphic Dimensions", vr_type::UNSIGNED_SHORT),
-    ((0x0070u16, 0x0021u16), "Number of Graphic Points", vr_type::UNSIGNED_SHORT),
-    ((0x0070u16, 0x0024u16), "Graphic Filled", vr_type::CODE_STRING),
-    ((0x0070u16, 0x0041u16), "Image Horizontal Flip", vr_type::CODE_STRING),
-    ((0x0070u16, 0x0042u16), "Image Rotation", vr_type::UNSIGNED_SHORT),
-    ((0x0070u16, 0x0052u16), "Displayed Area Top Left Hand Corner", vr_type::SIGNED_LONG),
-    ((0x0070u16, 0x0053u16), "Displayed Area Bottom Right Hand Corner", vr_type::SIGNED_LONG),
-    ((0x0070u16, 0x005Au16), "Displayed Area Selection Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0060u16), "Graphic Layer Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0062u16), "Graphic Layer Order", vr_type::INTEGER_STRING),
-    ((0x0070u16, 0x0066u16), "Graphic Layer Recommended Display Grayscale Value", vr_type::UNSIGNED_SHORT),
-    ((0x0070u16, 0x0067u16), "Graphic Layer Recommended Display RGB Value", vr_type::UNSIGNED_SHORT),
-    ((0x0070u16, 0x0068u16), "Graphic Layer Description", vr_type::LONG_STRING),
-    ((0x0070u16, 0x0080u16), "Content Label", vr_type::CODE_STRING),
-    ((0x0070u16, 0x0081u16), "Content Description", vr_type::LONG_STRING),
-    ((0x0070u16, 0x0082u16), "Presentation Creation Date", vr_type::DATE),
-    ((0x0070u16, 0x0083u16), "Presentation Creation Time", vr_type::TIME),
-    ((0x0070u16, 0x0084u16), "Content Creator's Name", vr_type::PERSON_NAME),
-    ((0x0070u16, 0x0086u16), "Content Creator's Identification Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0100u16), "Presentation Size Mode", vr_type::CODE_STRING),
-    ((0x0070u16, 0x0101u16), "Presentation Pixel Spacing", vr_type::DECIMAL_STRING),
-    ((0x0070u16, 0x0102u16), "Presentation Pixel Aspect Ratio", vr_type::INTEGER_STRING),
-    ((0x0070u16, 0x0103u16), "Presentation Pixel Magnification Ratio", vr_type::FLOAT),
-    ((0x0018u16, 0x2001u16), "Page Number Vector", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x2002u16), "Frame Label Vector", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x2003u16), "Frame Primary Angle Vector", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x2004u16), "Frame Secondary Angle Vector", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x2005u16), "Slice Location Vector", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x2006u16), "Display Window Label Vector", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x2010u16), "Nominal Scanned Pixel Spacing", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x2020u16), "Digitizing Device Transport Direction", vr_type::CODE_STRING),
-    ((0x0018u16, 0x2030u16), "Rotation of Scanned Film", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x3100u16), "IVUS Acquisition", vr_type::CODE_STRING),
-    ((0x0018u16, 0x3101u16), "IVUS Pullback Rate", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x3102u16), "IVUS Gated Rate", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x3103u16), "IVUS Pullback Start Frame Number", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x3104u16), "IVUS Pullback Stop Frame Number", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x3105u16), "Lesion Number", vr_type::INTEGER_STRING),
-    ((0x0008u16, 0x113Au16), "Referenced Waveform Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA170u16), "Purpose of Reference Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x0096u16), "Referring Physician Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1049u16), "Physician(s) of Record Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1052u16), "Performing Physician Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1062u16), "Physician(s) Reading Study Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1072u16), "Operator Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0032u16, 0x1031u16), "Requesting Physician Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x000Bu16), "Scheduled Performing Physician Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x1011u16), "Intended Recipients of Results Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x1101u16), "Person Identification Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x1102u16), "Person's Address", vr_type::SHORT_TEXT),
-    ((0x0040u16, 0x1103u16), "Person's Telephone Numbers", vr_type::LONG_STRING),
-    ((0x0040u16, 0x4001u16), "General Purpose Scheduled Procedure Step Status", vr_type::CODE_STRING),
-    ((0x0040u16, 0x4002u16), "General Purpose Performed Procedure Step Status", vr_type::CODE_STRING),
-    ((0x0040u16, 0x4003u16), "General Purpose Scheduled Procedure Step Priority", vr_type::CODE_STRING),
-    ((0x0040u16, 0x4004u16), "Scheduled Processing Applications Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4005u16), "Scheduled Procedure Step Start DateTime", vr_type::DATE_TIME),
-    ((0x0040u16, 0x4006u16), "Multiple Copies Flag", vr_type::CODE_STRING),
-    ((0x0040u16, 0x4007u16), "Performed Processing Applications Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4009u16), "Human Performer Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4010u16), "Scheduled Procedure Step Modification DateTime", vr_type::DATE_TIME),
-    ((0x0040u16, 0x4011u16), "Expected Completion DateTime", vr_type::DATE_TIME),
-    ((0x0040u16, 0x4015u16), "Resulting General Purpose Performed Procedure Steps Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4016u16), "Referenced General Purpose Scheduled Procedure Step Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4018u16), "Scheduled Workitem Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4019u16), "Performed Workitem Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4020u16), "Input Availability Flag", vr_type::CODE_STRING),
-    ((0x0040u16, 0x4021u16), "Input Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4022u16), "Relevant Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4023u16), "Referenced General Purpose Scheduled Procedure Step Transaction Uid", vr_type::UID),
-    ((0x0040u16, 0x4025u16), "Scheduled Station Name Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4026u16), "Scheduled Station Class Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4027u16), "Scheduled Station Geographic Location Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4028u16), "Performed Station Name Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4029u16), "Performed Station Class Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4030u16), "Performed Station Geographic Location Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4031u16), "Requested Subsequent Workitem Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4032u16), "Non-DICOM Output Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4033u16), "Output Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4034u16), "Scheduled Human Performers Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4035u16), "Actual Human Performers Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x4036u16), "Human Performer's Organization", vr_type::LONG_STRING),
-    ((0x0040u16, 0x4037u16), "Human Performer's Name", vr_type::PERSON_NAME),
-    ((0x0100u16, 0x0410u16), "SOP Instance Status", vr_type::CODE_STRING),
-    ((0x0100u16, 0x0420u16), "SOP Authorization DateTime", vr_type::DATE_TIME),
-    ((0x0100u16, 0x0424u16), "SOP Authorization Comment", vr_type::LONG_TEXT),
-    ((0x0100u16, 0x0426u16), "Authorization Equipment Certification Number", vr_type::LONG_STRING),
-    ((0x0400u16, 0x0005u16), "MAC ID Number", vr_type::UNSIGNED_SHORT),
-    ((0x0400u16, 0x0010u16), "MAC Calculation Transfer Syntax Uid", vr_type::UID),
-    ((0x0400u16, 0x0015u16), "MAC Algorithm", vr_type::CODE_STRING),
-    ((0x0400u16, 0x0020u16), "Data Elements Signed", vr_type::ATTRIBUTE),
-    ((0x0400u16, 0x0100u16), "Digital Signature Uid", vr_type::UID),
-    ((0x0400u16, 0x0105u16), "Digital Signature DateTime", vr_type::DATE_TIME),
-    ((0x0400u16, 0x0110u16), "Certificate Type", vr_type::CODE_STRING),
-    ((0x0400u16, 0x0115u16), "Certificate of Signer", vr_type::OTHER_BYTE),
-    ((0x0400u16, 0x0120u16), "Signature", vr_type::OTHER_BYTE),
-    ((0x0400u16, 0x0305u16), "Certified Timestamp Type", vr_type::CODE_STRING),
-    ((0x0400u16, 0x0310u16), "Certified Timestamp", vr_type::OTHER_BYTE),
-    ((0x4FFEu16, 0x0001u16), "MAC Parameters Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0xFFFAu16, 0xFFFAu16), "Digital Signatures Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0400u16, 0x0401u16), "Digital Signature Purpose Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0400u16, 0x0402u16), "Referenced Digital Signature Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0400u16, 0x0403u16), "Referenced SOP Instance MAC Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0400u16, 0x0404u16), "MAC", vr_type::OTHER_BYTE),
-    ((0x0400u16, 0x0500u16), "Encrypted Attributes Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0400u16, 0x0510u16), "Encrypted Content Transfer Syntax Uid", vr_type::UID),
-    ((0x0400u16, 0x0520u16), "Encrypted Content", vr_type::OTHER_BYTE),
-    ((0x0400u16, 0x0550u16), "Modified Attributes Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0400u16, 0x0561u16), "Original Attributes Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0400u16, 0x0562u16), "Attribute Modification DateTime", vr_type::DATE_TIME),
-    ((0x0400u16, 0x0563u16), "Modifying System", vr_type::LONG_STRING),
-    ((0x0400u16, 0x0564u16), "Source of Previous Values", vr_type::LONG_STRING),
-    ((0x0400u16, 0x0565u16), "Reason for the Attribute Modification", vr_type::CODE_STRING),
-    ((0x0012u16, 0x0010u16), "Clinical Trial Sponsor Name", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0020u16), "Clinical Trial Protocol ID", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0021u16), "Clinical Trial Protocol Name", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0030u16), "Clinical Trial Site ID", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0031u16), "Clinical Trial Site Name", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0040u16), "Clinical Trial Subject ID", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0042u16), "Clinical Trial Subject Reading ID", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0050u16), "Clinical Trial Time Point ID", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0051u16), "Clinical Trial Time Point Description", vr_type::SHORT_TEXT),
-    ((0x0012u16, 0x0060u16), "Clinical Trial Coordinating Center Name", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0062u16), "Patient Identity Removed", vr_type::CODE_STRING),
-    ((0x0012u16, 0x0063u16), "De-identification Method", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0064u16), "De-identification Method Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x9007u16), "Frame Type", vr_type::CODE_STRING),
-    ((0x0008u16, 0x9121u16), "Referenced Raw Data Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x9123u16), "Creator-Version Uid", vr_type::UID),
-    ((0x0008u16, 0x9124u16), "Derivation Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x9092u16), "Referenced Image Evidence Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x9154u16), "Source Image Evidence Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x9205u16), "Pixel Presentation", vr_type::CODE_STRING),
-    ((0x0008u16, 0x9206u16), "Volumetric Properties", vr_type::CODE_STRING),
-    ((0x0008u16, 0x9207u16), "Volume Based Calculation Technique", vr_type::CODE_STRING),
-    ((0x0008u16, 0x9208u16), "Complex Image Component", vr_type::CODE_STRING),
-    ((0x0008u16, 0x9209u16), "Acquisition Contrast", vr_type::CODE_STRING),
-    ((0x0008u16, 0x9215u16), "Derivation Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x9237u16), "Referenced Presentation State Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9004u16), "Content Qualification", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9005u16), "Pulse Sequence Name", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x9006u16), "MR Imaging Modifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9008u16), "Echo Pulse Sequence", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9009u16), "Inversion Recovery", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9010u16), "Flow Compensation", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9011u16), "Multiple Spin Echo", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9012u16), "Multi-planar Excitation", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9014u16), "Phase Contrast", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9015u16), "Time of Flight Contrast", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9016u16), "Spoiling", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9017u16), "Steady State Pulse Sequence", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9018u16), "Echo Planar Pulse Sequence", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9019u16), "Tag Angle First Axis", vr_type::DOUBLE),
-    ((0x0018u16, 0x9020u16), "Magnetization Transfer", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9021u16), "T2 Preparation", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9022u16), "Blood Signal Nulling", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9024u16), "Saturation Recovery", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9025u16), "Spectrally Selected Suppression", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9026u16), "Spectrally Selected Excitation", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9027u16), "Spatial Pre-saturation", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9028u16), "Tagging", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9029u16), "Oversampling Phase", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9030u16), "Tag Spacing First Dimension", vr_type::DOUBLE),
-    ((0x0018u16, 0x9032u16), "Geometry of k-Space Traversal", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9033u16), "Segmented k-Space Traversal", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9034u16), "Rectilinear Phase Encode Reordering", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9035u16), "Tag Thickness", vr_type::DOUBLE),
-    ((0x0018u16, 0x9036u16), "Partial Fourier Direction", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9037u16), "Cardiac Synchronization Technique", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9041u16), "Receive Coil Manufacturer Name", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9042u16), "MR Receive Coil Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9043u16), "Receive Coil Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9044u16), "Quadrature Receive Coil", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9045u16), "Multi-Coil Definition Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9046u16), "Multi-Coil Configuration", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9047u16), "Multi-Coil Element Name", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x9048u16), "Multi-Coil Element Used", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9049u16), "MR Transmit Coil Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9050u16), "Transmit Coil Manufacturer Name", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9051u16), "Transmit Coil Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9052u16), "Spectral Width", vr_type::DOUBLE),
-    ((0x0018u16, 0x9053u16), "Chemical Shift Reference", vr_type::DOUBLE),
-    ((0x0018u16, 0x9054u16), "Volume Localization Technique", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9058u16), "MR Acquisition Frequency Encoding Steps", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9059u16), "De-coupling", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9060u16), "De-coupled Nucleus", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9061u16), "De-coupling Frequency", vr_type::DOUBLE),
-    ((0x0018u16, 0x9062u16), "De-coupling Method", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9063u16), "De-coupling Chemical Shift Reference", vr_type::DOUBLE),
-    ((0x0018u16, 0x9064u16), "k-space Filtering", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9065u16), "Time Domain Filtering", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9066u16), "Number of Zero fills", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9067u16), "Baseline Correction", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9070u16), "Cardiac R-R Interval Specified", vr_type::DOUBLE),
-    ((0x0018u16, 0x9073u16), "Acquisition Duration", vr_type::DOUBLE),
-    ((0x0018u16, 0x9074u16), "Frame Acquisition DateTime", vr_type::DATE_TIME),
-    ((0x0018u16, 0x9075u16), "Diffusion Directionality", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9076u16), "Diffusion Gradient Direction Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9077u16), "Parallel Acquisition", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9078u16), "Parallel Acquisition Technique", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9079u16), "Inversion Times", vr_type::DOUBLE),
-    ((0x0018u16, 0x9080u16), "Metabolite Map Description", vr_type::SHORT_TEXT),
-    ((0x0018u16, 0x9081u16), "Partial Fourier", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9082u16), "Effective Echo Time", vr_type::DOUBLE),
-    ((0x0018u16, 0x9083u16), "Metabolite Map Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9084u16), "Chemical Shift Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9085u16), "Cardiac Signal Source", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9087u16), "Diffusion b-value", vr_type::DOUBLE),
-    ((0x0018u16, 0x9089u16), "Diffusion Gradient Orientation", vr_type::DOUBLE),
-    ((0x0018u16, 0x9090u16), "Velocity Encoding Direction", vr_type::DOUBLE),
-    ((0x0018u16, 0x9091u16), "Velocity Encoding Minimum Value", vr_type::DOUBLE),
-    ((0x0018u16, 0x9093u16), "Number of k-Space Trajectories", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9094u16), "Coverage of k-Space", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9095u16), "Spectroscopy Acquisition Phase Rows", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x9069u16), "Parallel Reduction Factor In-Plane", vr_type::DOUBLE),
-    ((0x0018u16, 0x9098u16), "Transmitter Frequency", vr_type::DOUBLE),
-    ((0x0018u16, 0x9100u16), "Resonant Nucleus", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9101u16), "Frequency Correction", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9103u16), "MR Spectroscopy FOV/Geometry Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9104u16), "Slab Thickness", vr_type::DOUBLE),
-    ((0x0018u16, 0x9105u16), "Slab Orientation", vr_type::DOUBLE),
-    ((0x0018u16, 0x9106u16), "Mid Slab Position", vr_type::DOUBLE),
-    ((0x0018u16, 0x9107u16), "MR Spatial Saturation Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9112u16), "MR Timing and Related Parameters Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9114u16), "MR Echo Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9115u16), "MR Modifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9117u16), "MR Diffusion Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9118u16), "Cardiac Synchronization Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9119u16), "MR Averages Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9125u16), "MR FOV/Geometry Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9127u16), "Spectroscopy Acquisition Data Columns", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x9126u16), "Volume Localization Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9147u16), "Diffusion Anisotropy Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9151u16), "Frame Reference DateTime", vr_type::DATE_TIME),
-    ((0x0018u16, 0x9152u16), "MR Metabolite Map Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9155u16), "Parallel Reduction Factor out-of-plane", vr_type::DOUBLE),
-    ((0x0018u16, 0x9159u16), "Spectroscopy Acquisition Out-of-plane Phase Steps", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x9166u16), "Bulk Motion Status", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9168u16), "Parallel Reduction Factor Second In-Plane", vr_type::DOUBLE),
-    ((0x0018u16, 0x9169u16), "Cardiac Beat Rejection Technique", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9170u16), "Respiratory Motion Compensation Technique", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9171u16), "Respiratory Signal Source", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9172u16), "Bulk Motion Compensation Technique", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9173u16), "Bulk Motion Signal Source", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9174u16), "Applicable Safety Standard Agency", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9175u16), "Applicable Safety Standard Description", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9176u16), "Operating Mode Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9177u16), "Operating Mode Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9178u16), "Operating Mode", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9179u16), "Specific Absorption Rate Definition", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9180u16), "Gradient Output Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9181u16), "Specific Absorption Rate Value", vr_type::DOUBLE),
-    ((0x0018u16, 0x9182u16), "Gradient Output", vr_type::DOUBLE),
-    ((0x0018u16, 0x9183u16), "Flow Compensation Direction", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9184u16), "Tagging Delay", vr_type::DOUBLE),
-    ((0x0018u16, 0x9185u16), "Respiratory Motion Compensation Technique Description", vr_type::SHORT_TEXT),
-    ((0x0018u16, 0x9186u16), "Respiratory Signal Source ID", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x9197u16), "MR Velocity Encoding Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9198u16), "First Order Phase Correction", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9199u16), "Water Referenced Phase Correction", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9200u16), "MR Spectroscopy Acquisition Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9214u16), "Respiratory Cycle Position", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9217u16), "Velocity Encoding Maximum Value", vr_type::DOUBLE),
-    ((0x0018u16, 0x9218u16), "Tag Spacing Second Dimension", vr_type::DOUBLE),
-    ((0x0018u16, 0x9219u16), "Tag Angle Second Axis", vr_type::SIGNED_SHORT),
-    ((0x0018u16, 0x9220u16), "Frame Acquisition Duration", vr_type::DOUBLE),
-    ((0x0018u16, 0x9226u16), "MR Image Frame Type Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9227u16), "MR Spectroscopy Frame Type Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9231u16), "MR Acquisition Phase Encoding Steps in-Plane", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9232u16), "MR Acquisition Phase Encoding Steps out-of-plane", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9234u16), "Spectroscopy Acquisition Phase Columns", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x9236u16), "Cardiac Cycle Position", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9239u16), "Specific Absorption Rate Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9240u16), "RF Echo Train Length", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9241u16), "Gradient Echo Train Length", vr_type::UNSIGNED_SHORT),
-    ((0x0020u16, 0x9056u16), "Stack ID", vr_type::SHORT_STRING),
-    ((0x0020u16, 0x9057u16), "In-Stack Position Number", vr_type::UNSIGNED_LONG),
-    ((0x0020u16, 0x9071u16), "Frame Anatomy Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9072u16), "Frame Laterality", vr_type::CODE_STRING),
-    ((0x0020u16, 0x9111u16), "Frame Content Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9113u16), "Plane Position Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9116u16), "Plane Orientation Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9128u16), "Temporal Position Index", vr_type::UNSIGNED_LONG),
-    ((0x0020u16, 0x9153u16), "Nominal Cardiac Trigger Delay Time", vr_type::DOUBLE),
-    ((0x0020u16, 0x9156u16), "Frame Acquisition Number", vr_type::UNSIGNED_SHORT),
-    ((0x0020u16, 0x9157u16), "Dimension Index Values", vr_type::UNSIGNED_LONG),
-    ((0x0020u16, 0x9158u16), "Frame Comments", vr_type::LONG_TEXT),
-    ((0x0020u16, 0x9161u16), "Concatenation Uid", vr_type::UID),
-    ((0x0020u16, 0x9162u16), "In-Concatenation Number", vr_type::UNSIGNED_SHORT),
-    ((0x0020u16, 0x9163u16), "In-Concatenation Total Number", vr_type::UNSIGNED_SHORT),
-    ((0x0020u16, 0x9164u16), "Dimension Organization Uid", vr_type::UID),
-    ((0x0020u16, 0x9165u16), "Dimension Index Pointer", vr_type::ATTRIBUTE),
-    ((0x0020u16, 0x9167u16), "Functional Group Pointer", vr_type::ATTRIBUTE),
-    ((0x0020u16, 0x9213u16), "Dimension Index Private Creator", vr_type::LONG_STRING),
-    ((0x0020u16, 0x9221u16), "Dimension Organization Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9222u16), "Dimension Index Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9228u16), "Concatenation Frame Offset Number", vr_type::UNSIGNED_LONG),
-    ((0x0020u16, 0x9238u16), "Functional Group Private Creator", vr_type::LONG_STRING),
-    ((0x0020u16, 0x9251u16), "R-R Interval Time Nominal", vr_type::DOUBLE),
-    ((0x0020u16, 0x9253u16), "Respiratory Synchronization Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9254u16), "Respiratory Interval Time", vr_type::DOUBLE),
-    ((0x0020u16, 0x9255u16), "Nominal Respiratory Trigger Delay Time", vr_type::DOUBLE),
-    ((0x0020u16, 0x9256u16), "Respiratory Trigger Delay Threshold", vr_type::DOUBLE),
-    ((0x0018u16, 0x9295u16), "Chemical Shift Minimum Integration Limit in ppm", vr_type::DOUBLE),
-    ((0x0018u16, 0x9296u16), "Chemical Shift Maximum Integration Limit in ppm", vr_type::DOUBLE),
-    ((0x0028u16, 0x9001u16), "Data Point Rows", vr_type::UNSIGNED_LONG),
-    ((0x0028u16, 0x9002u16), "Data Point Columns", vr_type::UNSIGNED_LONG),
-    ((0x0028u16, 0x9003u16), "Signal Domain Columns", vr_type::CODE_STRING),
-    ((0x0028u16, 0x9108u16), "Data Representation", vr_type::CODE_STRING),
-    ((0x0028u16, 0x9110u16), "Pixel Measures Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x9132u16), "Frame VOI LUT Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x9145u16), "Pixel Value Transformation Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x9235u16), "Signal Domain Rows", vr_type::CODE_STRING),
-    ((0x0040u16, 0x9096u16), "Real World Value Mapping Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x9210u16), "LUT Label", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x9211u16), "Real World Value Last Value Mapped", vr_type::UNSIGNED_SHORT),
-    ((0x0040u16, 0x9212u16), "Real World Value LUT Data", vr_type::DOUBLE),
-    ((0x0040u16, 0x9216u16), "Real World Value First Value Mapped", vr_type::UNSIGNED_SHORT),
-    ((0x0040u16, 0x9224u16), "Real World Value Intercept", vr_type::DOUBLE),
-    ((0x0040u16, 0x9225u16), "Real World Value Slope", vr_type::DOUBLE),
-    ((0x5200u16, 0x9229u16), "Shared Functional Groups Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x5200u16, 0x9230u16), "Per-frame Functional Groups Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x5600u16, 0x0010u16), "First Order Phase Correction Angle", vr_type::OTHER_FLOAT),
-    ((0x5600u16, 0x0020u16), "Spectroscopy Data", vr_type::OTHER_FLOAT),
-    ((0x0018u16, 0x9301u16), "CT Acquisition Type Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9302u16), "Acquisition Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9303u16), "Tube Angle", vr_type::DOUBLE),
-    ((0x0018u16, 0x9304u16), "CT Acquisition Details Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9305u16), "Revolution Time", vr_type::DOUBLE),
-    ((0x0018u16, 0x9306u16), "Single Collimation Width", vr_type::DOUBLE),
-    ((0x0018u16, 0x9307u16), "Total Collimation Width", vr_type::DOUBLE),
-    ((0x0018u16, 0x9308u16), "CT Table Dynamics Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9309u16), "Table Speed", vr_type::DOUBLE),
-    ((0x0018u16, 0x9310u16), "Table Feed per Rotation", vr_type::DOUBLE),
-    ((0x0018u16, 0x9311u16), "Spiral Pitch Factor", vr_type::DOUBLE),
-    ((0x0018u16, 0x9312u16), "CT Geometry Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9313u16), "Data Collection Center (Patient)", vr_type::DOUBLE),
-    ((0x0018u16, 0x9314u16), "CT Reconstruction Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9315u16), "Reconstruction Algorithm", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9316u16), "Convolution Kernel Group", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9317u16), "Reconstruction Field of View", vr_type::DOUBLE),
-    ((0x0018u16, 0x9318u16), "Reconstruction Target Center (Patient)", vr_type::DOUBLE),
-    ((0x0018u16, 0x9319u16), "Reconstruction Angle", vr_type::DOUBLE),
-    ((0x0018u16, 0x9320u16), "Image Filter", vr_type::SHORT_STRING),
-    ((0x0018u16, 0x9321u16), "CT Exposure Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9322u16), "Reconstruction Pixel Spacing", vr_type::DOUBLE),
-    ((0x0018u16, 0x9323u16), "Exposure Modulation Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9324u16), "Estimated Dose Saving", vr_type::DOUBLE),
-    ((0x0018u16, 0x9325u16), "CT X-Ray Details Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9326u16), "CT Position Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9327u16), "Table Position", vr_type::DOUBLE),
-    ((0x0018u16, 0x9328u16), "Exposure Time in ms", vr_type::DOUBLE),
-    ((0x0018u16, 0x9329u16), "CT Image Frame Type Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9330u16), "X-Ray Tube Current in mA", vr_type::DOUBLE),
-    ((0x0018u16, 0x9332u16), "Exposure in mAs", vr_type::DOUBLE),
-    ((0x0018u16, 0x9333u16), "Constant Volume Flag", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9334u16), "Fluoroscopy Flag", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9335u16), "Distance Source to Data Collection Center", vr_type::DOUBLE),
-    ((0x0018u16, 0x9337u16), "Contrast/Bolus Agent Number", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9338u16), "Contrast/Bolus Ingredient Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9340u16), "Contrast Administration Profile Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9341u16), "Contrast/Bolus Usage Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9342u16), "Contrast/Bolus Agent Administered", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9343u16), "Contrast/Bolus Agent Detected", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9344u16), "Contrast/Bolus Agent Phase", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9345u16), "CTDIvol", vr_type::DOUBLE),
-    ((0x0008u16, 0x1200u16), "Studies Containing Other Referenced Instances Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0306u16), "Shape Type", vr_type::CODE_STRING),
-    ((0x0070u16, 0x0308u16), "Registration Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0309u16), "Matrix Registration Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x030Au16), "Matrix Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x030Cu16), "Frame of Reference Transformation Matrix Type", vr_type::CODE_STRING),
-    ((0x0070u16, 0x030Du16), "Registration Type Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x030Fu16), "Fiducial Description", vr_type::SHORT_TEXT),
-    ((0x0070u16, 0x0310u16), "Fiducial Identifier", vr_type::SHORT_STRING),
-    ((0x0070u16, 0x0311u16), "Fiducial Identifier Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0312u16), "Contour Uncertainty Radius", vr_type::DOUBLE),
-    ((0x0070u16, 0x0314u16), "Used Fiducials Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0318u16), "Graphic Coordinates Data Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x031Au16), "Fiducial Uid", vr_type::UID),
-    ((0x0070u16, 0x031Cu16), "Fiducial Set Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x031Eu16), "Fiducial Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0021u16), "Treatment Session Ion Beam Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0041u16), "Ion Control Point Delivery Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x0045u16), "Meterset Rate Set", vr_type::FLOAT),
-    ((0x3008u16, 0x0046u16), "Meterset Rate Delivered", vr_type::FLOAT),
-    ((0x3008u16, 0x0047u16), "Scan Spot Metersets Delivered", vr_type::FLOAT),
-    ((0x3008u16, 0x0061u16), "Parameter Sequence Pointer", vr_type::ATTRIBUTE),
-    ((0x3008u16, 0x0063u16), "Parameter Item Index", vr_type::INTEGER_STRING),
-    ((0x3008u16, 0x0065u16), "Parameter Pointer", vr_type::ATTRIBUTE),
-    ((0x3008u16, 0x0068u16), "Corrected Parameter Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x006Au16), "Correction Value", vr_type::FLOAT),
-    ((0x3008u16, 0x00F0u16), "Recorded Snout Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x00F2u16), "Recorded Range Shifter Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x00F4u16), "Recorded Lateral Spreading Device Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3008u16, 0x00F6u16), "Recorded Range Modulator Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x004Bu16), "Snout Position Tolerance", vr_type::FLOAT),
-    ((0x300Au16, 0x004Fu16), "Table Top Pitch Angle Tolerance", vr_type::FLOAT),
-    ((0x300Au16, 0x0050u16), "Table Top Roll Angle Tolerance", vr_type::FLOAT),
-    ((0x300Au16, 0x00BBu16), "Isocenter to Beam Limiting Device Distance", vr_type::FLOAT),
-    ((0x300Au16, 0x00D7u16), "Total Wedge Tray Water-Equivalent Thickness", vr_type::FLOAT),
-    ((0x300Au16, 0x00D9u16), "Isocenter to Wedge Tray Distance", vr_type::FLOAT),
-    ((0x300Au16, 0x00DBu16), "Wedge Thin Edge Position", vr_type::FLOAT),
-    ((0x300Au16, 0x00F3u16), "Total Block Tray Water-Equivalent Thickness", vr_type::FLOAT),
-    ((0x300Au16, 0x00F7u16), "Isocenter to Block Tray Distance", vr_type::FLOAT),
-    ((0x300Au16, 0x0140u16), "Table Top Pitch Angle", vr_type::FLOAT),
-    ((0x300Au16, 0x0142u16), "Table Top Pitch Rotation Direction", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0144u16), "Table Top Roll Angle", vr_type::FLOAT),
-    ((0x300Au16, 0x0146u16), "Table Top Roll Rotation Direction", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0148u16), "Head Fixation Angle", vr_type::FLOAT),
-    ((0x300Au16, 0x014Au16), "Gantry Pitch Angle", vr_type::FLOAT),
-    ((0x300Au16, 0x014Cu16), "Gantry Pitch Rotation Direction", vr_type::CODE_STRING),
-    ((0x300Au16, 0x014Eu16), "Gantry Pitch Angle Tolerance", vr_type::FLOAT),
-    ((0x300Au16, 0x02E3u16), "Total Compensator Tray Water-Equivalent Thickness", vr_type::FLOAT),
-    ((0x300Au16, 0x02E4u16), "Isocenter to Compensator Tray Distance", vr_type::FLOAT),
-    ((0x300Au16, 0x02E5u16), "Compensator Column Offset", vr_type::FLOAT),
-    ((0x300Au16, 0x02E6u16), "Isocenter to Compensator Distances", vr_type::FLOAT),
-    ((0x300Au16, 0x02E7u16), "Compensator Relative Stopping Power Ratio", vr_type::FLOAT),
-    ((0x300Au16, 0x02E8u16), "Compensator Milling Tool Diameter", vr_type::FLOAT),
-    ((0x300Au16, 0x02EAu16), "Ion Range Compensator Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0302u16), "Radiation Mass Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0304u16), "Radiation Atomic Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0306u16), "Radiation Charge State", vr_type::SIGNED_SHORT),
-    ((0x300Au16, 0x0308u16), "Scan Mode", vr_type::CODE_STRING),
-    ((0x300Au16, 0x030Au16), "Virtual Source-Axis Distances", vr_type::FLOAT),
-    ((0x300Au16, 0x030Cu16), "Snout Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x030Du16), "Snout Position", vr_type::FLOAT),
-    ((0x300Au16, 0x030Fu16), "Snout ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0312u16), "Number of Range Shifters", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0314u16), "Range Shifter Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0316u16), "Range Shifter Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0318u16), "Range Shifter ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0320u16), "Range Shifter Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0322u16), "Range Shifter Description", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0330u16), "Number of Lateral Spreading Devices", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0332u16), "Lateral Spreading Device Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0334u16), "Lateral Spreading Device Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0336u16), "Lateral Spreading Device ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0338u16), "Lateral Spreading Device Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x033Au16), "Lateral Spreading Device Description", vr_type::LONG_STRING),
-    ((0x300Au16, 0x033Cu16), "Lateral Spreading Device Water Equivalent Thickness", vr_type::FLOAT),
-    ((0x300Au16, 0x0340u16), "Number of Range Modulators", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0342u16), "Range Modulator Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0344u16), "Range Modulator Number", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0346u16), "Range Modulator ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0348u16), "Range Modulator Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x034Au16), "Range Modulator Description", vr_type::LONG_STRING),
-    ((0x300Au16, 0x034Cu16), "Beam Current Modulation ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0350u16), "Patient Support Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0352u16), "Patient Support ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0354u16), "Patient Support Accessory Code", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0356u16), "Fixation Light Azimuthal Angle", vr_type::FLOAT),
-    ((0x300Au16, 0x0358u16), "Fixation Light Polar Angle", vr_type::FLOAT),
-    ((0x300Au16, 0x035Au16), "Meterset Rate", vr_type::FLOAT),
-    ((0x300Au16, 0x0360u16), "Range Shifter Settings Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0362u16), "Range Shifter Setting", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0364u16), "Isocenter to Range Shifter Distance", vr_type::FLOAT),
-    ((0x300Au16, 0x0366u16), "Range Shifter Water Equivalent Thickness", vr_type::FLOAT),
-    ((0x300Au16, 0x0370u16), "Lateral Spreading Device Settings Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0372u16), "Lateral Spreading Device Setting", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0374u16), "Isocenter to Lateral Spreading Device Distance", vr_type::FLOAT),
-    ((0x300Au16, 0x0380u16), "Range Modulator Settings Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0382u16), "Range Modulator Gating Start Value", vr_type::FLOAT),
-    ((0x300Au16, 0x0384u16), "Range Modulator Gating Stop Value", vr_type::FLOAT),
-    ((0x300Au16, 0x0386u16), "Range Modulator Gating Start Water Equivalent Thickness", vr_type::FLOAT),
-    ((0x300Au16, 0x0388u16), "Range Modulator Gating Stop Water Equivalent Thickness", vr_type::FLOAT),
-    ((0x300Au16, 0x038Au16), "Isocenter to Range Modulator Distance", vr_type::FLOAT),
-    ((0x300Au16, 0x0390u16), "Scan Spot Tune ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0392u16), "Number of Scan Spot Positions", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x0394u16), "Scan Spot Position Map", vr_type::FLOAT),
-    ((0x300Au16, 0x0396u16), "Scan Spot Meterset Weights", vr_type::FLOAT),
-    ((0x300Au16, 0x0398u16), "Scanning Spot Size", vr_type::FLOAT),
-    ((0x300Au16, 0x039Au16), "Number of Paintings", vr_type::INTEGER_STRING),
-    ((0x300Au16, 0x03A0u16), "Ion Tolerance Table Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x03A2u16), "Ion Beam Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x03A4u16), "Ion Beam Limiting Device Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x03A6u16), "Ion Block Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x03A8u16), "Ion Control Point Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x03AAu16), "Ion Wedge Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x03ACu16), "Ion Wedge Position Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Cu16, 0x0100u16), "Referenced Range Shifter Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x0102u16), "Referenced Lateral Spreading Device Number", vr_type::INTEGER_STRING),
-    ((0x300Cu16, 0x0104u16), "Referenced Range Modulator Number", vr_type::INTEGER_STRING),
-    ((0x0008u16, 0x3010u16), "Irradiation Event Uid", vr_type::UID),
-    ((0x0008u16, 0x9410u16), "Referenced Other Plane Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x9458u16), "Frame Display Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x9459u16), "Recommended Display Frame Rate in Float", vr_type::FLOAT),
-    ((0x0008u16, 0x9460u16), "Skip Frame Range Flag", vr_type::CODE_STRING),
-    ((0x0010u16, 0x9431u16), "Examined Body Thickness", vr_type::FLOAT),
-    ((0x0018u16, 0x9401u16), "Projection Pixel Calibration Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9402u16), "Distance Source to Isocenter", vr_type::FLOAT),
-    ((0x0018u16, 0x9403u16), "Distance Object to Table Top", vr_type::FLOAT),
-    ((0x0018u16, 0x9404u16), "Object Pixel Spacing in Center of Beam", vr_type::FLOAT),
-    ((0x0018u16, 0x9405u16), "Positioner Position Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9406u16), "Table Position Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9407u16), "Collimator Shape Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9412u16), "XA/XRF Frame Characteristics Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9417u16), "Frame Acquisition Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9420u16), "X-Ray Receptor Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9423u16), "Acquisition Protocol Name", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9424u16), "Acquisition Protocol Description", vr_type::LONG_TEXT),
-    ((0x0018u16, 0x9425u16), "Contrast/Bolus Ingredient Opaque", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9426u16), "Distance Receptor Plane to Detector Housing", vr_type::FLOAT),
-    ((0x0018u16, 0x9427u16), "Intensifier Active Shape", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9428u16), "Intensifier Active Dimension(s)", vr_type::FLOAT),
-    ((0x0018u16, 0x9429u16), "Physical Detector Size", vr_type::FLOAT),
-    ((0x0018u16, 0x9430u16), "Position of Isocenter Projection", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9432u16), "Field of View Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9433u16), "Field of View Description", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9434u16), "Exposure Control Sensing Regions Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9435u16), "Exposure Control Sensing Region Shape", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9436u16), "Exposure Control Sensing Region Left Vertical Edge", vr_type::SIGNED_SHORT),
-    ((0x0018u16, 0x9437u16), "Exposure Control Sensing Region Right Vertical Edge", vr_type::SIGNED_SHORT),
-    ((0x0018u16, 0x9438u16), "Exposure Control Sensing Region Upper Horizontal Edge", vr_type::SIGNED_SHORT),
-    ((0x0018u16, 0x9439u16), "Exposure Control Sensing Region Lower Horizontal Edge", vr_type::SIGNED_SHORT),
-    ((0x0018u16, 0x9440u16), "Center of Circular Exposure Control Sensing Region", vr_type::SIGNED_SHORT),
-    ((0x0018u16, 0x9441u16), "Radius of Circular Exposure Control Sensing Region", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9442u16), "Vertices of the Polygonal Exposure Control Sensing Region", vr_type::SIGNED_SHORT),
-    ((0x0018u16, 0x9447u16), "Column Angulation (Patient)", vr_type::FLOAT),
-    ((0x0018u16, 0x9449u16), "Beam Angle", vr_type::FLOAT),
-    ((0x0018u16, 0x9451u16), "Frame Detector Parameters Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9452u16), "Calculated Anatomy Thickness", vr_type::FLOAT),
-    ((0x0018u16, 0x9455u16), "Calibration Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9456u16), "Object Thickness Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9457u16), "Plane Identification", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9461u16), "Field of View Dimension(s) in Float", vr_type::FLOAT),
-    ((0x0018u16, 0x9462u16), "Isocenter Reference System Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9463u16), "Positioner Isocenter Primary Angle", vr_type::FLOAT),
-    ((0x0018u16, 0x9464u16), "Positioner Isocenter Secondary Angle", vr_type::FLOAT),
-    ((0x0018u16, 0x9465u16), "Positioner Isocenter Detector Rotation Angle", vr_type::FLOAT),
-    ((0x0018u16, 0x9466u16), "Table X Position to Isocenter", vr_type::FLOAT),
-    ((0x0018u16, 0x9467u16), "Table Y Position to Isocenter", vr_type::FLOAT),
-    ((0x0018u16, 0x9468u16), "Table Z Position to Isocenter", vr_type::FLOAT),
-    ((0x0018u16, 0x9469u16), "Table Horizontal Rotation Angle", vr_type::FLOAT),
-    ((0x0018u16, 0x9470u16), "Table Head Tilt Angle", vr_type::FLOAT),
-    ((0x0018u16, 0x9471u16), "Table Cradle Tilt Angle", vr_type::FLOAT),
-    ((0x0018u16, 0x9472u16), "Frame Display Shutter Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9473u16), "Acquired Image Area Dose Product", vr_type::FLOAT),
-    ((0x0018u16, 0x9474u16), "C-arm Positioner Tabletop Relationship", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9476u16), "X-Ray Geometry Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9477u16), "Irradiation Event Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9421u16), "Dimension Description Label", vr_type::LONG_STRING),
-    ((0x0020u16, 0x9450u16), "Patient Orientation in Frame Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9453u16), "Frame Label", vr_type::LONG_STRING),
-    ((0x0028u16, 0x9411u16), "Display Filter Percentage", vr_type::FLOAT),
-    ((0x0028u16, 0x9415u16), "Frame Pixel Shift Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x9416u16), "Subtraction Item ID", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x9422u16), "Pixel Intensity Relationship LUT Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x9443u16), "Frame Pixel Data Properties Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x9444u16), "Geometrical Properties", vr_type::CODE_STRING),
-    ((0x0028u16, 0x9445u16), "Geometric Maximum Distortion", vr_type::FLOAT),
-    ((0x0028u16, 0x9446u16), "Image Processing Applied", vr_type::CODE_STRING),
-    ((0x0028u16, 0x9454u16), "Mask Selection Mode", vr_type::CODE_STRING),
-    ((0x0028u16, 0x9474u16), "LUT Function", vr_type::CODE_STRING),
-    ((0x0028u16, 0x2000u16), "ICC Profile", vr_type::OTHER_BYTE),
-    ((0x0070u16, 0x0401u16), "Graphic Layer Recommended Display CIELab Value", vr_type::UNSIGNED_SHORT),
-    ((0x0070u16, 0x0402u16), "Blending Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0403u16), "Relative Opacity", vr_type::FLOAT),
-    ((0x0070u16, 0x0404u16), "Referenced Spatial Registration Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0070u16, 0x0405u16), "Blending Position", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0002u16), "Hanging Protocol Name", vr_type::SHORT_STRING),
-    ((0x0072u16, 0x0004u16), "Hanging Protocol Description", vr_type::LONG_STRING),
-    ((0x0072u16, 0x0006u16), "Hanging Protocol Level", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0008u16), "Hanging Protocol Creator", vr_type::LONG_STRING),
-    ((0x0072u16, 0x000Au16), "Hanging Protocol Creation DateTime", vr_type::DATE_TIME),
-    ((0x0072u16, 0x000Cu16), "Hanging Protocol Definition Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x000Eu16), "Hanging Protocol User Identification Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0010u16), "Hanging Protocol User Group Name", vr_type::LONG_STRING),
-    ((0x0072u16, 0x0012u16), "Source Hanging Protocol Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0014u16), "Number of Priors Referenced", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0020u16), "Image Sets Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0022u16), "Image Set Selector Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0024u16), "Image Set Selector Usage Flag", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0026u16), "Selector Attribute", vr_type::ATTRIBUTE),
-    ((0x0072u16, 0x0028u16), "Selector Value Number", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0030u16), "Time Based Image Sets Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0032u16), "Image Set Number", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0034u16), "Image Set Selector Category", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0038u16), "Relative Time", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x003Au16), "Relative Time Units", vr_type::CODE_STRING),
-    ((0x0072u16, 0x003Cu16), "Abstract Prior Value", vr_type::SIGNED_SHORT),
-    ((0x0072u16, 0x003Eu16), "Abstract Prior Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0040u16), "Image Set Label", vr_type::LONG_STRING),
-    ((0x0072u16, 0x0050u16), "Selector Attribute VR", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0052u16), "Selector Sequence Pointer", vr_type::ATTRIBUTE),
-    ((0x0072u16, 0x0054u16), "Selector Sequence Pointer Private Creator", vr_type::LONG_STRING),
-    ((0x0072u16, 0x0056u16), "Selector Attribute Private Creator", vr_type::LONG_STRING),
-    ((0x0072u16, 0x0060u16), "Selector AT Value", vr_type::ATTRIBUTE),
-    ((0x0072u16, 0x0062u16), "Selector CS Value", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0064u16), "Selector IS Value", vr_type::INTEGER_STRING),
-    ((0x0072u16, 0x0066u16), "Selector LO Value", vr_type::LONG_STRING),
-    ((0x0072u16, 0x0068u16), "Selector LT Value", vr_type::LONG_TEXT),
-    ((0x0072u16, 0x006Au16), "Selector PN Value", vr_type::PERSON_NAME),
-    ((0x0072u16, 0x006Cu16), "Selector SH Value", vr_type::SHORT_STRING),
-    ((0x0072u16, 0x006Eu16), "Selector ST Value", vr_type::SHORT_TEXT),
-    ((0x0072u16, 0x0070u16), "Selector UT Value", vr_type::UNLIMITED_TEXT),
-    ((0x0072u16, 0x0072u16), "Selector DS Value", vr_type::DECIMAL_STRING),
-    ((0x0072u16, 0x0074u16), "Selector FD Value", vr_type::DOUBLE),
-    ((0x0072u16, 0x0076u16), "Selector FL Value", vr_type::FLOAT),
-    ((0x0072u16, 0x0078u16), "Selector UL Value", vr_type::UNSIGNED_LONG),
-    ((0x0072u16, 0x007Au16), "Selector US Value", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x007Cu16), "Selector SL Value", vr_type::SIGNED_LONG),
-    ((0x0072u16, 0x007Eu16), "Selector SS Value", vr_type::SIGNED_SHORT),
-    ((0x0072u16, 0x0080u16), "Selector Code Sequence Value", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0100u16), "Number of Screens", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0102u16), "Nominal Screen Definition Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0104u16), "Number of Vertical Pixels", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0106u16), "Number of Horizontal Pixels", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0108u16), "Display Environment Spatial Position", vr_type::DOUBLE),
-    ((0x0072u16, 0x010Au16), "Screen Minimum Grayscale Bit Depth", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x010Cu16), "Screen Minimum Color Bit Depth", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x010Eu16), "Application Maximum Repaint Time", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0200u16), "Display Sets Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0202u16), "Display Set Number", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0203u16), "Display Set Label", vr_type::LONG_STRING),
-    ((0x0072u16, 0x0204u16), "Display Set Presentation Group", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0206u16), "Display Set Presentation Group Description", vr_type::LONG_STRING),
-    ((0x0072u16, 0x0208u16), "Partial Data Display Handling", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0210u16), "Synchronized Scrolling Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0212u16), "Display Set Scrolling Group", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0214u16), "Navigation Indicator Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0216u16), "Navigation Display Set", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0218u16), "Reference Display Sets", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0300u16), "Image Boxes Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0302u16), "Image Box Number", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0304u16), "Image Box Layout Type", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0306u16), "Image Box Tile Horizontal Dimension", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0308u16), "Image Box Tile Vertical Dimension", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0310u16), "Image Box Scroll Direction", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0312u16), "Image Box Small Scroll Type", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0314u16), "Image Box Small Scroll Amount", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0316u16), "Image Box Large Scroll Type", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0318u16), "Image Box Large Scroll Amount", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0320u16), "Image Box Overlap Priority", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0330u16), "Cine Relative to Real-Time", vr_type::DOUBLE),
-    ((0x0072u16, 0x0400u16), "Filter Operations Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0402u16), "Filter-by Category", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0404u16), "Filter-by Attribute Presence", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0406u16), "Filter-by Operator", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0500u16), "Blending Operation Type", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0510u16), "Reformatting Operation Type", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0512u16), "Reformatting Thickness", vr_type::DOUBLE),
-    ((0x0072u16, 0x0514u16), "Reformatting Interval", vr_type::DOUBLE),
-    ((0x0072u16, 0x0516u16), "Reformatting Operation Initial View Direction", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0520u16), "3D Rendering Type", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0600u16), "Sorting Operations Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0602u16), "Sort-by Category", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0604u16), "Sorting Direction", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0700u16), "Display Set Patient Orientation", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0702u16), "VOI Type", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0704u16), "Pseudo-Color Type", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0706u16), "Show Grayscale Inverted", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0710u16), "Show Image True Size Flag", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0712u16), "Show Graphic Annotation Flag", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0714u16), "Show Patient Demographics Flag", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0716u16), "Show Acquisition Techniques Flag", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0717u16), "Display Set Horizontal Justification", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0718u16), "Display Set Vertical Justification", vr_type::CODE_STRING),
-    ((0x2200u16, 0x0001u16), "Label Using Information Extracted From Instances", vr_type::CODE_STRING),
-    ((0x2200u16, 0x0002u16), "Label Text", vr_type::UNLIMITED_TEXT),
-    ((0x2200u16, 0x0003u16), "Label Style Selection", vr_type::CODE_STRING),
-    ((0x2200u16, 0x0004u16), "Media Disposition", vr_type::LONG_TEXT),
-    ((0x2200u16, 0x0005u16), "Barcode Value", vr_type::LONG_TEXT),
-    ((0x2200u16, 0x0006u16), "Barcode Symbology", vr_type::CODE_STRING),
-    ((0x2200u16, 0x0007u16), "Allow Media Splitting", vr_type::CODE_STRING),
-    ((0x2200u16, 0x0008u16), "Include Non-DICOM Objects", vr_type::CODE_STRING),
-    ((0x2200u16, 0x0009u16), "Include Display Application", vr_type::CODE_STRING),
-    ((0x2200u16, 0x000Au16), "Preserve Composite Instances After Media Creation", vr_type::CODE_STRING),
-    ((0x2200u16, 0x000Bu16), "Total Number of Pieces of Media Created", vr_type::UNSIGNED_SHORT),
-    ((0x2200u16, 0x000Cu16), "Requested Media Application Profile", vr_type::LONG_STRING),
-    ((0x2200u16, 0x000Du16), "Referenced Storage Media Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2200u16, 0x000Eu16), "Failure Attributes", vr_type::ATTRIBUTE),
-    ((0x2200u16, 0x000Fu16), "Allow Lossy Compression", vr_type::CODE_STRING),
-    ((0x2200u16, 0x0020u16), "Request Priority", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0003u16), "Samples per Pixel Used", vr_type::UNSIGNED_SHORT),
-    ((0x0022u16, 0x0001u16), "Light Path Filter Pass-Through Wavelength", vr_type::UNSIGNED_SHORT),
-    ((0x0022u16, 0x0002u16), "Light Path Filter Pass Band", vr_type::UNSIGNED_SHORT),
-    ((0x0022u16, 0x0003u16), "Image Path Filter Pass-Through Wavelength", vr_type::UNSIGNED_SHORT),
-    ((0x0022u16, 0x0004u16), "Image Path Filter Pass Band", vr_type::UNSIGNED_SHORT),
-    ((0x0022u16, 0x0005u16), "Patient Eye Movement Commanded", vr_type::CODE_STRING),
-    ((0x0022u16, 0x0006u16), "Patient Eye Movement Command Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x0007u16), "Spherical Lens Power", vr_type::FLOAT),
-    ((0x0022u16, 0x0008u16), "Cylinder Lens Power", vr_type::FLOAT),
-    ((0x0022u16, 0x0009u16), "Cylinder Axis", vr_type::FLOAT),
-    ((0x0022u16, 0x000Au16), "Emmetropic Magnification", vr_type::FLOAT),
-    ((0x0022u16, 0x000Bu16), "Intra Ocular Pressure", vr_type::FLOAT),
-    ((0x0022u16, 0x000Cu16), "Horizontal Field of View", vr_type::FLOAT),
-    ((0x0022u16, 0x000Du16), "Pupil Dilated", vr_type::CODE_STRING),
-    ((0x0022u16, 0x000Eu16), "Degree of Dilation", vr_type::FLOAT),
-    ((0x0022u16, 0x0010u16), "Stereo Baseline Angle", vr_type::FLOAT),
-    ((0x0022u16, 0x0011u16), "Stereo Baseline Displacement", vr_type::FLOAT),
-    ((0x0022u16, 0x0012u16), "Stereo Horizontal Pixel Offset", vr_type::FLOAT),
-    ((0x0022u16, 0x0013u16), "Stereo Vertical Pixel Offset", vr_type::FLOAT),
-    ((0x0022u16, 0x0014u16), "Stereo Rotation", vr_type::FLOAT),
-    ((0x0022u16, 0x0015u16), "Acquisition Device Type Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x0016u16), "Illumination Type Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x0017u16), "Light Path Filter Type Stack Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x0018u16), "Image Path Filter Type Stack Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x0019u16), "Lenses Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x001Au16), "Channel Description Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x001Bu16), "Refractive State Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x001Cu16), "Mydriatic Agent Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x001Du16), "Relative Image Position Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x0020u16), "Stereo Pairs Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x0021u16), "Left Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x0022u16), "Right Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0010u16, 0x0022u16), "Type of Patient ID", vr_type::CODE_STRING),
-    ((0x0010u16, 0x1002u16), "Other Patient IDs Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0010u16, 0x2201u16), "Patient Species Description", vr_type::LONG_STRING),
-    ((0x0010u16, 0x2202u16), "Patient Species Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0010u16, 0x2203u16), "Patient's Sex Neutered", vr_type::CODE_STRING),
-    ((0x0010u16, 0x2292u16), "Patient Breed Description", vr_type::LONG_STRING),
-    ((0x0010u16, 0x2293u16), "Patient Breed Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0010u16, 0x2294u16), "Breed Registration Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0010u16, 0x2295u16), "Breed Registration Number", vr_type::LONG_STRING),
-    ((0x0010u16, 0x2296u16), "Breed Registry Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0010u16, 0x2297u16), "Responsible Person", vr_type::PERSON_NAME),
-    ((0x0010u16, 0x2298u16), "Responsible Person Role", vr_type::CODE_STRING),
-    ((0x0010u16, 0x2299u16), "Responsible Organization", vr_type::LONG_STRING),
-    ((0x0062u16, 0x0001u16), "Segmentation Type", vr_type::CODE_STRING),
-    ((0x0062u16, 0x0002u16), "DICOM Segment Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0062u16, 0x0003u16), "Segmented Property Category Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0062u16, 0x0004u16), "DICOM Segment Number", vr_type::UNSIGNED_SHORT),
-    ((0x0062u16, 0x0005u16), "Segment Label", vr_type::LONG_STRING),
-    ((0x0062u16, 0x0006u16), "DICOM Segment Description", vr_type::SHORT_TEXT),
-    ((0x0062u16, 0x0008u16), "Segment Algorithm Type", vr_type::CODE_STRING),
-    ((0x0062u16, 0x0009u16), "Segment Algorithm Name", vr_type::LONG_STRING),
-    ((0x0062u16, 0x000Au16), "Segment Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0062u16, 0x000Bu16), "Referenced Segment Number", vr_type::UNSIGNED_SHORT),
-    ((0x0062u16, 0x000Cu16), "Recommended Display Grayscale Value", vr_type::UNSIGNED_SHORT),
-    ((0x0062u16, 0x000Du16), "Recommended Display CIELab Value", vr_type::UNSIGNED_SHORT),
-    ((0x0062u16, 0x000Eu16), "Maximum Fractional Value", vr_type::UNSIGNED_SHORT),
-    ((0x0062u16, 0x000Fu16), "Segmented Property Type Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0062u16, 0x0010u16), "Segmentation Fractional Type", vr_type::CODE_STRING),
-    ((0x0064u16, 0x0002u16), "Deformable Registration Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0064u16, 0x0003u16), "Source Frame of Reference Uid", vr_type::UID),
-    ((0x0064u16, 0x0005u16), "Deformable Registration Grid Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0064u16, 0x0007u16), "Grid Dimensions", vr_type::UNSIGNED_LONG),
-    ((0x0064u16, 0x0008u16), "Grid Resolution", vr_type::DOUBLE),
-    ((0x0064u16, 0x0009u16), "Vector Grid Data", vr_type::OTHER_FLOAT),
-    ((0x0064u16, 0x000Fu16), "Pre Deformation Matrix Registration Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0064u16, 0x0010u16), "Post Deformation Matrix Registration Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9504u16), "X-Ray 3D Frame Type Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9506u16), "Contributing Sources Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9507u16), "X-Ray 3D Acquisition Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9508u16), "Primary Positioner Scan Arc", vr_type::FLOAT),
-    ((0x0018u16, 0x9509u16), "Secondary Positioner Scan Arc", vr_type::FLOAT),
-    ((0x0018u16, 0x9510u16), "Primary Positioner Scan Start Angle", vr_type::FLOAT),
-    ((0x0018u16, 0x9511u16), "Secondary Positioner Scan Start Angle", vr_type::FLOAT),
-    ((0x0018u16, 0x9514u16), "Primary Positioner Increment", vr_type::FLOAT),
-    ((0x0018u16, 0x9515u16), "Secondary Positioner Increment", vr_type::FLOAT),
-    ((0x0018u16, 0x9516u16), "Start Acquisition DateTime", vr_type::DATE_TIME),
-    ((0x0018u16, 0x9517u16), "End Acquisition DateTime", vr_type::DATE_TIME),
-    ((0x0018u16, 0x9524u16), "Application Name", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9525u16), "Application Version", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9526u16), "Application Manufacturer", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9527u16), "Algorithm Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9528u16), "Algorithm Description", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9530u16), "X-Ray 3D Reconstruction Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9531u16), "Reconstruction Description", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9538u16), "Per Projection Acquisition Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9518u16), "Acquisition Index", vr_type::UNSIGNED_SHORT),
-    ((0x0020u16, 0x9529u16), "Contributing SOP Instances Reference Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9536u16), "Reconstruction Index", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x9520u16), "Image to Equipment Mapping Matrix", vr_type::DECIMAL_STRING),
-    ((0x0028u16, 0x9537u16), "Equipment Coordinate System Identification", vr_type::CODE_STRING),
-    ((0x0020u16, 0x9241u16), "Nominal Percentage of Cardiac Phase", vr_type::FLOAT),
-    ((0x0020u16, 0x9245u16), "Nominal Percentage of Respiratory Phase", vr_type::FLOAT),
-    ((0x0020u16, 0x9246u16), "Starting Respiratory Amplitude", vr_type::FLOAT),
-    ((0x0020u16, 0x9247u16), "Starting Respiratory Phase", vr_type::CODE_STRING),
-    ((0x0020u16, 0x9248u16), "Ending Respiratory Amplitude", vr_type::FLOAT),
-    ((0x0020u16, 0x9249u16), "Ending Respiratory Phase", vr_type::CODE_STRING),
-    ((0x0020u16, 0x9250u16), "Respiratory Trigger Type", vr_type::CODE_STRING),
-    ((0x0020u16, 0x9252u16), "Actual Cardiac Trigger Delay Time", vr_type::DOUBLE),
-    ((0x0020u16, 0x9257u16), "Actual Respiratory Trigger Delay Time", vr_type::DOUBLE),
-    ((0x0044u16, 0x0001u16), "Product Package Identifier", vr_type::SHORT_TEXT),
-    ((0x0044u16, 0x0002u16), "Substance Administration Approval", vr_type::CODE_STRING),
-    ((0x0044u16, 0x0003u16), "Approval Status Further Description", vr_type::LONG_TEXT),
-    ((0x0044u16, 0x0004u16), "Approval Status DateTime", vr_type::DATE_TIME),
-    ((0x0044u16, 0x0007u16), "Product Type Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0044u16, 0x0008u16), "Product Name", vr_type::LONG_STRING),
-    ((0x0044u16, 0x0009u16), "Product Description", vr_type::LONG_TEXT),
-    ((0x0044u16, 0x000Au16), "Product Lot Identifier", vr_type::LONG_STRING),
-    ((0x0044u16, 0x000Bu16), "Product Expiration DateTime", vr_type::DATE_TIME),
-    ((0x0044u16, 0x0010u16), "Substance Administration DateTime", vr_type::DATE_TIME),
-    ((0x0044u16, 0x0011u16), "Substance Administration Notes", vr_type::LONG_STRING),
-    ((0x0044u16, 0x0012u16), "Substance Administration Device ID", vr_type::LONG_STRING),
-    ((0x0044u16, 0x0013u16), "Product Parameter Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0044u16, 0x0019u16), "Substance Administration Parameter Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x0030u16), "Axial Length of the Eye", vr_type::FLOAT),
-    ((0x0022u16, 0x0031u16), "Ophthalmic Frame Location Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x0032u16), "Reference Coordinates", vr_type::FLOAT),
-    ((0x0022u16, 0x0035u16), "Depth Spatial Resolution", vr_type::FLOAT),
-    ((0x0022u16, 0x0036u16), "Maximum Depth Distortion", vr_type::FLOAT),
-    ((0x0022u16, 0x0037u16), "Along-Scan Spatial Resolution", vr_type::FLOAT),
-    ((0x0022u16, 0x0038u16), "Maximum Along-Scan Distortion", vr_type::FLOAT),
-    ((0x0022u16, 0x0039u16), "Ophthalmic Image Orientation", vr_type::CODE_STRING),
-    ((0x0022u16, 0x0041u16), "Depth of Transverse Image", vr_type::FLOAT),
-    ((0x0022u16, 0x0042u16), "Mydriatic Agent Concentration Units Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0022u16, 0x0048u16), "Across-Scan Spatial Resolution", vr_type::FLOAT),
-    ((0x0022u16, 0x0049u16), "Maximum Across-Scan Distortion", vr_type::FLOAT),
-    ((0x0022u16, 0x004Eu16), "Mydriatic Agent Concentration", vr_type::DECIMAL_STRING),
-    ((0x0022u16, 0x0055u16), "Illumination Wave Length", vr_type::FLOAT),
-    ((0x0022u16, 0x0056u16), "Illumination Power", vr_type::FLOAT),
-    ((0x0022u16, 0x0057u16), "Illumination Bandwidth", vr_type::FLOAT),
-    ((0x0022u16, 0x0058u16), "Mydriatic Agent Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0042u16, 0x0014u16), "List of MIME Types", vr_type::LONG_STRING),
-    ((0x0018u16, 0x9701u16), "Decay Correction DateTime", vr_type::DATE_TIME),
-    ((0x0018u16, 0x9715u16), "Start Density Threshold", vr_type::DOUBLE),
-    ((0x0018u16, 0x9716u16), "Start Relative Density Difference Threshold", vr_type::DOUBLE),
-    ((0x0018u16, 0x9717u16), "Start Cardiac Trigger Count Threshold", vr_type::DOUBLE),
-    ((0x0018u16, 0x9718u16), "Start Respiratory Trigger Count Threshold", vr_type::DOUBLE),
-    ((0x0018u16, 0x9719u16), "Termination Counts Threshold", vr_type::DOUBLE),
-    ((0x0018u16, 0x9720u16), "Termination Density Threshold", vr_type::DOUBLE),
-    ((0x0018u16, 0x9721u16), "Termination Relative Density Threshold", vr_type::DOUBLE),
-    ((0x0018u16, 0x9722u16), "Termination Time Threshold", vr_type::DOUBLE),
-    ((0x0018u16, 0x9723u16), "Termination Cardiac Trigger Count Threshold", vr_type::DOUBLE),
-    ((0x0018u16, 0x9724u16), "Termination Respiratory Trigger Count Threshold", vr_type::DOUBLE),
-    ((0x0018u16, 0x9725u16), "Detector Geometry", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9726u16), "Transverse Detector Separation", vr_type::DOUBLE),
-    ((0x0018u16, 0x9727u16), "Axial Detector Dimension", vr_type::DOUBLE),
-    ((0x0018u16, 0x9729u16), "Radiopharmaceutical Agent Number", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9732u16), "PET Frame Acquisition Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9733u16), "PET Detector Motion Details Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9734u16), "PET Table Dynamics Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9735u16), "PET Position Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9736u16), "PET Frame Correction Factors Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9737u16), "Radiopharmaceutical Usage Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9738u16), "Attenuation Correction Source", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9739u16), "Number of Iterations", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9740u16), "Number of Subsets", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x9749u16), "PET Reconstruction Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9751u16), "PET Frame Type Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9755u16), "Time of Flight Information Used", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9756u16), "Reconstruction Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9758u16), "Decay Corrected", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9759u16), "Attenuation Corrected", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9760u16), "Scatter Corrected", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9761u16), "Dead Time Corrected", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9762u16), "Gantry Motion Corrected", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9763u16), "Patient Motion Corrected", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9764u16), "Count Loss Normalization Corrected", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9765u16), "Randoms Corrected", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9766u16), "Non-uniform Radial Sampling Corrected", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9767u16), "Sensitivity Calibrated", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9768u16), "Detector Normalization Correction", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9769u16), "Iterative Reconstruction Method", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9770u16), "Attenuation Correction Temporal Relationship", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9771u16), "Patient Physiological State Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9772u16), "Patient Physiological State Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0500u16), "Scheduled Specimen Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0512u16), "Container Identifier", vr_type::LONG_STRING),
-    ((0x0040u16, 0x0513u16), "Issuer of the Container Identifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0515u16), "Alternate Container Identifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0518u16), "Container Type Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x051Au16), "Container Description", vr_type::LONG_STRING),
-    ((0x0040u16, 0x0520u16), "Container Component Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0560u16), "Specimen Description Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0562u16), "Issuer of the Specimen Identifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0554u16), "Specimen Uid", vr_type::UID),
-    ((0x0040u16, 0x0600u16), "Specimen Short Description", vr_type::LONG_STRING),
-    ((0x0040u16, 0x0602u16), "Specimen Detailed Description", vr_type::UNLIMITED_TEXT),
-    ((0x0040u16, 0x0610u16), "Specimen Preparation Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0612u16), "Specimen Preparation Step Content Item Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0620u16), "Specimen Localization Content Item Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0050u16, 0x0012u16), "Container Component Type Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0050u16, 0x0015u16), "Container Component Width", vr_type::DOUBLE),
-    ((0x0050u16, 0x0013u16), "Container Component Thickness", vr_type::DOUBLE),
-    ((0x0050u16, 0x001Au16), "Container Component Material", vr_type::CODE_STRING),
-    ((0x0050u16, 0x001Bu16), "Container Component ID", vr_type::LONG_STRING),
-    ((0x0050u16, 0x001Cu16), "Container Component Length", vr_type::DOUBLE),
-    ((0x0050u16, 0x001Du16), "Container Component Diameter", vr_type::DOUBLE),
-    ((0x0050u16, 0x001Eu16), "Container Component Description", vr_type::LONG_STRING),
-    ((0x0008u16, 0x0051u16), "Issuer of Accession Number Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0010u16, 0x0024u16), "Issuer of Patient ID Qualifiers Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0038u16, 0x0014u16), "Issuer of Admission ID Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0038u16, 0x0064u16), "Issuer of Service Episode ID Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0026u16), "Order Placer Identifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0027u16), "Order Filler Identifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0031u16), "Local Namespace Entity ID", vr_type::UNLIMITED_TEXT),
-    ((0x0040u16, 0x0032u16), "Universal Entity ID", vr_type::UNLIMITED_TEXT),
-    ((0x0040u16, 0x0033u16), "Universal Entity ID Type", vr_type::CODE_STRING),
-    ((0x0040u16, 0x0035u16), "Identifier Type Code", vr_type::CODE_STRING),
-    ((0x0040u16, 0x0036u16), "Assigning Facility Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0039u16), "Assigning Jurisdiction Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x003Au16), "Assigning Agency or Department Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1134u16), "Referenced Stereometric Instance Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x0042u16), "Initial Cine Run State", vr_type::CODE_STRING),
-    ((0x0072u16, 0x0420u16), "Structured Display Background CIELab Value", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0421u16), "Empty Image Box CIELab Value", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0422u16), "Structured Display Image Box Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0424u16), "Structured Display Text Box Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0427u16), "Referenced First Frame Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0430u16), "Image Box Synchronization Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0072u16, 0x0432u16), "Synchronized Image Box List", vr_type::UNSIGNED_SHORT),
-    ((0x0072u16, 0x0434u16), "Type of Synchronization", vr_type::CODE_STRING),
-    ((0x0046u16, 0x0012u16), "Lens Description", vr_type::LONG_STRING),
-    ((0x0046u16, 0x0014u16), "Right Lens Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0015u16), "Left Lens Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0016u16), "Unspecified Laterality Lens Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0018u16), "Cylinder Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0028u16), "Prism Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0030u16), "Horizontal Prism Power", vr_type::DOUBLE),
-    ((0x0046u16, 0x0032u16), "Horizontal Prism Base", vr_type::CODE_STRING),
-    ((0x0046u16, 0x0034u16), "Vertical Prism Power", vr_type::DOUBLE),
-    ((0x0046u16, 0x0036u16), "Vertical Prism Base", vr_type::CODE_STRING),
-    ((0x0046u16, 0x0038u16), "Lens Segment Type", vr_type::CODE_STRING),
-    ((0x0046u16, 0x0040u16), "Optical Transmittance", vr_type::DOUBLE),
-    ((0x0046u16, 0x0042u16), "Channel Width", vr_type::DOUBLE),
-    ((0x0046u16, 0x0044u16), "Pupil Size", vr_type::DOUBLE),
-    ((0x0046u16, 0x0046u16), "Corneal Size", vr_type::DOUBLE),
-    ((0x0046u16, 0x0050u16), "Autorefraction Right Eye Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0052u16), "Autorefraction Left Eye Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0060u16), "Distance Pupillary Distance", vr_type::DOUBLE),
-    ((0x0046u16, 0x0062u16), "Near Pupillary Distance", vr_type::DOUBLE),
-    ((0x0046u16, 0x0063u16), "Intermediate Pupillary Distance", vr_type::DOUBLE),
-    ((0x0046u16, 0x0064u16), "Other Pupillary Distance", vr_type::DOUBLE),
-    ((0x0046u16, 0x0070u16), "Keratometry Right Eye Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0071u16), "Keratometry Left Eye Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0074u16), "Steep Keratometric Axis Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0075u16), "Radius of Curvature", vr_type::DOUBLE),
-    ((0x0046u16, 0x0076u16), "Keratometric Power", vr_type::DOUBLE),
-    ((0x0046u16, 0x0077u16), "Keratometric Axis", vr_type::DOUBLE),
-    ((0x0046u16, 0x0080u16), "Flat Keratometric Axis Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0092u16), "Background Color", vr_type::CODE_STRING),
-    ((0x0046u16, 0x0094u16), "Optotype", vr_type::CODE_STRING),
-    ((0x0046u16, 0x0095u16), "Optotype Presentation", vr_type::CODE_STRING),
-    ((0x0046u16, 0x0097u16), "Subjective Refraction Right Eye Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0098u16), "Subjective Refraction Left Eye Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0100u16), "Add Near Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0101u16), "Add Intermediate Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0102u16), "Add Other Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0104u16), "Add Power", vr_type::DOUBLE),
-    ((0x0046u16, 0x0106u16), "Viewing Distance", vr_type::DOUBLE),
-    ((0x0046u16, 0x0121u16), "Visual Acuity Type Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0122u16), "Visual Acuity Right Eye Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0123u16), "Visual Acuity Left Eye Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0124u16), "Visual Acuity Both Eyes Open Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0125u16), "Viewing Distance Type", vr_type::CODE_STRING),
-    ((0x0046u16, 0x0135u16), "Visual Acuity Modifiers", vr_type::SIGNED_SHORT),
-    ((0x0046u16, 0x0137u16), "Decimal Visual Acuity", vr_type::DOUBLE),
-    ((0x0046u16, 0x0139u16), "Optotype Detailed Definition", vr_type::LONG_STRING),
-    ((0x0046u16, 0x0145u16), "Referenced Refractive Measurements Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0046u16, 0x0146u16), "Sphere Power", vr_type::DOUBLE),
-    ((0x0046u16, 0x0147u16), "Cylinder Power", vr_type::DOUBLE),
-    ((0x0066u16, 0x0001u16), "Number of Surfaces", vr_type::UNSIGNED_LONG),
-    ((0x0066u16, 0x0002u16), "Surface Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x0003u16), "Surface Number", vr_type::UNSIGNED_LONG),
-    ((0x0066u16, 0x0004u16), "Surface Comments", vr_type::LONG_TEXT),
-    ((0x0066u16, 0x0009u16), "Surface Processing", vr_type::CODE_STRING),
-    ((0x0066u16, 0x000Au16), "Surface Processing Ratio", vr_type::FLOAT),
-    ((0x0066u16, 0x000Bu16), "Surface Processing Description", vr_type::LONG_STRING),
-    ((0x0066u16, 0x000Cu16), "Recommended Presentation Opacity", vr_type::FLOAT),
-    ((0x0066u16, 0x000Du16), "Recommended Presentation Type", vr_type::CODE_STRING),
-    ((0x0066u16, 0x000Eu16), "Finite Volume", vr_type::CODE_STRING),
-    ((0x0066u16, 0x0010u16), "Manifold", vr_type::CODE_STRING),
-    ((0x0066u16, 0x0011u16), "Surface Points Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x0012u16), "Surface Points Normals Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x0013u16), "Surface Mesh Primitives Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x0015u16), "Number of Surface Points", vr_type::UNSIGNED_LONG),
-    ((0x0066u16, 0x0016u16), "Point Coordinates Data", vr_type::OTHER_FLOAT),
-    ((0x0066u16, 0x0017u16), "Point Position Accuracy", vr_type::FLOAT),
-    ((0x0066u16, 0x0018u16), "Mean Point Distance", vr_type::FLOAT),
-    ((0x0066u16, 0x0019u16), "Maximum Point Distance", vr_type::FLOAT),
-    ((0x0066u16, 0x001Au16), "Points Bounding Box Coordinates", vr_type::FLOAT),
-    ((0x0066u16, 0x001Bu16), "Axis of Rotation", vr_type::FLOAT),
-    ((0x0066u16, 0x001Cu16), "Center of Rotation", vr_type::FLOAT),
-    ((0x0066u16, 0x001Eu16), "Number of Vectors", vr_type::UNSIGNED_LONG),
-    ((0x0066u16, 0x001Fu16), "Vector Dimensionality", vr_type::UNSIGNED_SHORT),
-    ((0x0066u16, 0x0020u16), "Vector Accuracy", vr_type::FLOAT),
-    ((0x0066u16, 0x0021u16), "Vector Coordinate Data", vr_type::OTHER_FLOAT),
-    ((0x0066u16, 0x0023u16), "Triangle Point Index List", vr_type::OTHER_WORD),
-    ((0x0066u16, 0x0024u16), "Edge Point Index List", vr_type::OTHER_WORD),
-    ((0x0066u16, 0x0025u16), "Vertex Point Index List", vr_type::OTHER_WORD),
-    ((0x0066u16, 0x0026u16), "Triangle Strip Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x0027u16), "Triangle Fan Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x0028u16), "Line Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x0029u16), "Primitive Point Index List", vr_type::OTHER_WORD),
-    ((0x0066u16, 0x002Au16), "Surface Count", vr_type::UNSIGNED_LONG),
-    ((0x0066u16, 0x002Bu16), "Referenced Surface Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x002Cu16), "Referenced Surface Number", vr_type::UNSIGNED_LONG),
-    ((0x0066u16, 0x002Du16), "Segment Surface Generation Algorithm Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x002Eu16), "Segment Surface Source Instance Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x002Fu16), "Algorithm Family Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x0030u16), "Algorithm Name Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x0031u16), "Algorithm Version", vr_type::LONG_STRING),
-    ((0x0066u16, 0x0032u16), "Algorithm Parameters", vr_type::LONG_TEXT),
-    ((0x0066u16, 0x0034u16), "Facet Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x0035u16), "Surface Processing Algorithm Identification Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0066u16, 0x0036u16), "Algorithm Name", vr_type::LONG_STRING),
-    ((0x0008u16, 0x2133u16), "Event Timer Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2134u16), "Event Time Offset", vr_type::DOUBLE),
-    ((0x0008u16, 0x2135u16), "Event Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9801u16), "Depth(s) of Focus", vr_type::DOUBLE),
-    ((0x0018u16, 0x9803u16), "Excluded Intervals Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9804u16), "Exclusion Start DateTime", vr_type::DATE_TIME),
-    ((0x0018u16, 0x9805u16), "Exclusion Duration", vr_type::DOUBLE),
-    ((0x0018u16, 0x9806u16), "US Image Description Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9807u16), "Image Data Type Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9808u16), "Data Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x9809u16), "Transducer Scan Pattern Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x980Bu16), "Aliased Data Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x980Cu16), "Position Measuring Device Used", vr_type::CODE_STRING),
-    ((0x0018u16, 0x980Du16), "Transducer Geometry Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x980Eu16), "Transducer Beam Steering Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x980Fu16), "Transducer Application Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9301u16), "Image Position (Volume)", vr_type::DOUBLE),
-    ((0x0020u16, 0x9302u16), "Image Orientation (Volume)", vr_type::DOUBLE),
-    ((0x0020u16, 0x9307u16), "Ultrasound Acquisition Geometry", vr_type::CODE_STRING),
-    ((0x0020u16, 0x9308u16), "Apex Position", vr_type::DOUBLE),
-    ((0x0020u16, 0x9309u16), "Volume to Transducer Mapping Matrix", vr_type::DOUBLE),
-    ((0x0020u16, 0x930Au16), "Volume to Table Mapping Matrix", vr_type::DOUBLE),
-    ((0x0020u16, 0x930Cu16), "Patient Frame of Reference Source", vr_type::CODE_STRING),
-    ((0x0020u16, 0x930Du16), "Temporal Position Time Offset", vr_type::DOUBLE),
-    ((0x0020u16, 0x930Eu16), "Plane Position (Volume) Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x930Fu16), "Plane Orientation (Volume) Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9310u16), "Temporal Position Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0020u16, 0x9311u16), "Dimension Organization Type", vr_type::CODE_STRING),
-    ((0x0020u16, 0x9312u16), "Volume Frame of Reference Uid", vr_type::UID),
-    ((0x0020u16, 0x9313u16), "Table Frame of Reference Uid", vr_type::UID),
-    ((0x0028u16, 0x1104u16), "Alpha Palette Color Lookup Table Descriptor", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x1204u16), "Alpha Palette Color Lookup Table Data", vr_type::OTHER_WORD),
-    ((0x0028u16, 0x1401u16), "Data Frame Assignment Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x1402u16), "Data Path Assignment", vr_type::CODE_STRING),
-    ((0x0028u16, 0x1403u16), "Bits Mapped to Color Lookup Table", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x1404u16), "Blending LUT 1 Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x1405u16), "Blending LUT 1 Transfer Function", vr_type::CODE_STRING),
-    ((0x0028u16, 0x1406u16), "Blending Weight Constant", vr_type::DOUBLE),
-    ((0x0028u16, 0x1407u16), "Blending Lookup Table Descriptor", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x1408u16), "Blending Lookup Table Data", vr_type::OTHER_WORD),
-    ((0x0028u16, 0x140Bu16), "Enhanced Palette Color Lookup Table Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x140Cu16), "Blending LUT 2 Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x140Du16), "Blending LUT 2 Transfer Function", vr_type::CODE_STRING),
-    ((0x0028u16, 0x140Eu16), "Data Path ID", vr_type::CODE_STRING),
-    ((0x0028u16, 0x140Fu16), "RGB LUT Transfer Function", vr_type::CODE_STRING),
-    ((0x0028u16, 0x1410u16), "Alpha LUT Transfer Function", vr_type::CODE_STRING),
-    ((0x0040u16, 0x0261u16), "Performed Protocol Type", vr_type::CODE_STRING),
-    ((0x0008u16, 0x1161u16), "Simple Frame List", vr_type::UNSIGNED_LONG),
-    ((0x0008u16, 0x1162u16), "Calculated Frame List", vr_type::UNSIGNED_LONG),
-    ((0x0008u16, 0x1163u16), "Time Range", vr_type::DOUBLE),
-    ((0x0008u16, 0x1164u16), "Frame Extraction Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x1167u16), "Multi-Frame Source SOP Instance Uid", vr_type::UID),
-    ((0x0070u16, 0x0087u16), "Alternate Content Description Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x0006u16), "Language Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x9501u16), "Pixel Shift Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x9502u16), "Region Pixel Shift Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x9503u16), "Vertices of the Region", vr_type::SIGNED_SHORT),
-    ((0x0028u16, 0x9505u16), "Multi-Frame Presentation Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0028u16, 0x9506u16), "Pixel Shift Frame Range", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x9507u16), "LUT Frame Range", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x1005u16), "Generator ID", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1006u16), "Grid ID", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1007u16), "Cassette ID", vr_type::LONG_STRING),
-    ((0x0018u16, 0x1008u16), "Gantry ID", vr_type::LONG_STRING),
-    ((0x0040u16, 0xA494u16), "Archive Requested", vr_type::CODE_STRING),
-    (PIXEL_PADDING_RANGE_LIMIT, "Pixel Padding Range Limit", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0A02u16), "Pixel Spacing Calibration Type", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0A04u16), "Pixel Spacing Calibration Description", vr_type::LONG_STRING),
-    ((0x0038u16, 0x0060u16), "Service Episode ID", vr_type::LONG_STRING),
-    ((0x0038u16, 0x0062u16), "Service Episode Description", vr_type::LONG_STRING),
-    ((0x300Au16, 0x0420u16), "General Accessory Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0421u16), "General Accessory ID", vr_type::SHORT_STRING),
-    ((0x300Au16, 0x0422u16), "General Accessory Description", vr_type::SHORT_TEXT),
-    ((0x300Au16, 0x0423u16), "General Accessory Type", vr_type::CODE_STRING),
-    ((0x300Au16, 0x0424u16), "General Accessory Number", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x9601u16), "Diffusion b-matrix Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9602u16), "Diffusion b-value XX", vr_type::DOUBLE),
-    ((0x0018u16, 0x9603u16), "Diffusion b-value XY", vr_type::DOUBLE),
-    ((0x0018u16, 0x9604u16), "Diffusion b-value XZ", vr_type::DOUBLE),
-    ((0x0018u16, 0x9605u16), "Diffusion b-value YY", vr_type::DOUBLE),
-    ((0x0018u16, 0x9606u16), "Diffusion b-value YZ", vr_type::DOUBLE),
-    ((0x0018u16, 0x9607u16), "Diffusion b-value ZZ", vr_type::DOUBLE),
-    ((0x0012u16, 0x0071u16), "Clinical Trial Series ID", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0072u16), "Clinical Trial Series Description", vr_type::LONG_STRING),
-    ((0x0032u16, 0x1034u16), "Requesting Service Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x003Au16, 0x0230u16), "Waveform Data Display Scale", vr_type::FLOAT),
-    ((0x003Au16, 0x0231u16), "Waveform Display Background CIELab Value", vr_type::UNSIGNED_SHORT),
-    ((0x003Au16, 0x0240u16), "Waveform Presentation Group Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x003Au16, 0x0241u16), "Presentation Group Number", vr_type::UNSIGNED_SHORT),
-    ((0x003Au16, 0x0242u16), "Channel Display Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x003Au16, 0x0244u16), "Channel Recommended Display CIELab Value", vr_type::UNSIGNED_SHORT),
-    ((0x003Au16, 0x0245u16), "Channel Position", vr_type::FLOAT),
-    ((0x003Au16, 0x0246u16), "Display Shading Flag", vr_type::CODE_STRING),
-    ((0x003Au16, 0x0247u16), "Fractional Channel Display Scale", vr_type::FLOAT),
-    ((0x003Au16, 0x0248u16), "Absolute Channel Display Scale", vr_type::FLOAT),
-    ((0x0018u16, 0x9351u16), "Calcium Scoring Mass Factor Patient", vr_type::FLOAT),
-    ((0x0018u16, 0x9352u16), "Calcium Scoring Mass Factor Device", vr_type::FLOAT),
-    ((0x0018u16, 0x9346u16), "CTDI Phantom Type Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9360u16), "CT Additional X-Ray Source Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x300Au16, 0x0412u16), "Control Point Orientation", vr_type::FLOAT),
-    ((0x300Au16, 0x02EBu16), "Compensator Description", vr_type::LONG_TEXT),
-    ((0x3006u16, 0x00B6u16), "ROI Elemental Composition Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3006u16, 0x00B7u16), "ROI Elemental Composition Atomic Number", vr_type::UNSIGNED_SHORT),
-    ((0x3006u16, 0x00B8u16), "ROI Elemental Composition Atomic Mass Fraction", vr_type::FLOAT),
-    ((0x3002u16, 0x0050u16), "Primary Fluence Mode Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3002u16, 0x0051u16), "Fluence Mode", vr_type::CODE_STRING),
-    ((0x3002u16, 0x0052u16), "Fluence Mode ID", vr_type::SHORT_STRING),
-    ((0x0028u16, 0x9478u16), "Mask Visibility Percentage", vr_type::FLOAT),
-    ((0x0020u16, 0x0242u16), "SOP Instance Uid of Concatenation Source", vr_type::UID),
-    ((0x0018u16, 0x7056u16), "Filter Beam Path Length Minimum", vr_type::FLOAT),
-    ((0x0018u16, 0x7058u16), "Filter Beam Path Length Maximum", vr_type::FLOAT),
-    ((0x0008u16, 0x0117u16), "Context Uid", vr_type::UID),
-    ((0x0008u16, 0x103Fu16), "Series Description Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x1012u16), "Reason For Performed Procedure Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0010u16, 0x2210u16), "Anatomical Orientation Type", vr_type::CODE_STRING),
-    ((0x0040u16, 0xA496u16), "Preliminary Flag", vr_type::CODE_STRING),
-    ((0x0012u16, 0x0081u16), "Clinical Trial Protocol Ethics Committee Name", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0082u16), "Clinical Trial Protocol Ethics Committee Approval Number", vr_type::LONG_STRING),
-    ((0x0012u16, 0x0083u16), "Consent for Clinical Trial Use Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0012u16, 0x0084u16), "Distribution Type", vr_type::CODE_STRING),
-    ((0x0012u16, 0x0085u16), "Consent for Distribution Flag", vr_type::CODE_STRING),
-    ((0x0040u16, 0x4040u16), "Raw Data Handling", vr_type::CODE_STRING),
-    ((0x0040u16, 0xE011u16), "Retrieve Location Uid", vr_type::UID),
-    ((0x0018u16, 0x9353u16), "Energy Weighting Factor", vr_type::FLOAT),
-    ((0x0074u16, 0x1000u16), "Procedure Step State", vr_type::CODE_STRING),
-    ((0x0074u16, 0x1002u16), "Progress Information Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0074u16, 0x1004u16), "Procedure Step Progress", vr_type::DECIMAL_STRING),
-    ((0x0074u16, 0x1006u16), "Procedure Step Progress Description", vr_type::SHORT_TEXT),
-    ((0x0074u16, 0x1008u16), "Procedure Step Communications URI Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0074u16, 0x100Au16), "Contact URI", vr_type::SHORT_TEXT),
-    ((0x0074u16, 0x100Cu16), "Contact Display Name", vr_type::LONG_STRING),
-    ((0x0074u16, 0x100Eu16), "Procedure Step Discontinuation Reason Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0074u16, 0x1200u16), "Scheduled Procedure Step Priority", vr_type::CODE_STRING),
-    ((0x0074u16, 0x1202u16), "Worklist Label", vr_type::LONG_STRING),
-    ((0x0074u16, 0x1204u16), "Procedure Step Label", vr_type::LONG_STRING),
-    ((0x0074u16, 0x1210u16), "Scheduled Processing Parameters Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0074u16, 0x1212u16), "Performed Processing Parameters Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0074u16, 0x1216u16), "UPS Performed Procedure Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0074u16, 0x1224u16), "Replaced Procedure Step Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0074u16, 0x1230u16), "Deletion Lock", vr_type::CODE_STRING),
-    ((0x0074u16, 0x1234u16), "Receiving AE", vr_type::APPLICATION_ENTITY),
-    ((0x0074u16, 0x1236u16), "Requesting AE", vr_type::APPLICATION_ENTITY),
-    ((0x0074u16, 0x1238u16), "Reason For Cancellation", vr_type::LONG_TEXT),
-    ((0x0074u16, 0x1242u16), "SCP Status", vr_type::CODE_STRING),
-    ((0x0074u16, 0x1244u16), "Subscription List Status", vr_type::CODE_STRING),
-    ((0x0074u16, 0x1246u16), "UPS List Status", vr_type::CODE_STRING),
-    ((0x0040u16, 0x4041u16), "Input Readiness State", vr_type::CODE_STRING),
-    ((0x0040u16, 0x4050u16), "Performed Procedure Step Start DateTime", vr_type::DATE_TIME),
-    ((0x0040u16, 0x4051u16), "Performed Procedure Step End DateTime", vr_type::DATE_TIME),
-    ((0x0040u16, 0x4052u16), "Procedure Step Cancellation DateTime", vr_type::DATE_TIME),
-    ((0x0040u16, 0xE020u16), "Type of Instances", vr_type::CODE_STRING),
-    ((0x0040u16, 0xE021u16), "DICOM Retrieval Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xE022u16), "DICOM Media Retrieval Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xE023u16), "WADO Retrieval Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xE024u16), "XDS Retrieval Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xE031u16), "Repository Unique ID", vr_type::UID),
-    ((0x0040u16, 0xE032u16), "Home Community ID", vr_type::UID),
-    ((0x6000u16, 0x0010u16), "Overlay Rows", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x0011u16), "Overlay Columns", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x0012u16), "Overlay Planes", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x0015u16), "Number of Frames in Overlay", vr_type::INTEGER_STRING),
-    ((0x6000u16, 0x0022u16), "Overlay Description", vr_type::LONG_STRING),
-    ((0x6000u16, 0x0040u16), "Overlay Type", vr_type::CODE_STRING),
-    ((0x6000u16, 0x0045u16), "Overlay Subtype", vr_type::LONG_STRING),
-    ((0x6000u16, 0x0050u16), "Overlay Origin", vr_type::SIGNED_SHORT),
-    ((0x6000u16, 0x0051u16), "Image Frame Origin", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x0052u16), "Overlay Plane Origin", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x0100u16), "Overlay Bits Allocated", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x0102u16), "Overlay Bit Position", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x1301u16), "ROI Area", vr_type::INTEGER_STRING),
-    ((0x6000u16, 0x1302u16), "ROI Mean", vr_type::DECIMAL_STRING),
-    ((0x6000u16, 0x1303u16), "ROI Standard Deviation", vr_type::DECIMAL_STRING),
-    ((0x6000u16, 0x1500u16), "Overlay Label", vr_type::LONG_STRING),
-    ((0x6000u16, 0x3000u16), "Overlay Data", vr_type::OTHER_BYTE),
-    ((0x5000u16, 0x1001u16), "Curve Activation Layer", vr_type::CODE_STRING),
-    ((0x6000u16, 0x1001u16), "Overlay Activation Layer", vr_type::CODE_STRING),
-    ((0x0000u16, 0x0001u16), "Command Length to End", vr_type::UNSIGNED_LONG),
-    ((0x0000u16, 0x0010u16), "Command Recognition Code", vr_type::CODE_STRING),
-    ((0x0000u16, 0x0200u16), "Initiator", vr_type::APPLICATION_ENTITY),
-    ((0x0000u16, 0x0300u16), "Receiver", vr_type::APPLICATION_ENTITY),
-    ((0x0000u16, 0x0400u16), "FIND Location", vr_type::APPLICATION_ENTITY),
-    ((0x0000u16, 0x0850u16), "Number of Matches", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x0860u16), "Response Sequence Number", vr_type::UNSIGNED_SHORT),
-    ((0x0000u16, 0x4000u16), "DIALOG Receiver", vr_type::ATTRIBUTE),
-    ((0x0000u16, 0x4010u16), "Terminal Type", vr_type::ATTRIBUTE),
-    ((0x0000u16, 0x5010u16), "Message Set ID", vr_type::SHORT_STRING),
-    ((0x0000u16, 0x5020u16), "End Message ID", vr_type::SHORT_STRING),
-    ((0x0000u16, 0x5110u16), "Display Format", vr_type::ATTRIBUTE),
-    ((0x0000u16, 0x5120u16), "Page Position ID", vr_type::ATTRIBUTE),
-    ((0x0000u16, 0x5130u16), "Text Format ID", vr_type::CODE_STRING),
-    ((0x0000u16, 0x5140u16), "Normal/Reverse", vr_type::CODE_STRING),
-    ((0x0000u16, 0x5150u16), "Add Gray Scale", vr_type::CODE_STRING),
-    ((0x0000u16, 0x5160u16), "Borders", vr_type::CODE_STRING),
-    ((0x0000u16, 0x5170u16), "Copies", vr_type::INTEGER_STRING),
-    ((0x0000u16, 0x5180u16), "Magnification Type", vr_type::CODE_STRING),
-    ((0x0000u16, 0x5190u16), "Erase", vr_type::CODE_STRING),
-    ((0x0000u16, 0x51A0u16), "Print", vr_type::CODE_STRING),
-    ((0x0000u16, 0x51B0u16), "Overlays", vr_type::UNSIGNED_SHORT),
-    ((0x0008u16, 0x0000u16), "Group 0008 Length", vr_type::UNSIGNED_LONG),
-    ((0x0008u16, 0x0001u16), "Length to End", vr_type::UNSIGNED_LONG),
-    ((0x0008u16, 0x0010u16), "Recognition Code", vr_type::CODE_STRING),
-    ((0x0008u16, 0x0040u16), "Data Set Type", vr_type::UNSIGNED_SHORT),
-    ((0x0008u16, 0x0041u16), "Data Set Subtype", vr_type::LONG_STRING),
-    ((0x0008u16, 0x0042u16), "Nuclear Medicine Series Type", vr_type::CODE_STRING),
-    ((0x0008u16, 0x1000u16), "Network ID", vr_type::APPLICATION_ENTITY),
-    ((0x0008u16, 0x1145u16), "Referenced Curve Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2110u16), "Lossy Image Compression", vr_type::CODE_STRING),
-    ((0x0008u16, 0x2200u16), "Transducer Position", vr_type::CODE_STRING),
-    ((0x0008u16, 0x2204u16), "Transducer Orientation", vr_type::CODE_STRING),
-    ((0x0008u16, 0x2208u16), "Anatomic Structure", vr_type::CODE_STRING),
-    ((0x0008u16, 0x2240u16), "Transducer Position Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2242u16), "Transducer Position Modifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2244u16), "Transducer Orientation Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2246u16), "Transducer Orientation Modifier Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x4000u16), "Identifying Comments", vr_type::LONG_TEXT),
-    ((0x0010u16, 0x0000u16), "Group 0010 Length", vr_type::UNSIGNED_LONG),
-    ((0x0010u16, 0x1050u16), "Insurance Plan Identification", vr_type::LONG_STRING),
-    ((0x0018u16, 0x0000u16), "Group 0018 Length", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x0030u16), "Radionuclide", vr_type::LONG_STRING),
-    ((0x0018u16, 0x0032u16), "Energy Window Centerline", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0033u16), "Energy Window Total Width", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x0037u16), "Therapy Type", vr_type::CODE_STRING),
-    ((0x0018u16, 0x0039u16), "Therapy Description", vr_type::CODE_STRING),
-    ((0x0018u16, 0x1146u16), "Rotation Offset", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x1240u16), "Upper/Lower Pixel Values", vr_type::INTEGER_STRING),
-    ((0x0018u16, 0x4000u16), "Acquisition Comments", vr_type::LONG_TEXT),
-    ((0x0018u16, 0x5030u16), "Dynamic Range", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x5040u16), "Total Gain", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x5210u16), "Image Transformation Matrix", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x5212u16), "Image Translation Vector", vr_type::DECIMAL_STRING),
-    ((0x0018u16, 0x6038u16), "Doppler Sample Volume X Position", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x603Au16), "Doppler Sample Volume Y Position", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x603Cu16), "TM-Line Position X0", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x603Eu16), "TM-Line Position Y0", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x6040u16), "TM-Line Position X1", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x6042u16), "TM-Line Position Y1", vr_type::UNSIGNED_LONG),
-    ((0x0020u16, 0x0000u16), "Group 0020 Length", vr_type::UNSIGNED_LONG),
-    ((0x0020u16, 0x0014u16), "Isotope Number", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0015u16), "Phase Number", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0016u16), "Interval Number", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0017u16), "Time Slot Number", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0018u16), "Angle Number", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x0030u16), "Image Position", vr_type::DECIMAL_STRING),
-    ((0x0020u16, 0x0035u16), "Image Orientation", vr_type::DECIMAL_STRING),
-    ((0x0020u16, 0x0050u16), "Location", vr_type::DECIMAL_STRING),
-    ((0x0020u16, 0x0070u16), "Image Geometry Type", vr_type::LONG_STRING),
-    ((0x0020u16, 0x0080u16), "Masking Image", vr_type::CODE_STRING),
-    ((0x0020u16, 0x1001u16), "Acquisitions in Series", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x1004u16), "Acquisitions in Study", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x1005u16), "Images in Study", vr_type::INTEGER_STRING),
-    ((0x0020u16, 0x1020u16), "Reference", vr_type::CODE_STRING),
-    ((0x0020u16, 0x3100u16), "Source Image IDs", vr_type::CODE_STRING),
-    ((0x0020u16, 0x3401u16), "Modifying Device ID", vr_type::CODE_STRING),
-    ((0x0020u16, 0x3402u16), "Modified Image ID", vr_type::CODE_STRING),
-    ((0x0020u16, 0x3403u16), "Modified Image Date", vr_type::DATE),
-    ((0x0020u16, 0x3404u16), "Modifying Device Manufacturer", vr_type::LONG_STRING),
-    ((0x0020u16, 0x3405u16), "Modified Image Time", vr_type::TIME),
-    ((0x0020u16, 0x3406u16), "Modified Image Description", vr_type::LONG_STRING),
-    ((0x0020u16, 0x5000u16), "Original Image Identification", vr_type::ATTRIBUTE),
-    ((0x0020u16, 0x5002u16), "Original Image Identification Nomenclature", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0000u16), "Group 0028 Length", vr_type::UNSIGNED_LONG),
-    ((0x0028u16, 0x0005u16), "Image Dimensions", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0040u16), "Image Format", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0050u16), "Manipulated Image", vr_type::LONG_STRING),
-    ((0x0028u16, 0x005Fu16), "Compression Recognition Code", vr_type::LONG_STRING),
-    ((0x0028u16, 0x0060u16), "Compression Code", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0061u16), "Compression Originator", vr_type::SHORT_STRING),
-    ((0x0028u16, 0x0062u16), "Compression Label", vr_type::LONG_STRING),
-    ((0x0028u16, 0x0063u16), "Compression Description", vr_type::SHORT_STRING),
-    ((0x0028u16, 0x0065u16), "Compression Sequence", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0066u16), "Compression Step Pointers", vr_type::ATTRIBUTE),
-    ((0x0028u16, 0x0068u16), "Repeat Interval", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0069u16), "Bits Grouped", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0070u16), "Perimeter Table", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0071u16), "Perimeter Value", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0080u16), "Predictor Rows", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0081u16), "Predictor Columns", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0082u16), "Predictor Constants", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0090u16), "Blocked Pixels", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0091u16), "Block Rows", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0092u16), "Block Columns", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0093u16), "Row Overlap", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0094u16), "Column Overlap", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0104u16), "Smallest Valid Pixel Value", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0105u16), "Largest Valid Pixel Value", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0200u16), "Image Location", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0400u16), "Transform Label", vr_type::LONG_STRING),
-    ((0x0028u16, 0x0401u16), "Transform Version Number", vr_type::LONG_STRING),
-    ((0x0028u16, 0x0403u16), "Sequence of Compressed Data", vr_type::LONG_STRING),
-    ((0x0028u16, 0x0410u16), "Rows For Nth Order Coefficients", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0411u16), "Columns For Nth Order Coefficients", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0412u16), "Coefficient Coding", vr_type::LONG_STRING),
-    ((0x0028u16, 0x0413u16), "Coefficient Coding Pointers", vr_type::ATTRIBUTE),
-    ((0x0028u16, 0x0700u16), "DCT Label", vr_type::LONG_STRING),
-    ((0x0028u16, 0x0701u16), "Data Block Description", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0702u16), "Data Block", vr_type::ATTRIBUTE),
-    ((0x0028u16, 0x0710u16), "Normalization Factor Format", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0720u16), "Zonal Map Number Format", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0721u16), "Zonal Map Location", vr_type::ATTRIBUTE),
-    ((0x0028u16, 0x0722u16), "Zonal Map Format", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0730u16), "Adaptive Map Format", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0740u16), "Code Number Format", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0800u16), "Code Label", vr_type::CODE_STRING),
-    ((0x0028u16, 0x0802u16), "Number of Tables", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0803u16), "Code Table Location", vr_type::ATTRIBUTE),
-    ((0x0028u16, 0x0804u16), "Bits For Code Word", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0808u16), "Image Data Location", vr_type::ATTRIBUTE),
-    ((0x0028u16, 0x1080u16), "Gray Scale", vr_type::CODE_STRING),
-    ((0x0028u16, 0x1100u16), "Gray Lookup Table Descriptor", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x1200u16), "Gray Lookup Table Data", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x4000u16), "Image Presentation Comments", vr_type::LONG_TEXT),
-    ((0x0028u16, 0x6030u16), "Mask Pointer(s)", vr_type::UNSIGNED_SHORT),
-    ((0x0032u16, 0x0000u16), "Group 0032 Length", vr_type::UNSIGNED_LONG),
-    ((0x0032u16, 0x000Au16), "Study Status ID", vr_type::CODE_STRING),
-    ((0x0032u16, 0x000Cu16), "Study Priority ID", vr_type::CODE_STRING),
-    ((0x0032u16, 0x0012u16), "Study ID Issuer", vr_type::LONG_STRING),
-    ((0x0032u16, 0x0032u16), "Study Verified Date", vr_type::DATE),
-    ((0x0032u16, 0x0033u16), "Study Verified Time", vr_type::TIME),
-    ((0x0032u16, 0x0034u16), "Study Read Date", vr_type::DATE),
-    ((0x0032u16, 0x0035u16), "Study Read Time", vr_type::TIME),
-    ((0x0032u16, 0x1000u16), "Scheduled Study Start Date", vr_type::DATE),
-    ((0x0032u16, 0x1001u16), "Scheduled Study Start Time", vr_type::TIME),
-    ((0x0032u16, 0x1010u16), "Scheduled Study Stop Date", vr_type::DATE),
-    ((0x0032u16, 0x1011u16), "Scheduled Study Stop Time", vr_type::TIME),
-    ((0x0032u16, 0x1020u16), "Scheduled Study Location", vr_type::LONG_STRING),
-    ((0x0032u16, 0x1021u16), "Scheduled Study Location AE Title", vr_type::APPLICATION_ENTITY),
-    ((0x0032u16, 0x1030u16), "Reason for Study", vr_type::LONG_STRING),
-    ((0x0032u16, 0x1040u16), "Study Arrival Date", vr_type::DATE),
-    ((0x0032u16, 0x1041u16), "Study Arrival Time", vr_type::TIME),
-    ((0x0032u16, 0x1050u16), "Study Completion Date", vr_type::DATE),
-    ((0x0032u16, 0x1051u16), "Study Completion Time", vr_type::TIME),
-    ((0x0032u16, 0x1055u16), "Study Component Status ID", vr_type::CODE_STRING),
-    ((0x0038u16, 0x0000u16), "Group 0038 Length", vr_type::UNSIGNED_LONG),
-    ((0x0038u16, 0x0011u16), "Issuer of Admission ID", vr_type::LONG_STRING),
-    ((0x0038u16, 0x001Au16), "Scheduled Admission Date", vr_type::DATE),
-    ((0x0038u16, 0x001Bu16), "Scheduled Admission Time", vr_type::TIME),
-    ((0x0038u16, 0x001Cu16), "Scheduled Discharge Date", vr_type::DATE),
-    ((0x0038u16, 0x001Du16), "Scheduled Discharge Time", vr_type::TIME),
-    ((0x0038u16, 0x001Eu16), "Scheduled Patient Institution Residence", vr_type::LONG_STRING),
-    ((0x0038u16, 0x0030u16), "Discharge Date", vr_type::DATE),
-    ((0x0038u16, 0x0032u16), "Discharge Time", vr_type::TIME),
-    ((0x0038u16, 0x0040u16), "Discharge Diagnosis Description", vr_type::LONG_STRING),
-    ((0x0038u16, 0x0044u16), "Discharge Diagnosis Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0038u16, 0x0061u16), "Issuer of Service Episode ID", vr_type::LONG_STRING),
-    ((0x0040u16, 0x0000u16), "Group 0040 Length", vr_type::UNSIGNED_LONG),
-    ((0x0040u16, 0x0307u16), "Distance Source to Support", vr_type::DECIMAL_STRING),
-    ((0x0040u16, 0x0330u16), "Referenced Procedure Step Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x050Au16), "Specimen Accession Number", vr_type::LONG_STRING),
-    ((0x0040u16, 0x0550u16), "Specimen Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x06FAu16), "Slide Identifier", vr_type::LONG_STRING),
-    ((0x0040u16, 0x1006u16), "Placer Order Number/Procedure", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x1007u16), "Filler Order Number/Procedure", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x2001u16), "Reason for the Imaging Service Request", vr_type::LONG_STRING),
-    ((0x0040u16, 0x2006u16), "Placer Order Number/Imaging Service Request", vr_type::SHORT_STRING),
-    ((0x0040u16, 0x2007u16), "Filler Order Number/Imaging Service Request", vr_type::SHORT_STRING),
-    ((0x0040u16, 0xA090u16), "Equivalent CDA Document Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0050u16, 0x0000u16), "Group 0050 Length", vr_type::UNSIGNED_LONG),
-    ((0x0054u16, 0x0000u16), "Group 0054 Length", vr_type::UNSIGNED_LONG),
-    ((0x0060u16, 0x0000u16), "Group 0060 Length", vr_type::UNSIGNED_LONG),
-    ((0x0070u16, 0x0000u16), "Group 0070 Length", vr_type::UNSIGNED_LONG),
-    ((0x0088u16, 0x0000u16), "Group 0088 Length", vr_type::UNSIGNED_LONG),
-    ((0x2000u16, 0x0000u16), "Group 2000 Length", vr_type::UNSIGNED_LONG),
-    ((0x2010u16, 0x0000u16), "Group 2010 Length", vr_type::UNSIGNED_LONG),
-    ((0x2020u16, 0x0000u16), "Group 2020 Length", vr_type::UNSIGNED_LONG),
-    ((0x2020u16, 0x0130u16), "Referenced Image Overlay Box Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2020u16, 0x0140u16), "Referenced VOI LUT Box Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2030u16, 0x0000u16), "Group 2030 Length", vr_type::UNSIGNED_LONG),
-    ((0x2040u16, 0x0000u16), "Group 2040 Length", vr_type::UNSIGNED_LONG),
-    ((0x2040u16, 0x0010u16), "Referenced Overlay Plane Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2040u16, 0x0011u16), "Referenced Overlay Plane Groups", vr_type::UNSIGNED_SHORT),
-    ((0x2040u16, 0x0020u16), "Overlay Pixel Data Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2040u16, 0x0060u16), "Overlay Magnification Type", vr_type::CODE_STRING),
-    ((0x2040u16, 0x0070u16), "Overlay Smoothing Type", vr_type::CODE_STRING),
-    ((0x2040u16, 0x0072u16), "Overlay or Image Magnification", vr_type::CODE_STRING),
-    ((0x2040u16, 0x0074u16), "Magnify to Number of Columns", vr_type::UNSIGNED_SHORT),
-    ((0x2040u16, 0x0080u16), "Overlay Foreground Density", vr_type::CODE_STRING),
-    ((0x2040u16, 0x0082u16), "Overlay Background Density", vr_type::CODE_STRING),
-    ((0x2040u16, 0x0090u16), "Overlay Mode", vr_type::CODE_STRING),
-    ((0x2040u16, 0x0100u16), "Threshold Density", vr_type::CODE_STRING),
-    ((0x2040u16, 0x0500u16), "Referenced Image Box Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2050u16, 0x0000u16), "Group 2050 Length", vr_type::UNSIGNED_LONG),
-    ((0x2100u16, 0x0000u16), "Group 2100 Length", vr_type::UNSIGNED_LONG),
-    ((0x2110u16, 0x0000u16), "Group 2110 Length", vr_type::UNSIGNED_LONG),
-    ((0x2120u16, 0x0000u16), "Group 2120 Length", vr_type::UNSIGNED_LONG),
-    ((0x2120u16, 0x0010u16), "Queue Status", vr_type::CODE_STRING),
-    ((0x2120u16, 0x0050u16), "Print Job Description Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2120u16, 0x0070u16), "Referenced Print Job Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2130u16, 0x0000u16), "Group 2130 Length", vr_type::UNSIGNED_LONG),
-    ((0x2130u16, 0x0010u16), "Print Management Capabilities Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2130u16, 0x0015u16), "Printer Characteristics Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2130u16, 0x0030u16), "Film Box Content Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2130u16, 0x0040u16), "Image Box Content Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2130u16, 0x0050u16), "Annotation Content Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2130u16, 0x0060u16), "Image Overlay Box Content Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2130u16, 0x0080u16), "Presentation LUT Content Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2130u16, 0x00A0u16), "Proposed Study Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x2130u16, 0x00C0u16), "Original Image Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x3002u16, 0x0000u16), "Group 3002 Length", vr_type::UNSIGNED_LONG),
-    ((0x3004u16, 0x0000u16), "Group 3004 Length", vr_type::UNSIGNED_LONG),
-    ((0x3006u16, 0x0000u16), "Group 3006 Length", vr_type::UNSIGNED_LONG),
-    ((0x3008u16, 0x0000u16), "Group 3008 Length", vr_type::UNSIGNED_LONG),
-    ((0x300Au16, 0x0000u16), "Group 300A Length", vr_type::UNSIGNED_LONG),
-    ((0x300Cu16, 0x0000u16), "Group 300C Length", vr_type::UNSIGNED_LONG),
-    ((0x300Eu16, 0x0000u16), "Group 300E Length", vr_type::UNSIGNED_LONG),
-    ((0x4000u16, 0x0000u16), "Group 4000 Length", vr_type::UNSIGNED_LONG),
-    ((0x4000u16, 0x0010u16), "Arbitrary", vr_type::LONG_TEXT),
-    ((0x4000u16, 0x4000u16), "Text Comments", vr_type::LONG_TEXT),
-    ((0x4008u16, 0x0000u16), "Group 4008 Length", vr_type::UNSIGNED_LONG),
-    ((0x4008u16, 0x0040u16), "Results ID", vr_type::SHORT_STRING),
-    ((0x4008u16, 0x0042u16), "Results ID Issuer", vr_type::LONG_STRING),
-    ((0x4008u16, 0x0050u16), "Referenced Interpretation Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x4008u16, 0x0100u16), "Interpretation Recorded Date", vr_type::DATE),
-    ((0x4008u16, 0x0101u16), "Interpretation Recorded Time", vr_type::TIME),
-    ((0x4008u16, 0x0102u16), "Interpretation Recorder", vr_type::PERSON_NAME),
-    ((0x4008u16, 0x0103u16), "Reference to Recorded Sound", vr_type::LONG_STRING),
-    ((0x4008u16, 0x0108u16), "Interpretation Transcription Date", vr_type::DATE),
-    ((0x4008u16, 0x0109u16), "Interpretation Transcription Time", vr_type::TIME),
-    ((0x4008u16, 0x010Au16), "Interpretation Transcriber", vr_type::PERSON_NAME),
-    ((0x4008u16, 0x010Bu16), "Interpretation Text", vr_type::SHORT_TEXT),
-    ((0x4008u16, 0x010Cu16), "Interpretation Author", vr_type::PERSON_NAME),
-    ((0x4008u16, 0x0111u16), "Interpretation Approver Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x4008u16, 0x0112u16), "Interpretation Approval Date", vr_type::DATE),
-    ((0x4008u16, 0x0113u16), "Interpretation Approval Time", vr_type::TIME),
-    ((0x4008u16, 0x0114u16), "Physician Approving Interpretation", vr_type::PERSON_NAME),
-    ((0x4008u16, 0x0115u16), "Interpretation Diagnosis Description", vr_type::LONG_TEXT),
-    ((0x4008u16, 0x0117u16), "Interpretation Diagnosis Code Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x4008u16, 0x0118u16), "Results Distribution List Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x4008u16, 0x0119u16), "Distribution Name", vr_type::PERSON_NAME),
-    ((0x4008u16, 0x011Au16), "Distribution Address", vr_type::LONG_STRING),
-    ((0x4008u16, 0x0200u16), "Interpretation ID", vr_type::SHORT_STRING),
-    ((0x4008u16, 0x0202u16), "Interpretation ID Issuer", vr_type::LONG_STRING),
-    ((0x4008u16, 0x0210u16), "Interpretation Type ID", vr_type::CODE_STRING),
-    ((0x4008u16, 0x0212u16), "Interpretation Status ID", vr_type::CODE_STRING),
-    ((0x4008u16, 0x0300u16), "Impressions", vr_type::SHORT_TEXT),
-    ((0x4008u16, 0x4000u16), "Results Comments", vr_type::SHORT_TEXT),
-    ((0x5000u16, 0x0000u16), "Group 50xx Length", vr_type::UNSIGNED_LONG),
-    ((0x5000u16, 0x0005u16), "Curve Dimensions", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x0010u16), "Number of Points", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x0020u16), "Type of Data", vr_type::CODE_STRING),
-    ((0x5000u16, 0x0022u16), "Curve Description", vr_type::LONG_STRING),
-    ((0x5000u16, 0x0030u16), "Axis Units", vr_type::SHORT_STRING),
-    ((0x5000u16, 0x0040u16), "Axis Labels", vr_type::SHORT_STRING),
-    ((0x5000u16, 0x0103u16), "Data Value Representation", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x0104u16), "Minimum Coordinate Value", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x0105u16), "Maximum Coordinate Value", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x0106u16), "Curve Range", vr_type::SHORT_STRING),
-    ((0x5000u16, 0x0110u16), "Curve Data Descriptor", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x0112u16), "Coordinate Start Value", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x0114u16), "Coordinate Step Value", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x2000u16), "Audio Type", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x2002u16), "Audio Sample Format", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x2004u16), "Number of Channels", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x2006u16), "Number of Samples", vr_type::UNSIGNED_LONG),
-    ((0x5000u16, 0x2008u16), "Sample Rate", vr_type::UNSIGNED_LONG),
-    ((0x5000u16, 0x200Au16), "Total Time", vr_type::UNSIGNED_LONG),
-    ((0x5000u16, 0x200Cu16), "Audio Sample Data", vr_type::OTHER_BYTE),
-    ((0x5000u16, 0x200Eu16), "Audio Comments", vr_type::LONG_TEXT),
-    ((0x5000u16, 0x2500u16), "Curve Label", vr_type::LONG_STRING),
-    ((0x5000u16, 0x2600u16), "Curve Referenced Overlay Sequence", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x5000u16, 0x2610u16), "Curve Referenced Overlay Group", vr_type::UNSIGNED_SHORT),
-    ((0x5000u16, 0x3000u16), "Curve Data", vr_type::OTHER_BYTE),
-    ((0x6000u16, 0x0000u16), "Group 60xx Length", vr_type::UNSIGNED_LONG),
-    ((0x6000u16, 0x0060u16), "Overlay Compression Code", vr_type::CODE_STRING),
-    ((0x6000u16, 0x0110u16), "Overlay Format", vr_type::CODE_STRING),
-    ((0x6000u16, 0x0200u16), "Overlay Location", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x1100u16), "Overlay Descriptor - Gray", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x1101u16), "Overlay Descriptor - Red", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x1102u16), "Overlay Descriptor - Green", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x1103u16), "Overlay Descriptor - Blue", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x1200u16), "Overlays - Gray", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x1201u16), "Overlays - Red", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x1202u16), "Overlays - Green", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x1203u16), "Overlays - Blue", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x4000u16), "Overlay Comments", vr_type::LONG_TEXT),
-    ((0x7FE0u16, 0x0000u16), "Group 7FE0 Length", vr_type::UNSIGNED_LONG),
-    ((0x0008u16, 0x000Au16), "Sequence Item Number", vr_type::UNSIGNED_SHORT),
-    ((0x0018u16, 0x1810u16), "Acquisition Time Stamp", vr_type::DATE_TIME),
-    ((0x003Au16, 0x0000u16), "Group 003A Length", vr_type::UNSIGNED_LONG),
-    ((0x0040u16, 0xA0A0u16), "Type of Reference", vr_type::CODE_STRING),
-    ((0x0040u16, 0xA290u16), "Coordinate Geometric Type", vr_type::LONG_STRING),
-    ((0x0040u16, 0xA29Au16), "Pixel Coordinate Set", vr_type::UNSIGNED_SHORT),
-    ((0x5400u16, 0x0000u16), "Group 5400 Length", vr_type::UNSIGNED_LONG),
-    ((0x0040u16, 0xDB06u16), "Template Version", vr_type::DATE_TIME),
-    ((0x0040u16, 0xDB07u16), "Template Local Version", vr_type::DATE_TIME),
-    ((0x0040u16, 0xDB0Bu16), "Template Extension Flag", vr_type::CODE_STRING),
-    ((0x0040u16, 0xDB0Cu16), "Template Extension Organization Uid", vr_type::UID),
-    ((0x0040u16, 0xDB0Du16), "Template Extension Creator Uid", vr_type::UID),
-    ((0x0100u16, 0x0000u16), "Group 0100 Length", vr_type::UNSIGNED_LONG),
-    ((0x0400u16, 0x0000u16), "Group 0400 Length", vr_type::UNSIGNED_LONG),
-    ((0x4FFEu16, 0x0000u16), "Group 4FFE Length", vr_type::UNSIGNED_LONG),
-    ((0x0012u16, 0x0000u16), "Group 0012 Length", vr_type::UNSIGNED_LONG),
-    ((0x0018u16, 0x9195u16), "Chemical Shift Minimum Integration Limit in Hz", vr_type::DOUBLE),
-    ((0x0018u16, 0x9196u16), "Chemical Shift Maximum Integration Limit in Hz", vr_type::DOUBLE),
-    ((0x0028u16, 0x9099u16), "Largest Monochrome Pixel Value", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x0404u16), "Details of Coefficients", vr_type::ATTRIBUTE),
-    ((0x0028u16, 0x0402u16), "Number of Transform Steps", vr_type::UNSIGNED_SHORT),
-    ((0x1000u16, 0x0000u16), "Group 1000 Length", vr_type::UNSIGNED_LONG),
-    ((0x1000u16, 0x1110u16), "Escape Triplet", vr_type::UNSIGNED_SHORT),
-    ((0x1000u16, 0x1111u16), "Run Length Triplet", vr_type::UNSIGNED_SHORT),
-    ((0x1000u16, 0x1112u16), "Huffman Table Size", vr_type::UNSIGNED_SHORT),
-    ((0x1000u16, 0x1113u16), "Huffman Table Triplet", vr_type::UNSIGNED_SHORT),
-    ((0x1000u16, 0x1114u16), "Shift Table Size", vr_type::UNSIGNED_SHORT),
-    ((0x1000u16, 0x1115u16), "Shift Table Triplet", vr_type::UNSIGNED_SHORT),
-    ((0x1010u16, 0x0000u16), "Zonal Map", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x0061u16), "Overlay Compression Originator", vr_type::SHORT_STRING),
-    ((0x6000u16, 0x0062u16), "Overlay Compression Label", vr_type::SHORT_STRING),
-    ((0x6000u16, 0x0063u16), "Overlay Compression Description", vr_type::CODE_STRING),
-    ((0x6000u16, 0x0066u16), "Overlay Compression Step Pointers", vr_type::ATTRIBUTE),
-    ((0x6000u16, 0x0068u16), "Overlay Repeat Interval", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x0069u16), "Overlay Bits Grouped", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x0800u16), "Overlay Code Label", vr_type::CODE_STRING),
-    ((0x6000u16, 0x0802u16), "Overlay Number of Tables", vr_type::UNSIGNED_SHORT),
-    ((0x6000u16, 0x0803u16), "Overlay Code Table Location", vr_type::ATTRIBUTE),
-    ((0x6000u16, 0x0804u16), "Overlay Bits For Code Word", vr_type::UNSIGNED_SHORT),
-    ((0x7FE0u16, 0x0020u16), "Coefficients SDVN", vr_type::OTHER_WORD),
-    ((0x7FE0u16, 0x0030u16), "Coefficients SDHN", vr_type::OTHER_WORD),
-    ((0x7FE0u16, 0x0040u16), "Coefficients SDDN", vr_type::OTHER_WORD),
-    ((0x7F00u16, 0x0010u16), "Variable Pixel Data", vr_type::OTHER_BYTE),
-    ((0x7F00u16, 0x0011u16), "Variable Next Data Group", vr_type::UNSIGNED_SHORT),
-    ((0x7F00u16, 0x0020u16), "Variable Coefficients SDVN", vr_type::OTHER_WORD),
-    ((0x7F00u16, 0x0030u16), "Variable Coefficients SDHN", vr_type::OTHER_WORD),
-    ((0x7F00u16, 0x0040u16), "Variable Coefficients SDDN", vr_type::OTHER_WORD),
-    ((0x0008u16, 0x2251u16), "Anatomic Structure Space Or Region Code Sequence (Trial)", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2253u16), "Anatomic Portal Of Entrance Code Sequence (Trial)", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2255u16), "Anatomic Approach Direction Code Sequence (Trial)", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2256u16), "Anatomic Perspective Description (Trial)", vr_type::SHORT_TEXT),
-    ((0x0008u16, 0x2257u16), "Anatomic Perspective Code Sequence (Trial)", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x2258u16), "Anatomic Location Of Examining Instrument Description (Trial)", vr_type::SHORT_TEXT),
-    ((0x0008u16, 0x2259u16), "Anatomic Location Of Examining Instrument Code Sequence (Trial)", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x225Au16), "Anatomic Structure Space Or Region Modifier Code Sequence (Trial)", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0008u16, 0x225Cu16), "OnAxis Background Anatomic Structure Code Sequence (Trial)", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0018u16, 0x9096u16), "Parallel Reduction Factor In-plane (Retired)", vr_type::DOUBLE),
-    ((0x0020u16, 0x1003u16), "Images in Series", vr_type::INTEGER_STRING),
-    ((0x0028u16, 0x1111u16), "Large Red Palette Color Lookup Table Descriptor", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x1112u16), "Large Green Palette Color Lookup Table Descriptor", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x1113u16), "Large Blue Palette Color Lookup Table Descriptor", vr_type::UNSIGNED_SHORT),
-    ((0x0028u16, 0x1211u16), "Large Red Palette Color Lookup Table Data", vr_type::OTHER_WORD),
-    ((0x0028u16, 0x1212u16), "Large Green Palette Color Lookup Table Data", vr_type::OTHER_WORD),
-    ((0x0028u16, 0x1213u16), "Large Blue Palette Color Lookup Table Data", vr_type::OTHER_WORD),
-    ((0x0028u16, 0x1214u16), "Large Palette Color Lookup Table Uid", vr_type::UID),
-    ((0x0040u16, 0x0552u16), "Specimen Description Sequence - Trial", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0x0553u16), "Specimen Description - Trial", vr_type::SHORT_TEXT),
-    ((0x0040u16, 0x09F8u16), "Vital Stain Code Sequence - Trial", vr_type::SEQUENCE_OF_ITEMS),
-    ((0x0040u16, 0xA353u16), "Address - Trial", vr_type::SHORT_TEXT),
-    ((0x0040u16, 0xA354u16), "Telephone Number - Trial", vr_type::LONG_STRING),
-    ((0x0070u16, 0x0040u16), "Image Rotation (Retired)", vr_type::INTEGER_STRING),
-    ((0x0070u16, 0x0050u16), "Displayed Area Top Left Hand Corner (Trial)", vr_type::UNSIGNED_SHORT),
-    ((0x0070u16, 0x0051u16), "Displayed Area Bottom Right Hand Corner (Trial)", vr_type::UNSIGNED_SHORT),
-    ((0x0074u16, 0x0000u16), "Group 0074 Length", vr_type::UNSIGNED_LONG),
-    ((0x0004u16, 0x0000u16), "Group 0004 Length", vr_type::UNSIGNED_LONG)
+// Sorted by tag, lookups are binary searches.
+pub static TAGS: [((u16,u16), &str, &str, u16); 3017] = [
+    ((0x0000u16, 0x0000u16), "Group 0000 Length", "CommandGroupLength", vr_type::UNSIGNED_LONG),
+    ((0x0000u16, 0x0001u16), "Command Length to End", "CommandLengthToEnd", vr_type::UNSIGNED_LONG),
+    ((0x0000u16, 0x0002u16), "Affected SOP Class Uid", "AffectedSOPClassUID", vr_type::UID),
+    ((0x0000u16, 0x0003u16), "Requested SOP Class Uid", "RequestedSOPClassUID", vr_type::UID),
+    ((0x0000u16, 0x0010u16), "Command Recognition Code", "CommandRecognitionCode", vr_type::CODE_STRING),
+    ((0x0000u16, 0x0100u16), "Command Field", "CommandField", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x0110u16), "Message ID", "MessageID", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x0120u16), "Message ID Being Responded To", "MessageIDBeingRespondedTo", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x0200u16), "Initiator", "Initiator", vr_type::APPLICATION_ENTITY),
+    ((0x0000u16, 0x0300u16), "Receiver", "Receiver", vr_type::APPLICATION_ENTITY),
+    ((0x0000u16, 0x0400u16), "FIND Location", "FINDLocation", vr_type::APPLICATION_ENTITY),
+    ((0x0000u16, 0x0600u16), "Move Destination", "MoveDestination", vr_type::APPLICATION_ENTITY),
+    ((0x0000u16, 0x0700u16), "Priority", "Priority", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x0800u16), "Data Set Type", "CommandDataSetType", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x0850u16), "Number of Matches", "NumberOfMatches", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x0860u16), "Response Sequence Number", "ResponseSequenceNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x0900u16), "Status", "Status", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x0901u16), "Offending Element", "OffendingElement", vr_type::ATTRIBUTE),
+    ((0x0000u16, 0x0902u16), "Error Comment", "ErrorComment", vr_type::LONG_STRING),
+    ((0x0000u16, 0x0903u16), "Error ID", "ErrorID", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x1000u16), "Affected SOP Instance Uid", "AffectedSOPInstanceUID", vr_type::UID),
+    ((0x0000u16, 0x1001u16), "Requested SOP Instance Uid", "RequestedSOPInstanceUID", vr_type::UID),
+    ((0x0000u16, 0x1002u16), "Event Type ID", "EventTypeID", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x1005u16), "Attribute Identifier List", "AttributeIdentifierList", vr_type::ATTRIBUTE),
+    ((0x0000u16, 0x1008u16), "Action Type ID", "ActionTypeID", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x1020u16), "Number of Remaining Suboperations", "NumberOfRemainingSuboperations", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x1021u16), "Number of Completed Suboperations", "NumberOfCompletedSuboperations", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x1022u16), "Number of Failed Suboperations", "NumberOfFailedSuboperations", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x1023u16), "Number of Warning Suboperations", "NumberOfWarningSuboperations", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x1030u16), "Move Originator Application Entity Title", "MoveOriginatorApplicationEntityTitle", vr_type::APPLICATION_ENTITY),
+    ((0x0000u16, 0x1031u16), "Move Originator Message ID", "MoveOriginatorMessageID", vr_type::UNSIGNED_SHORT),
+    ((0x0000u16, 0x4000u16), "DIALOG Receiver", "DIALOGReceiver", vr_type::ATTRIBUTE),
+    ((0x0000u16, 0x4010u16), "Terminal Type", "TerminalType", vr_type::ATTRIBUTE),
+    ((0x0000u16, 0x5010u16), "Message Set ID", "MessageSetID", vr_type::SHORT_STRING),
+    ((0x0000u16, 0x5020u16), "End Message ID", "EndMessageID", vr_type::SHORT_STRING),
+    ((0x0000u16, 0x5110u16), "Display Format", "DisplayFormat", vr_type::ATTRIBUTE),
+    ((0x0000u16, 0x5120u16), "Page Position ID", "PagePositionID", vr_type::ATTRIBUTE),
+    ((0x0000u16, 0x5130u16), "Text Format ID", "TextFormatID", vr_type::CODE_STRING),
+    ((0x0000u16, 0x5140u16), "Normal/Reverse", "NormalReverse", vr_type::CODE_STRING),
+    ((0x0000u16, 0x5150u16), "Add Gray Scale", "AddGrayScale", vr_type::CODE_STRING),
+    ((0x0000u16, 0x5160u16), "Borders", "Borders", vr_type::CODE_STRING),
+    ((0x0000u16, 0x5170u16), "Copies", "Copies", vr_type::INTEGER_STRING),
+    ((0x0000u16, 0x5180u16), "Magnification Type", "CommandMagnificationType", vr_type::CODE_STRING),
+    ((0x0000u16, 0x5190u16), "Erase", "Erase", vr_type::CODE_STRING),
+    ((0x0000u16, 0x51A0u16), "Print", "Print", vr_type::CODE_STRING),
+    ((0x0000u16, 0x51B0u16), "Overlays", "Overlays", vr_type::UNSIGNED_SHORT),
+    (FILE_META_GROUP_LENGTH, "Group 0002 Length", "FileMetaInformationGroupLength", vr_type::UNSIGNED_LONG),
+    (FILE_META_INFORMATION_VERSION, "File Meta Information Version", "FileMetaInformationVersion", vr_type::OTHER_BYTE),
+    (MEDIA_STORAGE_SOP_CLASS_UID, "Media Storage SOP Class Uid", "MediaStorageSOPClassUID", vr_type::UID),
+    (MEDIA_STORAGE_SOP_INSTANCE_UID, "Media Storage SOP Instance Uid", "MediaStorageSOPInstanceUID", vr_type::UID),
+    (TRANSFER_SYNTAX_UID,    "Transfer Syntax Uid", "TransferSyntaxUID", vr_type::UID),
+    (IMPLEMENTATION_CLASS_UID, "Implementation Class Uid", "ImplementationClassUID", vr_type::UID),
+    (IMPLEMENTATION_VERSION_NAME, "Implementation Version Name", "ImplementationVersionName", vr_type::SHORT_STRING),
+    (SOURCE_APPLICATION_ENTITY_TITLE, "Source Application Entity Title", "SourceApplicationEntityTitle", vr_type::APPLICATION_ENTITY),
+    (PRIVATE_INFORMATION_CREATOR_UID, "Private Information Creator Uid", "PrivateInformationCreatorUID", vr_type::UID),
+    (PRIVATE_INFORMATION, "Private Information", "PrivateInformation", vr_type::OTHER_BYTE),
+    ((0x0004u16, 0x0000u16), "Group 0004 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0004u16, 0x1130u16), "File-set ID", "FileSetID", vr_type::CODE_STRING),
+    ((0x0004u16, 0x1141u16), "File-set Descriptor File ID", "FileSetDescriptorFileID", vr_type::CODE_STRING),
+    ((0x0004u16, 0x1142u16), "Specific Character Set of File-set Descriptor File", "SpecificCharacterSetOfFileSetDescriptorFile", vr_type::CODE_STRING),
+    ((0x0004u16, 0x1200u16), "Offset of the First Directory Record of the Root Directory Entity", "OffsetOfTheFirstDirectoryRecordOfTheRootDirectoryEntity", vr_type::UNSIGNED_LONG),
+    ((0x0004u16, 0x1202u16), "Offset of the Last Directory Record of the Root Directory Entity", "OffsetOfTheLastDirectoryRecordOfTheRootDirectoryEntity", vr_type::UNSIGNED_LONG),
+    ((0x0004u16, 0x1212u16), "File-set Consistency Flag", "FileSetConsistencyFlag", vr_type::UNSIGNED_SHORT),
+    ((0x0004u16, 0x1220u16), "Directory Record Sequence", "DirectoryRecordSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0004u16, 0x1400u16), "Offset of the Next Directory Record", "OffsetOfTheNextDirectoryRecord", vr_type::UNSIGNED_LONG),
+    ((0x0004u16, 0x1410u16), "Record In-use Flag", "RecordInUseFlag", vr_type::UNSIGNED_SHORT),
+    ((0x0004u16, 0x1420u16), "Offset of Referenced Lower-Level Directory Entity", "OffsetOfReferencedLowerLevelDirectoryEntity", vr_type::UNSIGNED_LONG),
+    ((0x0004u16, 0x1430u16), "Directory Record Type", "DirectoryRecordType", vr_type::CODE_STRING),
+    ((0x0004u16, 0x1432u16), "Private Record Uid", "PrivateRecordUID", vr_type::UID),
+    ((0x0004u16, 0x1500u16), "Referenced File ID", "ReferencedFileID", vr_type::CODE_STRING),
+    ((0x0004u16, 0x1504u16), "MRDR Directory Record Offset", "MRDRDirectoryRecordOffset", vr_type::UNSIGNED_LONG),
+    ((0x0004u16, 0x1510u16), "Referenced SOP Class Uid in File", "ReferencedSOPClassUIDInFile", vr_type::UID),
+    ((0x0004u16, 0x1511u16), "Referenced SOP Instance Uid in File", "ReferencedSOPInstanceUIDInFile", vr_type::UID),
+    ((0x0004u16, 0x1512u16), "Referenced Transfer Syntax Uid in File", "ReferencedTransferSyntaxUIDInFile", vr_type::UID),
+    ((0x0004u16, 0x151Au16), "Referenced Related General SOP Class Uid in File", "ReferencedRelatedGeneralSOPClassUIDInFile", vr_type::UID),
+    ((0x0004u16, 0x1600u16), "Number of References", "NumberOfReferences", vr_type::UNSIGNED_LONG),
+    ((0x0008u16, 0x0000u16), "Group 0008 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0008u16, 0x0001u16), "Length to End", "LengthToEnd", vr_type::UNSIGNED_LONG),
+    ((0x0008u16, 0x0005u16), "Specific Character Set", "SpecificCharacterSet", vr_type::CODE_STRING),
+    ((0x0008u16, 0x0006u16), "Language Code Sequence", "LanguageCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x0008u16), "Image Type", "ImageType", vr_type::CODE_STRING),
+    ((0x0008u16, 0x000Au16), "Sequence Item Number", "SequenceItemNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0008u16, 0x0010u16), "Recognition Code", "RecognitionCode", vr_type::CODE_STRING),
+    ((0x0008u16, 0x0012u16), "Instance Creation Date", "InstanceCreationDate", vr_type::DATE),
+    ((0x0008u16, 0x0013u16), "Instance Creation Time", "InstanceCreationTime", vr_type::TIME),
+    ((0x0008u16, 0x0014u16), "Instance Creator Uid", "InstanceCreatorUID", vr_type::UID),
+    (SOP_CLASS_UID,          "SOP Class Uid", "SOPClassUID", vr_type::UID),
+    (SOP_INSTANCE_UID,       "SOP Instance Uid", "SOPInstanceUID", vr_type::UID),
+    ((0x0008u16, 0x001Au16), "Related General SOP Class Uid", "RelatedGeneralSOPClassUID", vr_type::UID),
+    ((0x0008u16, 0x001Bu16), "Original Specialized SOP Class Uid", "OriginalSpecializedSOPClassUID", vr_type::UID),
+    ((0x0008u16, 0x0020u16), "Study Date", "StudyDate", vr_type::DATE),
+    ((0x0008u16, 0x0021u16), "Series Date", "SeriesDate", vr_type::DATE),
+    ((0x0008u16, 0x0022u16), "Acquisition Date", "AcquisitionDate", vr_type::DATE),
+    ((0x0008u16, 0x0023u16), "Content Date", "ContentDate", vr_type::DATE),
+    ((0x0008u16, 0x0024u16), "Overlay Date", "OverlayDate", vr_type::DATE),
+    ((0x0008u16, 0x0025u16), "Curve Date", "CurveDate", vr_type::DATE),
+    ((0x0008u16, 0x002Au16), "Acquisition DateTime", "AcquisitionDateTime", vr_type::DATE_TIME),
+    ((0x0008u16, 0x0030u16), "Study Time", "StudyTime", vr_type::TIME),
+    ((0x0008u16, 0x0031u16), "Series Time", "SeriesTime", vr_type::TIME),
+    ((0x0008u16, 0x0032u16), "Acquisition Time", "AcquisitionTime", vr_type::TIME),
+    ((0x0008u16, 0x0033u16), "Content Time", "ContentTime", vr_type::TIME),
+    ((0x0008u16, 0x0034u16), "Overlay Time", "OverlayTime", vr_type::TIME),
+    ((0x0008u16, 0x0035u16), "Curve Time", "CurveTime", vr_type::TIME),
+    ((0x0008u16, 0x0040u16), "Data Set Type", "DataSetType", vr_type::UNSIGNED_SHORT),
+    ((0x0008u16, 0x0041u16), "Data Set Subtype", "DataSetSubtype", vr_type::LONG_STRING),
+    ((0x0008u16, 0x0042u16), "Nuclear Medicine Series Type", "NuclearMedicineSeriesType", vr_type::CODE_STRING),
+    ((0x0008u16, 0x0050u16), "Accession Number", "AccessionNumber", vr_type::SHORT_STRING),
+    ((0x0008u16, 0x0051u16), "Issuer of Accession Number Sequence", "IssuerOfAccessionNumberSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x0052u16), "Query/Retrieve Level", "QueryRetrieveLevel", vr_type::CODE_STRING),
+    ((0x0008u16, 0x0054u16), "Retrieve AE Title", "RetrieveAETitle", vr_type::APPLICATION_ENTITY),
+    ((0x0008u16, 0x0056u16), "Instance Availability", "InstanceAvailability", vr_type::CODE_STRING),
+    ((0x0008u16, 0x0058u16), "Failed SOP Instance Uid List", "FailedSOPInstanceUIDList", vr_type::UID),
+    ((0x0008u16, 0x0060u16), "Modality", "Modality", vr_type::CODE_STRING),
+    ((0x0008u16, 0x0061u16), "Modalities in Study", "ModalitiesInStudy", vr_type::CODE_STRING),
+    ((0x0008u16, 0x0062u16), "SOP Classes in Study", "SOPClassesInStudy", vr_type::UID),
+    ((0x0008u16, 0x0064u16), "Conversion Type", "ConversionType", vr_type::CODE_STRING),
+    ((0x0008u16, 0x0068u16), "Presentation Intent Type", "PresentationIntentType", vr_type::CODE_STRING),
+    ((0x0008u16, 0x0070u16), "Manufacturer", "Manufacturer", vr_type::LONG_STRING),
+    ((0x0008u16, 0x0080u16), "Institution Name", "InstitutionName", vr_type::LONG_STRING),
+    ((0x0008u16, 0x0081u16), "Institution Address", "InstitutionAddress", vr_type::SHORT_TEXT),
+    ((0x0008u16, 0x0082u16), "Institution Code Sequence", "InstitutionCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x0090u16), "Referring Physician's Name", "ReferringPhysicianName", vr_type::PERSON_NAME),
+    ((0x0008u16, 0x0092u16), "Referring Physician's Address", "ReferringPhysicianAddress", vr_type::SHORT_TEXT),
+    ((0x0008u16, 0x0094u16), "Referring Physician's Telephone Numbers", "ReferringPhysicianTelephoneNumbers", vr_type::SHORT_STRING),
+    ((0x0008u16, 0x0096u16), "Referring Physician Identification Sequence", "ReferringPhysicianIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x0100u16), "Code Value", "CodeValue", vr_type::SHORT_STRING),
+    ((0x0008u16, 0x0102u16), "Coding Scheme Designator", "CodingSchemeDesignator", vr_type::SHORT_STRING),
+    ((0x0008u16, 0x0103u16), "Coding Scheme Version", "CodingSchemeVersion", vr_type::SHORT_STRING),
+    ((0x0008u16, 0x0104u16), "Code Meaning", "CodeMeaning", vr_type::LONG_STRING),
+    ((0x0008u16, 0x0105u16), "Mapping Resource", "MappingResource", vr_type::CODE_STRING),
+    ((0x0008u16, 0x0106u16), "Context Group Version", "ContextGroupVersion", vr_type::DATE_TIME),
+    ((0x0008u16, 0x0107u16), "Context Group Local Version", "ContextGroupLocalVersion", vr_type::DATE_TIME),
+    ((0x0008u16, 0x010Bu16), "Context Group Extension Flag", "ContextGroupExtensionFlag", vr_type::CODE_STRING),
+    ((0x0008u16, 0x010Cu16), "Coding Scheme Uid", "CodingSchemeUID", vr_type::UID),
+    ((0x0008u16, 0x010Du16), "Context Group Extension Creator Uid", "ContextGroupExtensionCreatorUID", vr_type::UID),
+    ((0x0008u16, 0x010Eu16), "Coding Scheme URL", "CodingSchemeURL", vr_type::UNIVERSAL_RESOURCE),
+    ((0x0008u16, 0x010Fu16), "Context Identifier", "ContextIdentifier", vr_type::CODE_STRING),
+    ((0x0008u16, 0x0110u16), "Coding Scheme Identification Sequence", "CodingSchemeIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x0112u16), "Coding Scheme Registry", "CodingSchemeRegistry", vr_type::LONG_STRING),
+    ((0x0008u16, 0x0114u16), "Coding Scheme External ID", "CodingSchemeExternalID", vr_type::SHORT_TEXT),
+    ((0x0008u16, 0x0115u16), "Coding Scheme Name", "CodingSchemeName", vr_type::SHORT_TEXT),
+    ((0x0008u16, 0x0116u16), "Coding Scheme Responsible Organization", "CodingSchemeResponsibleOrganization", vr_type::SHORT_TEXT),
+    ((0x0008u16, 0x0117u16), "Context Uid", "ContextUID", vr_type::UID),
+    ((0x0008u16, 0x0119u16), "Long Code Value", "LongCodeValue", vr_type::UNLIMITED_CHARACTERS),
+    ((0x0008u16, 0x0120u16), "URN Code Value", "URNCodeValue", vr_type::UNIVERSAL_RESOURCE),
+    ((0x0008u16, 0x0201u16), "Timezone Offset From UTC", "TimezoneOffsetFromUTC", vr_type::SHORT_STRING),
+    ((0x0008u16, 0x1000u16), "Network ID", "NetworkID", vr_type::APPLICATION_ENTITY),
+    ((0x0008u16, 0x1010u16), "Station Name", "StationName", vr_type::SHORT_STRING),
+    ((0x0008u16, 0x1030u16), "Study Description", "StudyDescription", vr_type::LONG_STRING),
+    ((0x0008u16, 0x1032u16), "Procedure Code Sequence", "ProcedureCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x103Eu16), "Series Description", "SeriesDescription", vr_type::LONG_STRING),
+    ((0x0008u16, 0x103Fu16), "Series Description Code Sequence", "SeriesDescriptionCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1040u16), "Institutional Department Name", "InstitutionalDepartmentName", vr_type::LONG_STRING),
+    ((0x0008u16, 0x1048u16), "Physician(s) of Record", "PhysiciansOfRecord", vr_type::PERSON_NAME),
+    ((0x0008u16, 0x1049u16), "Physician(s) of Record Identification Sequence", "PhysiciansOfRecordIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1050u16), "Performing Physician's Name", "PerformingPhysicianName", vr_type::PERSON_NAME),
+    ((0x0008u16, 0x1052u16), "Performing Physician Identification Sequence", "PerformingPhysicianIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1060u16), "Name of Physician(s) Reading Study", "NameOfPhysiciansReadingStudy", vr_type::PERSON_NAME),
+    ((0x0008u16, 0x1062u16), "Physician(s) Reading Study Identification Sequence", "PhysiciansReadingStudyIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1070u16), "Operators' Name", "OperatorsName", vr_type::PERSON_NAME),
+    ((0x0008u16, 0x1072u16), "Operator Identification Sequence", "OperatorIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1080u16), "Admitting Diagnoses Description", "AdmittingDiagnosesDescription", vr_type::LONG_STRING),
+    ((0x0008u16, 0x1084u16), "Admitting Diagnoses Code Sequence", "AdmittingDiagnosesCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1090u16), "Manufacturer's Model Name", "ManufacturerModelName", vr_type::LONG_STRING),
+    ((0x0008u16, 0x1100u16), "Referenced Results Sequence", "ReferencedResultsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1110u16), "Referenced Study Sequence", "ReferencedStudySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1111u16), "Referenced Performed Procedure Step Sequence", "ReferencedPerformedProcedureStepSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1115u16), "Referenced Series Sequence", "ReferencedSeriesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1120u16), "Referenced Patient Sequence", "ReferencedPatientSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1125u16), "Referenced Visit Sequence", "ReferencedVisitSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1130u16), "Referenced Overlay Sequence", "ReferencedOverlaySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1134u16), "Referenced Stereometric Instance Sequence", "ReferencedStereometricInstanceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x113Au16), "Referenced Waveform Sequence", "ReferencedWaveformSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1140u16), "Referenced Image Sequence", "ReferencedImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1145u16), "Referenced Curve Sequence", "ReferencedCurveSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x114Au16), "Referenced Instance Sequence", "ReferencedInstanceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x114Bu16), "Referenced Real World Value Mapping Instance Sequence", "ReferencedRealWorldValueMappingInstanceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1150u16), "Referenced SOP Class Uid", "ReferencedSOPClassUID", vr_type::UID),
+    ((0x0008u16, 0x1155u16), "Referenced SOP Instance Uid", "ReferencedSOPInstanceUID", vr_type::UID),
+    ((0x0008u16, 0x115Au16), "SOP Classes Supported", "SOPClassesSupported", vr_type::UID),
+    ((0x0008u16, 0x1160u16), "Referenced Frame Number", "ReferencedFrameNumber", vr_type::INTEGER_STRING),
+    ((0x0008u16, 0x1161u16), "Simple Frame List", "SimpleFrameList", vr_type::UNSIGNED_LONG),
+    ((0x0008u16, 0x1162u16), "Calculated Frame List", "CalculatedFrameList", vr_type::UNSIGNED_LONG),
+    ((0x0008u16, 0x1163u16), "Time Range", "TimeRange", vr_type::DOUBLE),
+    ((0x0008u16, 0x1164u16), "Frame Extraction Sequence", "FrameExtractionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1167u16), "Multi-Frame Source SOP Instance Uid", "MultiFrameSourceSOPInstanceUID", vr_type::UID),
+    ((0x0008u16, 0x1195u16), "Transaction Uid", "TransactionUID", vr_type::UID),
+    ((0x0008u16, 0x1197u16), "Failure Reason", "FailureReason", vr_type::UNSIGNED_SHORT),
+    ((0x0008u16, 0x1198u16), "Failed SOP Sequence", "FailedSOPSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1199u16), "Referenced SOP Sequence", "ReferencedSOPSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1200u16), "Studies Containing Other Referenced Instances Sequence", "StudiesContainingOtherReferencedInstancesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x1250u16), "Related Series Sequence", "RelatedSeriesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2110u16), "Lossy Image Compression", "LossyImageCompressionRetired", vr_type::CODE_STRING),
+    ((0x0008u16, 0x2111u16), "Derivation Description", "DerivationDescription", vr_type::SHORT_TEXT),
+    ((0x0008u16, 0x2112u16), "Source Image Sequence", "SourceImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2120u16), "Stage Name", "StageName", vr_type::SHORT_STRING),
+    ((0x0008u16, 0x2122u16), "Stage Number", "StageNumber", vr_type::INTEGER_STRING),
+    ((0x0008u16, 0x2124u16), "Number of Stages", "NumberOfStages", vr_type::INTEGER_STRING),
+    ((0x0008u16, 0x2127u16), "View Name", "ViewName", vr_type::SHORT_STRING),
+    ((0x0008u16, 0x2128u16), "View Number", "ViewNumber", vr_type::INTEGER_STRING),
+    ((0x0008u16, 0x2129u16), "Number of Event Timers", "NumberOfEventTimers", vr_type::INTEGER_STRING),
+    ((0x0008u16, 0x212Au16), "Number of Views in Stage", "NumberOfViewsInStage", vr_type::INTEGER_STRING),
+    ((0x0008u16, 0x2130u16), "Event Elapsed Time(s)", "EventElapsedTimes", vr_type::DECIMAL_STRING),
+    ((0x0008u16, 0x2132u16), "Event Timer Name(s)", "EventTimerNames", vr_type::LONG_STRING),
+    ((0x0008u16, 0x2133u16), "Event Timer Sequence", "EventTimerSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2134u16), "Event Time Offset", "EventTimeOffset", vr_type::DOUBLE),
+    ((0x0008u16, 0x2135u16), "Event Code Sequence", "EventCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2142u16), "Start Trim", "StartTrim", vr_type::INTEGER_STRING),
+    ((0x0008u16, 0x2143u16), "Stop Trim", "StopTrim", vr_type::INTEGER_STRING),
+    ((0x0008u16, 0x2144u16), "Recommended Display Frame Rate", "RecommendedDisplayFrameRate", vr_type::INTEGER_STRING),
+    ((0x0008u16, 0x2200u16), "Transducer Position", "TransducerPosition", vr_type::CODE_STRING),
+    ((0x0008u16, 0x2204u16), "Transducer Orientation", "TransducerOrientation", vr_type::CODE_STRING),
+    ((0x0008u16, 0x2208u16), "Anatomic Structure", "AnatomicStructure", vr_type::CODE_STRING),
+    ((0x0008u16, 0x2218u16), "Anatomic Region Sequence", "AnatomicRegionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2220u16), "Anatomic Region Modifier Sequence", "AnatomicRegionModifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2228u16), "Primary Anatomic Structure Sequence", "PrimaryAnatomicStructureSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2229u16), "Anatomic Structure, Space or Region Sequence", "AnatomicStructureSpaceOrRegionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2230u16), "Primary Anatomic Structure Modifier Sequence", "PrimaryAnatomicStructureModifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2240u16), "Transducer Position Sequence", "TransducerPositionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2242u16), "Transducer Position Modifier Sequence", "TransducerPositionModifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2244u16), "Transducer Orientation Sequence", "TransducerOrientationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2246u16), "Transducer Orientation Modifier Sequence", "TransducerOrientationModifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2251u16), "Anatomic Structure Space Or Region Code Sequence (Trial)", "AnatomicStructureSpaceOrRegionCodeSequenceTrial", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2253u16), "Anatomic Portal Of Entrance Code Sequence (Trial)", "AnatomicPortalOfEntranceCodeSequenceTrial", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2255u16), "Anatomic Approach Direction Code Sequence (Trial)", "AnatomicApproachDirectionCodeSequenceTrial", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2256u16), "Anatomic Perspective Description (Trial)", "AnatomicPerspectiveDescriptionTrial", vr_type::SHORT_TEXT),
+    ((0x0008u16, 0x2257u16), "Anatomic Perspective Code Sequence (Trial)", "AnatomicPerspectiveCodeSequenceTrial", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x2258u16), "Anatomic Location Of Examining Instrument Description (Trial)", "AnatomicLocationOfExaminingInstrumentDescriptionTrial", vr_type::SHORT_TEXT),
+    ((0x0008u16, 0x2259u16), "Anatomic Location Of Examining Instrument Code Sequence (Trial)", "AnatomicLocationOfExaminingInstrumentCodeSequenceTrial", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x225Au16), "Anatomic Structure Space Or Region Modifier Code Sequence (Trial)", "AnatomicStructureSpaceOrRegionModifierCodeSequenceTrial", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x225Cu16), "OnAxis Background Anatomic Structure Code Sequence (Trial)", "OnAxisBackgroundAnatomicStructureCodeSequenceTrial", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x3001u16), "Alternate Representation Sequence", "AlternateRepresentationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x3010u16), "Irradiation Event Uid", "IrradiationEventUID", vr_type::UID),
+    ((0x0008u16, 0x4000u16), "Identifying Comments", "IdentifyingComments", vr_type::LONG_TEXT),
+    ((0x0008u16, 0x9007u16), "Frame Type", "FrameType", vr_type::CODE_STRING),
+    ((0x0008u16, 0x9092u16), "Referenced Image Evidence Sequence", "ReferencedImageEvidenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x9121u16), "Referenced Raw Data Sequence", "ReferencedRawDataSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x9123u16), "Creator-Version Uid", "CreatorVersionUID", vr_type::UID),
+    ((0x0008u16, 0x9124u16), "Derivation Image Sequence", "DerivationImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x9154u16), "Source Image Evidence Sequence", "SourceImageEvidenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x9205u16), "Pixel Presentation", "PixelPresentation", vr_type::CODE_STRING),
+    ((0x0008u16, 0x9206u16), "Volumetric Properties", "VolumetricProperties", vr_type::CODE_STRING),
+    ((0x0008u16, 0x9207u16), "Volume Based Calculation Technique", "VolumeBasedCalculationTechnique", vr_type::CODE_STRING),
+    ((0x0008u16, 0x9208u16), "Complex Image Component", "ComplexImageComponent", vr_type::CODE_STRING),
+    ((0x0008u16, 0x9209u16), "Acquisition Contrast", "AcquisitionContrast", vr_type::CODE_STRING),
+    ((0x0008u16, 0x9215u16), "Derivation Code Sequence", "DerivationCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x9237u16), "Referenced Presentation State Sequence", "ReferencedPresentationStateSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x9410u16), "Referenced Other Plane Sequence", "ReferencedOtherPlaneSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x9458u16), "Frame Display Sequence", "FrameDisplaySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0008u16, 0x9459u16), "Recommended Display Frame Rate in Float", "RecommendedDisplayFrameRateInFloat", vr_type::FLOAT),
+    ((0x0008u16, 0x9460u16), "Skip Frame Range Flag", "SkipFrameRangeFlag", vr_type::CODE_STRING),
+    ((0x0010u16, 0x0000u16), "Group 0010 Length", "", vr_type::UNSIGNED_LONG),
+    (PATIENT_NAME,           "Patient's Name", "PatientName", vr_type::PERSON_NAME),
+    ((0x0010u16, 0x0020u16), "Patient ID", "PatientID", vr_type::LONG_STRING),
+    ((0x0010u16, 0x0021u16), "Issuer of Patient ID", "IssuerOfPatientID", vr_type::LONG_STRING),
+    ((0x0010u16, 0x0022u16), "Type of Patient ID", "TypeOfPatientID", vr_type::CODE_STRING),
+    ((0x0010u16, 0x0024u16), "Issuer of Patient ID Qualifiers Sequence", "IssuerOfPatientIDQualifiersSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0010u16, 0x0030u16), "Patient's Birth Date", "PatientBirthDate", vr_type::DATE),
+    ((0x0010u16, 0x0032u16), "Patient's Birth Time", "PatientBirthTime", vr_type::TIME),
+    ((0x0010u16, 0x0040u16), "Patient's Sex", "PatientSex", vr_type::CODE_STRING),
+    ((0x0010u16, 0x0050u16), "Patient's Insurance Plan Code Sequence", "PatientInsurancePlanCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0010u16, 0x0101u16), "Patient's Primary Language Code Sequence", "PatientPrimaryLanguageCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0010u16, 0x0102u16), "Patient's Primary Language Modifier Code Sequence", "PatientPrimaryLanguageModifierCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0010u16, 0x1000u16), "Other Patient IDs", "OtherPatientIDs", vr_type::LONG_STRING),
+    ((0x0010u16, 0x1001u16), "Other Patient Names", "OtherPatientNames", vr_type::PERSON_NAME),
+    ((0x0010u16, 0x1002u16), "Other Patient IDs Sequence", "OtherPatientIDsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0010u16, 0x1005u16), "Patient's Birth Name", "PatientBirthName", vr_type::PERSON_NAME),
+    ((0x0010u16, 0x1010u16), "Patient's Age", "PatientAge", vr_type::AGE_STRING),
+    ((0x0010u16, 0x1020u16), "Patient's Size", "PatientSize", vr_type::DECIMAL_STRING),
+    ((0x0010u16, 0x1030u16), "Patient's Weight", "PatientWeight", vr_type::DECIMAL_STRING),
+    ((0x0010u16, 0x1040u16), "Patient's Address", "PatientAddress", vr_type::LONG_STRING),
+    ((0x0010u16, 0x1050u16), "Insurance Plan Identification", "InsurancePlanIdentification", vr_type::LONG_STRING),
+    ((0x0010u16, 0x1060u16), "Patient's Mother's Birth Name", "PatientMotherBirthName", vr_type::PERSON_NAME),
+    ((0x0010u16, 0x1080u16), "Military Rank", "MilitaryRank", vr_type::LONG_STRING),
+    ((0x0010u16, 0x1081u16), "Branch of Service", "BranchOfService", vr_type::LONG_STRING),
+    ((0x0010u16, 0x1090u16), "Medical Record Locator", "MedicalRecordLocator", vr_type::LONG_STRING),
+    ((0x0010u16, 0x2000u16), "Medical Alerts", "MedicalAlerts", vr_type::LONG_STRING),
+    ((0x0010u16, 0x2110u16), "Allergies", "Allergies", vr_type::LONG_STRING),
+    ((0x0010u16, 0x2150u16), "Country of Residence", "CountryOfResidence", vr_type::LONG_STRING),
+    ((0x0010u16, 0x2152u16), "Region of Residence", "RegionOfResidence", vr_type::LONG_STRING),
+    ((0x0010u16, 0x2154u16), "Patient's Telephone Numbers", "PatientTelephoneNumbers", vr_type::SHORT_STRING),
+    ((0x0010u16, 0x2160u16), "Ethnic Group", "EthnicGroup", vr_type::SHORT_STRING),
+    ((0x0010u16, 0x2180u16), "Occupation", "Occupation", vr_type::SHORT_STRING),
+    ((0x0010u16, 0x21A0u16), "Smoking Status", "SmokingStatus", vr_type::CODE_STRING),
+    ((0x0010u16, 0x21B0u16), "Additional Patient History", "AdditionalPatientHistory", vr_type::LONG_TEXT),
+    ((0x0010u16, 0x21C0u16), "Pregnancy Status", "PregnancyStatus", vr_type::UNSIGNED_SHORT),
+    ((0x0010u16, 0x21D0u16), "Last Menstrual Date", "LastMenstrualDate", vr_type::DATE),
+    ((0x0010u16, 0x21F0u16), "Patient's Religious Preference", "PatientReligiousPreference", vr_type::LONG_STRING),
+    ((0x0010u16, 0x2201u16), "Patient Species Description", "PatientSpeciesDescription", vr_type::LONG_STRING),
+    ((0x0010u16, 0x2202u16), "Patient Species Code Sequence", "PatientSpeciesCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0010u16, 0x2203u16), "Patient's Sex Neutered", "PatientSexNeutered", vr_type::CODE_STRING),
+    ((0x0010u16, 0x2210u16), "Anatomical Orientation Type", "AnatomicalOrientationType", vr_type::CODE_STRING),
+    ((0x0010u16, 0x2292u16), "Patient Breed Description", "PatientBreedDescription", vr_type::LONG_STRING),
+    ((0x0010u16, 0x2293u16), "Patient Breed Code Sequence", "PatientBreedCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0010u16, 0x2294u16), "Breed Registration Sequence", "BreedRegistrationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0010u16, 0x2295u16), "Breed Registration Number", "BreedRegistrationNumber", vr_type::LONG_STRING),
+    ((0x0010u16, 0x2296u16), "Breed Registry Code Sequence", "BreedRegistryCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0010u16, 0x2297u16), "Responsible Person", "ResponsiblePerson", vr_type::PERSON_NAME),
+    ((0x0010u16, 0x2298u16), "Responsible Person Role", "ResponsiblePersonRole", vr_type::CODE_STRING),
+    ((0x0010u16, 0x2299u16), "Responsible Organization", "ResponsibleOrganization", vr_type::LONG_STRING),
+    ((0x0010u16, 0x4000u16), "Patient Comments", "PatientComments", vr_type::LONG_TEXT),
+    ((0x0010u16, 0x9431u16), "Examined Body Thickness", "ExaminedBodyThickness", vr_type::FLOAT),
+    ((0x0012u16, 0x0000u16), "Group 0012 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0012u16, 0x0010u16), "Clinical Trial Sponsor Name", "ClinicalTrialSponsorName", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0020u16), "Clinical Trial Protocol ID", "ClinicalTrialProtocolID", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0021u16), "Clinical Trial Protocol Name", "ClinicalTrialProtocolName", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0030u16), "Clinical Trial Site ID", "ClinicalTrialSiteID", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0031u16), "Clinical Trial Site Name", "ClinicalTrialSiteName", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0040u16), "Clinical Trial Subject ID", "ClinicalTrialSubjectID", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0042u16), "Clinical Trial Subject Reading ID", "ClinicalTrialSubjectReadingID", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0050u16), "Clinical Trial Time Point ID", "ClinicalTrialTimePointID", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0051u16), "Clinical Trial Time Point Description", "ClinicalTrialTimePointDescription", vr_type::SHORT_TEXT),
+    ((0x0012u16, 0x0060u16), "Clinical Trial Coordinating Center Name", "ClinicalTrialCoordinatingCenterName", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0062u16), "Patient Identity Removed", "PatientIdentityRemoved", vr_type::CODE_STRING),
+    ((0x0012u16, 0x0063u16), "De-identification Method", "DeIdentificationMethod", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0064u16), "De-identification Method Code Sequence", "DeIdentificationMethodCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0012u16, 0x0071u16), "Clinical Trial Series ID", "ClinicalTrialSeriesID", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0072u16), "Clinical Trial Series Description", "ClinicalTrialSeriesDescription", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0081u16), "Clinical Trial Protocol Ethics Committee Name", "ClinicalTrialProtocolEthicsCommitteeName", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0082u16), "Clinical Trial Protocol Ethics Committee Approval Number", "ClinicalTrialProtocolEthicsCommitteeApprovalNumber", vr_type::LONG_STRING),
+    ((0x0012u16, 0x0083u16), "Consent for Clinical Trial Use Sequence", "ConsentForClinicalTrialUseSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0012u16, 0x0084u16), "Distribution Type", "DistributionType", vr_type::CODE_STRING),
+    ((0x0012u16, 0x0085u16), "Consent for Distribution Flag", "ConsentForDistributionFlag", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0000u16), "Group 0018 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x0010u16), "Contrast/Bolus Agent", "ContrastBolusAgent", vr_type::LONG_STRING),
+    ((0x0018u16, 0x0012u16), "Contrast/Bolus Agent Sequence", "ContrastBolusAgentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x0014u16), "Contrast/Bolus Administration Route Sequence", "ContrastBolusAdministrationRouteSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x0015u16), "Body Part Examined", "BodyPartExamined", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0020u16), "Scanning Sequence", "ScanningSequence", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0021u16), "Sequence Variant", "SequenceVariant", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0022u16), "Scan Options", "ScanOptions", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0023u16), "MR Acquisition Type", "MRAcquisitionType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0024u16), "Sequence Name", "SequenceName", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x0025u16), "Angio Flag", "AngioFlag", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0026u16), "Intervention Drug Information Sequence", "InterventionDrugInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x0027u16), "Intervention Drug Stop Time", "InterventionDrugStopTime", vr_type::TIME),
+    ((0x0018u16, 0x0028u16), "Intervention Drug Dose", "InterventionDrugDose", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0029u16), "Intervention Drug Code Sequence", "InterventionDrugCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x002Au16), "Additional Drug Sequence", "AdditionalDrugSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x0030u16), "Radionuclide", "Radionuclide", vr_type::LONG_STRING),
+    ((0x0018u16, 0x0031u16), "Radiopharmaceutical", "Radiopharmaceutical", vr_type::LONG_STRING),
+    ((0x0018u16, 0x0032u16), "Energy Window Centerline", "EnergyWindowCenterline", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0033u16), "Energy Window Total Width", "EnergyWindowTotalWidth", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0034u16), "Intervention Drug Name", "InterventionDrugName", vr_type::LONG_STRING),
+    ((0x0018u16, 0x0035u16), "Intervention Drug Start Time", "InterventionDrugStartTime", vr_type::TIME),
+    ((0x0018u16, 0x0036u16), "Intervention Sequence", "InterventionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x0037u16), "Therapy Type", "TherapyType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0038u16), "Intervention Status", "InterventionStatus", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0039u16), "Therapy Description", "TherapyDescription", vr_type::CODE_STRING),
+    ((0x0018u16, 0x003Au16), "Intervention Description", "InterventionDescription", vr_type::SHORT_TEXT),
+    ((0x0018u16, 0x0040u16), "Cine Rate", "CineRate", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x0042u16), "Initial Cine Run State", "InitialCineRunState", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0050u16), "Slice Thickness", "SliceThickness", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0060u16), "KVP", "KVP", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0070u16), "Counts Accumulated", "CountsAccumulated", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x0071u16), "Acquisition Termination Condition", "AcquisitionTerminationCondition", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0072u16), "Effective Duration", "EffectiveDuration", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0073u16), "Acquisition Start Condition", "AcquisitionStartCondition", vr_type::CODE_STRING),
+    ((0x0018u16, 0x0074u16), "Acquisition Start Condition Data", "AcquisitionStartConditionData", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x0075u16), "Acquisition Termination Condition Data", "AcquisitionTerminationConditionData", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x0080u16), "Repetition Time", "RepetitionTime", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0081u16), "Echo Time", "EchoTime", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0082u16), "Inversion Time", "InversionTime", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0083u16), "Number of Averages", "NumberOfAverages", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0084u16), "Imaging Frequency", "ImagingFrequency", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0085u16), "Imaged Nucleus", "ImagedNucleus", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x0086u16), "Echo Number(s)", "EchoNumbers", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x0087u16), "Magnetic Field Strength", "MagneticFieldStrength", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0088u16), "Spacing Between Slices", "SpacingBetweenSlices", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0089u16), "Number of Phase Encoding Steps", "NumberOfPhaseEncodingSteps", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x0090u16), "Data Collection Diameter", "DataCollectionDiameter", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0091u16), "Echo Train Length", "EchoTrainLength", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x0093u16), "Percent Sampling", "PercentSampling", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0094u16), "Percent Phase Field of View", "PercentPhaseFieldOfView", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x0095u16), "Pixel Bandwidth", "PixelBandwidth", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1000u16), "Device Serial Number", "DeviceSerialNumber", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1002u16), "Device Uid", "DeviceUID", vr_type::UID),
+    ((0x0018u16, 0x1003u16), "Device ID", "DeviceID", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1004u16), "Plate ID", "PlateID", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1005u16), "Generator ID", "GeneratorID", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1006u16), "Grid ID", "GridID", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1007u16), "Cassette ID", "CassetteID", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1008u16), "Gantry ID", "GantryID", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1010u16), "Secondary Capture Device ID", "SecondaryCaptureDeviceID", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1011u16), "Hardcopy Creation Device ID", "HardcopyCreationDeviceID", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1012u16), "Date of Secondary Capture", "DateOfSecondaryCapture", vr_type::DATE),
+    ((0x0018u16, 0x1014u16), "Time of Secondary Capture", "TimeOfSecondaryCapture", vr_type::TIME),
+    ((0x0018u16, 0x1016u16), "Secondary Capture Device Manufacturer", "SecondaryCaptureDeviceManufacturer", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1017u16), "Hardcopy Device Manufacturer", "HardcopyDeviceManufacturer", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1018u16), "Secondary Capture Device Manufacturer's Model Name", "SecondaryCaptureDeviceManufacturerModelName", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1019u16), "Secondary Capture Device Software Version(s)", "SecondaryCaptureDeviceSoftwareVersions", vr_type::LONG_STRING),
+    ((0x0018u16, 0x101Au16), "Hardcopy Device Software Version", "HardcopyDeviceSoftwareVersion", vr_type::LONG_STRING),
+    ((0x0018u16, 0x101Bu16), "Hardcopy Device Manufacturer's Model Name", "HardcopyDeviceManufacturerModelName", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1020u16), "Software Version(s)", "SoftwareVersions", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1022u16), "Video Image Format Acquired", "VideoImageFormatAcquired", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x1023u16), "Digital Image Format Acquired", "DigitalImageFormatAcquired", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1030u16), "Protocol Name", "ProtocolName", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1040u16), "Contrast/Bolus Route", "ContrastBolusRoute", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1041u16), "Contrast/Bolus Volume", "ContrastBolusVolume", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1042u16), "Contrast/Bolus Start Time", "ContrastBolusStartTime", vr_type::TIME),
+    ((0x0018u16, 0x1043u16), "Contrast/Bolus Stop Time", "ContrastBolusStopTime", vr_type::TIME),
+    ((0x0018u16, 0x1044u16), "Contrast/Bolus Total Dose", "ContrastBolusTotalDose", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1045u16), "Syringe Counts", "SyringeCounts", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1046u16), "Contrast Flow Rate", "ContrastFlowRate", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1047u16), "Contrast Flow Duration", "ContrastFlowDuration", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1048u16), "Contrast/Bolus Ingredient", "ContrastBolusIngredient", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1049u16), "Contrast/Bolus Ingredient Concentration", "ContrastBolusIngredientConcentration", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1050u16), "Spatial Resolution", "SpatialResolution", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1060u16), "Trigger Time", "TriggerTime", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1061u16), "Trigger Source or Type", "TriggerSourceOrType", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1062u16), "Nominal Interval", "NominalInterval", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1063u16), "Frame Time", "FrameTime", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1064u16), "Cardiac Framing Type", "CardiacFramingType", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1065u16), "Frame Time Vector", "FrameTimeVector", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1066u16), "Frame Delay", "FrameDelay", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1067u16), "Image Trigger Delay", "ImageTriggerDelay", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1068u16), "Multiplex Group Time Offset", "MultiplexGroupTimeOffset", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1069u16), "Trigger Time Offset", "TriggerTimeOffset", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x106Au16), "Synchronization Trigger", "SynchronizationTrigger", vr_type::CODE_STRING),
+    ((0x0018u16, 0x106Cu16), "Synchronization Channel", "SynchronizationChannel", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x106Eu16), "Trigger Sample Position", "TriggerSamplePosition", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x1070u16), "Radiopharmaceutical Route", "RadiopharmaceuticalRoute", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1071u16), "Radiopharmaceutical Volume", "RadiopharmaceuticalVolume", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1072u16), "Radiopharmaceutical Start Time", "RadiopharmaceuticalStartTime", vr_type::TIME),
+    ((0x0018u16, 0x1073u16), "Radiopharmaceutical Stop Time", "RadiopharmaceuticalStopTime", vr_type::TIME),
+    ((0x0018u16, 0x1074u16), "Radionuclide Total Dose", "RadionuclideTotalDose", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1075u16), "Radionuclide Half Life", "RadionuclideHalfLife", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1076u16), "Radionuclide Positron Fraction", "RadionuclidePositronFraction", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1077u16), "Radiopharmaceutical Specific Activity", "RadiopharmaceuticalSpecificActivity", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1078u16), "Radiopharmaceutical Start DateTime", "RadiopharmaceuticalStartDateTime", vr_type::DATE_TIME),
+    ((0x0018u16, 0x1079u16), "Radiopharmaceutical Stop DateTime", "RadiopharmaceuticalStopDateTime", vr_type::DATE_TIME),
+    ((0x0018u16, 0x1080u16), "Beat Rejection Flag", "BeatRejectionFlag", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1081u16), "Low R-R Value", "LowRRValue", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1082u16), "High R-R Value", "HighRRValue", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1083u16), "Intervals Acquired", "IntervalsAcquired", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1084u16), "Intervals Rejected", "IntervalsRejected", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1085u16), "PVC Rejection", "PVCRejection", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1086u16), "Skip Beats", "SkipBeats", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1088u16), "Heart Rate", "HeartRate", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1090u16), "Cardiac Number of Images", "CardiacNumberOfImages", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1094u16), "Trigger Window", "TriggerWindow", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1100u16), "Reconstruction Diameter", "ReconstructionDiameter", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1110u16), "Distance Source to Detector", "DistanceSourceToDetector", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1111u16), "Distance Source to Patient", "DistanceSourceToPatient", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1114u16), "Estimated Radiographic Magnification Factor", "EstimatedRadiographicMagnificationFactor", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1120u16), "Gantry/Detector Tilt", "GantryDetectorTilt", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1121u16), "Gantry/Detector Slew", "GantryDetectorSlew", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1130u16), "Table Height", "TableHeight", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1131u16), "Table Traverse", "TableTraverse", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1134u16), "Table Motion", "TableMotion", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1135u16), "Table Vertical Increment", "TableVerticalIncrement", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1136u16), "Table Lateral Increment", "TableLateralIncrement", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1137u16), "Table Longitudinal Increment", "TableLongitudinalIncrement", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1138u16), "Table Angle", "TableAngle", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x113Au16), "Table Type", "TableType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1140u16), "Rotation Direction", "RotationDirection", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1141u16), "Angular Position", "AngularPosition", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1142u16), "Radial Position", "RadialPosition", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1143u16), "Scan Arc", "ScanArc", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1144u16), "Angular Step", "AngularStep", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1145u16), "Center of Rotation Offset", "CenterOfRotationOffset", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1146u16), "Rotation Offset", "RotationOffset", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1147u16), "Field of View Shape", "FieldOfViewShape", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1149u16), "Field of View Dimension(s)", "FieldOfViewDimensions", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1150u16), "Exposure Time", "ExposureTime", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1151u16), "X-Ray Tube Current", "XRayTubeCurrent", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1152u16), "Exposure", "Exposure", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1153u16), "Exposure in uAs", "ExposureInuAs", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1154u16), "Average Pulse Width", "AveragePulseWidth", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1155u16), "Radiation Setting", "RadiationSetting", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1156u16), "Rectification Type", "RectificationType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x115Au16), "Radiation Mode", "RadiationMode", vr_type::CODE_STRING),
+    ((0x0018u16, 0x115Eu16), "Image and Fluoroscopy Area Dose Product", "ImageAndFluoroscopyAreaDoseProduct", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1160u16), "Filter Type", "FilterType", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x1161u16), "Type of Filters", "TypeOfFilters", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1162u16), "Intensifier Size", "IntensifierSize", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1164u16), "Imager Pixel Spacing", "ImagerPixelSpacing", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1166u16), "Grid", "Grid", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1170u16), "Generator Power", "GeneratorPower", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1180u16), "Collimator/Grid Name", "CollimatorGridName", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x1181u16), "Collimator Type", "CollimatorType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1182u16), "Focal Distance", "FocalDistance", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1183u16), "X Focus Center", "XFocusCenter", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1184u16), "Y Focus Center", "YFocusCenter", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1190u16), "Focal Spot(s)", "FocalSpots", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1191u16), "Anode Target Material", "AnodeTargetMaterial", vr_type::CODE_STRING),
+    ((0x0018u16, 0x11A0u16), "Body Part Thickness", "BodyPartThickness", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x11A2u16), "Compression Force", "CompressionForce", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1200u16), "Date of Last Calibration", "DateOfLastCalibration", vr_type::DATE),
+    ((0x0018u16, 0x1201u16), "Time of Last Calibration", "TimeOfLastCalibration", vr_type::TIME),
+    ((0x0018u16, 0x1210u16), "Convolution Kernel", "ConvolutionKernel", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x1240u16), "Upper/Lower Pixel Values", "UpperLowerPixelValues", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1242u16), "Actual Frame Duration", "ActualFrameDuration", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1243u16), "Count Rate", "CountRate", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1244u16), "Preferred Playback Sequencing", "PreferredPlaybackSequencing", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x1250u16), "Receive Coil Name", "ReceiveCoilName", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x1251u16), "Transmit Coil Name", "TransmitCoilName", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x1260u16), "Plate Type", "PlateType", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x1261u16), "Phosphor Type", "PhosphorType", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1300u16), "Scan Velocity", "ScanVelocity", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1301u16), "Whole Body Technique", "WholeBodyTechnique", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1302u16), "Scan Length", "ScanLength", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1310u16), "Acquisition Matrix", "AcquisitionMatrix", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x1312u16), "In-Plane Phase Encoding Direction", "InPlanePhaseEncodingDirection", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1314u16), "Flip Angle", "FlipAngle", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1315u16), "Variable Flip Angle Flag", "VariableFlipAngleFlag", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1316u16), "SAR", "SAR", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1318u16), "dB/dt", "dBdt", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1400u16), "Acquisition Device Processing Description", "AcquisitionDeviceProcessingDescription", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1401u16), "Acquisition Device Processing Code", "AcquisitionDeviceProcessingCode", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1402u16), "Cassette Orientation", "CassetteOrientation", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1403u16), "Cassette Size", "CassetteSize", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1404u16), "Exposures on Plate", "ExposuresOnPlate", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x1405u16), "Relative X-Ray Exposure", "RelativeXRayExposure", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1450u16), "Column Angulation", "ColumnAngulation", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1460u16), "Tomo Layer Height", "TomoLayerHeight", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1470u16), "Tomo Angle", "TomoAngle", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1480u16), "Tomo Time", "TomoTime", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1490u16), "Tomo Type", "TomoType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1491u16), "Tomo Class", "TomoClass", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1495u16), "Number of Tomosynthesis Source Images", "NumberOfTomosynthesisSourceImages", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1500u16), "Positioner Motion", "PositionerMotion", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1508u16), "Positioner Type", "PositionerType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1510u16), "Positioner Primary Angle", "PositionerPrimaryAngle", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1511u16), "Positioner Secondary Angle", "PositionerSecondaryAngle", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1520u16), "Positioner Primary Angle Increment", "PositionerPrimaryAngleIncrement", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1521u16), "Positioner Secondary Angle Increment", "PositionerSecondaryAngleIncrement", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1530u16), "Detector Primary Angle", "DetectorPrimaryAngle", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1531u16), "Detector Secondary Angle", "DetectorSecondaryAngle", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x1600u16), "Shutter Shape", "ShutterShape", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1602u16), "Shutter Left Vertical Edge", "ShutterLeftVerticalEdge", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1604u16), "Shutter Right Vertical Edge", "ShutterRightVerticalEdge", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1606u16), "Shutter Upper Horizontal Edge", "ShutterUpperHorizontalEdge", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1608u16), "Shutter Lower Horizontal Edge", "ShutterLowerHorizontalEdge", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1610u16), "Center of Circular Shutter", "CenterOfCircularShutter", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1612u16), "Radius of Circular Shutter", "RadiusOfCircularShutter", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1620u16), "Vertices of the Polygonal Shutter", "VerticesOfThePolygonalShutter", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1622u16), "Shutter Presentation Value", "ShutterPresentationValue", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x1623u16), "Shutter Overlay Group", "ShutterOverlayGroup", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x1624u16), "Shutter Presentation Color CIELab Value", "ShutterPresentationColorCIELabValue", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x1700u16), "Collimator Shape", "CollimatorShape", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1702u16), "Collimator Left Vertical Edge", "CollimatorLeftVerticalEdge", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1704u16), "Collimator Right Vertical Edge", "CollimatorRightVerticalEdge", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1706u16), "Collimator Upper Horizontal Edge", "CollimatorUpperHorizontalEdge", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1708u16), "Collimator Lower Horizontal Edge", "CollimatorLowerHorizontalEdge", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1710u16), "Center of Circular Collimator", "CenterOfCircularCollimator", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1712u16), "Radius of Circular Collimator", "RadiusOfCircularCollimator", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1720u16), "Vertices of the Polygonal Collimator", "VerticesOfThePolygonalCollimator", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x1800u16), "Acquisition Time Synchronized", "AcquisitionTimeSynchronized", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1801u16), "Time Source", "TimeSource", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x1802u16), "Time Distribution Protocol", "TimeDistributionProtocol", vr_type::CODE_STRING),
+    ((0x0018u16, 0x1803u16), "NTP Source Address", "NTPSourceAddress", vr_type::LONG_STRING),
+    ((0x0018u16, 0x1810u16), "Acquisition Time Stamp", "AcquisitionTimeStamp", vr_type::DATE_TIME),
+    ((0x0018u16, 0x2001u16), "Page Number Vector", "PageNumberVector", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x2002u16), "Frame Label Vector", "FrameLabelVector", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x2003u16), "Frame Primary Angle Vector", "FramePrimaryAngleVector", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x2004u16), "Frame Secondary Angle Vector", "FrameSecondaryAngleVector", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x2005u16), "Slice Location Vector", "SliceLocationVector", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x2006u16), "Display Window Label Vector", "DisplayWindowLabelVector", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x2010u16), "Nominal Scanned Pixel Spacing", "NominalScannedPixelSpacing", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x2020u16), "Digitizing Device Transport Direction", "DigitizingDeviceTransportDirection", vr_type::CODE_STRING),
+    ((0x0018u16, 0x2030u16), "Rotation of Scanned Film", "RotationOfScannedFilm", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x3100u16), "IVUS Acquisition", "IVUSAcquisition", vr_type::CODE_STRING),
+    ((0x0018u16, 0x3101u16), "IVUS Pullback Rate", "IVUSPullbackRate", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x3102u16), "IVUS Gated Rate", "IVUSGatedRate", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x3103u16), "IVUS Pullback Start Frame Number", "IVUSPullbackStartFrameNumber", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x3104u16), "IVUS Pullback Stop Frame Number", "IVUSPullbackStopFrameNumber", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x3105u16), "Lesion Number", "LesionNumber", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x4000u16), "Acquisition Comments", "AcquisitionComments", vr_type::LONG_TEXT),
+    ((0x0018u16, 0x5000u16), "Output Power", "OutputPower", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x5010u16), "Transducer Data", "TransducerData", vr_type::LONG_STRING),
+    ((0x0018u16, 0x5012u16), "Focus Depth", "FocusDepth", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x5020u16), "Processing Function", "ProcessingFunction", vr_type::LONG_STRING),
+    ((0x0018u16, 0x5021u16), "Postprocessing Function", "PostprocessingFunction", vr_type::LONG_STRING),
+    ((0x0018u16, 0x5022u16), "Mechanical Index", "MechanicalIndex", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x5024u16), "Bone Thermal Index", "BoneThermalIndex", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x5026u16), "Cranial Thermal Index", "CranialThermalIndex", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x5027u16), "Soft Tissue Thermal Index", "SoftTissueThermalIndex", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x5028u16), "Soft Tissue-focus Thermal Index", "SoftTissueFocusThermalIndex", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x5029u16), "Soft Tissue-surface Thermal Index", "SoftTissueSurfaceThermalIndex", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x5030u16), "Dynamic Range", "DynamicRange", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x5040u16), "Total Gain", "TotalGain", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x5050u16), "Depth of Scan Field", "DepthOfScanField", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x5100u16), "Patient Position", "PatientPosition", vr_type::CODE_STRING),
+    ((0x0018u16, 0x5101u16), "View Position", "ViewPosition", vr_type::CODE_STRING),
+    ((0x0018u16, 0x5104u16), "Projection Eponymous Name Code Sequence", "ProjectionEponymousNameCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x5210u16), "Image Transformation Matrix", "ImageTransformationMatrix", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x5212u16), "Image Translation Vector", "ImageTranslationVector", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x6000u16), "Sensitivity", "Sensitivity", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x6011u16), "Sequence of Ultrasound Regions", "SequenceOfUltrasoundRegions", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x6012u16), "Region Spatial Format", "RegionSpatialFormat", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x6014u16), "Region Data Type", "RegionDataType", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x6016u16), "Region Flags", "RegionFlags", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x6018u16), "Region Location Min X0", "RegionLocationMinX0", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x601Au16), "Region Location Min Y0", "RegionLocationMinY0", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x601Cu16), "Region Location Max X1", "RegionLocationMaxX1", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x601Eu16), "Region Location Max Y1", "RegionLocationMaxY1", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x6020u16), "Reference Pixel X0", "ReferencePixelX0", vr_type::SIGNED_LONG),
+    ((0x0018u16, 0x6022u16), "Reference Pixel Y0", "ReferencePixelY0", vr_type::SIGNED_LONG),
+    ((0x0018u16, 0x6024u16), "Physical Units X Direction", "PhysicalUnitsXDirection", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x6026u16), "Physical Units Y Direction", "PhysicalUnitsYDirection", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x6028u16), "Reference Pixel Physical Value X", "ReferencePixelPhysicalValueX", vr_type::DOUBLE),
+    ((0x0018u16, 0x602Au16), "Reference Pixel Physical Value Y", "ReferencePixelPhysicalValueY", vr_type::DOUBLE),
+    ((0x0018u16, 0x602Cu16), "Physical Delta X", "PhysicalDeltaX", vr_type::DOUBLE),
+    ((0x0018u16, 0x602Eu16), "Physical Delta Y", "PhysicalDeltaY", vr_type::DOUBLE),
+    ((0x0018u16, 0x6030u16), "Transducer Frequency", "TransducerFrequency", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x6031u16), "Transducer Type", "TransducerType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x6032u16), "Pulse Repetition Frequency", "PulseRepetitionFrequency", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x6034u16), "Doppler Correction Angle", "DopplerCorrectionAngle", vr_type::DOUBLE),
+    ((0x0018u16, 0x6036u16), "Steering Angle", "SteeringAngle", vr_type::DOUBLE),
+    ((0x0018u16, 0x6038u16), "Doppler Sample Volume X Position", "DopplerSampleVolumeXPositionRetired", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x6039u16), "Doppler Sample Volume X Position", "DopplerSampleVolumeXPosition", vr_type::SIGNED_LONG),
+    ((0x0018u16, 0x603Au16), "Doppler Sample Volume Y Position", "DopplerSampleVolumeYPositionRetired", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x603Bu16), "Doppler Sample Volume Y Position", "DopplerSampleVolumeYPosition", vr_type::SIGNED_LONG),
+    ((0x0018u16, 0x603Cu16), "TM-Line Position X0", "TMLinePositionX0Retired", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x603Du16), "TM-Line Position X0", "TMLinePositionX0", vr_type::SIGNED_LONG),
+    ((0x0018u16, 0x603Eu16), "TM-Line Position Y0", "TMLinePositionY0Retired", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x603Fu16), "TM-Line Position Y0", "TMLinePositionY0", vr_type::SIGNED_LONG),
+    ((0x0018u16, 0x6040u16), "TM-Line Position X1", "TMLinePositionX1Retired", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x6041u16), "TM-Line Position X1", "TMLinePositionX1", vr_type::SIGNED_LONG),
+    ((0x0018u16, 0x6042u16), "TM-Line Position Y1", "TMLinePositionY1Retired", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x6043u16), "TM-Line Position Y1", "TMLinePositionY1", vr_type::SIGNED_LONG),
+    ((0x0018u16, 0x6044u16), "Pixel Component Organization", "PixelComponentOrganization", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x6046u16), "Pixel Component Mask", "PixelComponentMask", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x6048u16), "Pixel Component Range Start", "PixelComponentRangeStart", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x604Au16), "Pixel Component Range Stop", "PixelComponentRangeStop", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x604Cu16), "Pixel Component Physical Units", "PixelComponentPhysicalUnits", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x604Eu16), "Pixel Component Data Type", "PixelComponentDataType", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x6050u16), "Number of Table Break Points", "NumberOfTableBreakPoints", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x6052u16), "Table of X Break Points", "TableOfXBreakPoints", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x6054u16), "Table of Y Break Points", "TableOfYBreakPoints", vr_type::DOUBLE),
+    ((0x0018u16, 0x6056u16), "Number of Table Entries", "NumberOfTableEntries", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x6058u16), "Table of Pixel Values", "TableOfPixelValues", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x605Au16), "Table of Parameter Values", "TableOfParameterValues", vr_type::FLOAT),
+    ((0x0018u16, 0x6060u16), "R Wave Time Vector", "RWaveTimeVector", vr_type::FLOAT),
+    ((0x0018u16, 0x7000u16), "Detector Conditions Nominal Flag", "DetectorConditionsNominalFlag", vr_type::CODE_STRING),
+    ((0x0018u16, 0x7001u16), "Detector Temperature", "DetectorTemperature", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7004u16), "Detector Type", "DetectorType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x7005u16), "Detector Configuration", "DetectorConfiguration", vr_type::CODE_STRING),
+    ((0x0018u16, 0x7006u16), "Detector Description", "DetectorDescription", vr_type::LONG_TEXT),
+    ((0x0018u16, 0x7008u16), "Detector Mode", "DetectorMode", vr_type::LONG_TEXT),
+    ((0x0018u16, 0x700Au16), "Detector ID", "DetectorID", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x700Cu16), "Date of Last Detector Calibration", "DateOfLastDetectorCalibration", vr_type::DATE),
+    ((0x0018u16, 0x700Eu16), "Time of Last Detector Calibration", "TimeOfLastDetectorCalibration", vr_type::TIME),
+    ((0x0018u16, 0x7010u16), "Exposures on Detector Since Last Calibration", "ExposuresOnDetectorSinceLastCalibration", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x7011u16), "Exposures on Detector Since Manufactured", "ExposuresOnDetectorSinceManufactured", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x7012u16), "Detector Time Since Last Exposure", "DetectorTimeSinceLastExposure", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7014u16), "Detector Active Time", "DetectorActiveTime", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7016u16), "Detector Activation Offset From Exposure", "DetectorActivationOffsetFromExposure", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x701Au16), "Detector Binning", "DetectorBinning", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7020u16), "Detector Element Physical Size", "DetectorElementPhysicalSize", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7022u16), "Detector Element Spacing", "DetectorElementSpacing", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7024u16), "Detector Active Shape", "DetectorActiveShape", vr_type::CODE_STRING),
+    ((0x0018u16, 0x7026u16), "Detector Active Dimension(s)", "DetectorActiveDimensions", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7028u16), "Detector Active Origin", "DetectorActiveOrigin", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x702Au16), "Detector Manufacturer Name", "DetectorManufacturerName", vr_type::LONG_STRING),
+    ((0x0018u16, 0x702Bu16), "Detector Manufacturer's Model Name", "DetectorManufacturerModelName", vr_type::LONG_STRING),
+    ((0x0018u16, 0x7030u16), "Field of View Origin", "FieldOfViewOrigin", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7032u16), "Field of View Rotation", "FieldOfViewRotation", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7034u16), "Field of View Horizontal Flip", "FieldOfViewHorizontalFlip", vr_type::CODE_STRING),
+    ((0x0018u16, 0x7040u16), "Grid Absorbing Material", "GridAbsorbingMaterial", vr_type::LONG_TEXT),
+    ((0x0018u16, 0x7041u16), "Grid Spacing Material", "GridSpacingMaterial", vr_type::LONG_TEXT),
+    ((0x0018u16, 0x7042u16), "Grid Thickness", "GridThickness", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7044u16), "Grid Pitch", "GridPitch", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7046u16), "Grid Aspect Ratio", "GridAspectRatio", vr_type::INTEGER_STRING),
+    ((0x0018u16, 0x7048u16), "Grid Period", "GridPeriod", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x704Cu16), "Grid Focal Distance", "GridFocalDistance", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7050u16), "Filter Material", "FilterMaterial", vr_type::CODE_STRING),
+    ((0x0018u16, 0x7052u16), "Filter Thickness Minimum", "FilterThicknessMinimum", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7054u16), "Filter Thickness Maximum", "FilterThicknessMaximum", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x7056u16), "Filter Beam Path Length Minimum", "FilterBeamPathLengthMinimum", vr_type::FLOAT),
+    ((0x0018u16, 0x7058u16), "Filter Beam Path Length Maximum", "FilterBeamPathLengthMaximum", vr_type::FLOAT),
+    ((0x0018u16, 0x7060u16), "Exposure Control Mode", "ExposureControlMode", vr_type::CODE_STRING),
+    ((0x0018u16, 0x7062u16), "Exposure Control Mode Description", "ExposureControlModeDescription", vr_type::LONG_TEXT),
+    ((0x0018u16, 0x7064u16), "Exposure Status", "ExposureStatus", vr_type::CODE_STRING),
+    ((0x0018u16, 0x7065u16), "PhotoTimer Setting", "PhotoTimerSetting", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x8150u16), "Exposure Time in uS", "ExposureTimeInuS", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x8151u16), "X-Ray Tube Current in uA", "XRayTubeCurrentInuA", vr_type::DECIMAL_STRING),
+    ((0x0018u16, 0x9004u16), "Content Qualification", "ContentQualification", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9005u16), "Pulse Sequence Name", "PulseSequenceName", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x9006u16), "MR Imaging Modifier Sequence", "MRImagingModifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9008u16), "Echo Pulse Sequence", "EchoPulseSequence", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9009u16), "Inversion Recovery", "InversionRecovery", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9010u16), "Flow Compensation", "FlowCompensation", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9011u16), "Multiple Spin Echo", "MultipleSpinEcho", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9012u16), "Multi-planar Excitation", "MultiPlanarExcitation", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9014u16), "Phase Contrast", "PhaseContrast", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9015u16), "Time of Flight Contrast", "TimeOfFlightContrast", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9016u16), "Spoiling", "Spoiling", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9017u16), "Steady State Pulse Sequence", "SteadyStatePulseSequence", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9018u16), "Echo Planar Pulse Sequence", "EchoPlanarPulseSequence", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9019u16), "Tag Angle First Axis", "TagAngleFirstAxis", vr_type::DOUBLE),
+    ((0x0018u16, 0x9020u16), "Magnetization Transfer", "MagnetizationTransfer", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9021u16), "T2 Preparation", "T2Preparation", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9022u16), "Blood Signal Nulling", "BloodSignalNulling", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9024u16), "Saturation Recovery", "SaturationRecovery", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9025u16), "Spectrally Selected Suppression", "SpectrallySelectedSuppression", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9026u16), "Spectrally Selected Excitation", "SpectrallySelectedExcitation", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9027u16), "Spatial Pre-saturation", "SpatialPreSaturation", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9028u16), "Tagging", "Tagging", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9029u16), "Oversampling Phase", "OversamplingPhase", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9030u16), "Tag Spacing First Dimension", "TagSpacingFirstDimension", vr_type::DOUBLE),
+    ((0x0018u16, 0x9032u16), "Geometry of k-Space Traversal", "GeometryOfKSpaceTraversal", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9033u16), "Segmented k-Space Traversal", "SegmentedKSpaceTraversal", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9034u16), "Rectilinear Phase Encode Reordering", "RectilinearPhaseEncodeReordering", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9035u16), "Tag Thickness", "TagThickness", vr_type::DOUBLE),
+    ((0x0018u16, 0x9036u16), "Partial Fourier Direction", "PartialFourierDirection", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9037u16), "Cardiac Synchronization Technique", "CardiacSynchronizationTechnique", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9041u16), "Receive Coil Manufacturer Name", "ReceiveCoilManufacturerName", vr_type::LONG_STRING),
+    ((0x0018u16, 0x9042u16), "MR Receive Coil Sequence", "MRReceiveCoilSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9043u16), "Receive Coil Type", "ReceiveCoilType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9044u16), "Quadrature Receive Coil", "QuadratureReceiveCoil", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9045u16), "Multi-Coil Definition Sequence", "MultiCoilDefinitionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9046u16), "Multi-Coil Configuration", "MultiCoilConfiguration", vr_type::LONG_STRING),
+    ((0x0018u16, 0x9047u16), "Multi-Coil Element Name", "MultiCoilElementName", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x9048u16), "Multi-Coil Element Used", "MultiCoilElementUsed", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9049u16), "MR Transmit Coil Sequence", "MRTransmitCoilSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9050u16), "Transmit Coil Manufacturer Name", "TransmitCoilManufacturerName", vr_type::LONG_STRING),
+    ((0x0018u16, 0x9051u16), "Transmit Coil Type", "TransmitCoilType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9052u16), "Spectral Width", "SpectralWidth", vr_type::DOUBLE),
+    ((0x0018u16, 0x9053u16), "Chemical Shift Reference", "ChemicalShiftReference", vr_type::DOUBLE),
+    ((0x0018u16, 0x9054u16), "Volume Localization Technique", "VolumeLocalizationTechnique", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9058u16), "MR Acquisition Frequency Encoding Steps", "MRAcquisitionFrequencyEncodingSteps", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9059u16), "De-coupling", "DeCoupling", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9060u16), "De-coupled Nucleus", "DeCoupledNucleus", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9061u16), "De-coupling Frequency", "DeCouplingFrequency", vr_type::DOUBLE),
+    ((0x0018u16, 0x9062u16), "De-coupling Method", "DeCouplingMethod", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9063u16), "De-coupling Chemical Shift Reference", "DeCouplingChemicalShiftReference", vr_type::DOUBLE),
+    ((0x0018u16, 0x9064u16), "k-space Filtering", "KSpaceFiltering", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9065u16), "Time Domain Filtering", "TimeDomainFiltering", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9066u16), "Number of Zero fills", "NumberOfZeroFills", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9067u16), "Baseline Correction", "BaselineCorrection", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9069u16), "Parallel Reduction Factor In-Plane", "ParallelReductionFactorInPlane", vr_type::DOUBLE),
+    ((0x0018u16, 0x9070u16), "Cardiac R-R Interval Specified", "CardiacRRIntervalSpecified", vr_type::DOUBLE),
+    ((0x0018u16, 0x9073u16), "Acquisition Duration", "AcquisitionDuration", vr_type::DOUBLE),
+    ((0x0018u16, 0x9074u16), "Frame Acquisition DateTime", "FrameAcquisitionDateTime", vr_type::DATE_TIME),
+    ((0x0018u16, 0x9075u16), "Diffusion Directionality", "DiffusionDirectionality", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9076u16), "Diffusion Gradient Direction Sequence", "DiffusionGradientDirectionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9077u16), "Parallel Acquisition", "ParallelAcquisition", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9078u16), "Parallel Acquisition Technique", "ParallelAcquisitionTechnique", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9079u16), "Inversion Times", "InversionTimes", vr_type::DOUBLE),
+    ((0x0018u16, 0x9080u16), "Metabolite Map Description", "MetaboliteMapDescription", vr_type::SHORT_TEXT),
+    ((0x0018u16, 0x9081u16), "Partial Fourier", "PartialFourier", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9082u16), "Effective Echo Time", "EffectiveEchoTime", vr_type::DOUBLE),
+    ((0x0018u16, 0x9083u16), "Metabolite Map Code Sequence", "MetaboliteMapCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9084u16), "Chemical Shift Sequence", "ChemicalShiftSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9085u16), "Cardiac Signal Source", "CardiacSignalSource", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9087u16), "Diffusion b-value", "DiffusionBValue", vr_type::DOUBLE),
+    ((0x0018u16, 0x9089u16), "Diffusion Gradient Orientation", "DiffusionGradientOrientation", vr_type::DOUBLE),
+    ((0x0018u16, 0x9090u16), "Velocity Encoding Direction", "VelocityEncodingDirection", vr_type::DOUBLE),
+    ((0x0018u16, 0x9091u16), "Velocity Encoding Minimum Value", "VelocityEncodingMinimumValue", vr_type::DOUBLE),
+    ((0x0018u16, 0x9093u16), "Number of k-Space Trajectories", "NumberOfKSpaceTrajectories", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9094u16), "Coverage of k-Space", "CoverageOfKSpace", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9095u16), "Spectroscopy Acquisition Phase Rows", "SpectroscopyAcquisitionPhaseRows", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x9096u16), "Parallel Reduction Factor In-plane (Retired)", "ParallelReductionFactorInPlaneRetired", vr_type::DOUBLE),
+    ((0x0018u16, 0x9098u16), "Transmitter Frequency", "TransmitterFrequency", vr_type::DOUBLE),
+    ((0x0018u16, 0x9100u16), "Resonant Nucleus", "ResonantNucleus", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9101u16), "Frequency Correction", "FrequencyCorrection", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9103u16), "MR Spectroscopy FOV/Geometry Sequence", "MRSpectroscopyFOVGeometrySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9104u16), "Slab Thickness", "SlabThickness", vr_type::DOUBLE),
+    ((0x0018u16, 0x9105u16), "Slab Orientation", "SlabOrientation", vr_type::DOUBLE),
+    ((0x0018u16, 0x9106u16), "Mid Slab Position", "MidSlabPosition", vr_type::DOUBLE),
+    ((0x0018u16, 0x9107u16), "MR Spatial Saturation Sequence", "MRSpatialSaturationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9112u16), "MR Timing and Related Parameters Sequence", "MRTimingAndRelatedParametersSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9114u16), "MR Echo Sequence", "MREchoSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9115u16), "MR Modifier Sequence", "MRModifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9117u16), "MR Diffusion Sequence", "MRDiffusionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9118u16), "Cardiac Synchronization Sequence", "CardiacSynchronizationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9119u16), "MR Averages Sequence", "MRAveragesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9125u16), "MR FOV/Geometry Sequence", "MRFOVGeometrySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9126u16), "Volume Localization Sequence", "VolumeLocalizationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9127u16), "Spectroscopy Acquisition Data Columns", "SpectroscopyAcquisitionDataColumns", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x9147u16), "Diffusion Anisotropy Type", "DiffusionAnisotropyType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9151u16), "Frame Reference DateTime", "FrameReferenceDateTime", vr_type::DATE_TIME),
+    ((0x0018u16, 0x9152u16), "MR Metabolite Map Sequence", "MRMetaboliteMapSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9155u16), "Parallel Reduction Factor out-of-plane", "ParallelReductionFactorOutOfPlane", vr_type::DOUBLE),
+    ((0x0018u16, 0x9159u16), "Spectroscopy Acquisition Out-of-plane Phase Steps", "SpectroscopyAcquisitionOutOfPlanePhaseSteps", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x9166u16), "Bulk Motion Status", "BulkMotionStatus", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9168u16), "Parallel Reduction Factor Second In-Plane", "ParallelReductionFactorSecondInPlane", vr_type::DOUBLE),
+    ((0x0018u16, 0x9169u16), "Cardiac Beat Rejection Technique", "CardiacBeatRejectionTechnique", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9170u16), "Respiratory Motion Compensation Technique", "RespiratoryMotionCompensationTechnique", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9171u16), "Respiratory Signal Source", "RespiratorySignalSource", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9172u16), "Bulk Motion Compensation Technique", "BulkMotionCompensationTechnique", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9173u16), "Bulk Motion Signal Source", "BulkMotionSignalSource", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9174u16), "Applicable Safety Standard Agency", "ApplicableSafetyStandardAgency", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9175u16), "Applicable Safety Standard Description", "ApplicableSafetyStandardDescription", vr_type::LONG_STRING),
+    ((0x0018u16, 0x9176u16), "Operating Mode Sequence", "OperatingModeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9177u16), "Operating Mode Type", "OperatingModeType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9178u16), "Operating Mode", "OperatingMode", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9179u16), "Specific Absorption Rate Definition", "SpecificAbsorptionRateDefinition", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9180u16), "Gradient Output Type", "GradientOutputType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9181u16), "Specific Absorption Rate Value", "SpecificAbsorptionRateValue", vr_type::DOUBLE),
+    ((0x0018u16, 0x9182u16), "Gradient Output", "GradientOutput", vr_type::DOUBLE),
+    ((0x0018u16, 0x9183u16), "Flow Compensation Direction", "FlowCompensationDirection", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9184u16), "Tagging Delay", "TaggingDelay", vr_type::DOUBLE),
+    ((0x0018u16, 0x9185u16), "Respiratory Motion Compensation Technique Description", "RespiratoryMotionCompensationTechniqueDescription", vr_type::SHORT_TEXT),
+    ((0x0018u16, 0x9186u16), "Respiratory Signal Source ID", "RespiratorySignalSourceID", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x9195u16), "Chemical Shift Minimum Integration Limit in Hz", "ChemicalShiftMinimumIntegrationLimitInHz", vr_type::DOUBLE),
+    ((0x0018u16, 0x9196u16), "Chemical Shift Maximum Integration Limit in Hz", "ChemicalShiftMaximumIntegrationLimitInHz", vr_type::DOUBLE),
+    ((0x0018u16, 0x9197u16), "MR Velocity Encoding Sequence", "MRVelocityEncodingSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9198u16), "First Order Phase Correction", "FirstOrderPhaseCorrection", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9199u16), "Water Referenced Phase Correction", "WaterReferencedPhaseCorrection", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9200u16), "MR Spectroscopy Acquisition Type", "MRSpectroscopyAcquisitionType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9214u16), "Respiratory Cycle Position", "RespiratoryCyclePosition", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9217u16), "Velocity Encoding Maximum Value", "VelocityEncodingMaximumValue", vr_type::DOUBLE),
+    ((0x0018u16, 0x9218u16), "Tag Spacing Second Dimension", "TagSpacingSecondDimension", vr_type::DOUBLE),
+    ((0x0018u16, 0x9219u16), "Tag Angle Second Axis", "TagAngleSecondAxis", vr_type::SIGNED_SHORT),
+    ((0x0018u16, 0x9220u16), "Frame Acquisition Duration", "FrameAcquisitionDuration", vr_type::DOUBLE),
+    ((0x0018u16, 0x9226u16), "MR Image Frame Type Sequence", "MRImageFrameTypeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9227u16), "MR Spectroscopy Frame Type Sequence", "MRSpectroscopyFrameTypeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9231u16), "MR Acquisition Phase Encoding Steps in-Plane", "MRAcquisitionPhaseEncodingStepsInPlane", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9232u16), "MR Acquisition Phase Encoding Steps out-of-plane", "MRAcquisitionPhaseEncodingStepsOutOfPlane", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9234u16), "Spectroscopy Acquisition Phase Columns", "SpectroscopyAcquisitionPhaseColumns", vr_type::UNSIGNED_LONG),
+    ((0x0018u16, 0x9236u16), "Cardiac Cycle Position", "CardiacCyclePosition", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9239u16), "Specific Absorption Rate Sequence", "SpecificAbsorptionRateSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9240u16), "RF Echo Train Length", "RFEchoTrainLength", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9241u16), "Gradient Echo Train Length", "GradientEchoTrainLength", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9295u16), "Chemical Shift Minimum Integration Limit in ppm", "ChemicalShiftMinimumIntegrationLimitInppm", vr_type::DOUBLE),
+    ((0x0018u16, 0x9296u16), "Chemical Shift Maximum Integration Limit in ppm", "ChemicalShiftMaximumIntegrationLimitInppm", vr_type::DOUBLE),
+    ((0x0018u16, 0x9301u16), "CT Acquisition Type Sequence", "CTAcquisitionTypeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9302u16), "Acquisition Type", "AcquisitionType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9303u16), "Tube Angle", "TubeAngle", vr_type::DOUBLE),
+    ((0x0018u16, 0x9304u16), "CT Acquisition Details Sequence", "CTAcquisitionDetailsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9305u16), "Revolution Time", "RevolutionTime", vr_type::DOUBLE),
+    ((0x0018u16, 0x9306u16), "Single Collimation Width", "SingleCollimationWidth", vr_type::DOUBLE),
+    ((0x0018u16, 0x9307u16), "Total Collimation Width", "TotalCollimationWidth", vr_type::DOUBLE),
+    ((0x0018u16, 0x9308u16), "CT Table Dynamics Sequence", "CTTableDynamicsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9309u16), "Table Speed", "TableSpeed", vr_type::DOUBLE),
+    ((0x0018u16, 0x9310u16), "Table Feed per Rotation", "TableFeedPerRotation", vr_type::DOUBLE),
+    ((0x0018u16, 0x9311u16), "Spiral Pitch Factor", "SpiralPitchFactor", vr_type::DOUBLE),
+    ((0x0018u16, 0x9312u16), "CT Geometry Sequence", "CTGeometrySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9313u16), "Data Collection Center (Patient)", "DataCollectionCenterPatient", vr_type::DOUBLE),
+    ((0x0018u16, 0x9314u16), "CT Reconstruction Sequence", "CTReconstructionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9315u16), "Reconstruction Algorithm", "ReconstructionAlgorithm", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9316u16), "Convolution Kernel Group", "ConvolutionKernelGroup", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9317u16), "Reconstruction Field of View", "ReconstructionFieldOfView", vr_type::DOUBLE),
+    ((0x0018u16, 0x9318u16), "Reconstruction Target Center (Patient)", "ReconstructionTargetCenterPatient", vr_type::DOUBLE),
+    ((0x0018u16, 0x9319u16), "Reconstruction Angle", "ReconstructionAngle", vr_type::DOUBLE),
+    ((0x0018u16, 0x9320u16), "Image Filter", "ImageFilter", vr_type::SHORT_STRING),
+    ((0x0018u16, 0x9321u16), "CT Exposure Sequence", "CTExposureSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9322u16), "Reconstruction Pixel Spacing", "ReconstructionPixelSpacing", vr_type::DOUBLE),
+    ((0x0018u16, 0x9323u16), "Exposure Modulation Type", "ExposureModulationType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9324u16), "Estimated Dose Saving", "EstimatedDoseSaving", vr_type::DOUBLE),
+    ((0x0018u16, 0x9325u16), "CT X-Ray Details Sequence", "CTXRayDetailsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9326u16), "CT Position Sequence", "CTPositionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9327u16), "Table Position", "TablePosition", vr_type::DOUBLE),
+    ((0x0018u16, 0x9328u16), "Exposure Time in ms", "ExposureTimeInms", vr_type::DOUBLE),
+    ((0x0018u16, 0x9329u16), "CT Image Frame Type Sequence", "CTImageFrameTypeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9330u16), "X-Ray Tube Current in mA", "XRayTubeCurrentInmA", vr_type::DOUBLE),
+    ((0x0018u16, 0x9332u16), "Exposure in mAs", "ExposureInmAs", vr_type::DOUBLE),
+    ((0x0018u16, 0x9333u16), "Constant Volume Flag", "ConstantVolumeFlag", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9334u16), "Fluoroscopy Flag", "FluoroscopyFlag", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9335u16), "Distance Source to Data Collection Center", "DistanceSourceToDataCollectionCenter", vr_type::DOUBLE),
+    ((0x0018u16, 0x9337u16), "Contrast/Bolus Agent Number", "ContrastBolusAgentNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9338u16), "Contrast/Bolus Ingredient Code Sequence", "ContrastBolusIngredientCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9340u16), "Contrast Administration Profile Sequence", "ContrastAdministrationProfileSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9341u16), "Contrast/Bolus Usage Sequence", "ContrastBolusUsageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9342u16), "Contrast/Bolus Agent Administered", "ContrastBolusAgentAdministered", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9343u16), "Contrast/Bolus Agent Detected", "ContrastBolusAgentDetected", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9344u16), "Contrast/Bolus Agent Phase", "ContrastBolusAgentPhase", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9345u16), "CTDIvol", "CTDIvol", vr_type::DOUBLE),
+    ((0x0018u16, 0x9346u16), "CTDI Phantom Type Code Sequence", "CTDIPhantomTypeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9351u16), "Calcium Scoring Mass Factor Patient", "CalciumScoringMassFactorPatient", vr_type::FLOAT),
+    ((0x0018u16, 0x9352u16), "Calcium Scoring Mass Factor Device", "CalciumScoringMassFactorDevice", vr_type::FLOAT),
+    ((0x0018u16, 0x9353u16), "Energy Weighting Factor", "EnergyWeightingFactor", vr_type::FLOAT),
+    ((0x0018u16, 0x9360u16), "CT Additional X-Ray Source Sequence", "CTAdditionalXRaySourceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9401u16), "Projection Pixel Calibration Sequence", "ProjectionPixelCalibrationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9402u16), "Distance Source to Isocenter", "DistanceSourceToIsocenter", vr_type::FLOAT),
+    ((0x0018u16, 0x9403u16), "Distance Object to Table Top", "DistanceObjectToTableTop", vr_type::FLOAT),
+    ((0x0018u16, 0x9404u16), "Object Pixel Spacing in Center of Beam", "ObjectPixelSpacingInCenterOfBeam", vr_type::FLOAT),
+    ((0x0018u16, 0x9405u16), "Positioner Position Sequence", "PositionerPositionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9406u16), "Table Position Sequence", "TablePositionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9407u16), "Collimator Shape Sequence", "CollimatorShapeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9412u16), "XA/XRF Frame Characteristics Sequence", "XAXRFFrameCharacteristicsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9417u16), "Frame Acquisition Sequence", "FrameAcquisitionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9420u16), "X-Ray Receptor Type", "XRayReceptorType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9423u16), "Acquisition Protocol Name", "AcquisitionProtocolName", vr_type::LONG_STRING),
+    ((0x0018u16, 0x9424u16), "Acquisition Protocol Description", "AcquisitionProtocolDescription", vr_type::LONG_TEXT),
+    ((0x0018u16, 0x9425u16), "Contrast/Bolus Ingredient Opaque", "ContrastBolusIngredientOpaque", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9426u16), "Distance Receptor Plane to Detector Housing", "DistanceReceptorPlaneToDetectorHousing", vr_type::FLOAT),
+    ((0x0018u16, 0x9427u16), "Intensifier Active Shape", "IntensifierActiveShape", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9428u16), "Intensifier Active Dimension(s)", "IntensifierActiveDimensions", vr_type::FLOAT),
+    ((0x0018u16, 0x9429u16), "Physical Detector Size", "PhysicalDetectorSize", vr_type::FLOAT),
+    ((0x0018u16, 0x9430u16), "Position of Isocenter Projection", "PositionOfIsocenterProjection", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9432u16), "Field of View Sequence", "FieldOfViewSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9433u16), "Field of View Description", "FieldOfViewDescription", vr_type::LONG_STRING),
+    ((0x0018u16, 0x9434u16), "Exposure Control Sensing Regions Sequence", "ExposureControlSensingRegionsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9435u16), "Exposure Control Sensing Region Shape", "ExposureControlSensingRegionShape", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9436u16), "Exposure Control Sensing Region Left Vertical Edge", "ExposureControlSensingRegionLeftVerticalEdge", vr_type::SIGNED_SHORT),
+    ((0x0018u16, 0x9437u16), "Exposure Control Sensing Region Right Vertical Edge", "ExposureControlSensingRegionRightVerticalEdge", vr_type::SIGNED_SHORT),
+    ((0x0018u16, 0x9438u16), "Exposure Control Sensing Region Upper Horizontal Edge", "ExposureControlSensingRegionUpperHorizontalEdge", vr_type::SIGNED_SHORT),
+    ((0x0018u16, 0x9439u16), "Exposure Control Sensing Region Lower Horizontal Edge", "ExposureControlSensingRegionLowerHorizontalEdge", vr_type::SIGNED_SHORT),
+    ((0x0018u16, 0x9440u16), "Center of Circular Exposure Control Sensing Region", "CenterOfCircularExposureControlSensingRegion", vr_type::SIGNED_SHORT),
+    ((0x0018u16, 0x9441u16), "Radius of Circular Exposure Control Sensing Region", "RadiusOfCircularExposureControlSensingRegion", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9442u16), "Vertices of the Polygonal Exposure Control Sensing Region", "VerticesOfThePolygonalExposureControlSensingRegion", vr_type::SIGNED_SHORT),
+    ((0x0018u16, 0x9447u16), "Column Angulation (Patient)", "ColumnAngulationPatient", vr_type::FLOAT),
+    ((0x0018u16, 0x9449u16), "Beam Angle", "BeamAngle", vr_type::FLOAT),
+    ((0x0018u16, 0x9451u16), "Frame Detector Parameters Sequence", "FrameDetectorParametersSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9452u16), "Calculated Anatomy Thickness", "CalculatedAnatomyThickness", vr_type::FLOAT),
+    ((0x0018u16, 0x9455u16), "Calibration Sequence", "CalibrationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9456u16), "Object Thickness Sequence", "ObjectThicknessSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9457u16), "Plane Identification", "PlaneIdentification", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9461u16), "Field of View Dimension(s) in Float", "FieldOfViewDimensionsInFloat", vr_type::FLOAT),
+    ((0x0018u16, 0x9462u16), "Isocenter Reference System Sequence", "IsocenterReferenceSystemSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9463u16), "Positioner Isocenter Primary Angle", "PositionerIsocenterPrimaryAngle", vr_type::FLOAT),
+    ((0x0018u16, 0x9464u16), "Positioner Isocenter Secondary Angle", "PositionerIsocenterSecondaryAngle", vr_type::FLOAT),
+    ((0x0018u16, 0x9465u16), "Positioner Isocenter Detector Rotation Angle", "PositionerIsocenterDetectorRotationAngle", vr_type::FLOAT),
+    ((0x0018u16, 0x9466u16), "Table X Position to Isocenter", "TableXPositionToIsocenter", vr_type::FLOAT),
+    ((0x0018u16, 0x9467u16), "Table Y Position to Isocenter", "TableYPositionToIsocenter", vr_type::FLOAT),
+    ((0x0018u16, 0x9468u16), "Table Z Position to Isocenter", "TableZPositionToIsocenter", vr_type::FLOAT),
+    ((0x0018u16, 0x9469u16), "Table Horizontal Rotation Angle", "TableHorizontalRotationAngle", vr_type::FLOAT),
+    ((0x0018u16, 0x9470u16), "Table Head Tilt Angle", "TableHeadTiltAngle", vr_type::FLOAT),
+    ((0x0018u16, 0x9471u16), "Table Cradle Tilt Angle", "TableCradleTiltAngle", vr_type::FLOAT),
+    ((0x0018u16, 0x9472u16), "Frame Display Shutter Sequence", "FrameDisplayShutterSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9473u16), "Acquired Image Area Dose Product", "AcquiredImageAreaDoseProduct", vr_type::FLOAT),
+    ((0x0018u16, 0x9474u16), "C-arm Positioner Tabletop Relationship", "CArmPositionerTabletopRelationship", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9476u16), "X-Ray Geometry Sequence", "XRayGeometrySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9477u16), "Irradiation Event Identification Sequence", "IrradiationEventIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9504u16), "X-Ray 3D Frame Type Sequence", "XRay3DFrameTypeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9506u16), "Contributing Sources Sequence", "ContributingSourcesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9507u16), "X-Ray 3D Acquisition Sequence", "XRay3DAcquisitionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9508u16), "Primary Positioner Scan Arc", "PrimaryPositionerScanArc", vr_type::FLOAT),
+    ((0x0018u16, 0x9509u16), "Secondary Positioner Scan Arc", "SecondaryPositionerScanArc", vr_type::FLOAT),
+    ((0x0018u16, 0x9510u16), "Primary Positioner Scan Start Angle", "PrimaryPositionerScanStartAngle", vr_type::FLOAT),
+    ((0x0018u16, 0x9511u16), "Secondary Positioner Scan Start Angle", "SecondaryPositionerScanStartAngle", vr_type::FLOAT),
+    ((0x0018u16, 0x9514u16), "Primary Positioner Increment", "PrimaryPositionerIncrement", vr_type::FLOAT),
+    ((0x0018u16, 0x9515u16), "Secondary Positioner Increment", "SecondaryPositionerIncrement", vr_type::FLOAT),
+    ((0x0018u16, 0x9516u16), "Start Acquisition DateTime", "StartAcquisitionDateTime", vr_type::DATE_TIME),
+    ((0x0018u16, 0x9517u16), "End Acquisition DateTime", "EndAcquisitionDateTime", vr_type::DATE_TIME),
+    ((0x0018u16, 0x9524u16), "Application Name", "ApplicationName", vr_type::LONG_STRING),
+    ((0x0018u16, 0x9525u16), "Application Version", "ApplicationVersion", vr_type::LONG_STRING),
+    ((0x0018u16, 0x9526u16), "Application Manufacturer", "ApplicationManufacturer", vr_type::LONG_STRING),
+    ((0x0018u16, 0x9527u16), "Algorithm Type", "AlgorithmType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9528u16), "Algorithm Description", "AlgorithmDescription", vr_type::LONG_STRING),
+    ((0x0018u16, 0x9530u16), "X-Ray 3D Reconstruction Sequence", "XRay3DReconstructionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9531u16), "Reconstruction Description", "ReconstructionDescription", vr_type::LONG_STRING),
+    ((0x0018u16, 0x9538u16), "Per Projection Acquisition Sequence", "PerProjectionAcquisitionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9601u16), "Diffusion b-matrix Sequence", "DiffusionBMatrixSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9602u16), "Diffusion b-value XX", "DiffusionBValueXX", vr_type::DOUBLE),
+    ((0x0018u16, 0x9603u16), "Diffusion b-value XY", "DiffusionBValueXY", vr_type::DOUBLE),
+    ((0x0018u16, 0x9604u16), "Diffusion b-value XZ", "DiffusionBValueXZ", vr_type::DOUBLE),
+    ((0x0018u16, 0x9605u16), "Diffusion b-value YY", "DiffusionBValueYY", vr_type::DOUBLE),
+    ((0x0018u16, 0x9606u16), "Diffusion b-value YZ", "DiffusionBValueYZ", vr_type::DOUBLE),
+    ((0x0018u16, 0x9607u16), "Diffusion b-value ZZ", "DiffusionBValueZZ", vr_type::DOUBLE),
+    ((0x0018u16, 0x9701u16), "Decay Correction DateTime", "DecayCorrectionDateTime", vr_type::DATE_TIME),
+    ((0x0018u16, 0x9715u16), "Start Density Threshold", "StartDensityThreshold", vr_type::DOUBLE),
+    ((0x0018u16, 0x9716u16), "Start Relative Density Difference Threshold", "StartRelativeDensityDifferenceThreshold", vr_type::DOUBLE),
+    ((0x0018u16, 0x9717u16), "Start Cardiac Trigger Count Threshold", "StartCardiacTriggerCountThreshold", vr_type::DOUBLE),
+    ((0x0018u16, 0x9718u16), "Start Respiratory Trigger Count Threshold", "StartRespiratoryTriggerCountThreshold", vr_type::DOUBLE),
+    ((0x0018u16, 0x9719u16), "Termination Counts Threshold", "TerminationCountsThreshold", vr_type::DOUBLE),
+    ((0x0018u16, 0x9720u16), "Termination Density Threshold", "TerminationDensityThreshold", vr_type::DOUBLE),
+    ((0x0018u16, 0x9721u16), "Termination Relative Density Threshold", "TerminationRelativeDensityThreshold", vr_type::DOUBLE),
+    ((0x0018u16, 0x9722u16), "Termination Time Threshold", "TerminationTimeThreshold", vr_type::DOUBLE),
+    ((0x0018u16, 0x9723u16), "Termination Cardiac Trigger Count Threshold", "TerminationCardiacTriggerCountThreshold", vr_type::DOUBLE),
+    ((0x0018u16, 0x9724u16), "Termination Respiratory Trigger Count Threshold", "TerminationRespiratoryTriggerCountThreshold", vr_type::DOUBLE),
+    ((0x0018u16, 0x9725u16), "Detector Geometry", "DetectorGeometry", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9726u16), "Transverse Detector Separation", "TransverseDetectorSeparation", vr_type::DOUBLE),
+    ((0x0018u16, 0x9727u16), "Axial Detector Dimension", "AxialDetectorDimension", vr_type::DOUBLE),
+    ((0x0018u16, 0x9729u16), "Radiopharmaceutical Agent Number", "RadiopharmaceuticalAgentNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9732u16), "PET Frame Acquisition Sequence", "PETFrameAcquisitionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9733u16), "PET Detector Motion Details Sequence", "PETDetectorMotionDetailsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9734u16), "PET Table Dynamics Sequence", "PETTableDynamicsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9735u16), "PET Position Sequence", "PETPositionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9736u16), "PET Frame Correction Factors Sequence", "PETFrameCorrectionFactorsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9737u16), "Radiopharmaceutical Usage Sequence", "RadiopharmaceuticalUsageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9738u16), "Attenuation Correction Source", "AttenuationCorrectionSource", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9739u16), "Number of Iterations", "NumberOfIterations", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9740u16), "Number of Subsets", "NumberOfSubsets", vr_type::UNSIGNED_SHORT),
+    ((0x0018u16, 0x9749u16), "PET Reconstruction Sequence", "PETReconstructionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9751u16), "PET Frame Type Sequence", "PETFrameTypeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9755u16), "Time of Flight Information Used", "TimeOfFlightInformationUsed", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9756u16), "Reconstruction Type", "ReconstructionType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9758u16), "Decay Corrected", "DecayCorrected", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9759u16), "Attenuation Corrected", "AttenuationCorrected", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9760u16), "Scatter Corrected", "ScatterCorrected", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9761u16), "Dead Time Corrected", "DeadTimeCorrected", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9762u16), "Gantry Motion Corrected", "GantryMotionCorrected", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9763u16), "Patient Motion Corrected", "PatientMotionCorrected", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9764u16), "Count Loss Normalization Corrected", "CountLossNormalizationCorrected", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9765u16), "Randoms Corrected", "RandomsCorrected", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9766u16), "Non-uniform Radial Sampling Corrected", "NonUniformRadialSamplingCorrected", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9767u16), "Sensitivity Calibrated", "SensitivityCalibrated", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9768u16), "Detector Normalization Correction", "DetectorNormalizationCorrection", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9769u16), "Iterative Reconstruction Method", "IterativeReconstructionMethod", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9770u16), "Attenuation Correction Temporal Relationship", "AttenuationCorrectionTemporalRelationship", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9771u16), "Patient Physiological State Sequence", "PatientPhysiologicalStateSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9772u16), "Patient Physiological State Code Sequence", "PatientPhysiologicalStateCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9801u16), "Depth(s) of Focus", "DepthsOfFocus", vr_type::DOUBLE),
+    ((0x0018u16, 0x9803u16), "Excluded Intervals Sequence", "ExcludedIntervalsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9804u16), "Exclusion Start DateTime", "ExclusionStartDateTime", vr_type::DATE_TIME),
+    ((0x0018u16, 0x9805u16), "Exclusion Duration", "ExclusionDuration", vr_type::DOUBLE),
+    ((0x0018u16, 0x9806u16), "US Image Description Sequence", "USImageDescriptionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9807u16), "Image Data Type Sequence", "ImageDataTypeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x9808u16), "Data Type", "DataType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x9809u16), "Transducer Scan Pattern Code Sequence", "TransducerScanPatternCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x980Bu16), "Aliased Data Type", "AliasedDataType", vr_type::CODE_STRING),
+    ((0x0018u16, 0x980Cu16), "Position Measuring Device Used", "PositionMeasuringDeviceUsed", vr_type::CODE_STRING),
+    ((0x0018u16, 0x980Du16), "Transducer Geometry Code Sequence", "TransducerGeometryCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x980Eu16), "Transducer Beam Steering Code Sequence", "TransducerBeamSteeringCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0x980Fu16), "Transducer Application Code Sequence", "TransducerApplicationCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0xA001u16), "Contributing Equipment Sequence", "ContributingEquipmentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0018u16, 0xA002u16), "Contribution DateTime", "ContributionDateTime", vr_type::DATE_TIME),
+    ((0x0018u16, 0xA003u16), "Contribution Description", "ContributionDescription", vr_type::SHORT_TEXT),
+    ((0x0020u16, 0x0000u16), "Group 0020 Length", "", vr_type::UNSIGNED_LONG),
+    (STUDY_INSTANCE_UID,     "Study Instance Uid", "StudyInstanceUID", vr_type::UID),
+    (SERIES_INSTANCE_UID,    "Series Instance Uid", "SeriesInstanceUID", vr_type::UID),
+    ((0x0020u16, 0x0010u16), "Study ID", "StudyID", vr_type::SHORT_STRING),
+    ((0x0020u16, 0x0011u16), "Series Number", "SeriesNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0012u16), "Acquisition Number", "AcquisitionNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0013u16), "Instance Number", "InstanceNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0014u16), "Isotope Number", "IsotopeNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0015u16), "Phase Number", "PhaseNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0016u16), "Interval Number", "IntervalNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0017u16), "Time Slot Number", "TimeSlotNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0018u16), "Angle Number", "AngleNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0019u16), "Item Number", "ItemNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0020u16), "Patient Orientation", "PatientOrientation", vr_type::CODE_STRING),
+    ((0x0020u16, 0x0022u16), "Overlay Number", "OverlayNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0024u16), "Curve Number", "CurveNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0026u16), "LUT Number", "LUTNumber", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0030u16), "Image Position", "ImagePosition", vr_type::DECIMAL_STRING),
+    (POSITION,               "Image Position (Patient)", "ImagePositionPatient", vr_type::DECIMAL_STRING),
+    ((0x0020u16, 0x0035u16), "Image Orientation", "ImageOrientation", vr_type::DECIMAL_STRING),
+    ((0x0020u16, 0x0037u16), "Image Orientation (Patient)", "ImageOrientationPatient", vr_type::DECIMAL_STRING),
+    ((0x0020u16, 0x0050u16), "Location", "Location", vr_type::DECIMAL_STRING),
+    ((0x0020u16, 0x0052u16), "Frame of Reference Uid", "FrameOfReferenceUID", vr_type::UID),
+    ((0x0020u16, 0x0060u16), "Laterality", "Laterality", vr_type::CODE_STRING),
+    ((0x0020u16, 0x0062u16), "Image Laterality", "ImageLaterality", vr_type::CODE_STRING),
+    ((0x0020u16, 0x0070u16), "Image Geometry Type", "ImageGeometryType", vr_type::LONG_STRING),
+    ((0x0020u16, 0x0080u16), "Masking Image", "MaskingImage", vr_type::CODE_STRING),
+    ((0x0020u16, 0x0100u16), "Temporal Position Identifier", "TemporalPositionIdentifier", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0105u16), "Number of Temporal Positions", "NumberOfTemporalPositions", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x0110u16), "Temporal Resolution", "TemporalResolution", vr_type::DECIMAL_STRING),
+    ((0x0020u16, 0x0200u16), "Synchronization Frame of Reference Uid", "SynchronizationFrameOfReferenceUID", vr_type::UID),
+    ((0x0020u16, 0x0242u16), "SOP Instance Uid of Concatenation Source", "SOPInstanceUIDOfConcatenationSource", vr_type::UID),
+    ((0x0020u16, 0x1000u16), "Series in Study", "SeriesInStudy", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1001u16), "Acquisitions in Series", "AcquisitionsInSeries", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1002u16), "Images in Acquisition", "ImagesInAcquisition", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1003u16), "Images in Series", "ImagesInSeries", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1004u16), "Acquisitions in Study", "AcquisitionsInStudy", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1005u16), "Images in Study", "ImagesInStudy", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1020u16), "Reference", "Reference", vr_type::CODE_STRING),
+    ((0x0020u16, 0x1040u16), "Position Reference Indicator", "PositionReferenceIndicator", vr_type::LONG_STRING),
+    (LOCATION,               "Slice Location", "SliceLocation", vr_type::DECIMAL_STRING),
+    ((0x0020u16, 0x1070u16), "Other Study Numbers", "OtherStudyNumbers", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1200u16), "Number of Patient Related Studies", "NumberOfPatientRelatedStudies", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1202u16), "Number of Patient Related Series", "NumberOfPatientRelatedSeries", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1204u16), "Number of Patient Related Instances", "NumberOfPatientRelatedInstances", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1206u16), "Number of Study Related Series", "NumberOfStudyRelatedSeries", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1208u16), "Number of Study Related Instances", "NumberOfStudyRelatedInstances", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x1209u16), "Number of Series Related Instances", "NumberOfSeriesRelatedInstances", vr_type::INTEGER_STRING),
+    ((0x0020u16, 0x3100u16), "Source Image IDs", "SourceImageIDs", vr_type::CODE_STRING),
+    ((0x0020u16, 0x3401u16), "Modifying Device ID", "ModifyingDeviceID", vr_type::CODE_STRING),
+    ((0x0020u16, 0x3402u16), "Modified Image ID", "ModifiedImageID", vr_type::CODE_STRING),
+    ((0x0020u16, 0x3403u16), "Modified Image Date", "ModifiedImageDate", vr_type::DATE),
+    ((0x0020u16, 0x3404u16), "Modifying Device Manufacturer", "ModifyingDeviceManufacturer", vr_type::LONG_STRING),
+    ((0x0020u16, 0x3405u16), "Modified Image Time", "ModifiedImageTime", vr_type::TIME),
+    ((0x0020u16, 0x3406u16), "Modified Image Description", "ModifiedImageDescription", vr_type::LONG_STRING),
+    ((0x0020u16, 0x4000u16), "Image Comments", "ImageComments", vr_type::LONG_TEXT),
+    ((0x0020u16, 0x5000u16), "Original Image Identification", "OriginalImageIdentification", vr_type::ATTRIBUTE),
+    ((0x0020u16, 0x5002u16), "Original Image Identification Nomenclature", "OriginalImageIdentificationNomenclature", vr_type::CODE_STRING),
+    ((0x0020u16, 0x9056u16), "Stack ID", "StackID", vr_type::SHORT_STRING),
+    ((0x0020u16, 0x9057u16), "In-Stack Position Number", "InStackPositionNumber", vr_type::UNSIGNED_LONG),
+    ((0x0020u16, 0x9071u16), "Frame Anatomy Sequence", "FrameAnatomySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x9072u16), "Frame Laterality", "FrameLaterality", vr_type::CODE_STRING),
+    ((0x0020u16, 0x9111u16), "Frame Content Sequence", "FrameContentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x9113u16), "Plane Position Sequence", "PlanePositionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x9116u16), "Plane Orientation Sequence", "PlaneOrientationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x9128u16), "Temporal Position Index", "TemporalPositionIndex", vr_type::UNSIGNED_LONG),
+    ((0x0020u16, 0x9153u16), "Nominal Cardiac Trigger Delay Time", "NominalCardiacTriggerDelayTime", vr_type::DOUBLE),
+    ((0x0020u16, 0x9156u16), "Frame Acquisition Number", "FrameAcquisitionNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0020u16, 0x9157u16), "Dimension Index Values", "DimensionIndexValues", vr_type::UNSIGNED_LONG),
+    ((0x0020u16, 0x9158u16), "Frame Comments", "FrameComments", vr_type::LONG_TEXT),
+    ((0x0020u16, 0x9161u16), "Concatenation Uid", "ConcatenationUID", vr_type::UID),
+    ((0x0020u16, 0x9162u16), "In-Concatenation Number", "InConcatenationNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0020u16, 0x9163u16), "In-Concatenation Total Number", "InConcatenationTotalNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0020u16, 0x9164u16), "Dimension Organization Uid", "DimensionOrganizationUID", vr_type::UID),
+    ((0x0020u16, 0x9165u16), "Dimension Index Pointer", "DimensionIndexPointer", vr_type::ATTRIBUTE),
+    ((0x0020u16, 0x9167u16), "Functional Group Pointer", "FunctionalGroupPointer", vr_type::ATTRIBUTE),
+    ((0x0020u16, 0x9213u16), "Dimension Index Private Creator", "DimensionIndexPrivateCreator", vr_type::LONG_STRING),
+    ((0x0020u16, 0x9221u16), "Dimension Organization Sequence", "DimensionOrganizationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x9222u16), "Dimension Index Sequence", "DimensionIndexSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x9228u16), "Concatenation Frame Offset Number", "ConcatenationFrameOffsetNumber", vr_type::UNSIGNED_LONG),
+    ((0x0020u16, 0x9238u16), "Functional Group Private Creator", "FunctionalGroupPrivateCreator", vr_type::LONG_STRING),
+    ((0x0020u16, 0x9241u16), "Nominal Percentage of Cardiac Phase", "NominalPercentageOfCardiacPhase", vr_type::FLOAT),
+    ((0x0020u16, 0x9245u16), "Nominal Percentage of Respiratory Phase", "NominalPercentageOfRespiratoryPhase", vr_type::FLOAT),
+    ((0x0020u16, 0x9246u16), "Starting Respiratory Amplitude", "StartingRespiratoryAmplitude", vr_type::FLOAT),
+    ((0x0020u16, 0x9247u16), "Starting Respiratory Phase", "StartingRespiratoryPhase", vr_type::CODE_STRING),
+    ((0x0020u16, 0x9248u16), "Ending Respiratory Amplitude", "EndingRespiratoryAmplitude", vr_type::FLOAT),
+    ((0x0020u16, 0x9249u16), "Ending Respiratory Phase", "EndingRespiratoryPhase", vr_type::CODE_STRING),
+    ((0x0020u16, 0x9250u16), "Respiratory Trigger Type", "RespiratoryTriggerType", vr_type::CODE_STRING),
+    ((0x0020u16, 0x9251u16), "R-R Interval Time Nominal", "RRIntervalTimeNominal", vr_type::DOUBLE),
+    ((0x0020u16, 0x9252u16), "Actual Cardiac Trigger Delay Time", "ActualCardiacTriggerDelayTime", vr_type::DOUBLE),
+    ((0x0020u16, 0x9253u16), "Respiratory Synchronization Sequence", "RespiratorySynchronizationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x9254u16), "Respiratory Interval Time", "RespiratoryIntervalTime", vr_type::DOUBLE),
+    ((0x0020u16, 0x9255u16), "Nominal Respiratory Trigger Delay Time", "NominalRespiratoryTriggerDelayTime", vr_type::DOUBLE),
+    ((0x0020u16, 0x9256u16), "Respiratory Trigger Delay Threshold", "RespiratoryTriggerDelayThreshold", vr_type::DOUBLE),
+    ((0x0020u16, 0x9257u16), "Actual Respiratory Trigger Delay Time", "ActualRespiratoryTriggerDelayTime", vr_type::DOUBLE),
+    ((0x0020u16, 0x9301u16), "Image Position (Volume)", "ImagePositionVolume", vr_type::DOUBLE),
+    ((0x0020u16, 0x9302u16), "Image Orientation (Volume)", "ImageOrientationVolume", vr_type::DOUBLE),
+    ((0x0020u16, 0x9307u16), "Ultrasound Acquisition Geometry", "UltrasoundAcquisitionGeometry", vr_type::CODE_STRING),
+    ((0x0020u16, 0x9308u16), "Apex Position", "ApexPosition", vr_type::DOUBLE),
+    ((0x0020u16, 0x9309u16), "Volume to Transducer Mapping Matrix", "VolumeToTransducerMappingMatrix", vr_type::DOUBLE),
+    ((0x0020u16, 0x930Au16), "Volume to Table Mapping Matrix", "VolumeToTableMappingMatrix", vr_type::DOUBLE),
+    ((0x0020u16, 0x930Cu16), "Patient Frame of Reference Source", "PatientFrameOfReferenceSource", vr_type::CODE_STRING),
+    ((0x0020u16, 0x930Du16), "Temporal Position Time Offset", "TemporalPositionTimeOffset", vr_type::DOUBLE),
+    ((0x0020u16, 0x930Eu16), "Plane Position (Volume) Sequence", "PlanePositionVolumeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x930Fu16), "Plane Orientation (Volume) Sequence", "PlaneOrientationVolumeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x9310u16), "Temporal Position Sequence", "TemporalPositionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x9311u16), "Dimension Organization Type", "DimensionOrganizationType", vr_type::CODE_STRING),
+    ((0x0020u16, 0x9312u16), "Volume Frame of Reference Uid", "VolumeFrameOfReferenceUID", vr_type::UID),
+    ((0x0020u16, 0x9313u16), "Table Frame of Reference Uid", "TableFrameOfReferenceUID", vr_type::UID),
+    ((0x0020u16, 0x9421u16), "Dimension Description Label", "DimensionDescriptionLabel", vr_type::LONG_STRING),
+    ((0x0020u16, 0x9450u16), "Patient Orientation in Frame Sequence", "PatientOrientationInFrameSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x9453u16), "Frame Label", "FrameLabel", vr_type::LONG_STRING),
+    ((0x0020u16, 0x9518u16), "Acquisition Index", "AcquisitionIndex", vr_type::UNSIGNED_SHORT),
+    ((0x0020u16, 0x9529u16), "Contributing SOP Instances Reference Sequence", "ContributingSOPInstancesReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0020u16, 0x9536u16), "Reconstruction Index", "ReconstructionIndex", vr_type::UNSIGNED_SHORT),
+    ((0x0022u16, 0x0001u16), "Light Path Filter Pass-Through Wavelength", "LightPathFilterPassThroughWavelength", vr_type::UNSIGNED_SHORT),
+    ((0x0022u16, 0x0002u16), "Light Path Filter Pass Band", "LightPathFilterPassBand", vr_type::UNSIGNED_SHORT),
+    ((0x0022u16, 0x0003u16), "Image Path Filter Pass-Through Wavelength", "ImagePathFilterPassThroughWavelength", vr_type::UNSIGNED_SHORT),
+    ((0x0022u16, 0x0004u16), "Image Path Filter Pass Band", "ImagePathFilterPassBand", vr_type::UNSIGNED_SHORT),
+    ((0x0022u16, 0x0005u16), "Patient Eye Movement Commanded", "PatientEyeMovementCommanded", vr_type::CODE_STRING),
+    ((0x0022u16, 0x0006u16), "Patient Eye Movement Command Code Sequence", "PatientEyeMovementCommandCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x0007u16), "Spherical Lens Power", "SphericalLensPower", vr_type::FLOAT),
+    ((0x0022u16, 0x0008u16), "Cylinder Lens Power", "CylinderLensPower", vr_type::FLOAT),
+    ((0x0022u16, 0x0009u16), "Cylinder Axis", "CylinderAxis", vr_type::FLOAT),
+    ((0x0022u16, 0x000Au16), "Emmetropic Magnification", "EmmetropicMagnification", vr_type::FLOAT),
+    ((0x0022u16, 0x000Bu16), "Intra Ocular Pressure", "IntraOcularPressure", vr_type::FLOAT),
+    ((0x0022u16, 0x000Cu16), "Horizontal Field of View", "HorizontalFieldOfView", vr_type::FLOAT),
+    ((0x0022u16, 0x000Du16), "Pupil Dilated", "PupilDilated", vr_type::CODE_STRING),
+    ((0x0022u16, 0x000Eu16), "Degree of Dilation", "DegreeOfDilation", vr_type::FLOAT),
+    ((0x0022u16, 0x0010u16), "Stereo Baseline Angle", "StereoBaselineAngle", vr_type::FLOAT),
+    ((0x0022u16, 0x0011u16), "Stereo Baseline Displacement", "StereoBaselineDisplacement", vr_type::FLOAT),
+    ((0x0022u16, 0x0012u16), "Stereo Horizontal Pixel Offset", "StereoHorizontalPixelOffset", vr_type::FLOAT),
+    ((0x0022u16, 0x0013u16), "Stereo Vertical Pixel Offset", "StereoVerticalPixelOffset", vr_type::FLOAT),
+    ((0x0022u16, 0x0014u16), "Stereo Rotation", "StereoRotation", vr_type::FLOAT),
+    ((0x0022u16, 0x0015u16), "Acquisition Device Type Code Sequence", "AcquisitionDeviceTypeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x0016u16), "Illumination Type Code Sequence", "IlluminationTypeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x0017u16), "Light Path Filter Type Stack Code Sequence", "LightPathFilterTypeStackCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x0018u16), "Image Path Filter Type Stack Code Sequence", "ImagePathFilterTypeStackCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x0019u16), "Lenses Code Sequence", "LensesCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x001Au16), "Channel Description Code Sequence", "ChannelDescriptionCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x001Bu16), "Refractive State Sequence", "RefractiveStateSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x001Cu16), "Mydriatic Agent Code Sequence", "MydriaticAgentCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x001Du16), "Relative Image Position Code Sequence", "RelativeImagePositionCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x0020u16), "Stereo Pairs Sequence", "StereoPairsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x0021u16), "Left Image Sequence", "LeftImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x0022u16), "Right Image Sequence", "RightImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x0030u16), "Axial Length of the Eye", "AxialLengthOfTheEye", vr_type::FLOAT),
+    ((0x0022u16, 0x0031u16), "Ophthalmic Frame Location Sequence", "OphthalmicFrameLocationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x0032u16), "Reference Coordinates", "ReferenceCoordinates", vr_type::FLOAT),
+    ((0x0022u16, 0x0035u16), "Depth Spatial Resolution", "DepthSpatialResolution", vr_type::FLOAT),
+    ((0x0022u16, 0x0036u16), "Maximum Depth Distortion", "MaximumDepthDistortion", vr_type::FLOAT),
+    ((0x0022u16, 0x0037u16), "Along-Scan Spatial Resolution", "AlongScanSpatialResolution", vr_type::FLOAT),
+    ((0x0022u16, 0x0038u16), "Maximum Along-Scan Distortion", "MaximumAlongScanDistortion", vr_type::FLOAT),
+    ((0x0022u16, 0x0039u16), "Ophthalmic Image Orientation", "OphthalmicImageOrientation", vr_type::CODE_STRING),
+    ((0x0022u16, 0x0041u16), "Depth of Transverse Image", "DepthOfTransverseImage", vr_type::FLOAT),
+    ((0x0022u16, 0x0042u16), "Mydriatic Agent Concentration Units Sequence", "MydriaticAgentConcentrationUnitsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0022u16, 0x0048u16), "Across-Scan Spatial Resolution", "AcrossScanSpatialResolution", vr_type::FLOAT),
+    ((0x0022u16, 0x0049u16), "Maximum Across-Scan Distortion", "MaximumAcrossScanDistortion", vr_type::FLOAT),
+    ((0x0022u16, 0x004Eu16), "Mydriatic Agent Concentration", "MydriaticAgentConcentration", vr_type::DECIMAL_STRING),
+    ((0x0022u16, 0x0055u16), "Illumination Wave Length", "IlluminationWaveLength", vr_type::FLOAT),
+    ((0x0022u16, 0x0056u16), "Illumination Power", "IlluminationPower", vr_type::FLOAT),
+    ((0x0022u16, 0x0057u16), "Illumination Bandwidth", "IlluminationBandwidth", vr_type::FLOAT),
+    ((0x0022u16, 0x0058u16), "Mydriatic Agent Sequence", "MydriaticAgentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x0000u16), "Group 0028 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0028u16, 0x0002u16), "Samples per Pixel", "SamplesPerPixel", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0003u16), "Samples per Pixel Used", "SamplesPerPixelUsed", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0004u16), "Photometric Interpretation", "PhotometricInterpretation", vr_type::CODE_STRING),
+    ((0x0028u16, 0x0005u16), "Image Dimensions", "ImageDimensions", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0006u16), "Planar Configuration", "PlanarConfiguration", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0008u16), "Number of Frames", "NumberOfFrames", vr_type::INTEGER_STRING),
+    ((0x0028u16, 0x0009u16), "Frame Increment Pointer", "FrameIncrementPointer", vr_type::ATTRIBUTE),
+    ((0x0028u16, 0x000Au16), "Frame Dimension Pointer", "FrameDimensionPointer", vr_type::ATTRIBUTE),
+    (ROWS,                   "Rows", "Rows", vr_type::UNSIGNED_SHORT),
+    (COLUMNS,                "Columns", "Columns", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0012u16), "Planes", "Planes", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0014u16), "Ultrasound Color Data Present", "UltrasoundColorDataPresent", vr_type::UNSIGNED_SHORT),
+    (PIXEL_SPACING,          "Pixel Spacing", "PixelSpacing", vr_type::DECIMAL_STRING),
+    ((0x0028u16, 0x0031u16), "Zoom Factor", "ZoomFactor", vr_type::DECIMAL_STRING),
+    ((0x0028u16, 0x0032u16), "Zoom Center", "ZoomCenter", vr_type::DECIMAL_STRING),
+    ((0x0028u16, 0x0034u16), "Pixel Aspect Ratio", "PixelAspectRatio", vr_type::INTEGER_STRING),
+    ((0x0028u16, 0x0040u16), "Image Format", "ImageFormat", vr_type::CODE_STRING),
+    ((0x0028u16, 0x0050u16), "Manipulated Image", "ManipulatedImage", vr_type::LONG_STRING),
+    ((0x0028u16, 0x0051u16), "Corrected Image", "CorrectedImage", vr_type::CODE_STRING),
+    ((0x0028u16, 0x005Fu16), "Compression Recognition Code", "CompressionRecognitionCode", vr_type::LONG_STRING),
+    ((0x0028u16, 0x0060u16), "Compression Code", "CompressionCode", vr_type::CODE_STRING),
+    ((0x0028u16, 0x0061u16), "Compression Originator", "CompressionOriginator", vr_type::SHORT_STRING),
+    ((0x0028u16, 0x0062u16), "Compression Label", "CompressionLabel", vr_type::LONG_STRING),
+    ((0x0028u16, 0x0063u16), "Compression Description", "CompressionDescription", vr_type::SHORT_STRING),
+    ((0x0028u16, 0x0065u16), "Compression Sequence", "CompressionSequence", vr_type::CODE_STRING),
+    ((0x0028u16, 0x0066u16), "Compression Step Pointers", "CompressionStepPointers", vr_type::ATTRIBUTE),
+    ((0x0028u16, 0x0068u16), "Repeat Interval", "RepeatInterval", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0069u16), "Bits Grouped", "BitsGrouped", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0070u16), "Perimeter Table", "PerimeterTable", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0071u16), "Perimeter Value", "PerimeterValue", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0080u16), "Predictor Rows", "PredictorRows", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0081u16), "Predictor Columns", "PredictorColumns", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0082u16), "Predictor Constants", "PredictorConstants", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0090u16), "Blocked Pixels", "BlockedPixels", vr_type::CODE_STRING),
+    ((0x0028u16, 0x0091u16), "Block Rows", "BlockRows", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0092u16), "Block Columns", "BlockColumns", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0093u16), "Row Overlap", "RowOverlap", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0094u16), "Column Overlap", "ColumnOverlap", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0100u16), "Bits Allocated", "BitsAllocated", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0101u16), "Bits Stored", "BitsStored", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0102u16), "High Bit", "HighBit", vr_type::UNSIGNED_SHORT),
+    (PIXEL_REPRESENTATION,   "Pixel Representation", "PixelRepresentation", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0104u16), "Smallest Valid Pixel Value", "SmallestValidPixelValue", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0105u16), "Largest Valid Pixel Value", "LargestValidPixelValue", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0106u16), "Smallest Image Pixel Value", "SmallestImagePixelValue", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0107u16), "Largest Image Pixel Value", "LargestImagePixelValue", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0108u16), "Smallest Pixel Value in Series", "SmallestPixelValueInSeries", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0109u16), "Largest Pixel Value in Series", "LargestPixelValueInSeries", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0110u16), "Smallest Image Pixel Value in Plane", "SmallestImagePixelValueInPlane", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0111u16), "Largest Image Pixel Value in Plane", "LargestImagePixelValueInPlane", vr_type::UNSIGNED_SHORT),
+    (PIXEL_PADDING,          "Pixel Padding Value", "PixelPaddingValue", vr_type::UNSIGNED_SHORT),
+    (PIXEL_PADDING_RANGE_LIMIT, "Pixel Padding Range Limit", "PixelPaddingRangeLimit", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0200u16), "Image Location", "ImageLocation", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0300u16), "Quality Control Image", "QualityControlImage", vr_type::CODE_STRING),
+    ((0x0028u16, 0x0301u16), "Burned In Annotation", "BurnedInAnnotation", vr_type::CODE_STRING),
+    ((0x0028u16, 0x0400u16), "Transform Label", "TransformLabel", vr_type::LONG_STRING),
+    ((0x0028u16, 0x0401u16), "Transform Version Number", "TransformVersionNumber", vr_type::LONG_STRING),
+    ((0x0028u16, 0x0402u16), "Number of Transform Steps", "NumberOfTransformSteps", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0403u16), "Sequence of Compressed Data", "SequenceOfCompressedData", vr_type::LONG_STRING),
+    ((0x0028u16, 0x0404u16), "Details of Coefficients", "DetailsOfCoefficients", vr_type::ATTRIBUTE),
+    ((0x0028u16, 0x0410u16), "Rows For Nth Order Coefficients", "RowsForNthOrderCoefficients", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0411u16), "Columns For Nth Order Coefficients", "ColumnsForNthOrderCoefficients", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0412u16), "Coefficient Coding", "CoefficientCoding", vr_type::LONG_STRING),
+    ((0x0028u16, 0x0413u16), "Coefficient Coding Pointers", "CoefficientCodingPointers", vr_type::ATTRIBUTE),
+    ((0x0028u16, 0x0700u16), "DCT Label", "DCTLabel", vr_type::LONG_STRING),
+    ((0x0028u16, 0x0701u16), "Data Block Description", "DataBlockDescription", vr_type::CODE_STRING),
+    ((0x0028u16, 0x0702u16), "Data Block", "DataBlock", vr_type::ATTRIBUTE),
+    ((0x0028u16, 0x0710u16), "Normalization Factor Format", "NormalizationFactorFormat", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0720u16), "Zonal Map Number Format", "ZonalMapNumberFormat", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0721u16), "Zonal Map Location", "ZonalMapLocation", vr_type::ATTRIBUTE),
+    ((0x0028u16, 0x0722u16), "Zonal Map Format", "ZonalMapFormat", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0730u16), "Adaptive Map Format", "AdaptiveMapFormat", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0740u16), "Code Number Format", "CodeNumberFormat", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0800u16), "Code Label", "CodeLabel", vr_type::CODE_STRING),
+    ((0x0028u16, 0x0802u16), "Number of Tables", "NumberOfTables", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0803u16), "Code Table Location", "CodeTableLocation", vr_type::ATTRIBUTE),
+    ((0x0028u16, 0x0804u16), "Bits For Code Word", "BitsForCodeWord", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x0808u16), "Image Data Location", "ImageDataLocation", vr_type::ATTRIBUTE),
+    ((0x0028u16, 0x0A02u16), "Pixel Spacing Calibration Type", "PixelSpacingCalibrationType", vr_type::CODE_STRING),
+    ((0x0028u16, 0x0A04u16), "Pixel Spacing Calibration Description", "PixelSpacingCalibrationDescription", vr_type::LONG_STRING),
+    ((0x0028u16, 0x1040u16), "Pixel Intensity Relationship", "PixelIntensityRelationship", vr_type::CODE_STRING),
+    ((0x0028u16, 0x1041u16), "Pixel Intensity Relationship Sign", "PixelIntensityRelationshipSign", vr_type::SIGNED_SHORT),
+    (WINDOW_CENTER,          "Window Center", "WindowCenter", vr_type::DECIMAL_STRING),
+    (WINDOW_WIDTH,           "Window Width", "WindowWidth", vr_type::DECIMAL_STRING),
+    (RESCALE_INTERCEPT,      "Rescale Intercept", "RescaleIntercept", vr_type::DECIMAL_STRING),
+    (RESCALE_SLOPE,          "Rescale Slope", "RescaleSlope", vr_type::DECIMAL_STRING),
+    ((0x0028u16, 0x1054u16), "Rescale Type", "RescaleType", vr_type::LONG_STRING),
+    ((0x0028u16, 0x1055u16), "Window Center and Width Explanation", "WindowCenterAndWidthExplanation", vr_type::LONG_STRING),
+    ((0x0028u16, 0x1056u16), "VOI LUT Function", "VOILUTFunction", vr_type::CODE_STRING),
+    ((0x0028u16, 0x1080u16), "Gray Scale", "GrayScale", vr_type::CODE_STRING),
+    ((0x0028u16, 0x1090u16), "Recommended Viewing Mode", "RecommendedViewingMode", vr_type::CODE_STRING),
+    ((0x0028u16, 0x1100u16), "Gray Lookup Table Descriptor", "GrayLookupTableDescriptor", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x1101u16), "Red Palette Color Lookup Table Descriptor", "RedPaletteColorLookupTableDescriptor", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x1102u16), "Green Palette Color Lookup Table Descriptor", "GreenPaletteColorLookupTableDescriptor", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x1103u16), "Blue Palette Color Lookup Table Descriptor", "BluePaletteColorLookupTableDescriptor", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x1104u16), "Alpha Palette Color Lookup Table Descriptor", "AlphaPaletteColorLookupTableDescriptor", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x1111u16), "Large Red Palette Color Lookup Table Descriptor", "LargeRedPaletteColorLookupTableDescriptor", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x1112u16), "Large Green Palette Color Lookup Table Descriptor", "LargeGreenPaletteColorLookupTableDescriptor", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x1113u16), "Large Blue Palette Color Lookup Table Descriptor", "LargeBluePaletteColorLookupTableDescriptor", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x1199u16), "Palette Color Lookup Table Uid", "PaletteColorLookupTableUID", vr_type::UID),
+    ((0x0028u16, 0x1200u16), "Gray Lookup Table Data", "GrayLookupTableData", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x1201u16), "Red Palette Color Lookup Table Data", "RedPaletteColorLookupTableData", vr_type::OTHER_WORD),
+    ((0x0028u16, 0x1202u16), "Green Palette Color Lookup Table Data", "GreenPaletteColorLookupTableData", vr_type::OTHER_WORD),
+    ((0x0028u16, 0x1203u16), "Blue Palette Color Lookup Table Data", "BluePaletteColorLookupTableData", vr_type::OTHER_WORD),
+    ((0x0028u16, 0x1204u16), "Alpha Palette Color Lookup Table Data", "AlphaPaletteColorLookupTableData", vr_type::OTHER_WORD),
+    ((0x0028u16, 0x1211u16), "Large Red Palette Color Lookup Table Data", "LargeRedPaletteColorLookupTableData", vr_type::OTHER_WORD),
+    ((0x0028u16, 0x1212u16), "Large Green Palette Color Lookup Table Data", "LargeGreenPaletteColorLookupTableData", vr_type::OTHER_WORD),
+    ((0x0028u16, 0x1213u16), "Large Blue Palette Color Lookup Table Data", "LargeBluePaletteColorLookupTableData", vr_type::OTHER_WORD),
+    ((0x0028u16, 0x1214u16), "Large Palette Color Lookup Table Uid", "LargePaletteColorLookupTableUID", vr_type::UID),
+    ((0x0028u16, 0x1221u16), "Segmented Red Palette Color Lookup Table Data", "SegmentedRedPaletteColorLookupTableData", vr_type::OTHER_WORD),
+    ((0x0028u16, 0x1222u16), "Segmented Green Palette Color Lookup Table Data", "SegmentedGreenPaletteColorLookupTableData", vr_type::OTHER_WORD),
+    ((0x0028u16, 0x1223u16), "Segmented Blue Palette Color Lookup Table Data", "SegmentedBluePaletteColorLookupTableData", vr_type::OTHER_WORD),
+    ((0x0028u16, 0x1300u16), "Breast Implant Present", "BreastImplantPresent", vr_type::CODE_STRING),
+    ((0x0028u16, 0x1350u16), "Partial View", "PartialView", vr_type::CODE_STRING),
+    ((0x0028u16, 0x1351u16), "Partial View Description", "PartialViewDescription", vr_type::SHORT_TEXT),
+    ((0x0028u16, 0x1352u16), "Partial View Code Sequence", "PartialViewCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x135Au16), "Spatial Locations Preserved", "SpatialLocationsPreserved", vr_type::CODE_STRING),
+    ((0x0028u16, 0x1401u16), "Data Frame Assignment Sequence", "DataFrameAssignmentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x1402u16), "Data Path Assignment", "DataPathAssignment", vr_type::CODE_STRING),
+    ((0x0028u16, 0x1403u16), "Bits Mapped to Color Lookup Table", "BitsMappedToColorLookupTable", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x1404u16), "Blending LUT 1 Sequence", "BlendingLUT1Sequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x1405u16), "Blending LUT 1 Transfer Function", "BlendingLUT1TransferFunction", vr_type::CODE_STRING),
+    ((0x0028u16, 0x1406u16), "Blending Weight Constant", "BlendingWeightConstant", vr_type::DOUBLE),
+    ((0x0028u16, 0x1407u16), "Blending Lookup Table Descriptor", "BlendingLookupTableDescriptor", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x1408u16), "Blending Lookup Table Data", "BlendingLookupTableData", vr_type::OTHER_WORD),
+    ((0x0028u16, 0x140Bu16), "Enhanced Palette Color Lookup Table Sequence", "EnhancedPaletteColorLookupTableSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x140Cu16), "Blending LUT 2 Sequence", "BlendingLUT2Sequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x140Du16), "Blending LUT 2 Transfer Function", "BlendingLUT2TransferFunction", vr_type::CODE_STRING),
+    ((0x0028u16, 0x140Eu16), "Data Path ID", "DataPathID", vr_type::CODE_STRING),
+    ((0x0028u16, 0x140Fu16), "RGB LUT Transfer Function", "RGBLUTTransferFunction", vr_type::CODE_STRING),
+    ((0x0028u16, 0x1410u16), "Alpha LUT Transfer Function", "AlphaLUTTransferFunction", vr_type::CODE_STRING),
+    ((0x0028u16, 0x2000u16), "ICC Profile", "ICCProfile", vr_type::OTHER_BYTE),
+    ((0x0028u16, 0x2110u16), "Lossy Image Compression", "LossyImageCompression", vr_type::CODE_STRING),
+    ((0x0028u16, 0x2112u16), "Lossy Image Compression Ratio", "LossyImageCompressionRatio", vr_type::DECIMAL_STRING),
+    ((0x0028u16, 0x2114u16), "Lossy Image Compression Method", "LossyImageCompressionMethod", vr_type::CODE_STRING),
+    ((0x0028u16, 0x3000u16), "Modality LUT Sequence", "ModalityLUTSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x3002u16), "LUT Descriptor", "LUTDescriptor", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x3003u16), "LUT Explanation", "LUTExplanation", vr_type::LONG_STRING),
+    ((0x0028u16, 0x3004u16), "Modality LUT Type", "ModalityLUTType", vr_type::LONG_STRING),
+    ((0x0028u16, 0x3006u16), "LUT Data", "LUTData", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x3010u16), "VOI LUT Sequence", "VOILUTSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x3110u16), "Softcopy VOI LUT Sequence", "SoftcopyVOILUTSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x4000u16), "Image Presentation Comments", "ImagePresentationComments", vr_type::LONG_TEXT),
+    ((0x0028u16, 0x5000u16), "Bi-Plane Acquisition Sequence", "BiPlaneAcquisitionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x6010u16), "Representative Frame Number", "RepresentativeFrameNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x6020u16), "Frame Numbers of Interest (FOI)", "FrameNumbersOfInterest", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x6022u16), "Frame of Interest Description", "FrameOfInterestDescription", vr_type::LONG_STRING),
+    ((0x0028u16, 0x6023u16), "Frame of Interest Type", "FrameOfInterestType", vr_type::CODE_STRING),
+    ((0x0028u16, 0x6030u16), "Mask Pointer(s)", "MaskPointers", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x6040u16), "R Wave Pointer", "RWavePointer", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x6100u16), "Mask Subtraction Sequence", "MaskSubtractionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x6101u16), "Mask Operation", "MaskOperation", vr_type::CODE_STRING),
+    ((0x0028u16, 0x6102u16), "Applicable Frame Range", "ApplicableFrameRange", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x6110u16), "Mask Frame Numbers", "MaskFrameNumbers", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x6112u16), "Contrast Frame Averaging", "ContrastFrameAveraging", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x6114u16), "Mask Sub-pixel Shift", "MaskSubPixelShift", vr_type::FLOAT),
+    ((0x0028u16, 0x6120u16), "TID Offset", "TIDOffset", vr_type::SIGNED_SHORT),
+    ((0x0028u16, 0x6190u16), "Mask Operation Explanation", "MaskOperationExplanation", vr_type::SHORT_TEXT),
+    ((0x0028u16, 0x7FE0u16), "Pixel Data Provider URL", "PixelDataProviderURL", vr_type::UNIVERSAL_RESOURCE),
+    ((0x0028u16, 0x9001u16), "Data Point Rows", "DataPointRows", vr_type::UNSIGNED_LONG),
+    ((0x0028u16, 0x9002u16), "Data Point Columns", "DataPointColumns", vr_type::UNSIGNED_LONG),
+    ((0x0028u16, 0x9003u16), "Signal Domain Columns", "SignalDomainColumns", vr_type::CODE_STRING),
+    ((0x0028u16, 0x9099u16), "Largest Monochrome Pixel Value", "LargestMonochromePixelValue", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x9108u16), "Data Representation", "DataRepresentation", vr_type::CODE_STRING),
+    ((0x0028u16, 0x9110u16), "Pixel Measures Sequence", "PixelMeasuresSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x9132u16), "Frame VOI LUT Sequence", "FrameVOILUTSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x9145u16), "Pixel Value Transformation Sequence", "PixelValueTransformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x9235u16), "Signal Domain Rows", "SignalDomainRows", vr_type::CODE_STRING),
+    ((0x0028u16, 0x9411u16), "Display Filter Percentage", "DisplayFilterPercentage", vr_type::FLOAT),
+    ((0x0028u16, 0x9415u16), "Frame Pixel Shift Sequence", "FramePixelShiftSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x9416u16), "Subtraction Item ID", "SubtractionItemID", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x9422u16), "Pixel Intensity Relationship LUT Sequence", "PixelIntensityRelationshipLUTSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x9443u16), "Frame Pixel Data Properties Sequence", "FramePixelDataPropertiesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x9444u16), "Geometrical Properties", "GeometricalProperties", vr_type::CODE_STRING),
+    ((0x0028u16, 0x9445u16), "Geometric Maximum Distortion", "GeometricMaximumDistortion", vr_type::FLOAT),
+    ((0x0028u16, 0x9446u16), "Image Processing Applied", "ImageProcessingApplied", vr_type::CODE_STRING),
+    ((0x0028u16, 0x9454u16), "Mask Selection Mode", "MaskSelectionMode", vr_type::CODE_STRING),
+    ((0x0028u16, 0x9474u16), "LUT Function", "LUTFunction", vr_type::CODE_STRING),
+    ((0x0028u16, 0x9478u16), "Mask Visibility Percentage", "MaskVisibilityPercentage", vr_type::FLOAT),
+    ((0x0028u16, 0x9501u16), "Pixel Shift Sequence", "PixelShiftSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x9502u16), "Region Pixel Shift Sequence", "RegionPixelShiftSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x9503u16), "Vertices of the Region", "VerticesOfTheRegion", vr_type::SIGNED_SHORT),
+    ((0x0028u16, 0x9505u16), "Multi-Frame Presentation Sequence", "MultiFramePresentationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0028u16, 0x9506u16), "Pixel Shift Frame Range", "PixelShiftFrameRange", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x9507u16), "LUT Frame Range", "LUTFrameRange", vr_type::UNSIGNED_SHORT),
+    ((0x0028u16, 0x9520u16), "Image to Equipment Mapping Matrix", "ImageToEquipmentMappingMatrix", vr_type::DECIMAL_STRING),
+    ((0x0028u16, 0x9537u16), "Equipment Coordinate System Identification", "EquipmentCoordinateSystemIdentification", vr_type::CODE_STRING),
+    ((0x0032u16, 0x0000u16), "Group 0032 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0032u16, 0x000Au16), "Study Status ID", "StudyStatusID", vr_type::CODE_STRING),
+    ((0x0032u16, 0x000Cu16), "Study Priority ID", "StudyPriorityID", vr_type::CODE_STRING),
+    ((0x0032u16, 0x0012u16), "Study ID Issuer", "StudyIDIssuer", vr_type::LONG_STRING),
+    ((0x0032u16, 0x0032u16), "Study Verified Date", "StudyVerifiedDate", vr_type::DATE),
+    ((0x0032u16, 0x0033u16), "Study Verified Time", "StudyVerifiedTime", vr_type::TIME),
+    ((0x0032u16, 0x0034u16), "Study Read Date", "StudyReadDate", vr_type::DATE),
+    ((0x0032u16, 0x0035u16), "Study Read Time", "StudyReadTime", vr_type::TIME),
+    ((0x0032u16, 0x1000u16), "Scheduled Study Start Date", "ScheduledStudyStartDate", vr_type::DATE),
+    ((0x0032u16, 0x1001u16), "Scheduled Study Start Time", "ScheduledStudyStartTime", vr_type::TIME),
+    ((0x0032u16, 0x1010u16), "Scheduled Study Stop Date", "ScheduledStudyStopDate", vr_type::DATE),
+    ((0x0032u16, 0x1011u16), "Scheduled Study Stop Time", "ScheduledStudyStopTime", vr_type::TIME),
+    ((0x0032u16, 0x1020u16), "Scheduled Study Location", "ScheduledStudyLocation", vr_type::LONG_STRING),
+    ((0x0032u16, 0x1021u16), "Scheduled Study Location AE Title", "ScheduledStudyLocationAETitle", vr_type::APPLICATION_ENTITY),
+    ((0x0032u16, 0x1030u16), "Reason for Study", "ReasonForStudy", vr_type::LONG_STRING),
+    ((0x0032u16, 0x1031u16), "Requesting Physician Identification Sequence", "RequestingPhysicianIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0032u16, 0x1032u16), "Requesting Physician", "RequestingPhysician", vr_type::PERSON_NAME),
+    ((0x0032u16, 0x1033u16), "Requesting Service", "RequestingService", vr_type::LONG_STRING),
+    ((0x0032u16, 0x1034u16), "Requesting Service Code Sequence", "RequestingServiceCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0032u16, 0x1040u16), "Study Arrival Date", "StudyArrivalDate", vr_type::DATE),
+    ((0x0032u16, 0x1041u16), "Study Arrival Time", "StudyArrivalTime", vr_type::TIME),
+    ((0x0032u16, 0x1050u16), "Study Completion Date", "StudyCompletionDate", vr_type::DATE),
+    ((0x0032u16, 0x1051u16), "Study Completion Time", "StudyCompletionTime", vr_type::TIME),
+    ((0x0032u16, 0x1055u16), "Study Component Status ID", "StudyComponentStatusID", vr_type::CODE_STRING),
+    ((0x0032u16, 0x1060u16), "Requested Procedure Description", "RequestedProcedureDescription", vr_type::LONG_STRING),
+    ((0x0032u16, 0x1064u16), "Requested Procedure Code Sequence", "RequestedProcedureCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0032u16, 0x1070u16), "Requested Contrast Agent", "RequestedContrastAgent", vr_type::LONG_STRING),
+    ((0x0032u16, 0x4000u16), "Study Comments", "StudyComments", vr_type::LONG_TEXT),
+    ((0x0038u16, 0x0000u16), "Group 0038 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0038u16, 0x0004u16), "Referenced Patient Alias Sequence", "ReferencedPatientAliasSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0038u16, 0x0008u16), "Visit Status ID", "VisitStatusID", vr_type::CODE_STRING),
+    ((0x0038u16, 0x0010u16), "Admission ID", "AdmissionID", vr_type::LONG_STRING),
+    ((0x0038u16, 0x0011u16), "Issuer of Admission ID", "IssuerOfAdmissionID", vr_type::LONG_STRING),
+    ((0x0038u16, 0x0014u16), "Issuer of Admission ID Sequence", "IssuerOfAdmissionIDSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0038u16, 0x0016u16), "Route of Admissions", "RouteOfAdmissions", vr_type::LONG_STRING),
+    ((0x0038u16, 0x001Au16), "Scheduled Admission Date", "ScheduledAdmissionDate", vr_type::DATE),
+    ((0x0038u16, 0x001Bu16), "Scheduled Admission Time", "ScheduledAdmissionTime", vr_type::TIME),
+    ((0x0038u16, 0x001Cu16), "Scheduled Discharge Date", "ScheduledDischargeDate", vr_type::DATE),
+    ((0x0038u16, 0x001Du16), "Scheduled Discharge Time", "ScheduledDischargeTime", vr_type::TIME),
+    ((0x0038u16, 0x001Eu16), "Scheduled Patient Institution Residence", "ScheduledPatientInstitutionResidence", vr_type::LONG_STRING),
+    ((0x0038u16, 0x0020u16), "Admitting Date", "AdmittingDate", vr_type::DATE),
+    ((0x0038u16, 0x0021u16), "Admitting Time", "AdmittingTime", vr_type::TIME),
+    ((0x0038u16, 0x0030u16), "Discharge Date", "DischargeDate", vr_type::DATE),
+    ((0x0038u16, 0x0032u16), "Discharge Time", "DischargeTime", vr_type::TIME),
+    ((0x0038u16, 0x0040u16), "Discharge Diagnosis Description", "DischargeDiagnosisDescription", vr_type::LONG_STRING),
+    ((0x0038u16, 0x0044u16), "Discharge Diagnosis Code Sequence", "DischargeDiagnosisCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0038u16, 0x0050u16), "Special Needs", "SpecialNeeds", vr_type::LONG_STRING),
+    ((0x0038u16, 0x0060u16), "Service Episode ID", "ServiceEpisodeID", vr_type::LONG_STRING),
+    ((0x0038u16, 0x0061u16), "Issuer of Service Episode ID", "IssuerOfServiceEpisodeID", vr_type::LONG_STRING),
+    ((0x0038u16, 0x0062u16), "Service Episode Description", "ServiceEpisodeDescription", vr_type::LONG_STRING),
+    ((0x0038u16, 0x0064u16), "Issuer of Service Episode ID Sequence", "IssuerOfServiceEpisodeIDSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0038u16, 0x0100u16), "Pertinent Documents Sequence", "PertinentDocumentsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0038u16, 0x0300u16), "Current Patient Location", "CurrentPatientLocation", vr_type::LONG_STRING),
+    ((0x0038u16, 0x0400u16), "Patient's Institution Residence", "PatientInstitutionResidence", vr_type::LONG_STRING),
+    ((0x0038u16, 0x0500u16), "Patient State", "PatientState", vr_type::LONG_STRING),
+    ((0x0038u16, 0x0502u16), "Patient Clinical Trial Participation Sequence", "PatientClinicalTrialParticipationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0038u16, 0x4000u16), "Visit Comments", "VisitComments", vr_type::LONG_TEXT),
+    ((0x003Au16, 0x0000u16), "Group 003A Length", "", vr_type::UNSIGNED_LONG),
+    ((0x003Au16, 0x0004u16), "Waveform Originality", "WaveformOriginality", vr_type::CODE_STRING),
+    ((0x003Au16, 0x0005u16), "Number of Waveform Channels", "NumberOfWaveformChannels", vr_type::UNSIGNED_SHORT),
+    ((0x003Au16, 0x0010u16), "Number of Waveform Samples", "NumberOfWaveformSamples", vr_type::UNSIGNED_LONG),
+    ((0x003Au16, 0x001Au16), "Sampling Frequency", "SamplingFrequency", vr_type::DECIMAL_STRING),
+    ((0x003Au16, 0x0020u16), "Multiplex Group Label", "MultiplexGroupLabel", vr_type::SHORT_STRING),
+    ((0x003Au16, 0x0200u16), "Channel Definition Sequence", "ChannelDefinitionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x003Au16, 0x0202u16), "Waveform Channel Number", "WaveformChannelNumber", vr_type::INTEGER_STRING),
+    ((0x003Au16, 0x0203u16), "Channel Label", "ChannelLabel", vr_type::SHORT_STRING),
+    ((0x003Au16, 0x0205u16), "Channel Status", "ChannelStatus", vr_type::CODE_STRING),
+    ((0x003Au16, 0x0208u16), "Channel Source Sequence", "ChannelSourceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x003Au16, 0x0209u16), "Channel Source Modifiers Sequence", "ChannelSourceModifiersSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x003Au16, 0x020Au16), "Source Waveform Sequence", "SourceWaveformSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x003Au16, 0x020Cu16), "Channel Derivation Description", "ChannelDerivationDescription", vr_type::LONG_STRING),
+    ((0x003Au16, 0x0210u16), "Channel Sensitivity", "ChannelSensitivity", vr_type::DECIMAL_STRING),
+    ((0x003Au16, 0x0211u16), "Channel Sensitivity Units Sequence", "ChannelSensitivityUnitsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x003Au16, 0x0212u16), "Channel Sensitivity Correction Factor", "ChannelSensitivityCorrectionFactor", vr_type::DECIMAL_STRING),
+    ((0x003Au16, 0x0213u16), "Channel Baseline", "ChannelBaseline", vr_type::DECIMAL_STRING),
+    ((0x003Au16, 0x0214u16), "Channel Time Skew", "ChannelTimeSkew", vr_type::DECIMAL_STRING),
+    ((0x003Au16, 0x0215u16), "Channel Sample Skew", "ChannelSampleSkew", vr_type::DECIMAL_STRING),
+    ((0x003Au16, 0x0218u16), "Channel Offset", "ChannelOffset", vr_type::DECIMAL_STRING),
+    ((0x003Au16, 0x021Au16), "Waveform Bits Stored", "WaveformBitsStored", vr_type::UNSIGNED_SHORT),
+    ((0x003Au16, 0x0220u16), "Filter Low Frequency", "FilterLowFrequency", vr_type::DECIMAL_STRING),
+    ((0x003Au16, 0x0221u16), "Filter High Frequency", "FilterHighFrequency", vr_type::DECIMAL_STRING),
+    ((0x003Au16, 0x0222u16), "Notch Filter Frequency", "NotchFilterFrequency", vr_type::DECIMAL_STRING),
+    ((0x003Au16, 0x0223u16), "Notch Filter Bandwidth", "NotchFilterBandwidth", vr_type::DECIMAL_STRING),
+    ((0x003Au16, 0x0230u16), "Waveform Data Display Scale", "WaveformDataDisplayScale", vr_type::FLOAT),
+    ((0x003Au16, 0x0231u16), "Waveform Display Background CIELab Value", "WaveformDisplayBackgroundCIELabValue", vr_type::UNSIGNED_SHORT),
+    ((0x003Au16, 0x0240u16), "Waveform Presentation Group Sequence", "WaveformPresentationGroupSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x003Au16, 0x0241u16), "Presentation Group Number", "PresentationGroupNumber", vr_type::UNSIGNED_SHORT),
+    ((0x003Au16, 0x0242u16), "Channel Display Sequence", "ChannelDisplaySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x003Au16, 0x0244u16), "Channel Recommended Display CIELab Value", "ChannelRecommendedDisplayCIELabValue", vr_type::UNSIGNED_SHORT),
+    ((0x003Au16, 0x0245u16), "Channel Position", "ChannelPosition", vr_type::FLOAT),
+    ((0x003Au16, 0x0246u16), "Display Shading Flag", "DisplayShadingFlag", vr_type::CODE_STRING),
+    ((0x003Au16, 0x0247u16), "Fractional Channel Display Scale", "FractionalChannelDisplayScale", vr_type::FLOAT),
+    ((0x003Au16, 0x0248u16), "Absolute Channel Display Scale", "AbsoluteChannelDisplayScale", vr_type::FLOAT),
+    ((0x003Au16, 0x0300u16), "Multiplexed Audio Channels Description Code Sequence", "MultiplexedAudioChannelsDescriptionCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x003Au16, 0x0301u16), "Channel Identification Code", "ChannelIdentificationCode", vr_type::INTEGER_STRING),
+    ((0x003Au16, 0x0302u16), "Channel Mode", "ChannelMode", vr_type::CODE_STRING),
+    ((0x0040u16, 0x0000u16), "Group 0040 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0040u16, 0x0001u16), "Scheduled Station AE Title", "ScheduledStationAETitle", vr_type::APPLICATION_ENTITY),
+    ((0x0040u16, 0x0002u16), "Scheduled Procedure Step Start Date", "ScheduledProcedureStepStartDate", vr_type::DATE),
+    ((0x0040u16, 0x0003u16), "Scheduled Procedure Step Start Time", "ScheduledProcedureStepStartTime", vr_type::TIME),
+    ((0x0040u16, 0x0004u16), "Scheduled Procedure Step End Date", "ScheduledProcedureStepEndDate", vr_type::DATE),
+    ((0x0040u16, 0x0005u16), "Scheduled Procedure Step End Time", "ScheduledProcedureStepEndTime", vr_type::TIME),
+    ((0x0040u16, 0x0006u16), "Scheduled Performing Physician's Name", "ScheduledPerformingPhysicianName", vr_type::PERSON_NAME),
+    ((0x0040u16, 0x0007u16), "Scheduled Procedure Step Description", "ScheduledProcedureStepDescription", vr_type::LONG_STRING),
+    ((0x0040u16, 0x0008u16), "Scheduled Protocol Code Sequence", "ScheduledProtocolCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0009u16), "Scheduled Procedure Step ID", "ScheduledProcedureStepID", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x000Au16), "Stage Code Sequence", "StageCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x000Bu16), "Scheduled Performing Physician Identification Sequence", "ScheduledPerformingPhysicianIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0010u16), "Scheduled Station Name", "ScheduledStationName", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x0011u16), "Scheduled Procedure Step Location", "ScheduledProcedureStepLocation", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x0012u16), "Pre-Medication", "PreMedication", vr_type::LONG_STRING),
+    ((0x0040u16, 0x0020u16), "Scheduled Procedure Step Status", "ScheduledProcedureStepStatus", vr_type::CODE_STRING),
+    ((0x0040u16, 0x0026u16), "Order Placer Identifier Sequence", "OrderPlacerIdentifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0027u16), "Order Filler Identifier Sequence", "OrderFillerIdentifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0031u16), "Local Namespace Entity ID", "LocalNamespaceEntityID", vr_type::UNLIMITED_TEXT),
+    ((0x0040u16, 0x0032u16), "Universal Entity ID", "UniversalEntityID", vr_type::UNLIMITED_TEXT),
+    ((0x0040u16, 0x0033u16), "Universal Entity ID Type", "UniversalEntityIDType", vr_type::CODE_STRING),
+    ((0x0040u16, 0x0035u16), "Identifier Type Code", "IdentifierTypeCode", vr_type::CODE_STRING),
+    ((0x0040u16, 0x0036u16), "Assigning Facility Sequence", "AssigningFacilitySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0039u16), "Assigning Jurisdiction Code Sequence", "AssigningJurisdictionCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x003Au16), "Assigning Agency or Department Code Sequence", "AssigningAgencyOrDepartmentCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0100u16), "Scheduled Procedure Step Sequence", "ScheduledProcedureStepSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0220u16), "Referenced Non-Image Composite SOP Instance Sequence", "ReferencedNonImageCompositeSOPInstanceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0241u16), "Performed Station AE Title", "PerformedStationAETitle", vr_type::APPLICATION_ENTITY),
+    ((0x0040u16, 0x0242u16), "Performed Station Name", "PerformedStationName", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x0243u16), "Performed Location", "PerformedLocation", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x0244u16), "Performed Procedure Step Start Date", "PerformedProcedureStepStartDate", vr_type::DATE),
+    ((0x0040u16, 0x0245u16), "Performed Procedure Step Start Time", "PerformedProcedureStepStartTime", vr_type::TIME),
+    ((0x0040u16, 0x0250u16), "Performed Procedure Step End Date", "PerformedProcedureStepEndDate", vr_type::DATE),
+    ((0x0040u16, 0x0251u16), "Performed Procedure Step End Time", "PerformedProcedureStepEndTime", vr_type::TIME),
+    ((0x0040u16, 0x0252u16), "Performed Procedure Step Status", "PerformedProcedureStepStatus", vr_type::CODE_STRING),
+    ((0x0040u16, 0x0253u16), "Performed Procedure Step ID", "PerformedProcedureStepID", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x0254u16), "Performed Procedure Step Description", "PerformedProcedureStepDescription", vr_type::LONG_STRING),
+    ((0x0040u16, 0x0255u16), "Performed Procedure Type Description", "PerformedProcedureTypeDescription", vr_type::LONG_STRING),
+    ((0x0040u16, 0x0260u16), "Performed Protocol Code Sequence", "PerformedProtocolCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0261u16), "Performed Protocol Type", "PerformedProtocolType", vr_type::CODE_STRING),
+    ((0x0040u16, 0x0270u16), "Scheduled Step Attributes Sequence", "ScheduledStepAttributesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0275u16), "Request Attributes Sequence", "RequestAttributesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0280u16), "Comments on the Performed Procedure Step", "CommentsOnThePerformedProcedureStep", vr_type::SHORT_TEXT),
+    ((0x0040u16, 0x0281u16), "Performed Procedure Step Discontinuation Reason Code Sequence", "PerformedProcedureStepDiscontinuationReasonCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0293u16), "Quantity Sequence", "QuantitySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0294u16), "Quantity", "Quantity", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0x0295u16), "Measuring Units Sequence", "MeasuringUnitsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0296u16), "Billing Item Sequence", "BillingItemSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0300u16), "Total Time of Fluoroscopy", "TotalTimeOfFluoroscopy", vr_type::UNSIGNED_SHORT),
+    ((0x0040u16, 0x0301u16), "Total Number of Exposures", "TotalNumberOfExposures", vr_type::UNSIGNED_SHORT),
+    ((0x0040u16, 0x0302u16), "Entrance Dose", "EntranceDose", vr_type::UNSIGNED_SHORT),
+    ((0x0040u16, 0x0303u16), "Exposed Area", "ExposedArea", vr_type::UNSIGNED_SHORT),
+    ((0x0040u16, 0x0306u16), "Distance Source to Entrance", "DistanceSourceToEntrance", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0x0307u16), "Distance Source to Support", "DistanceSourceToSupport", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0x030Eu16), "Exposure Dose Sequence", "ExposureDoseSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0310u16), "Comments on Radiation Dose", "CommentsOnRadiationDose", vr_type::SHORT_TEXT),
+    ((0x0040u16, 0x0312u16), "X-Ray Output", "XRayOutput", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0x0314u16), "Half Value Layer", "HalfValueLayer", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0x0316u16), "Organ Dose", "OrganDose", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0x0318u16), "Organ Exposed", "OrganExposed", vr_type::CODE_STRING),
+    ((0x0040u16, 0x0320u16), "Billing Procedure Step Sequence", "BillingProcedureStepSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0321u16), "Film Consumption Sequence", "FilmConsumptionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0324u16), "Billing Supplies and Devices Sequence", "BillingSuppliesAndDevicesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0330u16), "Referenced Procedure Step Sequence", "ReferencedProcedureStepSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0340u16), "Performed Series Sequence", "PerformedSeriesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0400u16), "Comments on the Scheduled Procedure Step", "CommentsOnTheScheduledProcedureStep", vr_type::LONG_TEXT),
+    ((0x0040u16, 0x0440u16), "Protocol Context Sequence", "ProtocolContextSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0441u16), "Content Item Modifier Sequence", "ContentItemModifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0500u16), "Scheduled Specimen Sequence", "ScheduledSpecimenSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x050Au16), "Specimen Accession Number", "SpecimenAccessionNumber", vr_type::LONG_STRING),
+    ((0x0040u16, 0x0512u16), "Container Identifier", "ContainerIdentifier", vr_type::LONG_STRING),
+    ((0x0040u16, 0x0513u16), "Issuer of the Container Identifier Sequence", "IssuerOfTheContainerIdentifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0515u16), "Alternate Container Identifier Sequence", "AlternateContainerIdentifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0518u16), "Container Type Code Sequence", "ContainerTypeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x051Au16), "Container Description", "ContainerDescription", vr_type::LONG_STRING),
+    ((0x0040u16, 0x0520u16), "Container Component Sequence", "ContainerComponentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0550u16), "Specimen Sequence", "SpecimenSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0551u16), "Specimen Identifier", "SpecimenIdentifier", vr_type::LONG_STRING),
+    ((0x0040u16, 0x0552u16), "Specimen Description Sequence - Trial", "SpecimenDescriptionSequenceTrial", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0553u16), "Specimen Description - Trial", "SpecimenDescriptionTrial", vr_type::SHORT_TEXT),
+    ((0x0040u16, 0x0554u16), "Specimen Uid", "SpecimenUID", vr_type::UID),
+    ((0x0040u16, 0x0555u16), "Acquisition Context Sequence", "AcquisitionContextSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0556u16), "Acquisition Context Description", "AcquisitionContextDescription", vr_type::SHORT_TEXT),
+    ((0x0040u16, 0x0560u16), "Specimen Description Sequence", "SpecimenDescriptionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0562u16), "Issuer of the Specimen Identifier Sequence", "IssuerOfTheSpecimenIdentifierSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x059Au16), "Specimen Type Code Sequence", "SpecimenTypeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0600u16), "Specimen Short Description", "SpecimenShortDescription", vr_type::LONG_STRING),
+    ((0x0040u16, 0x0602u16), "Specimen Detailed Description", "SpecimenDetailedDescription", vr_type::UNLIMITED_TEXT),
+    ((0x0040u16, 0x0610u16), "Specimen Preparation Sequence", "SpecimenPreparationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0612u16), "Specimen Preparation Step Content Item Sequence", "SpecimenPreparationStepContentItemSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x0620u16), "Specimen Localization Content Item Sequence", "SpecimenLocalizationContentItemSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x06FAu16), "Slide Identifier", "SlideIdentifier", vr_type::LONG_STRING),
+    ((0x0040u16, 0x071Au16), "Image Center Point Coordinates Sequence", "ImageCenterPointCoordinatesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x072Au16), "X offset in Slide Coordinate System", "XOffsetInSlideCoordinateSystem", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0x073Au16), "Y offset in Slide Coordinate System", "YOffsetInSlideCoordinateSystem", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0x074Au16), "Z offset in Slide Coordinate System", "ZOffsetInSlideCoordinateSystem", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0x08D8u16), "Pixel Spacing Sequence", "PixelSpacingSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x08DAu16), "Coordinate System Axis Code Sequence", "CoordinateSystemAxisCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x08EAu16), "Measurement Units Code Sequence", "MeasurementUnitsCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x09F8u16), "Vital Stain Code Sequence - Trial", "VitalStainCodeSequenceTrial", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x1001u16), "Requested Procedure ID", "RequestedProcedureID", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x1002u16), "Reason for the Requested Procedure", "ReasonForTheRequestedProcedure", vr_type::LONG_STRING),
+    ((0x0040u16, 0x1003u16), "Requested Procedure Priority", "RequestedProcedurePriority", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x1004u16), "Patient Transport Arrangements", "PatientTransportArrangements", vr_type::LONG_STRING),
+    ((0x0040u16, 0x1005u16), "Requested Procedure Location", "RequestedProcedureLocation", vr_type::LONG_STRING),
+    ((0x0040u16, 0x1006u16), "Placer Order Number/Procedure", "PlacerOrderNumberProcedure", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x1007u16), "Filler Order Number/Procedure", "FillerOrderNumberProcedure", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x1008u16), "Confidentiality Code", "ConfidentialityCode", vr_type::LONG_STRING),
+    ((0x0040u16, 0x1009u16), "Reporting Priority", "ReportingPriority", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x100Au16), "Reason for Requested Procedure Code Sequence", "ReasonForRequestedProcedureCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x1010u16), "Names of Intended Recipients of Results", "NamesOfIntendedRecipientsOfResults", vr_type::PERSON_NAME),
+    ((0x0040u16, 0x1011u16), "Intended Recipients of Results Identification Sequence", "IntendedRecipientsOfResultsIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x1012u16), "Reason For Performed Procedure Code Sequence", "ReasonForPerformedProcedureCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x1101u16), "Person Identification Code Sequence", "PersonIdentificationCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x1102u16), "Person's Address", "PersonAddress", vr_type::SHORT_TEXT),
+    ((0x0040u16, 0x1103u16), "Person's Telephone Numbers", "PersonTelephoneNumbers", vr_type::LONG_STRING),
+    ((0x0040u16, 0x1400u16), "Requested Procedure Comments", "RequestedProcedureComments", vr_type::LONG_TEXT),
+    ((0x0040u16, 0x2001u16), "Reason for the Imaging Service Request", "ReasonForTheImagingServiceRequest", vr_type::LONG_STRING),
+    ((0x0040u16, 0x2004u16), "Issue Date of Imaging Service Request", "IssueDateOfImagingServiceRequest", vr_type::DATE),
+    ((0x0040u16, 0x2005u16), "Issue Time of Imaging Service Request", "IssueTimeOfImagingServiceRequest", vr_type::TIME),
+    ((0x0040u16, 0x2006u16), "Placer Order Number/Imaging Service Request", "PlacerOrderNumberImagingServiceRequestRetired", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x2007u16), "Filler Order Number/Imaging Service Request", "FillerOrderNumberImagingServiceRequestRetired", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x2008u16), "Order Entered By", "OrderEnteredBy", vr_type::PERSON_NAME),
+    ((0x0040u16, 0x2009u16), "Order Enterer's Location", "OrderEntererLocation", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x2010u16), "Order Callback Phone Number", "OrderCallbackPhoneNumber", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x2016u16), "Placer Order Number/Imaging Service Request", "PlacerOrderNumberImagingServiceRequest", vr_type::LONG_STRING),
+    ((0x0040u16, 0x2017u16), "Filler Order Number/Imaging Service Request", "FillerOrderNumberImagingServiceRequest", vr_type::LONG_STRING),
+    ((0x0040u16, 0x2400u16), "Imaging Service Request Comments", "ImagingServiceRequestComments", vr_type::LONG_TEXT),
+    ((0x0040u16, 0x3001u16), "Confidentiality Constraint on Patient Data Description", "ConfidentialityConstraintOnPatientDataDescription", vr_type::LONG_STRING),
+    ((0x0040u16, 0x4001u16), "General Purpose Scheduled Procedure Step Status", "GeneralPurposeScheduledProcedureStepStatus", vr_type::CODE_STRING),
+    ((0x0040u16, 0x4002u16), "General Purpose Performed Procedure Step Status", "GeneralPurposePerformedProcedureStepStatus", vr_type::CODE_STRING),
+    ((0x0040u16, 0x4003u16), "General Purpose Scheduled Procedure Step Priority", "GeneralPurposeScheduledProcedureStepPriority", vr_type::CODE_STRING),
+    ((0x0040u16, 0x4004u16), "Scheduled Processing Applications Code Sequence", "ScheduledProcessingApplicationsCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4005u16), "Scheduled Procedure Step Start DateTime", "ScheduledProcedureStepStartDateTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0x4006u16), "Multiple Copies Flag", "MultipleCopiesFlag", vr_type::CODE_STRING),
+    ((0x0040u16, 0x4007u16), "Performed Processing Applications Code Sequence", "PerformedProcessingApplicationsCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4009u16), "Human Performer Code Sequence", "HumanPerformerCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4010u16), "Scheduled Procedure Step Modification DateTime", "ScheduledProcedureStepModificationDateTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0x4011u16), "Expected Completion DateTime", "ExpectedCompletionDateTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0x4015u16), "Resulting General Purpose Performed Procedure Steps Sequence", "ResultingGeneralPurposePerformedProcedureStepsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4016u16), "Referenced General Purpose Scheduled Procedure Step Sequence", "ReferencedGeneralPurposeScheduledProcedureStepSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4018u16), "Scheduled Workitem Code Sequence", "ScheduledWorkitemCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4019u16), "Performed Workitem Code Sequence", "PerformedWorkitemCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4020u16), "Input Availability Flag", "InputAvailabilityFlag", vr_type::CODE_STRING),
+    ((0x0040u16, 0x4021u16), "Input Information Sequence", "InputInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4022u16), "Relevant Information Sequence", "RelevantInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4023u16), "Referenced General Purpose Scheduled Procedure Step Transaction Uid", "ReferencedGeneralPurposeScheduledProcedureStepTransactionUID", vr_type::UID),
+    ((0x0040u16, 0x4025u16), "Scheduled Station Name Code Sequence", "ScheduledStationNameCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4026u16), "Scheduled Station Class Code Sequence", "ScheduledStationClassCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4027u16), "Scheduled Station Geographic Location Code Sequence", "ScheduledStationGeographicLocationCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4028u16), "Performed Station Name Code Sequence", "PerformedStationNameCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4029u16), "Performed Station Class Code Sequence", "PerformedStationClassCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4030u16), "Performed Station Geographic Location Code Sequence", "PerformedStationGeographicLocationCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4031u16), "Requested Subsequent Workitem Code Sequence", "RequestedSubsequentWorkitemCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4032u16), "Non-DICOM Output Code Sequence", "NonDICOMOutputCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4033u16), "Output Information Sequence", "OutputInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4034u16), "Scheduled Human Performers Sequence", "ScheduledHumanPerformersSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4035u16), "Actual Human Performers Sequence", "ActualHumanPerformersSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x4036u16), "Human Performer's Organization", "HumanPerformerOrganization", vr_type::LONG_STRING),
+    ((0x0040u16, 0x4037u16), "Human Performer's Name", "HumanPerformerName", vr_type::PERSON_NAME),
+    ((0x0040u16, 0x4040u16), "Raw Data Handling", "RawDataHandling", vr_type::CODE_STRING),
+    ((0x0040u16, 0x4041u16), "Input Readiness State", "InputReadinessState", vr_type::CODE_STRING),
+    ((0x0040u16, 0x4050u16), "Performed Procedure Step Start DateTime", "PerformedProcedureStepStartDateTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0x4051u16), "Performed Procedure Step End DateTime", "PerformedProcedureStepEndDateTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0x4052u16), "Procedure Step Cancellation DateTime", "ProcedureStepCancellationDateTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0x8302u16), "Entrance Dose in mGy", "EntranceDoseInmGy", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0x9094u16), "Referenced Image Real World Value Mapping Sequence", "ReferencedImageRealWorldValueMappingSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x9096u16), "Real World Value Mapping Sequence", "RealWorldValueMappingSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x9098u16), "Pixel Value Mapping Code Sequence", "PixelValueMappingCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0x9210u16), "LUT Label", "LUTLabel", vr_type::SHORT_STRING),
+    ((0x0040u16, 0x9211u16), "Real World Value Last Value Mapped", "RealWorldValueLastValueMapped", vr_type::UNSIGNED_SHORT),
+    ((0x0040u16, 0x9212u16), "Real World Value LUT Data", "RealWorldValueLUTData", vr_type::DOUBLE),
+    ((0x0040u16, 0x9216u16), "Real World Value First Value Mapped", "RealWorldValueFirstValueMapped", vr_type::UNSIGNED_SHORT),
+    ((0x0040u16, 0x9224u16), "Real World Value Intercept", "RealWorldValueIntercept", vr_type::DOUBLE),
+    ((0x0040u16, 0x9225u16), "Real World Value Slope", "RealWorldValueSlope", vr_type::DOUBLE),
+    ((0x0040u16, 0xA010u16), "Relationship Type", "RelationshipType", vr_type::CODE_STRING),
+    ((0x0040u16, 0xA027u16), "Verifying Organization", "VerifyingOrganization", vr_type::LONG_STRING),
+    ((0x0040u16, 0xA030u16), "Verification DateTime", "VerificationDateTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0xA032u16), "Observation DateTime", "ObservationDateTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0xA040u16), "Value Type", "ValueType", vr_type::CODE_STRING),
+    ((0x0040u16, 0xA043u16), "Concept Name Code Sequence", "ConceptNameCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA050u16), "Continuity of Content", "ContinuityOfContent", vr_type::CODE_STRING),
+    ((0x0040u16, 0xA073u16), "Verifying Observer Sequence", "VerifyingObserverSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA075u16), "Verifying Observer Name", "VerifyingObserverName", vr_type::PERSON_NAME),
+    ((0x0040u16, 0xA078u16), "Author Observer Sequence", "AuthorObserverSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA07Au16), "Participant Sequence", "ParticipantSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA07Cu16), "Custodial Organization Sequence", "CustodialOrganizationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA080u16), "Participation Type", "ParticipationType", vr_type::CODE_STRING),
+    ((0x0040u16, 0xA082u16), "Participation DateTime", "ParticipationDateTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0xA084u16), "Observer Type", "ObserverType", vr_type::CODE_STRING),
+    ((0x0040u16, 0xA088u16), "Verifying Observer Identification Code Sequence", "VerifyingObserverIdentificationCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA090u16), "Equivalent CDA Document Sequence", "EquivalentCDADocumentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA0A0u16), "Type of Reference", "TypeOfReference", vr_type::CODE_STRING),
+    ((0x0040u16, 0xA0B0u16), "Referenced Waveform Channels", "ReferencedWaveformChannels", vr_type::UNSIGNED_SHORT),
+    ((0x0040u16, 0xA120u16), "DateTime", "DateTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0xA121u16), "Date", "Date", vr_type::DATE),
+    ((0x0040u16, 0xA122u16), "Time", "Time", vr_type::TIME),
+    ((0x0040u16, 0xA123u16), "Person Name", "PersonName", vr_type::PERSON_NAME),
+    ((0x0040u16, 0xA124u16), "Uid", "UID", vr_type::UID),
+    ((0x0040u16, 0xA130u16), "Temporal Range Type", "TemporalRangeType", vr_type::CODE_STRING),
+    ((0x0040u16, 0xA132u16), "Referenced Sample Positions", "ReferencedSamplePositions", vr_type::UNSIGNED_LONG),
+    ((0x0040u16, 0xA136u16), "Referenced Frame Numbers", "ReferencedFrameNumbers", vr_type::UNSIGNED_SHORT),
+    ((0x0040u16, 0xA138u16), "Referenced Time Offsets", "ReferencedTimeOffsets", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0xA13Au16), "Referenced DateTime", "ReferencedDateTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0xA160u16), "Text Value", "TextValue", vr_type::UNLIMITED_TEXT),
+    ((0x0040u16, 0xA168u16), "Concept Code Sequence", "ConceptCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA170u16), "Purpose of Reference Code Sequence", "PurposeOfReferenceCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA180u16), "Annotation Group Number", "AnnotationGroupNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0040u16, 0xA195u16), "Modifier Code Sequence", "ModifierCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA290u16), "Coordinate Geometric Type", "CoordinateGeometricType", vr_type::LONG_STRING),
+    ((0x0040u16, 0xA29Au16), "Pixel Coordinate Set", "PixelCoordinateSet", vr_type::UNSIGNED_SHORT),
+    ((0x0040u16, 0xA300u16), "Measured Value Sequence", "MeasuredValueSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA301u16), "Numeric Value Qualifier Code Sequence", "NumericValueQualifierCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA30Au16), "Numeric Value", "NumericValue", vr_type::DECIMAL_STRING),
+    ((0x0040u16, 0xA353u16), "Address - Trial", "AddressTrial", vr_type::SHORT_TEXT),
+    ((0x0040u16, 0xA354u16), "Telephone Number - Trial", "TelephoneNumberTrial", vr_type::LONG_STRING),
+    ((0x0040u16, 0xA360u16), "Predecessor Documents Sequence", "PredecessorDocumentsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA370u16), "Referenced Request Sequence", "ReferencedRequestSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA372u16), "Performed Procedure Code Sequence", "PerformedProcedureCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA375u16), "Current Requested Procedure Evidence Sequence", "CurrentRequestedProcedureEvidenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA385u16), "Pertinent Other Evidence Sequence", "PertinentOtherEvidenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA390u16), "HL7 Structured Document Reference Sequence", "HL7StructuredDocumentReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA491u16), "Completion Flag", "CompletionFlag", vr_type::CODE_STRING),
+    ((0x0040u16, 0xA492u16), "Completion Flag Description", "CompletionFlagDescription", vr_type::LONG_STRING),
+    ((0x0040u16, 0xA493u16), "Verification Flag", "VerificationFlag", vr_type::CODE_STRING),
+    ((0x0040u16, 0xA494u16), "Archive Requested", "ArchiveRequested", vr_type::CODE_STRING),
+    ((0x0040u16, 0xA496u16), "Preliminary Flag", "PreliminaryFlag", vr_type::CODE_STRING),
+    ((0x0040u16, 0xA504u16), "Content Template Sequence", "ContentTemplateSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA525u16), "Identical Documents Sequence", "IdenticalDocumentsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xA730u16), "Content Sequence", "ContentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xB020u16), "Waveform Annotation Sequence", "WaveformAnnotationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xDB00u16), "Template Identifier", "TemplateIdentifier", vr_type::CODE_STRING),
+    ((0x0040u16, 0xDB06u16), "Template Version", "TemplateVersion", vr_type::DATE_TIME),
+    ((0x0040u16, 0xDB07u16), "Template Local Version", "TemplateLocalVersion", vr_type::DATE_TIME),
+    ((0x0040u16, 0xDB0Bu16), "Template Extension Flag", "TemplateExtensionFlag", vr_type::CODE_STRING),
+    ((0x0040u16, 0xDB0Cu16), "Template Extension Organization Uid", "TemplateExtensionOrganizationUID", vr_type::UID),
+    ((0x0040u16, 0xDB0Du16), "Template Extension Creator Uid", "TemplateExtensionCreatorUID", vr_type::UID),
+    ((0x0040u16, 0xDB73u16), "Referenced Content Item Identifier", "ReferencedContentItemIdentifier", vr_type::UNSIGNED_LONG),
+    ((0x0040u16, 0xE001u16), "HL7 Instance Identifier", "HL7InstanceIdentifier", vr_type::SHORT_TEXT),
+    ((0x0040u16, 0xE004u16), "HL7 Document Effective Time", "HL7DocumentEffectiveTime", vr_type::DATE_TIME),
+    ((0x0040u16, 0xE006u16), "HL7 Document Type Code Sequence", "HL7DocumentTypeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xE010u16), "Retrieve URI", "RetrieveURI", vr_type::UNIVERSAL_RESOURCE),
+    ((0x0040u16, 0xE011u16), "Retrieve Location Uid", "RetrieveLocationUID", vr_type::UID),
+    ((0x0040u16, 0xE020u16), "Type of Instances", "TypeOfInstances", vr_type::CODE_STRING),
+    ((0x0040u16, 0xE021u16), "DICOM Retrieval Sequence", "DICOMRetrievalSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xE022u16), "DICOM Media Retrieval Sequence", "DICOMMediaRetrievalSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xE023u16), "WADO Retrieval Sequence", "WADORetrievalSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xE024u16), "XDS Retrieval Sequence", "XDSRetrievalSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0040u16, 0xE031u16), "Repository Unique ID", "RepositoryUniqueID", vr_type::UID),
+    ((0x0040u16, 0xE032u16), "Home Community ID", "HomeCommunityID", vr_type::UID),
+    ((0x0042u16, 0x0010u16), "Document Title", "DocumentTitle", vr_type::SHORT_TEXT),
+    ((0x0042u16, 0x0011u16), "Encapsulated Document", "EncapsulatedDocument", vr_type::OTHER_BYTE),
+    ((0x0042u16, 0x0012u16), "MIME Type of Encapsulated Document", "MIMETypeOfEncapsulatedDocument", vr_type::LONG_STRING),
+    ((0x0042u16, 0x0013u16), "Source Instance Sequence", "SourceInstanceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0042u16, 0x0014u16), "List of MIME Types", "ListOfMIMETypes", vr_type::LONG_STRING),
+    ((0x0044u16, 0x0001u16), "Product Package Identifier", "ProductPackageIdentifier", vr_type::SHORT_TEXT),
+    ((0x0044u16, 0x0002u16), "Substance Administration Approval", "SubstanceAdministrationApproval", vr_type::CODE_STRING),
+    ((0x0044u16, 0x0003u16), "Approval Status Further Description", "ApprovalStatusFurtherDescription", vr_type::LONG_TEXT),
+    ((0x0044u16, 0x0004u16), "Approval Status DateTime", "ApprovalStatusDateTime", vr_type::DATE_TIME),
+    ((0x0044u16, 0x0007u16), "Product Type Code Sequence", "ProductTypeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0044u16, 0x0008u16), "Product Name", "ProductName", vr_type::LONG_STRING),
+    ((0x0044u16, 0x0009u16), "Product Description", "ProductDescription", vr_type::LONG_TEXT),
+    ((0x0044u16, 0x000Au16), "Product Lot Identifier", "ProductLotIdentifier", vr_type::LONG_STRING),
+    ((0x0044u16, 0x000Bu16), "Product Expiration DateTime", "ProductExpirationDateTime", vr_type::DATE_TIME),
+    ((0x0044u16, 0x0010u16), "Substance Administration DateTime", "SubstanceAdministrationDateTime", vr_type::DATE_TIME),
+    ((0x0044u16, 0x0011u16), "Substance Administration Notes", "SubstanceAdministrationNotes", vr_type::LONG_STRING),
+    ((0x0044u16, 0x0012u16), "Substance Administration Device ID", "SubstanceAdministrationDeviceID", vr_type::LONG_STRING),
+    ((0x0044u16, 0x0013u16), "Product Parameter Sequence", "ProductParameterSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0044u16, 0x0019u16), "Substance Administration Parameter Sequence", "SubstanceAdministrationParameterSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0012u16), "Lens Description", "LensDescription", vr_type::LONG_STRING),
+    ((0x0046u16, 0x0014u16), "Right Lens Sequence", "RightLensSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0015u16), "Left Lens Sequence", "LeftLensSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0016u16), "Unspecified Laterality Lens Sequence", "UnspecifiedLateralityLensSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0018u16), "Cylinder Sequence", "CylinderSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0028u16), "Prism Sequence", "PrismSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0030u16), "Horizontal Prism Power", "HorizontalPrismPower", vr_type::DOUBLE),
+    ((0x0046u16, 0x0032u16), "Horizontal Prism Base", "HorizontalPrismBase", vr_type::CODE_STRING),
+    ((0x0046u16, 0x0034u16), "Vertical Prism Power", "VerticalPrismPower", vr_type::DOUBLE),
+    ((0x0046u16, 0x0036u16), "Vertical Prism Base", "VerticalPrismBase", vr_type::CODE_STRING),
+    ((0x0046u16, 0x0038u16), "Lens Segment Type", "LensSegmentType", vr_type::CODE_STRING),
+    ((0x0046u16, 0x0040u16), "Optical Transmittance", "OpticalTransmittance", vr_type::DOUBLE),
+    ((0x0046u16, 0x0042u16), "Channel Width", "ChannelWidth", vr_type::DOUBLE),
+    ((0x0046u16, 0x0044u16), "Pupil Size", "PupilSize", vr_type::DOUBLE),
+    ((0x0046u16, 0x0046u16), "Corneal Size", "CornealSize", vr_type::DOUBLE),
+    ((0x0046u16, 0x0050u16), "Autorefraction Right Eye Sequence", "AutorefractionRightEyeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0052u16), "Autorefraction Left Eye Sequence", "AutorefractionLeftEyeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0060u16), "Distance Pupillary Distance", "DistancePupillaryDistance", vr_type::DOUBLE),
+    ((0x0046u16, 0x0062u16), "Near Pupillary Distance", "NearPupillaryDistance", vr_type::DOUBLE),
+    ((0x0046u16, 0x0063u16), "Intermediate Pupillary Distance", "IntermediatePupillaryDistance", vr_type::DOUBLE),
+    ((0x0046u16, 0x0064u16), "Other Pupillary Distance", "OtherPupillaryDistance", vr_type::DOUBLE),
+    ((0x0046u16, 0x0070u16), "Keratometry Right Eye Sequence", "KeratometryRightEyeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0071u16), "Keratometry Left Eye Sequence", "KeratometryLeftEyeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0074u16), "Steep Keratometric Axis Sequence", "SteepKeratometricAxisSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0075u16), "Radius of Curvature", "RadiusOfCurvature", vr_type::DOUBLE),
+    ((0x0046u16, 0x0076u16), "Keratometric Power", "KeratometricPower", vr_type::DOUBLE),
+    ((0x0046u16, 0x0077u16), "Keratometric Axis", "KeratometricAxis", vr_type::DOUBLE),
+    ((0x0046u16, 0x0080u16), "Flat Keratometric Axis Sequence", "FlatKeratometricAxisSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0092u16), "Background Color", "BackgroundColor", vr_type::CODE_STRING),
+    ((0x0046u16, 0x0094u16), "Optotype", "Optotype", vr_type::CODE_STRING),
+    ((0x0046u16, 0x0095u16), "Optotype Presentation", "OptotypePresentation", vr_type::CODE_STRING),
+    ((0x0046u16, 0x0097u16), "Subjective Refraction Right Eye Sequence", "SubjectiveRefractionRightEyeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0098u16), "Subjective Refraction Left Eye Sequence", "SubjectiveRefractionLeftEyeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0100u16), "Add Near Sequence", "AddNearSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0101u16), "Add Intermediate Sequence", "AddIntermediateSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0102u16), "Add Other Sequence", "AddOtherSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0104u16), "Add Power", "AddPower", vr_type::DOUBLE),
+    ((0x0046u16, 0x0106u16), "Viewing Distance", "ViewingDistance", vr_type::DOUBLE),
+    ((0x0046u16, 0x0121u16), "Visual Acuity Type Code Sequence", "VisualAcuityTypeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0122u16), "Visual Acuity Right Eye Sequence", "VisualAcuityRightEyeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0123u16), "Visual Acuity Left Eye Sequence", "VisualAcuityLeftEyeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0124u16), "Visual Acuity Both Eyes Open Sequence", "VisualAcuityBothEyesOpenSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0125u16), "Viewing Distance Type", "ViewingDistanceType", vr_type::CODE_STRING),
+    ((0x0046u16, 0x0135u16), "Visual Acuity Modifiers", "VisualAcuityModifiers", vr_type::SIGNED_SHORT),
+    ((0x0046u16, 0x0137u16), "Decimal Visual Acuity", "DecimalVisualAcuity", vr_type::DOUBLE),
+    ((0x0046u16, 0x0139u16), "Optotype Detailed Definition", "OptotypeDetailedDefinition", vr_type::LONG_STRING),
+    ((0x0046u16, 0x0145u16), "Referenced Refractive Measurements Sequence", "ReferencedRefractiveMeasurementsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0046u16, 0x0146u16), "Sphere Power", "SpherePower", vr_type::DOUBLE),
+    ((0x0046u16, 0x0147u16), "Cylinder Power", "CylinderPower", vr_type::DOUBLE),
+    ((0x0050u16, 0x0000u16), "Group 0050 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0050u16, 0x0004u16), "Calibration Image", "CalibrationImage", vr_type::CODE_STRING),
+    ((0x0050u16, 0x0010u16), "Device Sequence", "DeviceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0050u16, 0x0012u16), "Container Component Type Code Sequence", "ContainerComponentTypeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0050u16, 0x0013u16), "Container Component Thickness", "ContainerComponentThickness", vr_type::DOUBLE),
+    ((0x0050u16, 0x0014u16), "Device Length", "DeviceLength", vr_type::DECIMAL_STRING),
+    ((0x0050u16, 0x0015u16), "Container Component Width", "ContainerComponentWidth", vr_type::DOUBLE),
+    ((0x0050u16, 0x0016u16), "Device Diameter", "DeviceDiameter", vr_type::DECIMAL_STRING),
+    ((0x0050u16, 0x0017u16), "Device Diameter Units", "DeviceDiameterUnits", vr_type::CODE_STRING),
+    ((0x0050u16, 0x0018u16), "Device Volume", "DeviceVolume", vr_type::DECIMAL_STRING),
+    ((0x0050u16, 0x0019u16), "Inter-Marker Distance", "InterMarkerDistance", vr_type::DECIMAL_STRING),
+    ((0x0050u16, 0x001Au16), "Container Component Material", "ContainerComponentMaterial", vr_type::CODE_STRING),
+    ((0x0050u16, 0x001Bu16), "Container Component ID", "ContainerComponentID", vr_type::LONG_STRING),
+    ((0x0050u16, 0x001Cu16), "Container Component Length", "ContainerComponentLength", vr_type::DOUBLE),
+    ((0x0050u16, 0x001Du16), "Container Component Diameter", "ContainerComponentDiameter", vr_type::DOUBLE),
+    ((0x0050u16, 0x001Eu16), "Container Component Description", "ContainerComponentDescription", vr_type::LONG_STRING),
+    ((0x0050u16, 0x0020u16), "Device Description", "DeviceDescription", vr_type::LONG_STRING),
+    ((0x0054u16, 0x0000u16), "Group 0054 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0054u16, 0x0010u16), "Energy Window Vector", "EnergyWindowVector", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0011u16), "Number of Energy Windows", "NumberOfEnergyWindows", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0012u16), "Energy Window Information Sequence", "EnergyWindowInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0013u16), "Energy Window Range Sequence", "EnergyWindowRangeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0014u16), "Energy Window Lower Limit", "EnergyWindowLowerLimit", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x0015u16), "Energy Window Upper Limit", "EnergyWindowUpperLimit", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x0016u16), "Radiopharmaceutical Information Sequence", "RadiopharmaceuticalInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0017u16), "Residual Syringe Counts", "ResidualSyringeCounts", vr_type::INTEGER_STRING),
+    ((0x0054u16, 0x0018u16), "Energy Window Name", "EnergyWindowName", vr_type::SHORT_STRING),
+    ((0x0054u16, 0x0020u16), "Detector Vector", "DetectorVector", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0021u16), "Number of Detectors", "NumberOfDetectors", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0022u16), "Detector Information Sequence", "DetectorInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0030u16), "Phase Vector", "PhaseVector", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0031u16), "Number of Phases", "NumberOfPhases", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0032u16), "Phase Information Sequence", "PhaseInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0033u16), "Number of Frames in Phase", "NumberOfFramesInPhase", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0036u16), "Phase Delay", "PhaseDelay", vr_type::INTEGER_STRING),
+    ((0x0054u16, 0x0038u16), "Pause Between Frames", "PauseBetweenFrames", vr_type::INTEGER_STRING),
+    ((0x0054u16, 0x0039u16), "Phase Description", "PhaseDescription", vr_type::CODE_STRING),
+    ((0x0054u16, 0x0050u16), "Rotation Vector", "RotationVector", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0051u16), "Number of Rotations", "NumberOfRotations", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0052u16), "Rotation Information Sequence", "RotationInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0053u16), "Number of Frames in Rotation", "NumberOfFramesInRotation", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0060u16), "R-R Interval Vector", "RRIntervalVector", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0061u16), "Number of R-R Intervals", "NumberOfRRIntervals", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0062u16), "Gated Information Sequence", "GatedInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0063u16), "Data Information Sequence", "DataInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0070u16), "Time Slot Vector", "TimeSlotVector", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0071u16), "Number of Time Slots", "NumberOfTimeSlots", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0072u16), "Time Slot Information Sequence", "TimeSlotInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0073u16), "Time Slot Time", "TimeSlotTime", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x0080u16), "Slice Vector", "SliceVector", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0081u16), "Number of Slices", "NumberOfSlices", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0090u16), "Angular View Vector", "AngularViewVector", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0100u16), "Time Slice Vector", "TimeSliceVector", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0101u16), "Number of Time Slices", "NumberOfTimeSlices", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0200u16), "Start Angle", "StartAngle", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x0202u16), "Type of Detector Motion", "TypeOfDetectorMotion", vr_type::CODE_STRING),
+    ((0x0054u16, 0x0210u16), "Trigger Vector", "TriggerVector", vr_type::INTEGER_STRING),
+    ((0x0054u16, 0x0211u16), "Number of Triggers in Phase", "NumberOfTriggersInPhase", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0220u16), "View Code Sequence", "ViewCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0222u16), "View Modifier Code Sequence", "ViewModifierCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0300u16), "Radionuclide Code Sequence", "RadionuclideCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0302u16), "Administration Route Code Sequence", "AdministrationRouteCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0304u16), "Radiopharmaceutical Code Sequence", "RadiopharmaceuticalCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0306u16), "Calibration Data Sequence", "CalibrationDataSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0308u16), "Energy Window Number", "EnergyWindowNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x0400u16), "Image ID", "ImageID", vr_type::SHORT_STRING),
+    ((0x0054u16, 0x0410u16), "Patient Orientation Code Sequence", "PatientOrientationCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0412u16), "Patient Orientation Modifier Code Sequence", "PatientOrientationModifierCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0414u16), "Patient Gantry Relationship Code Sequence", "PatientGantryRelationshipCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0054u16, 0x0500u16), "Slice Progression Direction", "SliceProgressionDirection", vr_type::CODE_STRING),
+    ((0x0054u16, 0x1000u16), "Series Type", "SeriesType", vr_type::CODE_STRING),
+    ((0x0054u16, 0x1001u16), "Units", "Units", vr_type::CODE_STRING),
+    ((0x0054u16, 0x1002u16), "Counts Source", "CountsSource", vr_type::CODE_STRING),
+    ((0x0054u16, 0x1004u16), "Reprojection Method", "ReprojectionMethod", vr_type::CODE_STRING),
+    ((0x0054u16, 0x1100u16), "Randoms Correction Method", "RandomsCorrectionMethod", vr_type::CODE_STRING),
+    ((0x0054u16, 0x1101u16), "Attenuation Correction Method", "AttenuationCorrectionMethod", vr_type::LONG_STRING),
+    ((0x0054u16, 0x1102u16), "Decay Correction", "DecayCorrection", vr_type::CODE_STRING),
+    ((0x0054u16, 0x1103u16), "Reconstruction Method", "ReconstructionMethod", vr_type::LONG_STRING),
+    ((0x0054u16, 0x1104u16), "Detector Lines of Response Used", "DetectorLinesOfResponseUsed", vr_type::LONG_STRING),
+    ((0x0054u16, 0x1105u16), "Scatter Correction Method", "ScatterCorrectionMethod", vr_type::LONG_STRING),
+    ((0x0054u16, 0x1200u16), "Axial Acceptance", "AxialAcceptance", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x1201u16), "Axial Mash", "AxialMash", vr_type::INTEGER_STRING),
+    ((0x0054u16, 0x1202u16), "Transverse Mash", "TransverseMash", vr_type::INTEGER_STRING),
+    ((0x0054u16, 0x1203u16), "Detector Element Size", "DetectorElementSize", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x1210u16), "Coincidence Window Width", "CoincidenceWindowWidth", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x1220u16), "Secondary Counts Type", "SecondaryCountsType", vr_type::CODE_STRING),
+    ((0x0054u16, 0x1300u16), "Frame Reference Time", "FrameReferenceTime", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x1310u16), "Primary (Prompts) Counts Accumulated", "PrimaryPromptsCountsAccumulated", vr_type::INTEGER_STRING),
+    ((0x0054u16, 0x1311u16), "Secondary Counts Accumulated", "SecondaryCountsAccumulated", vr_type::INTEGER_STRING),
+    ((0x0054u16, 0x1320u16), "Slice Sensitivity Factor", "SliceSensitivityFactor", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x1321u16), "Decay Factor", "DecayFactor", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x1322u16), "Dose Calibration Factor", "DoseCalibrationFactor", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x1323u16), "Scatter Fraction Factor", "ScatterFractionFactor", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x1324u16), "Dead Time Factor", "DeadTimeFactor", vr_type::DECIMAL_STRING),
+    ((0x0054u16, 0x1330u16), "Image Index", "ImageIndex", vr_type::UNSIGNED_SHORT),
+    ((0x0054u16, 0x1400u16), "Counts Included", "CountsIncluded", vr_type::CODE_STRING),
+    ((0x0054u16, 0x1401u16), "Dead Time Correction Flag", "DeadTimeCorrectionFlag", vr_type::CODE_STRING),
+    ((0x0060u16, 0x0000u16), "Group 0060 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0060u16, 0x3000u16), "Histogram Sequence", "HistogramSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0060u16, 0x3002u16), "Histogram Number of Bins", "HistogramNumberOfBins", vr_type::UNSIGNED_SHORT),
+    ((0x0060u16, 0x3004u16), "Histogram First Bin Value", "HistogramFirstBinValue", vr_type::UNSIGNED_SHORT),
+    ((0x0060u16, 0x3006u16), "Histogram Last Bin Value", "HistogramLastBinValue", vr_type::UNSIGNED_SHORT),
+    ((0x0060u16, 0x3008u16), "Histogram Bin Width", "HistogramBinWidth", vr_type::UNSIGNED_SHORT),
+    ((0x0060u16, 0x3010u16), "Histogram Explanation", "HistogramExplanation", vr_type::LONG_STRING),
+    ((0x0060u16, 0x3020u16), "Histogram Data", "HistogramData", vr_type::UNSIGNED_LONG),
+    ((0x0062u16, 0x0001u16), "Segmentation Type", "SegmentationType", vr_type::CODE_STRING),
+    ((0x0062u16, 0x0002u16), "DICOM Segment Sequence", "DICOMSegmentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0062u16, 0x0003u16), "Segmented Property Category Code Sequence", "SegmentedPropertyCategoryCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0062u16, 0x0004u16), "DICOM Segment Number", "DICOMSegmentNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0062u16, 0x0005u16), "Segment Label", "SegmentLabel", vr_type::LONG_STRING),
+    ((0x0062u16, 0x0006u16), "DICOM Segment Description", "DICOMSegmentDescription", vr_type::SHORT_TEXT),
+    ((0x0062u16, 0x0008u16), "Segment Algorithm Type", "SegmentAlgorithmType", vr_type::CODE_STRING),
+    ((0x0062u16, 0x0009u16), "Segment Algorithm Name", "SegmentAlgorithmName", vr_type::LONG_STRING),
+    ((0x0062u16, 0x000Au16), "Segment Identification Sequence", "SegmentIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0062u16, 0x000Bu16), "Referenced Segment Number", "ReferencedSegmentNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0062u16, 0x000Cu16), "Recommended Display Grayscale Value", "RecommendedDisplayGrayscaleValue", vr_type::UNSIGNED_SHORT),
+    ((0x0062u16, 0x000Du16), "Recommended Display CIELab Value", "RecommendedDisplayCIELabValue", vr_type::UNSIGNED_SHORT),
+    ((0x0062u16, 0x000Eu16), "Maximum Fractional Value", "MaximumFractionalValue", vr_type::UNSIGNED_SHORT),
+    ((0x0062u16, 0x000Fu16), "Segmented Property Type Code Sequence", "SegmentedPropertyTypeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0062u16, 0x0010u16), "Segmentation Fractional Type", "SegmentationFractionalType", vr_type::CODE_STRING),
+    ((0x0064u16, 0x0002u16), "Deformable Registration Sequence", "DeformableRegistrationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0064u16, 0x0003u16), "Source Frame of Reference Uid", "SourceFrameOfReferenceUID", vr_type::UID),
+    ((0x0064u16, 0x0005u16), "Deformable Registration Grid Sequence", "DeformableRegistrationGridSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0064u16, 0x0007u16), "Grid Dimensions", "GridDimensions", vr_type::UNSIGNED_LONG),
+    ((0x0064u16, 0x0008u16), "Grid Resolution", "GridResolution", vr_type::DOUBLE),
+    ((0x0064u16, 0x0009u16), "Vector Grid Data", "VectorGridData", vr_type::OTHER_FLOAT),
+    ((0x0064u16, 0x000Fu16), "Pre Deformation Matrix Registration Sequence", "PreDeformationMatrixRegistrationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0064u16, 0x0010u16), "Post Deformation Matrix Registration Sequence", "PostDeformationMatrixRegistrationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0001u16), "Number of Surfaces", "NumberOfSurfaces", vr_type::UNSIGNED_LONG),
+    ((0x0066u16, 0x0002u16), "Surface Sequence", "SurfaceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0003u16), "Surface Number", "SurfaceNumber", vr_type::UNSIGNED_LONG),
+    ((0x0066u16, 0x0004u16), "Surface Comments", "SurfaceComments", vr_type::LONG_TEXT),
+    ((0x0066u16, 0x0009u16), "Surface Processing", "SurfaceProcessing", vr_type::CODE_STRING),
+    ((0x0066u16, 0x000Au16), "Surface Processing Ratio", "SurfaceProcessingRatio", vr_type::FLOAT),
+    ((0x0066u16, 0x000Bu16), "Surface Processing Description", "SurfaceProcessingDescription", vr_type::LONG_STRING),
+    ((0x0066u16, 0x000Cu16), "Recommended Presentation Opacity", "RecommendedPresentationOpacity", vr_type::FLOAT),
+    ((0x0066u16, 0x000Du16), "Recommended Presentation Type", "RecommendedPresentationType", vr_type::CODE_STRING),
+    ((0x0066u16, 0x000Eu16), "Finite Volume", "FiniteVolume", vr_type::CODE_STRING),
+    ((0x0066u16, 0x0010u16), "Manifold", "Manifold", vr_type::CODE_STRING),
+    ((0x0066u16, 0x0011u16), "Surface Points Sequence", "SurfacePointsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0012u16), "Surface Points Normals Sequence", "SurfacePointsNormalsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0013u16), "Surface Mesh Primitives Sequence", "SurfaceMeshPrimitivesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0015u16), "Number of Surface Points", "NumberOfSurfacePoints", vr_type::UNSIGNED_LONG),
+    ((0x0066u16, 0x0016u16), "Point Coordinates Data", "PointCoordinatesData", vr_type::OTHER_FLOAT),
+    ((0x0066u16, 0x0017u16), "Point Position Accuracy", "PointPositionAccuracy", vr_type::FLOAT),
+    ((0x0066u16, 0x0018u16), "Mean Point Distance", "MeanPointDistance", vr_type::FLOAT),
+    ((0x0066u16, 0x0019u16), "Maximum Point Distance", "MaximumPointDistance", vr_type::FLOAT),
+    ((0x0066u16, 0x001Au16), "Points Bounding Box Coordinates", "PointsBoundingBoxCoordinates", vr_type::FLOAT),
+    ((0x0066u16, 0x001Bu16), "Axis of Rotation", "AxisOfRotation", vr_type::FLOAT),
+    ((0x0066u16, 0x001Cu16), "Center of Rotation", "CenterOfRotation", vr_type::FLOAT),
+    ((0x0066u16, 0x001Eu16), "Number of Vectors", "NumberOfVectors", vr_type::UNSIGNED_LONG),
+    ((0x0066u16, 0x001Fu16), "Vector Dimensionality", "VectorDimensionality", vr_type::UNSIGNED_SHORT),
+    ((0x0066u16, 0x0020u16), "Vector Accuracy", "VectorAccuracy", vr_type::FLOAT),
+    ((0x0066u16, 0x0021u16), "Vector Coordinate Data", "VectorCoordinateData", vr_type::OTHER_FLOAT),
+    ((0x0066u16, 0x0023u16), "Triangle Point Index List", "TrianglePointIndexList", vr_type::OTHER_WORD),
+    ((0x0066u16, 0x0024u16), "Edge Point Index List", "EdgePointIndexList", vr_type::OTHER_WORD),
+    ((0x0066u16, 0x0025u16), "Vertex Point Index List", "VertexPointIndexList", vr_type::OTHER_WORD),
+    ((0x0066u16, 0x0026u16), "Triangle Strip Sequence", "TriangleStripSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0027u16), "Triangle Fan Sequence", "TriangleFanSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0028u16), "Line Sequence", "LineSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0029u16), "Primitive Point Index List", "PrimitivePointIndexList", vr_type::OTHER_WORD),
+    ((0x0066u16, 0x002Au16), "Surface Count", "SurfaceCount", vr_type::UNSIGNED_LONG),
+    ((0x0066u16, 0x002Bu16), "Referenced Surface Sequence", "ReferencedSurfaceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x002Cu16), "Referenced Surface Number", "ReferencedSurfaceNumber", vr_type::UNSIGNED_LONG),
+    ((0x0066u16, 0x002Du16), "Segment Surface Generation Algorithm Identification Sequence", "SegmentSurfaceGenerationAlgorithmIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x002Eu16), "Segment Surface Source Instance Sequence", "SegmentSurfaceSourceInstanceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x002Fu16), "Algorithm Family Code Sequence", "AlgorithmFamilyCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0030u16), "Algorithm Name Code Sequence", "AlgorithmNameCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0031u16), "Algorithm Version", "AlgorithmVersion", vr_type::LONG_STRING),
+    ((0x0066u16, 0x0032u16), "Algorithm Parameters", "AlgorithmParameters", vr_type::LONG_TEXT),
+    ((0x0066u16, 0x0034u16), "Facet Sequence", "FacetSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0035u16), "Surface Processing Algorithm Identification Sequence", "SurfaceProcessingAlgorithmIdentificationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0066u16, 0x0036u16), "Algorithm Name", "AlgorithmName", vr_type::LONG_STRING),
+    ((0x0066u16, 0x0040u16), "Long Primitive Point Index List", "LongPrimitivePointIndexList", vr_type::OTHER_LONG),
+    ((0x0066u16, 0x0041u16), "Long Triangle Point Index List", "LongTrianglePointIndexList", vr_type::OTHER_LONG),
+    ((0x0066u16, 0x0042u16), "Long Edge Point Index List", "LongEdgePointIndexList", vr_type::OTHER_LONG),
+    ((0x0066u16, 0x0043u16), "Long Vertex Point Index List", "LongVertexPointIndexList", vr_type::OTHER_LONG),
+    ((0x0070u16, 0x0000u16), "Group 0070 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0070u16, 0x0001u16), "Graphic Annotation Sequence", "GraphicAnnotationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0002u16), "Graphic Layer", "GraphicLayer", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0003u16), "Bounding Box Annotation Units", "BoundingBoxAnnotationUnits", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0004u16), "Anchor Point Annotation Units", "AnchorPointAnnotationUnits", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0005u16), "Graphic Annotation Units", "GraphicAnnotationUnits", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0006u16), "Unformatted Text Value", "UnformattedTextValue", vr_type::SHORT_TEXT),
+    ((0x0070u16, 0x0008u16), "Text Object Sequence", "TextObjectSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0009u16), "Graphic Object Sequence", "GraphicObjectSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0010u16), "Bounding Box Top Left Hand Corner", "BoundingBoxTopLeftHandCorner", vr_type::FLOAT),
+    ((0x0070u16, 0x0011u16), "Bounding Box Bottom Right Hand Corner", "BoundingBoxBottomRightHandCorner", vr_type::FLOAT),
+    ((0x0070u16, 0x0012u16), "Bounding Box Text Horizontal Justification", "BoundingBoxTextHorizontalJustification", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0014u16), "Anchor Point", "AnchorPoint", vr_type::FLOAT),
+    ((0x0070u16, 0x0015u16), "Anchor Point Visibility", "AnchorPointVisibility", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0020u16), "Graphic Dimensions", "GraphicDimensions", vr_type::UNSIGNED_SHORT),
+    ((0x0070u16, 0x0021u16), "Number of Graphic Points", "NumberOfGraphicPoints", vr_type::UNSIGNED_SHORT),
+    ((0x0070u16, 0x0022u16), "Graphic Data", "GraphicData", vr_type::FLOAT),
+    ((0x0070u16, 0x0023u16), "Graphic Type", "GraphicType", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0024u16), "Graphic Filled", "GraphicFilled", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0040u16), "Image Rotation (Retired)", "ImageRotationRetired", vr_type::INTEGER_STRING),
+    ((0x0070u16, 0x0041u16), "Image Horizontal Flip", "ImageHorizontalFlip", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0042u16), "Image Rotation", "ImageRotation", vr_type::UNSIGNED_SHORT),
+    ((0x0070u16, 0x0050u16), "Displayed Area Top Left Hand Corner (Trial)", "DisplayedAreaTopLeftHandCornerTrial", vr_type::UNSIGNED_SHORT),
+    ((0x0070u16, 0x0051u16), "Displayed Area Bottom Right Hand Corner (Trial)", "DisplayedAreaBottomRightHandCornerTrial", vr_type::UNSIGNED_SHORT),
+    ((0x0070u16, 0x0052u16), "Displayed Area Top Left Hand Corner", "DisplayedAreaTopLeftHandCorner", vr_type::SIGNED_LONG),
+    ((0x0070u16, 0x0053u16), "Displayed Area Bottom Right Hand Corner", "DisplayedAreaBottomRightHandCorner", vr_type::SIGNED_LONG),
+    ((0x0070u16, 0x005Au16), "Displayed Area Selection Sequence", "DisplayedAreaSelectionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0060u16), "Graphic Layer Sequence", "GraphicLayerSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0062u16), "Graphic Layer Order", "GraphicLayerOrder", vr_type::INTEGER_STRING),
+    ((0x0070u16, 0x0066u16), "Graphic Layer Recommended Display Grayscale Value", "GraphicLayerRecommendedDisplayGrayscaleValue", vr_type::UNSIGNED_SHORT),
+    ((0x0070u16, 0x0067u16), "Graphic Layer Recommended Display RGB Value", "GraphicLayerRecommendedDisplayRGBValue", vr_type::UNSIGNED_SHORT),
+    ((0x0070u16, 0x0068u16), "Graphic Layer Description", "GraphicLayerDescription", vr_type::LONG_STRING),
+    ((0x0070u16, 0x0080u16), "Content Label", "ContentLabel", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0081u16), "Content Description", "ContentDescription", vr_type::LONG_STRING),
+    ((0x0070u16, 0x0082u16), "Presentation Creation Date", "PresentationCreationDate", vr_type::DATE),
+    ((0x0070u16, 0x0083u16), "Presentation Creation Time", "PresentationCreationTime", vr_type::TIME),
+    ((0x0070u16, 0x0084u16), "Content Creator's Name", "ContentCreatorName", vr_type::PERSON_NAME),
+    ((0x0070u16, 0x0086u16), "Content Creator's Identification Code Sequence", "ContentCreatorIdentificationCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0087u16), "Alternate Content Description Sequence", "AlternateContentDescriptionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0100u16), "Presentation Size Mode", "PresentationSizeMode", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0101u16), "Presentation Pixel Spacing", "PresentationPixelSpacing", vr_type::DECIMAL_STRING),
+    ((0x0070u16, 0x0102u16), "Presentation Pixel Aspect Ratio", "PresentationPixelAspectRatio", vr_type::INTEGER_STRING),
+    ((0x0070u16, 0x0103u16), "Presentation Pixel Magnification Ratio", "PresentationPixelMagnificationRatio", vr_type::FLOAT),
+    ((0x0070u16, 0x0306u16), "Shape Type", "ShapeType", vr_type::CODE_STRING),
+    ((0x0070u16, 0x0308u16), "Registration Sequence", "RegistrationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0309u16), "Matrix Registration Sequence", "MatrixRegistrationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x030Au16), "Matrix Sequence", "MatrixSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x030Cu16), "Frame of Reference Transformation Matrix Type", "FrameOfReferenceTransformationMatrixType", vr_type::CODE_STRING),
+    ((0x0070u16, 0x030Du16), "Registration Type Code Sequence", "RegistrationTypeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x030Fu16), "Fiducial Description", "FiducialDescription", vr_type::SHORT_TEXT),
+    ((0x0070u16, 0x0310u16), "Fiducial Identifier", "FiducialIdentifier", vr_type::SHORT_STRING),
+    ((0x0070u16, 0x0311u16), "Fiducial Identifier Code Sequence", "FiducialIdentifierCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0312u16), "Contour Uncertainty Radius", "ContourUncertaintyRadius", vr_type::DOUBLE),
+    ((0x0070u16, 0x0314u16), "Used Fiducials Sequence", "UsedFiducialsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0318u16), "Graphic Coordinates Data Sequence", "GraphicCoordinatesDataSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x031Au16), "Fiducial Uid", "FiducialUID", vr_type::UID),
+    ((0x0070u16, 0x031Cu16), "Fiducial Set Sequence", "FiducialSetSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x031Eu16), "Fiducial Sequence", "FiducialSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0401u16), "Graphic Layer Recommended Display CIELab Value", "GraphicLayerRecommendedDisplayCIELabValue", vr_type::UNSIGNED_SHORT),
+    ((0x0070u16, 0x0402u16), "Blending Sequence", "BlendingSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0403u16), "Relative Opacity", "RelativeOpacity", vr_type::FLOAT),
+    ((0x0070u16, 0x0404u16), "Referenced Spatial Registration Sequence", "ReferencedSpatialRegistrationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0070u16, 0x0405u16), "Blending Position", "BlendingPosition", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0002u16), "Hanging Protocol Name", "HangingProtocolName", vr_type::SHORT_STRING),
+    ((0x0072u16, 0x0004u16), "Hanging Protocol Description", "HangingProtocolDescription", vr_type::LONG_STRING),
+    ((0x0072u16, 0x0006u16), "Hanging Protocol Level", "HangingProtocolLevel", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0008u16), "Hanging Protocol Creator", "HangingProtocolCreator", vr_type::LONG_STRING),
+    ((0x0072u16, 0x000Au16), "Hanging Protocol Creation DateTime", "HangingProtocolCreationDateTime", vr_type::DATE_TIME),
+    ((0x0072u16, 0x000Cu16), "Hanging Protocol Definition Sequence", "HangingProtocolDefinitionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x000Eu16), "Hanging Protocol User Identification Code Sequence", "HangingProtocolUserIdentificationCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0010u16), "Hanging Protocol User Group Name", "HangingProtocolUserGroupName", vr_type::LONG_STRING),
+    ((0x0072u16, 0x0012u16), "Source Hanging Protocol Sequence", "SourceHangingProtocolSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0014u16), "Number of Priors Referenced", "NumberOfPriorsReferenced", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0020u16), "Image Sets Sequence", "ImageSetsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0022u16), "Image Set Selector Sequence", "ImageSetSelectorSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0024u16), "Image Set Selector Usage Flag", "ImageSetSelectorUsageFlag", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0026u16), "Selector Attribute", "SelectorAttribute", vr_type::ATTRIBUTE),
+    ((0x0072u16, 0x0028u16), "Selector Value Number", "SelectorValueNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0030u16), "Time Based Image Sets Sequence", "TimeBasedImageSetsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0032u16), "Image Set Number", "ImageSetNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0034u16), "Image Set Selector Category", "ImageSetSelectorCategory", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0038u16), "Relative Time", "RelativeTime", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x003Au16), "Relative Time Units", "RelativeTimeUnits", vr_type::CODE_STRING),
+    ((0x0072u16, 0x003Cu16), "Abstract Prior Value", "AbstractPriorValue", vr_type::SIGNED_SHORT),
+    ((0x0072u16, 0x003Eu16), "Abstract Prior Code Sequence", "AbstractPriorCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0040u16), "Image Set Label", "ImageSetLabel", vr_type::LONG_STRING),
+    ((0x0072u16, 0x0050u16), "Selector Attribute VR", "SelectorAttributeVR", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0052u16), "Selector Sequence Pointer", "SelectorSequencePointer", vr_type::ATTRIBUTE),
+    ((0x0072u16, 0x0054u16), "Selector Sequence Pointer Private Creator", "SelectorSequencePointerPrivateCreator", vr_type::LONG_STRING),
+    ((0x0072u16, 0x0056u16), "Selector Attribute Private Creator", "SelectorAttributePrivateCreator", vr_type::LONG_STRING),
+    ((0x0072u16, 0x0060u16), "Selector AT Value", "SelectorATValue", vr_type::ATTRIBUTE),
+    ((0x0072u16, 0x0062u16), "Selector CS Value", "SelectorCSValue", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0064u16), "Selector IS Value", "SelectorISValue", vr_type::INTEGER_STRING),
+    ((0x0072u16, 0x0066u16), "Selector LO Value", "SelectorLOValue", vr_type::LONG_STRING),
+    ((0x0072u16, 0x0068u16), "Selector LT Value", "SelectorLTValue", vr_type::LONG_TEXT),
+    ((0x0072u16, 0x006Au16), "Selector PN Value", "SelectorPNValue", vr_type::PERSON_NAME),
+    ((0x0072u16, 0x006Cu16), "Selector SH Value", "SelectorSHValue", vr_type::SHORT_STRING),
+    ((0x0072u16, 0x006Eu16), "Selector ST Value", "SelectorSTValue", vr_type::SHORT_TEXT),
+    ((0x0072u16, 0x006Fu16), "Selector UC Value", "SelectorUCValue", vr_type::UNLIMITED_CHARACTERS),
+    ((0x0072u16, 0x0070u16), "Selector UT Value", "SelectorUTValue", vr_type::UNLIMITED_TEXT),
+    ((0x0072u16, 0x0071u16), "Selector UR Value", "SelectorURValue", vr_type::UNIVERSAL_RESOURCE),
+    ((0x0072u16, 0x0072u16), "Selector DS Value", "SelectorDSValue", vr_type::DECIMAL_STRING),
+    ((0x0072u16, 0x0073u16), "Selector OD Value", "SelectorODValue", vr_type::OTHER_DOUBLE),
+    ((0x0072u16, 0x0074u16), "Selector FD Value", "SelectorFDValue", vr_type::DOUBLE),
+    ((0x0072u16, 0x0075u16), "Selector OL Value", "SelectorOLValue", vr_type::OTHER_LONG),
+    ((0x0072u16, 0x0076u16), "Selector FL Value", "SelectorFLValue", vr_type::FLOAT),
+    ((0x0072u16, 0x0078u16), "Selector UL Value", "SelectorULValue", vr_type::UNSIGNED_LONG),
+    ((0x0072u16, 0x007Au16), "Selector US Value", "SelectorUSValue", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x007Cu16), "Selector SL Value", "SelectorSLValue", vr_type::SIGNED_LONG),
+    ((0x0072u16, 0x007Eu16), "Selector SS Value", "SelectorSSValue", vr_type::SIGNED_SHORT),
+    ((0x0072u16, 0x0080u16), "Selector Code Sequence Value", "SelectorCodeSequenceValue", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0081u16), "Selector OV Value", "SelectorOVValue", vr_type::OTHER_VERY_LONG),
+    ((0x0072u16, 0x0082u16), "Selector SV Value", "SelectorSVValue", vr_type::SIGNED_VERY_LONG),
+    ((0x0072u16, 0x0083u16), "Selector UV Value", "SelectorUVValue", vr_type::UNSIGNED_VERY_LONG),
+    ((0x0072u16, 0x0100u16), "Number of Screens", "NumberOfScreens", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0102u16), "Nominal Screen Definition Sequence", "NominalScreenDefinitionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0104u16), "Number of Vertical Pixels", "NumberOfVerticalPixels", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0106u16), "Number of Horizontal Pixels", "NumberOfHorizontalPixels", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0108u16), "Display Environment Spatial Position", "DisplayEnvironmentSpatialPosition", vr_type::DOUBLE),
+    ((0x0072u16, 0x010Au16), "Screen Minimum Grayscale Bit Depth", "ScreenMinimumGrayscaleBitDepth", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x010Cu16), "Screen Minimum Color Bit Depth", "ScreenMinimumColorBitDepth", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x010Eu16), "Application Maximum Repaint Time", "ApplicationMaximumRepaintTime", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0200u16), "Display Sets Sequence", "DisplaySetsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0202u16), "Display Set Number", "DisplaySetNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0203u16), "Display Set Label", "DisplaySetLabel", vr_type::LONG_STRING),
+    ((0x0072u16, 0x0204u16), "Display Set Presentation Group", "DisplaySetPresentationGroup", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0206u16), "Display Set Presentation Group Description", "DisplaySetPresentationGroupDescription", vr_type::LONG_STRING),
+    ((0x0072u16, 0x0208u16), "Partial Data Display Handling", "PartialDataDisplayHandling", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0210u16), "Synchronized Scrolling Sequence", "SynchronizedScrollingSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0212u16), "Display Set Scrolling Group", "DisplaySetScrollingGroup", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0214u16), "Navigation Indicator Sequence", "NavigationIndicatorSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0216u16), "Navigation Display Set", "NavigationDisplaySet", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0218u16), "Reference Display Sets", "ReferenceDisplaySets", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0300u16), "Image Boxes Sequence", "ImageBoxesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0302u16), "Image Box Number", "ImageBoxNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0304u16), "Image Box Layout Type", "ImageBoxLayoutType", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0306u16), "Image Box Tile Horizontal Dimension", "ImageBoxTileHorizontalDimension", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0308u16), "Image Box Tile Vertical Dimension", "ImageBoxTileVerticalDimension", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0310u16), "Image Box Scroll Direction", "ImageBoxScrollDirection", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0312u16), "Image Box Small Scroll Type", "ImageBoxSmallScrollType", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0314u16), "Image Box Small Scroll Amount", "ImageBoxSmallScrollAmount", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0316u16), "Image Box Large Scroll Type", "ImageBoxLargeScrollType", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0318u16), "Image Box Large Scroll Amount", "ImageBoxLargeScrollAmount", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0320u16), "Image Box Overlap Priority", "ImageBoxOverlapPriority", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0330u16), "Cine Relative to Real-Time", "CineRelativeToRealTime", vr_type::DOUBLE),
+    ((0x0072u16, 0x0400u16), "Filter Operations Sequence", "FilterOperationsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0402u16), "Filter-by Category", "FilterByCategory", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0404u16), "Filter-by Attribute Presence", "FilterByAttributePresence", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0406u16), "Filter-by Operator", "FilterByOperator", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0420u16), "Structured Display Background CIELab Value", "StructuredDisplayBackgroundCIELabValue", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0421u16), "Empty Image Box CIELab Value", "EmptyImageBoxCIELabValue", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0422u16), "Structured Display Image Box Sequence", "StructuredDisplayImageBoxSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0424u16), "Structured Display Text Box Sequence", "StructuredDisplayTextBoxSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0427u16), "Referenced First Frame Sequence", "ReferencedFirstFrameSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0430u16), "Image Box Synchronization Sequence", "ImageBoxSynchronizationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0432u16), "Synchronized Image Box List", "SynchronizedImageBoxList", vr_type::UNSIGNED_SHORT),
+    ((0x0072u16, 0x0434u16), "Type of Synchronization", "TypeOfSynchronization", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0500u16), "Blending Operation Type", "BlendingOperationType", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0510u16), "Reformatting Operation Type", "ReformattingOperationType", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0512u16), "Reformatting Thickness", "ReformattingThickness", vr_type::DOUBLE),
+    ((0x0072u16, 0x0514u16), "Reformatting Interval", "ReformattingInterval", vr_type::DOUBLE),
+    ((0x0072u16, 0x0516u16), "Reformatting Operation Initial View Direction", "ReformattingOperationInitialViewDirection", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0520u16), "3D Rendering Type", "ThreeDRenderingType", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0600u16), "Sorting Operations Sequence", "SortingOperationsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0072u16, 0x0602u16), "Sort-by Category", "SortByCategory", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0604u16), "Sorting Direction", "SortingDirection", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0700u16), "Display Set Patient Orientation", "DisplaySetPatientOrientation", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0702u16), "VOI Type", "VOIType", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0704u16), "Pseudo-Color Type", "PseudoColorType", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0706u16), "Show Grayscale Inverted", "ShowGrayscaleInverted", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0710u16), "Show Image True Size Flag", "ShowImageTrueSizeFlag", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0712u16), "Show Graphic Annotation Flag", "ShowGraphicAnnotationFlag", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0714u16), "Show Patient Demographics Flag", "ShowPatientDemographicsFlag", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0716u16), "Show Acquisition Techniques Flag", "ShowAcquisitionTechniquesFlag", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0717u16), "Display Set Horizontal Justification", "DisplaySetHorizontalJustification", vr_type::CODE_STRING),
+    ((0x0072u16, 0x0718u16), "Display Set Vertical Justification", "DisplaySetVerticalJustification", vr_type::CODE_STRING),
+    ((0x0074u16, 0x0000u16), "Group 0074 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0074u16, 0x1000u16), "Procedure Step State", "ProcedureStepState", vr_type::CODE_STRING),
+    ((0x0074u16, 0x1002u16), "Progress Information Sequence", "ProgressInformationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0074u16, 0x1004u16), "Procedure Step Progress", "ProcedureStepProgress", vr_type::DECIMAL_STRING),
+    ((0x0074u16, 0x1006u16), "Procedure Step Progress Description", "ProcedureStepProgressDescription", vr_type::SHORT_TEXT),
+    ((0x0074u16, 0x1008u16), "Procedure Step Communications URI Sequence", "ProcedureStepCommunicationsURISequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0074u16, 0x100Au16), "Contact URI", "ContactURI", vr_type::UNIVERSAL_RESOURCE),
+    ((0x0074u16, 0x100Cu16), "Contact Display Name", "ContactDisplayName", vr_type::LONG_STRING),
+    ((0x0074u16, 0x100Eu16), "Procedure Step Discontinuation Reason Code Sequence", "ProcedureStepDiscontinuationReasonCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0074u16, 0x1200u16), "Scheduled Procedure Step Priority", "ScheduledProcedureStepPriority", vr_type::CODE_STRING),
+    ((0x0074u16, 0x1202u16), "Worklist Label", "WorklistLabel", vr_type::LONG_STRING),
+    ((0x0074u16, 0x1204u16), "Procedure Step Label", "ProcedureStepLabel", vr_type::LONG_STRING),
+    ((0x0074u16, 0x1210u16), "Scheduled Processing Parameters Sequence", "ScheduledProcessingParametersSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0074u16, 0x1212u16), "Performed Processing Parameters Sequence", "PerformedProcessingParametersSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0074u16, 0x1216u16), "UPS Performed Procedure Sequence", "UPSPerformedProcedureSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0074u16, 0x1224u16), "Replaced Procedure Step Sequence", "ReplacedProcedureStepSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0074u16, 0x1230u16), "Deletion Lock", "DeletionLock", vr_type::CODE_STRING),
+    ((0x0074u16, 0x1234u16), "Receiving AE", "ReceivingAE", vr_type::APPLICATION_ENTITY),
+    ((0x0074u16, 0x1236u16), "Requesting AE", "RequestingAE", vr_type::APPLICATION_ENTITY),
+    ((0x0074u16, 0x1238u16), "Reason For Cancellation", "ReasonForCancellation", vr_type::LONG_TEXT),
+    ((0x0074u16, 0x1242u16), "SCP Status", "SCPStatus", vr_type::CODE_STRING),
+    ((0x0074u16, 0x1244u16), "Subscription List Status", "SubscriptionListStatus", vr_type::CODE_STRING),
+    ((0x0074u16, 0x1246u16), "UPS List Status", "UPSListStatus", vr_type::CODE_STRING),
+    ((0x0088u16, 0x0000u16), "Group 0088 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0088u16, 0x0130u16), "Storage Media File-set ID", "StorageMediaFileSetID", vr_type::SHORT_STRING),
+    ((0x0088u16, 0x0140u16), "Storage Media File-set Uid", "StorageMediaFileSetUID", vr_type::UID),
+    ((0x0088u16, 0x0200u16), "Icon Image Sequence", "IconImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0088u16, 0x0904u16), "Topic Title", "TopicTitle", vr_type::LONG_STRING),
+    ((0x0088u16, 0x0906u16), "Topic Subject", "TopicSubject", vr_type::SHORT_TEXT),
+    ((0x0088u16, 0x0910u16), "Topic Author", "TopicAuthor", vr_type::LONG_STRING),
+    ((0x0088u16, 0x0912u16), "Topic Keywords", "TopicKeywords", vr_type::LONG_STRING),
+    ((0x0100u16, 0x0000u16), "Group 0100 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0100u16, 0x0410u16), "SOP Instance Status", "SOPInstanceStatus", vr_type::CODE_STRING),
+    ((0x0100u16, 0x0420u16), "SOP Authorization DateTime", "SOPAuthorizationDateTime", vr_type::DATE_TIME),
+    ((0x0100u16, 0x0424u16), "SOP Authorization Comment", "SOPAuthorizationComment", vr_type::LONG_TEXT),
+    ((0x0100u16, 0x0426u16), "Authorization Equipment Certification Number", "AuthorizationEquipmentCertificationNumber", vr_type::LONG_STRING),
+    ((0x0400u16, 0x0000u16), "Group 0400 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x0400u16, 0x0005u16), "MAC ID Number", "MACIDNumber", vr_type::UNSIGNED_SHORT),
+    ((0x0400u16, 0x0010u16), "MAC Calculation Transfer Syntax Uid", "MACCalculationTransferSyntaxUID", vr_type::UID),
+    ((0x0400u16, 0x0015u16), "MAC Algorithm", "MACAlgorithm", vr_type::CODE_STRING),
+    ((0x0400u16, 0x0020u16), "Data Elements Signed", "DataElementsSigned", vr_type::ATTRIBUTE),
+    ((0x0400u16, 0x0100u16), "Digital Signature Uid", "DigitalSignatureUID", vr_type::UID),
+    ((0x0400u16, 0x0105u16), "Digital Signature DateTime", "DigitalSignatureDateTime", vr_type::DATE_TIME),
+    ((0x0400u16, 0x0110u16), "Certificate Type", "CertificateType", vr_type::CODE_STRING),
+    ((0x0400u16, 0x0115u16), "Certificate of Signer", "CertificateOfSigner", vr_type::OTHER_BYTE),
+    ((0x0400u16, 0x0120u16), "Signature", "Signature", vr_type::OTHER_BYTE),
+    ((0x0400u16, 0x0305u16), "Certified Timestamp Type", "CertifiedTimestampType", vr_type::CODE_STRING),
+    ((0x0400u16, 0x0310u16), "Certified Timestamp", "CertifiedTimestamp", vr_type::OTHER_BYTE),
+    ((0x0400u16, 0x0401u16), "Digital Signature Purpose Code Sequence", "DigitalSignaturePurposeCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0400u16, 0x0402u16), "Referenced Digital Signature Sequence", "ReferencedDigitalSignatureSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0400u16, 0x0403u16), "Referenced SOP Instance MAC Sequence", "ReferencedSOPInstanceMACSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0400u16, 0x0404u16), "MAC", "MAC", vr_type::OTHER_BYTE),
+    ((0x0400u16, 0x0500u16), "Encrypted Attributes Sequence", "EncryptedAttributesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0400u16, 0x0510u16), "Encrypted Content Transfer Syntax Uid", "EncryptedContentTransferSyntaxUID", vr_type::UID),
+    ((0x0400u16, 0x0520u16), "Encrypted Content", "EncryptedContent", vr_type::OTHER_BYTE),
+    ((0x0400u16, 0x0550u16), "Modified Attributes Sequence", "ModifiedAttributesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0400u16, 0x0561u16), "Original Attributes Sequence", "OriginalAttributesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x0400u16, 0x0562u16), "Attribute Modification DateTime", "AttributeModificationDateTime", vr_type::DATE_TIME),
+    ((0x0400u16, 0x0563u16), "Modifying System", "ModifyingSystem", vr_type::LONG_STRING),
+    ((0x0400u16, 0x0564u16), "Source of Previous Values", "SourceOfPreviousValues", vr_type::LONG_STRING),
+    ((0x0400u16, 0x0565u16), "Reason for the Attribute Modification", "ReasonForTheAttributeModification", vr_type::CODE_STRING),
+    ((0x1000u16, 0x0000u16), "Group 1000 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x1000u16, 0x1110u16), "Escape Triplet", "EscapeTriplet", vr_type::UNSIGNED_SHORT),
+    ((0x1000u16, 0x1111u16), "Run Length Triplet", "RunLengthTriplet", vr_type::UNSIGNED_SHORT),
+    ((0x1000u16, 0x1112u16), "Huffman Table Size", "HuffmanTableSize", vr_type::UNSIGNED_SHORT),
+    ((0x1000u16, 0x1113u16), "Huffman Table Triplet", "HuffmanTableTriplet", vr_type::UNSIGNED_SHORT),
+    ((0x1000u16, 0x1114u16), "Shift Table Size", "ShiftTableSize", vr_type::UNSIGNED_SHORT),
+    ((0x1000u16, 0x1115u16), "Shift Table Triplet", "ShiftTableTriplet", vr_type::UNSIGNED_SHORT),
+    ((0x1010u16, 0x0000u16), "Zonal Map", "ZonalMap", vr_type::UNSIGNED_SHORT),
+    ((0x2000u16, 0x0000u16), "Group 2000 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x2000u16, 0x0010u16), "Number of Copies", "NumberOfCopies", vr_type::INTEGER_STRING),
+    ((0x2000u16, 0x001Eu16), "Printer Configuration Sequence", "PrinterConfigurationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2000u16, 0x0020u16), "Print Priority", "PrintPriority", vr_type::CODE_STRING),
+    ((0x2000u16, 0x0030u16), "Medium Type", "MediumType", vr_type::CODE_STRING),
+    ((0x2000u16, 0x0040u16), "Film Destination", "FilmDestination", vr_type::CODE_STRING),
+    ((0x2000u16, 0x0050u16), "Film Session Label", "FilmSessionLabel", vr_type::LONG_STRING),
+    ((0x2000u16, 0x0060u16), "Memory Allocation", "MemoryAllocation", vr_type::INTEGER_STRING),
+    ((0x2000u16, 0x0061u16), "Maximum Memory Allocation", "MaximumMemoryAllocation", vr_type::INTEGER_STRING),
+    ((0x2000u16, 0x0062u16), "Color Image Printing Flag", "ColorImagePrintingFlag", vr_type::CODE_STRING),
+    ((0x2000u16, 0x0063u16), "Collation Flag", "CollationFlag", vr_type::CODE_STRING),
+    ((0x2000u16, 0x0065u16), "Annotation Flag", "AnnotationFlag", vr_type::CODE_STRING),
+    ((0x2000u16, 0x0067u16), "Image Overlay Flag", "ImageOverlayFlag", vr_type::CODE_STRING),
+    ((0x2000u16, 0x0069u16), "Presentation LUT Flag", "PresentationLUTFlag", vr_type::CODE_STRING),
+    ((0x2000u16, 0x006Au16), "Image Box Presentation LUT Flag", "ImageBoxPresentationLUTFlag", vr_type::CODE_STRING),
+    ((0x2000u16, 0x00A0u16), "Memory Bit Depth", "MemoryBitDepth", vr_type::UNSIGNED_SHORT),
+    ((0x2000u16, 0x00A1u16), "Printing Bit Depth", "PrintingBitDepth", vr_type::UNSIGNED_SHORT),
+    ((0x2000u16, 0x00A2u16), "Media Installed Sequence", "MediaInstalledSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2000u16, 0x00A4u16), "Other Media Available Sequence", "OtherMediaAvailableSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2000u16, 0x00A8u16), "Supported Image Display Formats Sequence", "SupportedImageDisplayFormatsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2000u16, 0x0500u16), "Referenced Film Box Sequence", "ReferencedFilmBoxSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2000u16, 0x0510u16), "Referenced Stored Print Sequence", "ReferencedStoredPrintSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2010u16, 0x0000u16), "Group 2010 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x2010u16, 0x0010u16), "Image Display Format", "ImageDisplayFormat", vr_type::SHORT_TEXT),
+    ((0x2010u16, 0x0030u16), "Annotation Display Format ID", "AnnotationDisplayFormatID", vr_type::CODE_STRING),
+    ((0x2010u16, 0x0040u16), "Film Orientation", "FilmOrientation", vr_type::CODE_STRING),
+    ((0x2010u16, 0x0050u16), "Film Size ID", "FilmSizeID", vr_type::CODE_STRING),
+    ((0x2010u16, 0x0052u16), "Printer Resolution ID", "PrinterResolutionID", vr_type::CODE_STRING),
+    ((0x2010u16, 0x0054u16), "Default Printer Resolution ID", "DefaultPrinterResolutionID", vr_type::CODE_STRING),
+    ((0x2010u16, 0x0060u16), "Magnification Type", "MagnificationType", vr_type::CODE_STRING),
+    ((0x2010u16, 0x0080u16), "Smoothing Type", "SmoothingType", vr_type::CODE_STRING),
+    ((0x2010u16, 0x00A6u16), "Default Magnification Type", "DefaultMagnificationType", vr_type::CODE_STRING),
+    ((0x2010u16, 0x00A7u16), "Other Magnification Types Available", "OtherMagnificationTypesAvailable", vr_type::CODE_STRING),
+    ((0x2010u16, 0x00A8u16), "Default Smoothing Type", "DefaultSmoothingType", vr_type::CODE_STRING),
+    ((0x2010u16, 0x00A9u16), "Other Smoothing Types Available", "OtherSmoothingTypesAvailable", vr_type::CODE_STRING),
+    ((0x2010u16, 0x0100u16), "Border Density", "BorderDensity", vr_type::CODE_STRING),
+    ((0x2010u16, 0x0110u16), "Empty Image Density", "EmptyImageDensity", vr_type::CODE_STRING),
+    ((0x2010u16, 0x0120u16), "Min Density", "MinDensity", vr_type::UNSIGNED_SHORT),
+    ((0x2010u16, 0x0130u16), "Max Density", "MaxDensity", vr_type::UNSIGNED_SHORT),
+    ((0x2010u16, 0x0140u16), "Trim", "Trim", vr_type::CODE_STRING),
+    ((0x2010u16, 0x0150u16), "Configuration Information", "ConfigurationInformation", vr_type::SHORT_TEXT),
+    ((0x2010u16, 0x0152u16), "Configuration Information Description", "ConfigurationInformationDescription", vr_type::LONG_TEXT),
+    ((0x2010u16, 0x0154u16), "Maximum Collated Films", "MaximumCollatedFilms", vr_type::INTEGER_STRING),
+    ((0x2010u16, 0x015Eu16), "Illumination", "Illumination", vr_type::UNSIGNED_SHORT),
+    ((0x2010u16, 0x0160u16), "Reflected Ambient Light", "ReflectedAmbientLight", vr_type::UNSIGNED_SHORT),
+    ((0x2010u16, 0x0376u16), "Printer Pixel Spacing", "PrinterPixelSpacing", vr_type::DECIMAL_STRING),
+    ((0x2010u16, 0x0500u16), "Referenced Film Session Sequence", "ReferencedFilmSessionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2010u16, 0x0510u16), "Referenced Image Box Sequence", "ReferencedImageBoxSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2010u16, 0x0520u16), "Referenced Basic Annotation Box Sequence", "ReferencedBasicAnnotationBoxSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2020u16, 0x0000u16), "Group 2020 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x2020u16, 0x0010u16), "Image Box Position", "ImageBoxPosition", vr_type::UNSIGNED_SHORT),
+    ((0x2020u16, 0x0020u16), "Polarity", "Polarity", vr_type::CODE_STRING),
+    ((0x2020u16, 0x0030u16), "Requested Image Size", "RequestedImageSize", vr_type::DECIMAL_STRING),
+    ((0x2020u16, 0x0040u16), "Requested Decimate/Crop Behavior", "RequestedDecimateCropBehavior", vr_type::CODE_STRING),
+    ((0x2020u16, 0x0050u16), "Requested Resolution ID", "RequestedResolutionID", vr_type::CODE_STRING),
+    ((0x2020u16, 0x00A0u16), "Requested Image Size Flag", "RequestedImageSizeFlag", vr_type::CODE_STRING),
+    ((0x2020u16, 0x00A2u16), "Decimate/Crop Result", "DecimateCropResult", vr_type::CODE_STRING),
+    ((0x2020u16, 0x0110u16), "Basic Grayscale Image Sequence", "BasicGrayscaleImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2020u16, 0x0111u16), "Basic Color Image Sequence", "BasicColorImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2020u16, 0x0130u16), "Referenced Image Overlay Box Sequence", "ReferencedImageOverlayBoxSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2020u16, 0x0140u16), "Referenced VOI LUT Box Sequence", "ReferencedVOILUTBoxSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2030u16, 0x0000u16), "Group 2030 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x2030u16, 0x0010u16), "Annotation Position", "AnnotationPosition", vr_type::UNSIGNED_SHORT),
+    ((0x2030u16, 0x0020u16), "Text String", "TextString", vr_type::LONG_STRING),
+    ((0x2040u16, 0x0000u16), "Group 2040 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x2040u16, 0x0010u16), "Referenced Overlay Plane Sequence", "ReferencedOverlayPlaneSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2040u16, 0x0011u16), "Referenced Overlay Plane Groups", "ReferencedOverlayPlaneGroups", vr_type::UNSIGNED_SHORT),
+    ((0x2040u16, 0x0020u16), "Overlay Pixel Data Sequence", "OverlayPixelDataSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2040u16, 0x0060u16), "Overlay Magnification Type", "OverlayMagnificationType", vr_type::CODE_STRING),
+    ((0x2040u16, 0x0070u16), "Overlay Smoothing Type", "OverlaySmoothingType", vr_type::CODE_STRING),
+    ((0x2040u16, 0x0072u16), "Overlay or Image Magnification", "OverlayOrImageMagnification", vr_type::CODE_STRING),
+    ((0x2040u16, 0x0074u16), "Magnify to Number of Columns", "MagnifyToNumberOfColumns", vr_type::UNSIGNED_SHORT),
+    ((0x2040u16, 0x0080u16), "Overlay Foreground Density", "OverlayForegroundDensity", vr_type::CODE_STRING),
+    ((0x2040u16, 0x0082u16), "Overlay Background Density", "OverlayBackgroundDensity", vr_type::CODE_STRING),
+    ((0x2040u16, 0x0090u16), "Overlay Mode", "OverlayMode", vr_type::CODE_STRING),
+    ((0x2040u16, 0x0100u16), "Threshold Density", "ThresholdDensity", vr_type::CODE_STRING),
+    ((0x2040u16, 0x0500u16), "Referenced Image Box Sequence", "ReferencedImageBoxSequenceRetired", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2050u16, 0x0000u16), "Group 2050 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x2050u16, 0x0010u16), "Presentation LUT Sequence", "PresentationLUTSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2050u16, 0x0020u16), "Presentation LUT Shape", "PresentationLUTShape", vr_type::CODE_STRING),
+    ((0x2050u16, 0x0500u16), "Referenced Presentation LUT Sequence", "ReferencedPresentationLUTSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2100u16, 0x0000u16), "Group 2100 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x2100u16, 0x0010u16), "Print Job ID", "PrintJobID", vr_type::SHORT_STRING),
+    ((0x2100u16, 0x0020u16), "Execution Status", "ExecutionStatus", vr_type::CODE_STRING),
+    ((0x2100u16, 0x0030u16), "Execution Status Info", "ExecutionStatusInfo", vr_type::CODE_STRING),
+    ((0x2100u16, 0x0040u16), "Creation Date", "CreationDate", vr_type::DATE),
+    ((0x2100u16, 0x0050u16), "Creation Time", "CreationTime", vr_type::TIME),
+    ((0x2100u16, 0x0070u16), "Originator", "Originator", vr_type::APPLICATION_ENTITY),
+    ((0x2100u16, 0x0140u16), "Destination AE", "DestinationAE", vr_type::APPLICATION_ENTITY),
+    ((0x2100u16, 0x0160u16), "Owner ID", "OwnerID", vr_type::SHORT_STRING),
+    ((0x2100u16, 0x0170u16), "Number of Films", "NumberOfFilms", vr_type::INTEGER_STRING),
+    ((0x2100u16, 0x0500u16), "Referenced Print Job Sequence (Pull Stored Print)", "ReferencedPrintJobSequencePullStoredPrint", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2110u16, 0x0000u16), "Group 2110 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x2110u16, 0x0010u16), "Printer Status", "PrinterStatus", vr_type::CODE_STRING),
+    ((0x2110u16, 0x0020u16), "Printer Status Info", "PrinterStatusInfo", vr_type::CODE_STRING),
+    ((0x2110u16, 0x0030u16), "Printer Name", "PrinterName", vr_type::LONG_STRING),
+    ((0x2110u16, 0x0099u16), "Print Queue ID", "PrintQueueID", vr_type::SHORT_STRING),
+    ((0x2120u16, 0x0000u16), "Group 2120 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x2120u16, 0x0010u16), "Queue Status", "QueueStatus", vr_type::CODE_STRING),
+    ((0x2120u16, 0x0050u16), "Print Job Description Sequence", "PrintJobDescriptionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2120u16, 0x0070u16), "Referenced Print Job Sequence", "ReferencedPrintJobSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2130u16, 0x0000u16), "Group 2130 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x2130u16, 0x0010u16), "Print Management Capabilities Sequence", "PrintManagementCapabilitiesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2130u16, 0x0015u16), "Printer Characteristics Sequence", "PrinterCharacteristicsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2130u16, 0x0030u16), "Film Box Content Sequence", "FilmBoxContentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2130u16, 0x0040u16), "Image Box Content Sequence", "ImageBoxContentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2130u16, 0x0050u16), "Annotation Content Sequence", "AnnotationContentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2130u16, 0x0060u16), "Image Overlay Box Content Sequence", "ImageOverlayBoxContentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2130u16, 0x0080u16), "Presentation LUT Content Sequence", "PresentationLUTContentSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2130u16, 0x00A0u16), "Proposed Study Sequence", "ProposedStudySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2130u16, 0x00C0u16), "Original Image Sequence", "OriginalImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2200u16, 0x0001u16), "Label Using Information Extracted From Instances", "LabelUsingInformationExtractedFromInstances", vr_type::CODE_STRING),
+    ((0x2200u16, 0x0002u16), "Label Text", "LabelText", vr_type::UNLIMITED_TEXT),
+    ((0x2200u16, 0x0003u16), "Label Style Selection", "LabelStyleSelection", vr_type::CODE_STRING),
+    ((0x2200u16, 0x0004u16), "Media Disposition", "MediaDisposition", vr_type::LONG_TEXT),
+    ((0x2200u16, 0x0005u16), "Barcode Value", "BarcodeValue", vr_type::LONG_TEXT),
+    ((0x2200u16, 0x0006u16), "Barcode Symbology", "BarcodeSymbology", vr_type::CODE_STRING),
+    ((0x2200u16, 0x0007u16), "Allow Media Splitting", "AllowMediaSplitting", vr_type::CODE_STRING),
+    ((0x2200u16, 0x0008u16), "Include Non-DICOM Objects", "IncludeNonDICOMObjects", vr_type::CODE_STRING),
+    ((0x2200u16, 0x0009u16), "Include Display Application", "IncludeDisplayApplication", vr_type::CODE_STRING),
+    ((0x2200u16, 0x000Au16), "Preserve Composite Instances After Media Creation", "PreserveCompositeInstancesAfterMediaCreation", vr_type::CODE_STRING),
+    ((0x2200u16, 0x000Bu16), "Total Number of Pieces of Media Created", "TotalNumberOfPiecesOfMediaCreated", vr_type::UNSIGNED_SHORT),
+    ((0x2200u16, 0x000Cu16), "Requested Media Application Profile", "RequestedMediaApplicationProfile", vr_type::LONG_STRING),
+    ((0x2200u16, 0x000Du16), "Referenced Storage Media Sequence", "ReferencedStorageMediaSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x2200u16, 0x000Eu16), "Failure Attributes", "FailureAttributes", vr_type::ATTRIBUTE),
+    ((0x2200u16, 0x000Fu16), "Allow Lossy Compression", "AllowLossyCompression", vr_type::CODE_STRING),
+    ((0x2200u16, 0x0020u16), "Request Priority", "RequestPriority", vr_type::CODE_STRING),
+    ((0x3002u16, 0x0000u16), "Group 3002 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x3002u16, 0x0002u16), "RT Image Label", "RTImageLabel", vr_type::SHORT_STRING),
+    ((0x3002u16, 0x0003u16), "RT Image Name", "RTImageName", vr_type::LONG_STRING),
+    ((0x3002u16, 0x0004u16), "RT Image Description", "RTImageDescription", vr_type::SHORT_TEXT),
+    ((0x3002u16, 0x000Au16), "Reported Values Origin", "ReportedValuesOrigin", vr_type::CODE_STRING),
+    ((0x3002u16, 0x000Cu16), "RT Image Plane", "RTImagePlane", vr_type::CODE_STRING),
+    ((0x3002u16, 0x000Du16), "X-Ray Image Receptor Translation", "XRayImageReceptorTranslation", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x000Eu16), "X-Ray Image Receptor Angle", "XRayImageReceptorAngle", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x0010u16), "RT Image Orientation", "RTImageOrientation", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x0011u16), "Image Plane Pixel Spacing", "ImagePlanePixelSpacing", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x0012u16), "RT Image Position", "RTImagePosition", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x0020u16), "Radiation Machine Name", "RadiationMachineName", vr_type::SHORT_STRING),
+    ((0x3002u16, 0x0022u16), "Radiation Machine SAD", "RadiationMachineSAD", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x0024u16), "Radiation Machine SSD", "RadiationMachineSSD", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x0026u16), "RT Image SID", "RTImageSID", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x0028u16), "Source to Reference Object Distance", "SourceToReferenceObjectDistance", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x0029u16), "Fraction Number", "FractionNumber", vr_type::INTEGER_STRING),
+    ((0x3002u16, 0x0030u16), "Exposure Sequence", "ExposureSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3002u16, 0x0032u16), "Meterset Exposure", "MetersetExposure", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x0034u16), "Diaphragm Position", "DiaphragmPosition", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x0040u16), "Fluence Map Sequence", "FluenceMapSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3002u16, 0x0041u16), "Fluence Data Source", "FluenceDataSource", vr_type::CODE_STRING),
+    ((0x3002u16, 0x0042u16), "Fluence Data Scale", "FluenceDataScale", vr_type::DECIMAL_STRING),
+    ((0x3002u16, 0x0050u16), "Primary Fluence Mode Sequence", "PrimaryFluenceModeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3002u16, 0x0051u16), "Fluence Mode", "FluenceMode", vr_type::CODE_STRING),
+    ((0x3002u16, 0x0052u16), "Fluence Mode ID", "FluenceModeID", vr_type::SHORT_STRING),
+    ((0x3004u16, 0x0000u16), "Group 3004 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x3004u16, 0x0001u16), "DVH Type", "DVHType", vr_type::CODE_STRING),
+    ((0x3004u16, 0x0002u16), "Dose Units", "DoseUnits", vr_type::CODE_STRING),
+    ((0x3004u16, 0x0004u16), "Dose Type", "DoseType", vr_type::CODE_STRING),
+    ((0x3004u16, 0x0006u16), "Dose Comment", "DoseComment", vr_type::LONG_STRING),
+    ((0x3004u16, 0x0008u16), "Normalization Point", "NormalizationPoint", vr_type::DECIMAL_STRING),
+    ((0x3004u16, 0x000Au16), "Dose Summation Type", "DoseSummationType", vr_type::CODE_STRING),
+    ((0x3004u16, 0x000Cu16), "Grid Frame Offset Vector", "GridFrameOffsetVector", vr_type::DECIMAL_STRING),
+    ((0x3004u16, 0x000Eu16), "Dose Grid Scaling", "DoseGridScaling", vr_type::DECIMAL_STRING),
+    ((0x3004u16, 0x0010u16), "RT Dose ROI Sequence", "RTDoseROISequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3004u16, 0x0012u16), "Dose Value", "DoseValue", vr_type::DECIMAL_STRING),
+    ((0x3004u16, 0x0014u16), "Tissue Heterogeneity Correction", "TissueHeterogeneityCorrection", vr_type::CODE_STRING),
+    ((0x3004u16, 0x0040u16), "DVH Normalization Point", "DVHNormalizationPoint", vr_type::DECIMAL_STRING),
+    ((0x3004u16, 0x0042u16), "DVH Normalization Dose Value", "DVHNormalizationDoseValue", vr_type::DECIMAL_STRING),
+    ((0x3004u16, 0x0050u16), "DVH Sequence", "DVHSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3004u16, 0x0052u16), "DVH Dose Scaling", "DVHDoseScaling", vr_type::DECIMAL_STRING),
+    ((0x3004u16, 0x0054u16), "DVH Volume Units", "DVHVolumeUnits", vr_type::CODE_STRING),
+    ((0x3004u16, 0x0056u16), "DVH Number of Bins", "DVHNumberOfBins", vr_type::INTEGER_STRING),
+    ((0x3004u16, 0x0058u16), "DVH Data", "DVHData", vr_type::DECIMAL_STRING),
+    ((0x3004u16, 0x0060u16), "DVH Referenced ROI Sequence", "DVHReferencedROISequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3004u16, 0x0062u16), "DVH ROI Contribution Type", "DVHROIContributionType", vr_type::CODE_STRING),
+    ((0x3004u16, 0x0070u16), "DVH Minimum Dose", "DVHMinimumDose", vr_type::DECIMAL_STRING),
+    ((0x3004u16, 0x0072u16), "DVH Maximum Dose", "DVHMaximumDose", vr_type::DECIMAL_STRING),
+    ((0x3004u16, 0x0074u16), "DVH Mean Dose", "DVHMeanDose", vr_type::DECIMAL_STRING),
+    ((0x3006u16, 0x0000u16), "Group 3006 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x3006u16, 0x0002u16), "Structure Set Label", "StructureSetLabel", vr_type::SHORT_STRING),
+    ((0x3006u16, 0x0004u16), "Structure Set Name", "StructureSetName", vr_type::LONG_STRING),
+    ((0x3006u16, 0x0006u16), "Structure Set Description", "StructureSetDescription", vr_type::SHORT_TEXT),
+    ((0x3006u16, 0x0008u16), "Structure Set Date", "StructureSetDate", vr_type::DATE),
+    ((0x3006u16, 0x0009u16), "Structure Set Time", "StructureSetTime", vr_type::TIME),
+    ((0x3006u16, 0x0010u16), "Referenced Frame of Reference Sequence", "ReferencedFrameOfReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x0012u16), "RT Referenced Study Sequence", "RTReferencedStudySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x0014u16), "RT Referenced Series Sequence", "RTReferencedSeriesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x0016u16), "Contour Image Sequence", "ContourImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x0020u16), "Structure Set ROI Sequence", "StructureSetROISequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x0022u16), "ROI Number", "ROINumber", vr_type::INTEGER_STRING),
+    ((0x3006u16, 0x0024u16), "Referenced Frame of Reference Uid", "ReferencedFrameOfReferenceUID", vr_type::UID),
+    ((0x3006u16, 0x0026u16), "ROI Name", "ROIName", vr_type::LONG_STRING),
+    ((0x3006u16, 0x0028u16), "ROI Description", "ROIDescription", vr_type::SHORT_TEXT),
+    ((0x3006u16, 0x002Au16), "ROI Display Color", "ROIDisplayColor", vr_type::INTEGER_STRING),
+    ((0x3006u16, 0x002Cu16), "ROI Volume", "ROIVolume", vr_type::DECIMAL_STRING),
+    ((0x3006u16, 0x0030u16), "RT Related ROI Sequence", "RTRelatedROISequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x0033u16), "RT ROI Relationship", "RTROIRelationship", vr_type::CODE_STRING),
+    ((0x3006u16, 0x0036u16), "ROI Generation Algorithm", "ROIGenerationAlgorithm", vr_type::CODE_STRING),
+    ((0x3006u16, 0x0038u16), "ROI Generation Description", "ROIGenerationDescription", vr_type::LONG_STRING),
+    ((0x3006u16, 0x0039u16), "ROI Contour Sequence", "ROIContourSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x0040u16), "Contour Sequence", "ContourSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x0042u16), "Contour Geometric Type", "ContourGeometricType", vr_type::CODE_STRING),
+    ((0x3006u16, 0x0044u16), "Contour Slab Thickness", "ContourSlabThickness", vr_type::DECIMAL_STRING),
+    ((0x3006u16, 0x0045u16), "Contour Offset Vector", "ContourOffsetVector", vr_type::DECIMAL_STRING),
+    ((0x3006u16, 0x0046u16), "Number of Contour Points", "NumberOfContourPoints", vr_type::INTEGER_STRING),
+    ((0x3006u16, 0x0048u16), "Contour Number", "ContourNumber", vr_type::INTEGER_STRING),
+    ((0x3006u16, 0x0049u16), "Attached Contours", "AttachedContours", vr_type::INTEGER_STRING),
+    ((0x3006u16, 0x0050u16), "Contour Data", "ContourData", vr_type::DECIMAL_STRING),
+    ((0x3006u16, 0x0080u16), "RT ROI Observations Sequence", "RTROIObservationsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x0082u16), "Observation Number", "ObservationNumber", vr_type::INTEGER_STRING),
+    ((0x3006u16, 0x0084u16), "Referenced ROI Number", "ReferencedROINumber", vr_type::INTEGER_STRING),
+    ((0x3006u16, 0x0085u16), "ROI Observation Label", "ROIObservationLabel", vr_type::SHORT_STRING),
+    ((0x3006u16, 0x0086u16), "RT ROI Identification Code Sequence", "RTROIIdentificationCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x0088u16), "ROI Observation Description", "ROIObservationDescription", vr_type::SHORT_TEXT),
+    ((0x3006u16, 0x00A0u16), "Related RT ROI Observations Sequence", "RelatedRTROIObservationsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x00A4u16), "RT ROI Interpreted Type", "RTROIInterpretedType", vr_type::CODE_STRING),
+    ((0x3006u16, 0x00A6u16), "ROI Interpreter", "ROIInterpreter", vr_type::PERSON_NAME),
+    ((0x3006u16, 0x00B0u16), "ROI Physical Properties Sequence", "ROIPhysicalPropertiesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x00B2u16), "ROI Physical Property", "ROIPhysicalProperty", vr_type::CODE_STRING),
+    ((0x3006u16, 0x00B4u16), "ROI Physical Property Value", "ROIPhysicalPropertyValue", vr_type::DECIMAL_STRING),
+    ((0x3006u16, 0x00B6u16), "ROI Elemental Composition Sequence", "ROIElementalCompositionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x00B7u16), "ROI Elemental Composition Atomic Number", "ROIElementalCompositionAtomicNumber", vr_type::UNSIGNED_SHORT),
+    ((0x3006u16, 0x00B8u16), "ROI Elemental Composition Atomic Mass Fraction", "ROIElementalCompositionAtomicMassFraction", vr_type::FLOAT),
+    ((0x3006u16, 0x00C0u16), "Frame of Reference Relationship Sequence", "FrameOfReferenceRelationshipSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3006u16, 0x00C2u16), "Related Frame of Reference Uid", "RelatedFrameOfReferenceUID", vr_type::UID),
+    ((0x3006u16, 0x00C4u16), "Frame of Reference Transformation Type", "FrameOfReferenceTransformationType", vr_type::CODE_STRING),
+    ((0x3006u16, 0x00C6u16), "Frame of Reference Transformation Matrix", "FrameOfReferenceTransformationMatrix", vr_type::DECIMAL_STRING),
+    ((0x3006u16, 0x00C8u16), "Frame of Reference Transformation Comment", "FrameOfReferenceTransformationComment", vr_type::LONG_STRING),
+    ((0x3008u16, 0x0000u16), "Group 3008 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x3008u16, 0x0010u16), "Measured Dose Reference Sequence", "MeasuredDoseReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0012u16), "Measured Dose Description", "MeasuredDoseDescription", vr_type::SHORT_TEXT),
+    ((0x3008u16, 0x0014u16), "Measured Dose Type", "MeasuredDoseType", vr_type::CODE_STRING),
+    ((0x3008u16, 0x0016u16), "Measured Dose Value", "MeasuredDoseValue", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0020u16), "Treatment Session Beam Sequence", "TreatmentSessionBeamSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0021u16), "Treatment Session Ion Beam Sequence", "TreatmentSessionIonBeamSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0022u16), "Current Fraction Number", "CurrentFractionNumber", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x0024u16), "Treatment Control Point Date", "TreatmentControlPointDate", vr_type::DATE),
+    ((0x3008u16, 0x0025u16), "Treatment Control Point Time", "TreatmentControlPointTime", vr_type::TIME),
+    ((0x3008u16, 0x002Au16), "Treatment Termination Status", "TreatmentTerminationStatus", vr_type::CODE_STRING),
+    ((0x3008u16, 0x002Bu16), "Treatment Termination Code", "TreatmentTerminationCode", vr_type::SHORT_STRING),
+    ((0x3008u16, 0x002Cu16), "Treatment Verification Status", "TreatmentVerificationStatus", vr_type::CODE_STRING),
+    ((0x3008u16, 0x0030u16), "Referenced Treatment Record Sequence", "ReferencedTreatmentRecordSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0032u16), "Specified Primary Meterset", "SpecifiedPrimaryMeterset", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0033u16), "Specified Secondary Meterset", "SpecifiedSecondaryMeterset", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0036u16), "Delivered Primary Meterset", "DeliveredPrimaryMeterset", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0037u16), "Delivered Secondary Meterset", "DeliveredSecondaryMeterset", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x003Au16), "Specified Treatment Time", "SpecifiedTreatmentTime", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x003Bu16), "Delivered Treatment Time", "DeliveredTreatmentTime", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0040u16), "Control Point Delivery Sequence", "ControlPointDeliverySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0041u16), "Ion Control Point Delivery Sequence", "IonControlPointDeliverySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0042u16), "Specified Meterset", "SpecifiedMeterset", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0044u16), "Delivered Meterset", "DeliveredMeterset", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0045u16), "Meterset Rate Set", "MetersetRateSet", vr_type::FLOAT),
+    ((0x3008u16, 0x0046u16), "Meterset Rate Delivered", "MetersetRateDelivered", vr_type::FLOAT),
+    ((0x3008u16, 0x0047u16), "Scan Spot Metersets Delivered", "ScanSpotMetersetsDelivered", vr_type::FLOAT),
+    ((0x3008u16, 0x0048u16), "Dose Rate Delivered", "DoseRateDelivered", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0050u16), "Treatment Summary Calculated Dose Reference Sequence", "TreatmentSummaryCalculatedDoseReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0052u16), "Cumulative Dose to Dose Reference", "CumulativeDoseToDoseReference", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0054u16), "First Treatment Date", "FirstTreatmentDate", vr_type::DATE),
+    ((0x3008u16, 0x0056u16), "Most Recent Treatment Date", "MostRecentTreatmentDate", vr_type::DATE),
+    ((0x3008u16, 0x005Au16), "Number of Fractions Delivered", "NumberOfFractionsDelivered", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x0060u16), "Override Sequence", "OverrideSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0061u16), "Parameter Sequence Pointer", "ParameterSequencePointer", vr_type::ATTRIBUTE),
+    ((0x3008u16, 0x0062u16), "Override Parameter Pointer", "OverrideParameterPointer", vr_type::ATTRIBUTE),
+    ((0x3008u16, 0x0063u16), "Parameter Item Index", "ParameterItemIndex", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x0064u16), "Measured Dose Reference Number", "MeasuredDoseReferenceNumber", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x0065u16), "Parameter Pointer", "ParameterPointer", vr_type::ATTRIBUTE),
+    ((0x3008u16, 0x0066u16), "Override Reason", "OverrideReason", vr_type::SHORT_TEXT),
+    ((0x3008u16, 0x0068u16), "Corrected Parameter Sequence", "CorrectedParameterSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x006Au16), "Correction Value", "CorrectionValue", vr_type::FLOAT),
+    ((0x3008u16, 0x0070u16), "Calculated Dose Reference Sequence", "CalculatedDoseReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0072u16), "Calculated Dose Reference Number", "CalculatedDoseReferenceNumber", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x0074u16), "Calculated Dose Reference Description", "CalculatedDoseReferenceDescription", vr_type::SHORT_TEXT),
+    ((0x3008u16, 0x0076u16), "Calculated Dose Reference Dose Value", "CalculatedDoseReferenceDoseValue", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0078u16), "Start Meterset", "StartMeterset", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x007Au16), "End Meterset", "EndMeterset", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0080u16), "Referenced Measured Dose Reference Sequence", "ReferencedMeasuredDoseReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0082u16), "Referenced Measured Dose Reference Number", "ReferencedMeasuredDoseReferenceNumber", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x0090u16), "Referenced Calculated Dose Reference Sequence", "ReferencedCalculatedDoseReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0092u16), "Referenced Calculated Dose Reference Number", "ReferencedCalculatedDoseReferenceNumber", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x00A0u16), "Beam Limiting Device Leaf Pairs Sequence", "BeamLimitingDeviceLeafPairsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x00B0u16), "Recorded Wedge Sequence", "RecordedWedgeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x00C0u16), "Recorded Compensator Sequence", "RecordedCompensatorSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x00D0u16), "Recorded Block Sequence", "RecordedBlockSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x00E0u16), "Treatment Summary Measured Dose Reference Sequence", "TreatmentSummaryMeasuredDoseReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x00F0u16), "Recorded Snout Sequence", "RecordedSnoutSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x00F2u16), "Recorded Range Shifter Sequence", "RecordedRangeShifterSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x00F4u16), "Recorded Lateral Spreading Device Sequence", "RecordedLateralSpreadingDeviceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x00F6u16), "Recorded Range Modulator Sequence", "RecordedRangeModulatorSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0100u16), "Recorded Source Sequence", "RecordedSourceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0105u16), "Source Serial Number", "SourceSerialNumber", vr_type::LONG_STRING),
+    ((0x3008u16, 0x0110u16), "Treatment Session Application Setup Sequence", "TreatmentSessionApplicationSetupSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0116u16), "Application Setup Check", "ApplicationSetupCheck", vr_type::CODE_STRING),
+    ((0x3008u16, 0x0120u16), "Recorded Brachy Accessory Device Sequence", "RecordedBrachyAccessoryDeviceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0122u16), "Referenced Brachy Accessory Device Number", "ReferencedBrachyAccessoryDeviceNumber", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x0130u16), "Recorded Channel Sequence", "RecordedChannelSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0132u16), "Specified Channel Total Time", "SpecifiedChannelTotalTime", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0134u16), "Delivered Channel Total Time", "DeliveredChannelTotalTime", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0136u16), "Specified Number of Pulses", "SpecifiedNumberOfPulses", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x0138u16), "Delivered Number of Pulses", "DeliveredNumberOfPulses", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x013Au16), "Specified Pulse Repetition Interval", "SpecifiedPulseRepetitionInterval", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x013Cu16), "Delivered Pulse Repetition Interval", "DeliveredPulseRepetitionInterval", vr_type::DECIMAL_STRING),
+    ((0x3008u16, 0x0140u16), "Recorded Source Applicator Sequence", "RecordedSourceApplicatorSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0142u16), "Referenced Source Applicator Number", "ReferencedSourceApplicatorNumber", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x0150u16), "Recorded Channel Shield Sequence", "RecordedChannelShieldSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0152u16), "Referenced Channel Shield Number", "ReferencedChannelShieldNumber", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x0160u16), "Brachy Control Point Delivered Sequence", "BrachyControlPointDeliveredSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0162u16), "Safe Position Exit Date", "SafePositionExitDate", vr_type::DATE),
+    ((0x3008u16, 0x0164u16), "Safe Position Exit Time", "SafePositionExitTime", vr_type::TIME),
+    ((0x3008u16, 0x0166u16), "Safe Position Return Date", "SafePositionReturnDate", vr_type::DATE),
+    ((0x3008u16, 0x0168u16), "Safe Position Return Time", "SafePositionReturnTime", vr_type::TIME),
+    ((0x3008u16, 0x0200u16), "Current Treatment Status", "CurrentTreatmentStatus", vr_type::CODE_STRING),
+    ((0x3008u16, 0x0202u16), "Treatment Status Comment", "TreatmentStatusComment", vr_type::SHORT_TEXT),
+    ((0x3008u16, 0x0220u16), "Fraction Group Summary Sequence", "FractionGroupSummarySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0223u16), "Referenced Fraction Number", "ReferencedFractionNumber", vr_type::INTEGER_STRING),
+    ((0x3008u16, 0x0224u16), "Fraction Group Type", "FractionGroupType", vr_type::CODE_STRING),
+    ((0x3008u16, 0x0230u16), "Beam Stopper Position", "BeamStopperPosition", vr_type::CODE_STRING),
+    ((0x3008u16, 0x0240u16), "Fraction Status Summary Sequence", "FractionStatusSummarySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x3008u16, 0x0250u16), "Treatment Date", "TreatmentDate", vr_type::DATE),
+    ((0x3008u16, 0x0251u16), "Treatment Time", "TreatmentTime", vr_type::TIME),
+    ((0x300Au16, 0x0000u16), "Group 300A Length", "", vr_type::UNSIGNED_LONG),
+    ((0x300Au16, 0x0002u16), "RT Plan Label", "RTPlanLabel", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0003u16), "RT Plan Name", "RTPlanName", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0004u16), "RT Plan Description", "RTPlanDescription", vr_type::SHORT_TEXT),
+    ((0x300Au16, 0x0006u16), "RT Plan Date", "RTPlanDate", vr_type::DATE),
+    ((0x300Au16, 0x0007u16), "RT Plan Time", "RTPlanTime", vr_type::TIME),
+    ((0x300Au16, 0x0009u16), "Treatment Protocols", "TreatmentProtocols", vr_type::LONG_STRING),
+    ((0x300Au16, 0x000Au16), "Plan Intent", "PlanIntent", vr_type::CODE_STRING),
+    ((0x300Au16, 0x000Bu16), "Treatment Sites", "TreatmentSites", vr_type::LONG_STRING),
+    ((0x300Au16, 0x000Cu16), "RT Plan Geometry", "RTPlanGeometry", vr_type::CODE_STRING),
+    ((0x300Au16, 0x000Eu16), "Prescription Description", "PrescriptionDescription", vr_type::SHORT_TEXT),
+    ((0x300Au16, 0x0010u16), "Dose Reference Sequence", "DoseReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0012u16), "Dose Reference Number", "DoseReferenceNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0013u16), "Dose Reference Uid", "DoseReferenceUID", vr_type::UID),
+    ((0x300Au16, 0x0014u16), "Dose Reference Structure Type", "DoseReferenceStructureType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0015u16), "Nominal Beam Energy Unit", "NominalBeamEnergyUnit", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0016u16), "Dose Reference Description", "DoseReferenceDescription", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0018u16), "Dose Reference Point Coordinates", "DoseReferencePointCoordinates", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x001Au16), "Nominal Prior Dose", "NominalPriorDose", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0020u16), "Dose Reference Type", "DoseReferenceType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0021u16), "Constraint Weight", "ConstraintWeight", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0022u16), "Delivery Warning Dose", "DeliveryWarningDose", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0023u16), "Delivery Maximum Dose", "DeliveryMaximumDose", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0025u16), "Target Minimum Dose", "TargetMinimumDose", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0026u16), "Target Prescription Dose", "TargetPrescriptionDose", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0027u16), "Target Maximum Dose", "TargetMaximumDose", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0028u16), "Target Underdose Volume Fraction", "TargetUnderdoseVolumeFraction", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x002Au16), "Organ at Risk Full-volume Dose", "OrganAtRiskFullVolumeDose", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x002Bu16), "Organ at Risk Limit Dose", "OrganAtRiskLimitDose", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x002Cu16), "Organ at Risk Maximum Dose", "OrganAtRiskMaximumDose", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x002Du16), "Organ at Risk Overdose Volume Fraction", "OrganAtRiskOverdoseVolumeFraction", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0040u16), "Tolerance Table Sequence", "ToleranceTableSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0042u16), "Tolerance Table Number", "ToleranceTableNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0043u16), "Tolerance Table Label", "ToleranceTableLabel", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0044u16), "Gantry Angle Tolerance", "GantryAngleTolerance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0046u16), "Beam Limiting Device Angle Tolerance", "BeamLimitingDeviceAngleTolerance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0048u16), "Beam Limiting Device Tolerance Sequence", "BeamLimitingDeviceToleranceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x004Au16), "Beam Limiting Device Position Tolerance", "BeamLimitingDevicePositionTolerance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x004Bu16), "Snout Position Tolerance", "SnoutPositionTolerance", vr_type::FLOAT),
+    ((0x300Au16, 0x004Cu16), "Patient Support Angle Tolerance", "PatientSupportAngleTolerance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x004Eu16), "Table Top Eccentric Angle Tolerance", "TableTopEccentricAngleTolerance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x004Fu16), "Table Top Pitch Angle Tolerance", "TableTopPitchAngleTolerance", vr_type::FLOAT),
+    ((0x300Au16, 0x0050u16), "Table Top Roll Angle Tolerance", "TableTopRollAngleTolerance", vr_type::FLOAT),
+    ((0x300Au16, 0x0051u16), "Table Top Vertical Position Tolerance", "TableTopVerticalPositionTolerance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0052u16), "Table Top Longitudinal Position Tolerance", "TableTopLongitudinalPositionTolerance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0053u16), "Table Top Lateral Position Tolerance", "TableTopLateralPositionTolerance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0055u16), "RT Plan Relationship", "RTPlanRelationship", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0070u16), "Fraction Group Sequence", "FractionGroupSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0071u16), "Fraction Group Number", "FractionGroupNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0072u16), "Fraction Group Description", "FractionGroupDescription", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0078u16), "Number of Fractions Planned", "NumberOfFractionsPlanned", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0079u16), "Number of Fraction Pattern Digits Per Day", "NumberOfFractionPatternDigitsPerDay", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x007Au16), "Repeat Fraction Cycle Length", "RepeatFractionCycleLength", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x007Bu16), "Fraction Pattern", "FractionPattern", vr_type::LONG_TEXT),
+    ((0x300Au16, 0x0080u16), "Number of Beams", "NumberOfBeams", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0082u16), "Beam Dose Specification Point", "BeamDoseSpecificationPoint", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0084u16), "Beam Dose", "BeamDose", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0086u16), "Beam Meterset", "BeamMeterset", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0088u16), "Beam Dose Point Depth", "BeamDosePointDepth", vr_type::FLOAT),
+    ((0x300Au16, 0x0089u16), "Beam Dose Point Equivalent Depth", "BeamDosePointEquivalentDepth", vr_type::FLOAT),
+    ((0x300Au16, 0x008Au16), "Beam Dose Point SSD", "BeamDosePointSSD", vr_type::FLOAT),
+    ((0x300Au16, 0x00A0u16), "Number of Brachy Application Setups", "NumberOfBrachyApplicationSetups", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00A2u16), "Brachy Application Setup Dose Specification Point", "BrachyApplicationSetupDoseSpecificationPoint", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00A4u16), "Brachy Application Setup Dose", "BrachyApplicationSetupDose", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00B0u16), "Beam Sequence", "BeamSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x00B2u16), "Treatment Machine Name", "TreatmentMachineName", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x00B3u16), "Primary Dosimeter Unit", "PrimaryDosimeterUnit", vr_type::CODE_STRING),
+    ((0x300Au16, 0x00B4u16), "Source-Axis Distance", "SourceAxisDistance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00B6u16), "Beam Limiting Device Sequence", "BeamLimitingDeviceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x00B8u16), "RT Beam Limiting Device Type", "RTBeamLimitingDeviceType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x00BAu16), "Source to Beam Limiting Device Distance", "SourceToBeamLimitingDeviceDistance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00BBu16), "Isocenter to Beam Limiting Device Distance", "IsocenterToBeamLimitingDeviceDistance", vr_type::FLOAT),
+    ((0x300Au16, 0x00BCu16), "Number of Leaf/Jaw Pairs", "NumberOfLeafJawPairs", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00BEu16), "Leaf Position Boundaries", "LeafPositionBoundaries", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00C0u16), "Beam Number", "BeamNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00C2u16), "Beam Name", "BeamName", vr_type::LONG_STRING),
+    ((0x300Au16, 0x00C3u16), "Beam Description", "BeamDescription", vr_type::SHORT_TEXT),
+    ((0x300Au16, 0x00C4u16), "Beam Type", "BeamType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x00C6u16), "Radiation Type", "RadiationType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x00C7u16), "High-Dose Technique Type", "HighDoseTechniqueType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x00C8u16), "Reference Image Number", "ReferenceImageNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00CAu16), "Planned Verification Image Sequence", "PlannedVerificationImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x00CCu16), "Imaging Device-Specific Acquisition Parameters", "ImagingDeviceSpecificAcquisitionParameters", vr_type::LONG_STRING),
+    ((0x300Au16, 0x00CEu16), "Treatment Delivery Type", "TreatmentDeliveryType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x00D0u16), "Number of Wedges", "NumberOfWedges", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00D1u16), "Wedge Sequence", "WedgeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x00D2u16), "Wedge Number", "WedgeNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00D3u16), "Wedge Type", "WedgeType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x00D4u16), "Wedge ID", "WedgeID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x00D5u16), "Wedge Angle", "WedgeAngle", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00D6u16), "Wedge Factor", "WedgeFactor", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00D7u16), "Total Wedge Tray Water-Equivalent Thickness", "TotalWedgeTrayWaterEquivalentThickness", vr_type::FLOAT),
+    ((0x300Au16, 0x00D8u16), "Wedge Orientation", "WedgeOrientation", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00D9u16), "Isocenter to Wedge Tray Distance", "IsocenterToWedgeTrayDistance", vr_type::FLOAT),
+    ((0x300Au16, 0x00DAu16), "Source to Wedge Tray Distance", "SourceToWedgeTrayDistance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00DBu16), "Wedge Thin Edge Position", "WedgeThinEdgePosition", vr_type::FLOAT),
+    ((0x300Au16, 0x00DCu16), "Bolus ID", "BolusID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x00DDu16), "Bolus Description", "BolusDescription", vr_type::SHORT_TEXT),
+    ((0x300Au16, 0x00E0u16), "Number of Compensators", "NumberOfCompensators", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00E1u16), "Material ID", "MaterialID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x00E2u16), "Total Compensator Tray Factor", "TotalCompensatorTrayFactor", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00E3u16), "Compensator Sequence", "CompensatorSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x00E4u16), "Compensator Number", "CompensatorNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00E5u16), "Compensator ID", "CompensatorID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x00E6u16), "Source to Compensator Tray Distance", "SourceToCompensatorTrayDistance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00E7u16), "Compensator Rows", "CompensatorRows", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00E8u16), "Compensator Columns", "CompensatorColumns", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00E9u16), "Compensator Pixel Spacing", "CompensatorPixelSpacing", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00EAu16), "Compensator Position", "CompensatorPosition", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00EBu16), "Compensator Transmission Data", "CompensatorTransmissionData", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00ECu16), "Compensator Thickness Data", "CompensatorThicknessData", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00EDu16), "Number of Boli", "NumberOfBoli", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00EEu16), "Compensator Type", "CompensatorType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x00F0u16), "Number of Blocks", "NumberOfBlocks", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00F2u16), "Total Block Tray Factor", "TotalBlockTrayFactor", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00F3u16), "Total Block Tray Water-Equivalent Thickness", "TotalBlockTrayWaterEquivalentThickness", vr_type::FLOAT),
+    ((0x300Au16, 0x00F4u16), "Block Sequence", "BlockSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x00F5u16), "Block Tray ID", "BlockTrayID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x00F6u16), "Source to Block Tray Distance", "SourceToBlockTrayDistance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x00F7u16), "Isocenter to Block Tray Distance", "IsocenterToBlockTrayDistance", vr_type::FLOAT),
+    ((0x300Au16, 0x00F8u16), "Block Type", "BlockType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x00F9u16), "Accessory Code", "AccessoryCode", vr_type::LONG_STRING),
+    ((0x300Au16, 0x00FAu16), "Block Divergence", "BlockDivergence", vr_type::CODE_STRING),
+    ((0x300Au16, 0x00FBu16), "Block Mounting Position", "BlockMountingPosition", vr_type::CODE_STRING),
+    ((0x300Au16, 0x00FCu16), "Block Number", "BlockNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x00FEu16), "Block Name", "BlockName", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0100u16), "Block Thickness", "BlockThickness", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0102u16), "Block Transmission", "BlockTransmission", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0104u16), "Block Number of Points", "BlockNumberOfPoints", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0106u16), "Block Data", "BlockData", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0107u16), "Applicator Sequence", "ApplicatorSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0108u16), "Applicator ID", "ApplicatorID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0109u16), "Applicator Type", "ApplicatorType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x010Au16), "Applicator Description", "ApplicatorDescription", vr_type::LONG_STRING),
+    ((0x300Au16, 0x010Cu16), "Cumulative Dose Reference Coefficient", "CumulativeDoseReferenceCoefficient", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x010Eu16), "Final Cumulative Meterset Weight", "FinalCumulativeMetersetWeight", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0110u16), "Number of Control Points", "NumberOfControlPoints", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0111u16), "Control Point Sequence", "ControlPointSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0112u16), "Control Point Index", "ControlPointIndex", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0114u16), "Nominal Beam Energy", "NominalBeamEnergy", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0115u16), "Dose Rate Set", "DoseRateSet", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0116u16), "Wedge Position Sequence", "WedgePositionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0118u16), "Wedge Position", "WedgePosition", vr_type::CODE_STRING),
+    ((0x300Au16, 0x011Au16), "Beam Limiting Device Position Sequence", "BeamLimitingDevicePositionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x011Cu16), "Leaf/Jaw Positions", "LeafJawPositions", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x011Eu16), "Gantry Angle", "GantryAngle", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x011Fu16), "Gantry Rotation Direction", "GantryRotationDirection", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0120u16), "Beam Limiting Device Angle", "BeamLimitingDeviceAngle", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0121u16), "Beam Limiting Device Rotation Direction", "BeamLimitingDeviceRotationDirection", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0122u16), "Patient Support Angle", "PatientSupportAngle", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0123u16), "Patient Support Rotation Direction", "PatientSupportRotationDirection", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0124u16), "Table Top Eccentric Axis Distance", "TableTopEccentricAxisDistance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0125u16), "Table Top Eccentric Angle", "TableTopEccentricAngle", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0126u16), "Table Top Eccentric Rotation Direction", "TableTopEccentricRotationDirection", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0128u16), "Table Top Vertical Position", "TableTopVerticalPosition", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0129u16), "Table Top Longitudinal Position", "TableTopLongitudinalPosition", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x012Au16), "Table Top Lateral Position", "TableTopLateralPosition", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x012Cu16), "Isocenter Position", "IsocenterPosition", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x012Eu16), "Surface Entry Point", "SurfaceEntryPoint", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0130u16), "Source to Surface Distance", "SourceToSurfaceDistance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0134u16), "Cumulative Meterset Weight", "CumulativeMetersetWeight", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0140u16), "Table Top Pitch Angle", "TableTopPitchAngle", vr_type::FLOAT),
+    ((0x300Au16, 0x0142u16), "Table Top Pitch Rotation Direction", "TableTopPitchRotationDirection", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0144u16), "Table Top Roll Angle", "TableTopRollAngle", vr_type::FLOAT),
+    ((0x300Au16, 0x0146u16), "Table Top Roll Rotation Direction", "TableTopRollRotationDirection", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0148u16), "Head Fixation Angle", "HeadFixationAngle", vr_type::FLOAT),
+    ((0x300Au16, 0x014Au16), "Gantry Pitch Angle", "GantryPitchAngle", vr_type::FLOAT),
+    ((0x300Au16, 0x014Cu16), "Gantry Pitch Rotation Direction", "GantryPitchRotationDirection", vr_type::CODE_STRING),
+    ((0x300Au16, 0x014Eu16), "Gantry Pitch Angle Tolerance", "GantryPitchAngleTolerance", vr_type::FLOAT),
+    ((0x300Au16, 0x0180u16), "Patient Setup Sequence", "PatientSetupSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0182u16), "Patient Setup Number", "PatientSetupNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0183u16), "Patient Setup Label", "PatientSetupLabel", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0184u16), "Patient Additional Position", "PatientAdditionalPosition", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0190u16), "Fixation Device Sequence", "FixationDeviceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0192u16), "Fixation Device Type", "FixationDeviceType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0194u16), "Fixation Device Label", "FixationDeviceLabel", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0196u16), "Fixation Device Description", "FixationDeviceDescription", vr_type::SHORT_TEXT),
+    ((0x300Au16, 0x0198u16), "Fixation Device Position", "FixationDevicePosition", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0199u16), "Fixation Device Pitch Angle", "FixationDevicePitchAngle", vr_type::FLOAT),
+    ((0x300Au16, 0x019Au16), "Fixation Device Roll Angle", "FixationDeviceRollAngle", vr_type::FLOAT),
+    ((0x300Au16, 0x01A0u16), "Shielding Device Sequence", "ShieldingDeviceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x01A2u16), "Shielding Device Type", "ShieldingDeviceType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x01A4u16), "Shielding Device Label", "ShieldingDeviceLabel", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x01A6u16), "Shielding Device Description", "ShieldingDeviceDescription", vr_type::SHORT_TEXT),
+    ((0x300Au16, 0x01A8u16), "Shielding Device Position", "ShieldingDevicePosition", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x01B0u16), "Setup Technique", "SetupTechnique", vr_type::CODE_STRING),
+    ((0x300Au16, 0x01B2u16), "Setup Technique Description", "SetupTechniqueDescription", vr_type::SHORT_TEXT),
+    ((0x300Au16, 0x01B4u16), "Setup Device Sequence", "SetupDeviceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x01B6u16), "Setup Device Type", "SetupDeviceType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x01B8u16), "Setup Device Label", "SetupDeviceLabel", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x01BAu16), "Setup Device Description", "SetupDeviceDescription", vr_type::SHORT_TEXT),
+    ((0x300Au16, 0x01BCu16), "Setup Device Parameter", "SetupDeviceParameter", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x01D0u16), "Setup Reference Description", "SetupReferenceDescription", vr_type::SHORT_TEXT),
+    ((0x300Au16, 0x01D2u16), "Table Top Vertical Setup Displacement", "TableTopVerticalSetupDisplacement", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x01D4u16), "Table Top Longitudinal Setup Displacement", "TableTopLongitudinalSetupDisplacement", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x01D6u16), "Table Top Lateral Setup Displacement", "TableTopLateralSetupDisplacement", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0200u16), "Brachy Treatment Technique", "BrachyTreatmentTechnique", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0202u16), "Brachy Treatment Type", "BrachyTreatmentType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0206u16), "Treatment Machine Sequence", "TreatmentMachineSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0210u16), "Source Sequence", "SourceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0212u16), "Source Number", "SourceNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0214u16), "Source Type", "SourceType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0216u16), "Source Manufacturer", "SourceManufacturer", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0218u16), "Active Source Diameter", "ActiveSourceDiameter", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x021Au16), "Active Source Length", "ActiveSourceLength", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0222u16), "Source Encapsulation Nominal Thickness", "SourceEncapsulationNominalThickness", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0224u16), "Source Encapsulation Nominal Transmission", "SourceEncapsulationNominalTransmission", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0226u16), "Source Isotope Name", "SourceIsotopeName", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0228u16), "Source Isotope Half Life", "SourceIsotopeHalfLife", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0229u16), "Source Strength Units", "SourceStrengthUnits", vr_type::CODE_STRING),
+    ((0x300Au16, 0x022Au16), "Reference Air Kerma Rate", "ReferenceAirKermaRate", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x022Bu16), "Source Strength", "SourceStrength", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x022Cu16), "Source Strength Reference Date", "SourceStrengthReferenceDate", vr_type::DATE),
+    ((0x300Au16, 0x022Eu16), "Source Strength Reference Time", "SourceStrengthReferenceTime", vr_type::TIME),
+    ((0x300Au16, 0x0230u16), "Application Setup Sequence", "ApplicationSetupSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0232u16), "Application Setup Type", "ApplicationSetupType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0234u16), "Application Setup Number", "ApplicationSetupNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0236u16), "Application Setup Name", "ApplicationSetupName", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0238u16), "Application Setup Manufacturer", "ApplicationSetupManufacturer", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0240u16), "Template Number", "TemplateNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0242u16), "Template Type", "TemplateType", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0244u16), "Template Name", "TemplateName", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0250u16), "Total Reference Air Kerma", "TotalReferenceAirKerma", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0260u16), "Brachy Accessory Device Sequence", "BrachyAccessoryDeviceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0262u16), "Brachy Accessory Device Number", "BrachyAccessoryDeviceNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0263u16), "Brachy Accessory Device ID", "BrachyAccessoryDeviceID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0264u16), "Brachy Accessory Device Type", "BrachyAccessoryDeviceType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0266u16), "Brachy Accessory Device Name", "BrachyAccessoryDeviceName", vr_type::LONG_STRING),
+    ((0x300Au16, 0x026Au16), "Brachy Accessory Device Nominal Thickness", "BrachyAccessoryDeviceNominalThickness", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x026Cu16), "Brachy Accessory Device Nominal Transmission", "BrachyAccessoryDeviceNominalTransmission", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0280u16), "Channel Sequence", "ChannelSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0282u16), "Channel Number", "ChannelNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0284u16), "Channel Length", "ChannelLength", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0286u16), "Channel Total Time", "ChannelTotalTime", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0288u16), "Source Movement Type", "SourceMovementType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x028Au16), "Number of Pulses", "NumberOfPulses", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x028Cu16), "Pulse Repetition Interval", "PulseRepetitionInterval", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0290u16), "Source Applicator Number", "SourceApplicatorNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0291u16), "Source Applicator ID", "SourceApplicatorID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0292u16), "Source Applicator Type", "SourceApplicatorType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0294u16), "Source Applicator Name", "SourceApplicatorName", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0296u16), "Source Applicator Length", "SourceApplicatorLength", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x0298u16), "Source Applicator Manufacturer", "SourceApplicatorManufacturer", vr_type::LONG_STRING),
+    ((0x300Au16, 0x029Cu16), "Source Applicator Wall Nominal Thickness", "SourceApplicatorWallNominalThickness", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x029Eu16), "Source Applicator Wall Nominal Transmission", "SourceApplicatorWallNominalTransmission", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x02A0u16), "Source Applicator Step Size", "SourceApplicatorStepSize", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x02A2u16), "Transfer Tube Number", "TransferTubeNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x02A4u16), "Transfer Tube Length", "TransferTubeLength", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x02B0u16), "Channel Shield Sequence", "ChannelShieldSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x02B2u16), "Channel Shield Number", "ChannelShieldNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x02B3u16), "Channel Shield ID", "ChannelShieldID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x02B4u16), "Channel Shield Name", "ChannelShieldName", vr_type::LONG_STRING),
+    ((0x300Au16, 0x02B8u16), "Channel Shield Nominal Thickness", "ChannelShieldNominalThickness", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x02BAu16), "Channel Shield Nominal Transmission", "ChannelShieldNominalTransmission", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x02C8u16), "Final Cumulative Time Weight", "FinalCumulativeTimeWeight", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x02D0u16), "Brachy Control Point Sequence", "BrachyControlPointSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x02D2u16), "Control Point Relative Position", "ControlPointRelativePosition", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x02D4u16), "Control Point 3D Position", "ControlPoint3DPosition", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x02D6u16), "Cumulative Time Weight", "CumulativeTimeWeight", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x02E0u16), "Compensator Divergence", "CompensatorDivergence", vr_type::CODE_STRING),
+    ((0x300Au16, 0x02E1u16), "Compensator Mounting Position", "CompensatorMountingPosition", vr_type::CODE_STRING),
+    ((0x300Au16, 0x02E2u16), "Source to Compensator Distance", "SourceToCompensatorDistance", vr_type::DECIMAL_STRING),
+    ((0x300Au16, 0x02E3u16), "Total Compensator Tray Water-Equivalent Thickness", "TotalCompensatorTrayWaterEquivalentThickness", vr_type::FLOAT),
+    ((0x300Au16, 0x02E4u16), "Isocenter to Compensator Tray Distance", "IsocenterToCompensatorTrayDistance", vr_type::FLOAT),
+    ((0x300Au16, 0x02E5u16), "Compensator Column Offset", "CompensatorColumnOffset", vr_type::FLOAT),
+    ((0x300Au16, 0x02E6u16), "Isocenter to Compensator Distances", "IsocenterToCompensatorDistances", vr_type::FLOAT),
+    ((0x300Au16, 0x02E7u16), "Compensator Relative Stopping Power Ratio", "CompensatorRelativeStoppingPowerRatio", vr_type::FLOAT),
+    ((0x300Au16, 0x02E8u16), "Compensator Milling Tool Diameter", "CompensatorMillingToolDiameter", vr_type::FLOAT),
+    ((0x300Au16, 0x02EAu16), "Ion Range Compensator Sequence", "IonRangeCompensatorSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x02EBu16), "Compensator Description", "CompensatorDescription", vr_type::LONG_TEXT),
+    ((0x300Au16, 0x0302u16), "Radiation Mass Number", "RadiationMassNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0304u16), "Radiation Atomic Number", "RadiationAtomicNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0306u16), "Radiation Charge State", "RadiationChargeState", vr_type::SIGNED_SHORT),
+    ((0x300Au16, 0x0308u16), "Scan Mode", "ScanMode", vr_type::CODE_STRING),
+    ((0x300Au16, 0x030Au16), "Virtual Source-Axis Distances", "VirtualSourceAxisDistances", vr_type::FLOAT),
+    ((0x300Au16, 0x030Cu16), "Snout Sequence", "SnoutSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x030Du16), "Snout Position", "SnoutPosition", vr_type::FLOAT),
+    ((0x300Au16, 0x030Fu16), "Snout ID", "SnoutID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0312u16), "Number of Range Shifters", "NumberOfRangeShifters", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0314u16), "Range Shifter Sequence", "RangeShifterSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0316u16), "Range Shifter Number", "RangeShifterNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0318u16), "Range Shifter ID", "RangeShifterID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0320u16), "Range Shifter Type", "RangeShifterType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0322u16), "Range Shifter Description", "RangeShifterDescription", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0330u16), "Number of Lateral Spreading Devices", "NumberOfLateralSpreadingDevices", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0332u16), "Lateral Spreading Device Sequence", "LateralSpreadingDeviceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0334u16), "Lateral Spreading Device Number", "LateralSpreadingDeviceNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0336u16), "Lateral Spreading Device ID", "LateralSpreadingDeviceID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0338u16), "Lateral Spreading Device Type", "LateralSpreadingDeviceType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x033Au16), "Lateral Spreading Device Description", "LateralSpreadingDeviceDescription", vr_type::LONG_STRING),
+    ((0x300Au16, 0x033Cu16), "Lateral Spreading Device Water Equivalent Thickness", "LateralSpreadingDeviceWaterEquivalentThickness", vr_type::FLOAT),
+    ((0x300Au16, 0x0340u16), "Number of Range Modulators", "NumberOfRangeModulators", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0342u16), "Range Modulator Sequence", "RangeModulatorSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0344u16), "Range Modulator Number", "RangeModulatorNumber", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0346u16), "Range Modulator ID", "RangeModulatorID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0348u16), "Range Modulator Type", "RangeModulatorType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x034Au16), "Range Modulator Description", "RangeModulatorDescription", vr_type::LONG_STRING),
+    ((0x300Au16, 0x034Cu16), "Beam Current Modulation ID", "BeamCurrentModulationID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0350u16), "Patient Support Type", "PatientSupportType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0352u16), "Patient Support ID", "PatientSupportID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0354u16), "Patient Support Accessory Code", "PatientSupportAccessoryCode", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0356u16), "Fixation Light Azimuthal Angle", "FixationLightAzimuthalAngle", vr_type::FLOAT),
+    ((0x300Au16, 0x0358u16), "Fixation Light Polar Angle", "FixationLightPolarAngle", vr_type::FLOAT),
+    ((0x300Au16, 0x035Au16), "Meterset Rate", "MetersetRate", vr_type::FLOAT),
+    ((0x300Au16, 0x0360u16), "Range Shifter Settings Sequence", "RangeShifterSettingsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0362u16), "Range Shifter Setting", "RangeShifterSetting", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0364u16), "Isocenter to Range Shifter Distance", "IsocenterToRangeShifterDistance", vr_type::FLOAT),
+    ((0x300Au16, 0x0366u16), "Range Shifter Water Equivalent Thickness", "RangeShifterWaterEquivalentThickness", vr_type::FLOAT),
+    ((0x300Au16, 0x0370u16), "Lateral Spreading Device Settings Sequence", "LateralSpreadingDeviceSettingsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0372u16), "Lateral Spreading Device Setting", "LateralSpreadingDeviceSetting", vr_type::LONG_STRING),
+    ((0x300Au16, 0x0374u16), "Isocenter to Lateral Spreading Device Distance", "IsocenterToLateralSpreadingDeviceDistance", vr_type::FLOAT),
+    ((0x300Au16, 0x0380u16), "Range Modulator Settings Sequence", "RangeModulatorSettingsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0382u16), "Range Modulator Gating Start Value", "RangeModulatorGatingStartValue", vr_type::FLOAT),
+    ((0x300Au16, 0x0384u16), "Range Modulator Gating Stop Value", "RangeModulatorGatingStopValue", vr_type::FLOAT),
+    ((0x300Au16, 0x0386u16), "Range Modulator Gating Start Water Equivalent Thickness", "RangeModulatorGatingStartWaterEquivalentThickness", vr_type::FLOAT),
+    ((0x300Au16, 0x0388u16), "Range Modulator Gating Stop Water Equivalent Thickness", "RangeModulatorGatingStopWaterEquivalentThickness", vr_type::FLOAT),
+    ((0x300Au16, 0x038Au16), "Isocenter to Range Modulator Distance", "IsocenterToRangeModulatorDistance", vr_type::FLOAT),
+    ((0x300Au16, 0x0390u16), "Scan Spot Tune ID", "ScanSpotTuneID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0392u16), "Number of Scan Spot Positions", "NumberOfScanSpotPositions", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x0394u16), "Scan Spot Position Map", "ScanSpotPositionMap", vr_type::FLOAT),
+    ((0x300Au16, 0x0396u16), "Scan Spot Meterset Weights", "ScanSpotMetersetWeights", vr_type::FLOAT),
+    ((0x300Au16, 0x0398u16), "Scanning Spot Size", "ScanningSpotSize", vr_type::FLOAT),
+    ((0x300Au16, 0x039Au16), "Number of Paintings", "NumberOfPaintings", vr_type::INTEGER_STRING),
+    ((0x300Au16, 0x03A0u16), "Ion Tolerance Table Sequence", "IonToleranceTableSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x03A2u16), "Ion Beam Sequence", "IonBeamSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x03A4u16), "Ion Beam Limiting Device Sequence", "IonBeamLimitingDeviceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x03A6u16), "Ion Block Sequence", "IonBlockSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x03A8u16), "Ion Control Point Sequence", "IonControlPointSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x03AAu16), "Ion Wedge Sequence", "IonWedgeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x03ACu16), "Ion Wedge Position Sequence", "IonWedgePositionSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0401u16), "Referenced Setup Image Sequence", "ReferencedSetupImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0402u16), "Setup Image Comment", "SetupImageComment", vr_type::SHORT_TEXT),
+    ((0x300Au16, 0x0410u16), "Motion Synchronization Sequence", "MotionSynchronizationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0412u16), "Control Point Orientation", "ControlPointOrientation", vr_type::FLOAT),
+    ((0x300Au16, 0x0420u16), "General Accessory Sequence", "GeneralAccessorySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Au16, 0x0421u16), "General Accessory ID", "GeneralAccessoryID", vr_type::SHORT_STRING),
+    ((0x300Au16, 0x0422u16), "General Accessory Description", "GeneralAccessoryDescription", vr_type::SHORT_TEXT),
+    ((0x300Au16, 0x0423u16), "General Accessory Type", "GeneralAccessoryType", vr_type::CODE_STRING),
+    ((0x300Au16, 0x0424u16), "General Accessory Number", "GeneralAccessoryNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x0000u16), "Group 300C Length", "", vr_type::UNSIGNED_LONG),
+    ((0x300Cu16, 0x0002u16), "Referenced RT Plan Sequence", "ReferencedRTPlanSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x0004u16), "Referenced Beam Sequence", "ReferencedBeamSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x0006u16), "Referenced Beam Number", "ReferencedBeamNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x0007u16), "Referenced Reference Image Number", "ReferencedReferenceImageNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x0008u16), "Start Cumulative Meterset Weight", "StartCumulativeMetersetWeight", vr_type::DECIMAL_STRING),
+    ((0x300Cu16, 0x0009u16), "End Cumulative Meterset Weight", "EndCumulativeMetersetWeight", vr_type::DECIMAL_STRING),
+    ((0x300Cu16, 0x000Au16), "Referenced Brachy Application Setup Sequence", "ReferencedBrachyApplicationSetupSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x000Cu16), "Referenced Brachy Application Setup Number", "ReferencedBrachyApplicationSetupNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x000Eu16), "Referenced Source Number", "ReferencedSourceNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x0020u16), "Referenced Fraction Group Sequence", "ReferencedFractionGroupSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x0022u16), "Referenced Fraction Group Number", "ReferencedFractionGroupNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x0040u16), "Referenced Verification Image Sequence", "ReferencedVerificationImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x0042u16), "Referenced Reference Image Sequence", "ReferencedReferenceImageSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x0050u16), "Referenced Dose Reference Sequence", "ReferencedDoseReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x0051u16), "Referenced Dose Reference Number", "ReferencedDoseReferenceNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x0055u16), "Brachy Referenced Dose Reference Sequence", "BrachyReferencedDoseReferenceSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x0060u16), "Referenced Structure Set Sequence", "ReferencedStructureSetSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x006Au16), "Referenced Patient Setup Number", "ReferencedPatientSetupNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x0080u16), "Referenced Dose Sequence", "ReferencedDoseSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x00A0u16), "Referenced Tolerance Table Number", "ReferencedToleranceTableNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x00B0u16), "Referenced Bolus Sequence", "ReferencedBolusSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x00C0u16), "Referenced Wedge Number", "ReferencedWedgeNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x00D0u16), "Referenced Compensator Number", "ReferencedCompensatorNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x00E0u16), "Referenced Block Number", "ReferencedBlockNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x00F0u16), "Referenced Control Point Index", "ReferencedControlPointIndex", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x00F2u16), "Referenced Control Point Sequence", "ReferencedControlPointSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x300Cu16, 0x00F4u16), "Referenced Start Control Point Index", "ReferencedStartControlPointIndex", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x00F6u16), "Referenced Stop Control Point Index", "ReferencedStopControlPointIndex", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x0100u16), "Referenced Range Shifter Number", "ReferencedRangeShifterNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x0102u16), "Referenced Lateral Spreading Device Number", "ReferencedLateralSpreadingDeviceNumber", vr_type::INTEGER_STRING),
+    ((0x300Cu16, 0x0104u16), "Referenced Range Modulator Number", "ReferencedRangeModulatorNumber", vr_type::INTEGER_STRING),
+    ((0x300Eu16, 0x0000u16), "Group 300E Length", "", vr_type::UNSIGNED_LONG),
+    ((0x300Eu16, 0x0002u16), "Approval Status", "ApprovalStatus", vr_type::CODE_STRING),
+    ((0x300Eu16, 0x0004u16), "Review Date", "ReviewDate", vr_type::DATE),
+    ((0x300Eu16, 0x0005u16), "Review Time", "ReviewTime", vr_type::TIME),
+    ((0x300Eu16, 0x0008u16), "Reviewer Name", "ReviewerName", vr_type::PERSON_NAME),
+    ((0x4000u16, 0x0000u16), "Group 4000 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x4000u16, 0x0010u16), "Arbitrary", "Arbitrary", vr_type::LONG_TEXT),
+    ((0x4000u16, 0x4000u16), "Text Comments", "TextComments", vr_type::LONG_TEXT),
+    ((0x4008u16, 0x0000u16), "Group 4008 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x4008u16, 0x0040u16), "Results ID", "ResultsID", vr_type::SHORT_STRING),
+    ((0x4008u16, 0x0042u16), "Results ID Issuer", "ResultsIDIssuer", vr_type::LONG_STRING),
+    ((0x4008u16, 0x0050u16), "Referenced Interpretation Sequence", "ReferencedInterpretationSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x4008u16, 0x0100u16), "Interpretation Recorded Date", "InterpretationRecordedDate", vr_type::DATE),
+    ((0x4008u16, 0x0101u16), "Interpretation Recorded Time", "InterpretationRecordedTime", vr_type::TIME),
+    ((0x4008u16, 0x0102u16), "Interpretation Recorder", "InterpretationRecorder", vr_type::PERSON_NAME),
+    ((0x4008u16, 0x0103u16), "Reference to Recorded Sound", "ReferenceToRecordedSound", vr_type::LONG_STRING),
+    ((0x4008u16, 0x0108u16), "Interpretation Transcription Date", "InterpretationTranscriptionDate", vr_type::DATE),
+    ((0x4008u16, 0x0109u16), "Interpretation Transcription Time", "InterpretationTranscriptionTime", vr_type::TIME),
+    ((0x4008u16, 0x010Au16), "Interpretation Transcriber", "InterpretationTranscriber", vr_type::PERSON_NAME),
+    ((0x4008u16, 0x010Bu16), "Interpretation Text", "InterpretationText", vr_type::SHORT_TEXT),
+    ((0x4008u16, 0x010Cu16), "Interpretation Author", "InterpretationAuthor", vr_type::PERSON_NAME),
+    ((0x4008u16, 0x0111u16), "Interpretation Approver Sequence", "InterpretationApproverSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x4008u16, 0x0112u16), "Interpretation Approval Date", "InterpretationApprovalDate", vr_type::DATE),
+    ((0x4008u16, 0x0113u16), "Interpretation Approval Time", "InterpretationApprovalTime", vr_type::TIME),
+    ((0x4008u16, 0x0114u16), "Physician Approving Interpretation", "PhysicianApprovingInterpretation", vr_type::PERSON_NAME),
+    ((0x4008u16, 0x0115u16), "Interpretation Diagnosis Description", "InterpretationDiagnosisDescription", vr_type::LONG_TEXT),
+    ((0x4008u16, 0x0117u16), "Interpretation Diagnosis Code Sequence", "InterpretationDiagnosisCodeSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x4008u16, 0x0118u16), "Results Distribution List Sequence", "ResultsDistributionListSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x4008u16, 0x0119u16), "Distribution Name", "DistributionName", vr_type::PERSON_NAME),
+    ((0x4008u16, 0x011Au16), "Distribution Address", "DistributionAddress", vr_type::LONG_STRING),
+    ((0x4008u16, 0x0200u16), "Interpretation ID", "InterpretationID", vr_type::SHORT_STRING),
+    ((0x4008u16, 0x0202u16), "Interpretation ID Issuer", "InterpretationIDIssuer", vr_type::LONG_STRING),
+    ((0x4008u16, 0x0210u16), "Interpretation Type ID", "InterpretationTypeID", vr_type::CODE_STRING),
+    ((0x4008u16, 0x0212u16), "Interpretation Status ID", "InterpretationStatusID", vr_type::CODE_STRING),
+    ((0x4008u16, 0x0300u16), "Impressions", "Impressions", vr_type::SHORT_TEXT),
+    ((0x4008u16, 0x4000u16), "Results Comments", "ResultsComments", vr_type::SHORT_TEXT),
+    ((0x4FFEu16, 0x0000u16), "Group 4FFE Length", "", vr_type::UNSIGNED_LONG),
+    ((0x4FFEu16, 0x0001u16), "MAC Parameters Sequence", "MACParametersSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x5000u16, 0x0000u16), "Group 50xx Length", "", vr_type::UNSIGNED_LONG),
+    ((0x5000u16, 0x0005u16), "Curve Dimensions", "CurveDimensions", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x0010u16), "Number of Points", "NumberOfPoints", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x0020u16), "Type of Data", "TypeOfData", vr_type::CODE_STRING),
+    ((0x5000u16, 0x0022u16), "Curve Description", "CurveDescription", vr_type::LONG_STRING),
+    ((0x5000u16, 0x0030u16), "Axis Units", "AxisUnits", vr_type::SHORT_STRING),
+    ((0x5000u16, 0x0040u16), "Axis Labels", "AxisLabels", vr_type::SHORT_STRING),
+    ((0x5000u16, 0x0103u16), "Data Value Representation", "DataValueRepresentation", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x0104u16), "Minimum Coordinate Value", "MinimumCoordinateValue", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x0105u16), "Maximum Coordinate Value", "MaximumCoordinateValue", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x0106u16), "Curve Range", "CurveRange", vr_type::SHORT_STRING),
+    ((0x5000u16, 0x0110u16), "Curve Data Descriptor", "CurveDataDescriptor", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x0112u16), "Coordinate Start Value", "CoordinateStartValue", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x0114u16), "Coordinate Step Value", "CoordinateStepValue", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x1001u16), "Curve Activation Layer", "CurveActivationLayer", vr_type::CODE_STRING),
+    ((0x5000u16, 0x2000u16), "Audio Type", "AudioType", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x2002u16), "Audio Sample Format", "AudioSampleFormat", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x2004u16), "Number of Channels", "NumberOfChannels", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x2006u16), "Number of Samples", "NumberOfSamples", vr_type::UNSIGNED_LONG),
+    ((0x5000u16, 0x2008u16), "Sample Rate", "SampleRate", vr_type::UNSIGNED_LONG),
+    ((0x5000u16, 0x200Au16), "Total Time", "TotalTime", vr_type::UNSIGNED_LONG),
+    ((0x5000u16, 0x200Cu16), "Audio Sample Data", "AudioSampleData", vr_type::OTHER_BYTE),
+    ((0x5000u16, 0x200Eu16), "Audio Comments", "AudioComments", vr_type::LONG_TEXT),
+    ((0x5000u16, 0x2500u16), "Curve Label", "CurveLabel", vr_type::LONG_STRING),
+    ((0x5000u16, 0x2600u16), "Curve Referenced Overlay Sequence", "CurveReferencedOverlaySequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x5000u16, 0x2610u16), "Curve Referenced Overlay Group", "CurveReferencedOverlayGroup", vr_type::UNSIGNED_SHORT),
+    ((0x5000u16, 0x3000u16), "Curve Data", "CurveData", vr_type::OTHER_BYTE),
+    ((0x5200u16, 0x9229u16), "Shared Functional Groups Sequence", "SharedFunctionalGroupsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x5200u16, 0x9230u16), "Per-frame Functional Groups Sequence", "PerFrameFunctionalGroupsSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x5400u16, 0x0000u16), "Group 5400 Length", "", vr_type::UNSIGNED_LONG),
+    ((0x5400u16, 0x0100u16), "Waveform Sequence", "WaveformSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0x5400u16, 0x0110u16), "Channel Minimum Value", "ChannelMinimumValue", vr_type::OTHER_BYTE),
+    ((0x5400u16, 0x0112u16), "Channel Maximum Value", "ChannelMaximumValue", vr_type::OTHER_BYTE),
+    ((0x5400u16, 0x1004u16), "Waveform Bits Allocated", "WaveformBitsAllocated", vr_type::UNSIGNED_SHORT),
+    ((0x5400u16, 0x1006u16), "Waveform Sample Interpretation", "WaveformSampleInterpretation", vr_type::CODE_STRING),
+    ((0x5400u16, 0x100Au16), "Waveform Padding Value", "WaveformPaddingValue", vr_type::OTHER_BYTE),
+    ((0x5400u16, 0x1010u16), "Waveform Data", "WaveformData", vr_type::OTHER_BYTE),
+    ((0x5600u16, 0x0010u16), "First Order Phase Correction Angle", "FirstOrderPhaseCorrectionAngle", vr_type::OTHER_FLOAT),
+    ((0x5600u16, 0x0020u16), "Spectroscopy Data", "SpectroscopyData", vr_type::OTHER_FLOAT),
+    ((0x6000u16, 0x0000u16), "Group 60xx Length", "", vr_type::UNSIGNED_LONG),
+    ((0x6000u16, 0x0010u16), "Overlay Rows", "OverlayRows", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x0011u16), "Overlay Columns", "OverlayColumns", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x0012u16), "Overlay Planes", "OverlayPlanes", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x0015u16), "Number of Frames in Overlay", "NumberOfFramesInOverlay", vr_type::INTEGER_STRING),
+    ((0x6000u16, 0x0022u16), "Overlay Description", "OverlayDescription", vr_type::LONG_STRING),
+    ((0x6000u16, 0x0040u16), "Overlay Type", "OverlayType", vr_type::CODE_STRING),
+    ((0x6000u16, 0x0045u16), "Overlay Subtype", "OverlaySubtype", vr_type::LONG_STRING),
+    ((0x6000u16, 0x0050u16), "Overlay Origin", "OverlayOrigin", vr_type::SIGNED_SHORT),
+    ((0x6000u16, 0x0051u16), "Image Frame Origin", "ImageFrameOrigin", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x0052u16), "Overlay Plane Origin", "OverlayPlaneOrigin", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x0060u16), "Overlay Compression Code", "OverlayCompressionCode", vr_type::CODE_STRING),
+    ((0x6000u16, 0x0061u16), "Overlay Compression Originator", "OverlayCompressionOriginator", vr_type::SHORT_STRING),
+    ((0x6000u16, 0x0062u16), "Overlay Compression Label", "OverlayCompressionLabel", vr_type::SHORT_STRING),
+    ((0x6000u16, 0x0063u16), "Overlay Compression Description", "OverlayCompressionDescription", vr_type::CODE_STRING),
+    ((0x6000u16, 0x0066u16), "Overlay Compression Step Pointers", "OverlayCompressionStepPointers", vr_type::ATTRIBUTE),
+    ((0x6000u16, 0x0068u16), "Overlay Repeat Interval", "OverlayRepeatInterval", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x0069u16), "Overlay Bits Grouped", "OverlayBitsGrouped", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x0100u16), "Overlay Bits Allocated", "OverlayBitsAllocated", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x0102u16), "Overlay Bit Position", "OverlayBitPosition", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x0110u16), "Overlay Format", "OverlayFormat", vr_type::CODE_STRING),
+    ((0x6000u16, 0x0200u16), "Overlay Location", "OverlayLocation", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x0800u16), "Overlay Code Label", "OverlayCodeLabel", vr_type::CODE_STRING),
+    ((0x6000u16, 0x0802u16), "Overlay Number of Tables", "OverlayNumberOfTables", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x0803u16), "Overlay Code Table Location", "OverlayCodeTableLocation", vr_type::ATTRIBUTE),
+    ((0x6000u16, 0x0804u16), "Overlay Bits For Code Word", "OverlayBitsForCodeWord", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x1001u16), "Overlay Activation Layer", "OverlayActivationLayer", vr_type::CODE_STRING),
+    ((0x6000u16, 0x1100u16), "Overlay Descriptor - Gray", "OverlayDescriptorGray", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x1101u16), "Overlay Descriptor - Red", "OverlayDescriptorRed", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x1102u16), "Overlay Descriptor - Green", "OverlayDescriptorGreen", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x1103u16), "Overlay Descriptor - Blue", "OverlayDescriptorBlue", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x1200u16), "Overlays - Gray", "OverlaysGray", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x1201u16), "Overlays - Red", "OverlaysRed", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x1202u16), "Overlays - Green", "OverlaysGreen", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x1203u16), "Overlays - Blue", "OverlaysBlue", vr_type::UNSIGNED_SHORT),
+    ((0x6000u16, 0x1301u16), "ROI Area", "ROIArea", vr_type::INTEGER_STRING),
+    ((0x6000u16, 0x1302u16), "ROI Mean", "ROIMean", vr_type::DECIMAL_STRING),
+    ((0x6000u16, 0x1303u16), "ROI Standard Deviation", "ROIStandardDeviation", vr_type::DECIMAL_STRING),
+    ((0x6000u16, 0x1500u16), "Overlay Label", "OverlayLabel", vr_type::LONG_STRING),
+    ((0x6000u16, 0x3000u16), "Overlay Data", "OverlayData", vr_type::OTHER_BYTE),
+    ((0x6000u16, 0x4000u16), "Overlay Comments", "OverlayComments", vr_type::LONG_TEXT),
+    ((0x7F00u16, 0x0010u16), "Variable Pixel Data", "VariablePixelData", vr_type::OTHER_BYTE),
+    ((0x7F00u16, 0x0011u16), "Variable Next Data Group", "VariableNextDataGroup", vr_type::UNSIGNED_SHORT),
+    ((0x7F00u16, 0x0020u16), "Variable Coefficients SDVN", "VariableCoefficientsSDVN", vr_type::OTHER_WORD),
+    ((0x7F00u16, 0x0030u16), "Variable Coefficients SDHN", "VariableCoefficientsSDHN", vr_type::OTHER_WORD),
+    ((0x7F00u16, 0x0040u16), "Variable Coefficients SDDN", "VariableCoefficientsSDDN", vr_type::OTHER_WORD),
+    ((0x7FE0u16, 0x0000u16), "Group 7FE0 Length", "", vr_type::UNSIGNED_LONG),
+    (EXTENDED_OFFSET_TABLE, "Extended Offset Table", "ExtendedOffsetTable", vr_type::OTHER_VERY_LONG),
+    (EXTENDED_OFFSET_TABLE_LENGTHS, "Extended Offset Table Lengths", "ExtendedOffsetTableLengths", vr_type::OTHER_VERY_LONG),
+    ((0x7FE0u16, 0x0008u16), "Float Pixel Data", "FloatPixelData", vr_type::OTHER_FLOAT),
+    ((0x7FE0u16, 0x0009u16), "Double Float Pixel Data", "DoubleFloatPixelData", vr_type::OTHER_DOUBLE),
+    (PIXEL_DATA,            "Pixel Data", "PixelData", vr_type::OTHER_BYTE),
+    ((0x7FE0u16, 0x0020u16), "Coefficients SDVN", "CoefficientsSDVN", vr_type::OTHER_WORD),
+    ((0x7FE0u16, 0x0030u16), "Coefficients SDHN", "CoefficientsSDHN", vr_type::OTHER_WORD),
+    ((0x7FE0u16, 0x0040u16), "Coefficients SDDN", "CoefficientsSDDN", vr_type::OTHER_WORD),
+    ((0xFFFAu16, 0xFFFAu16), "Digital Signatures Sequence", "DigitalSignaturesSequence", vr_type::SEQUENCE_OF_ITEMS),
+    ((0xFFFCu16, 0xFFFCu16), "Data Set Trailing Padding", "DataSetTrailingPadding", vr_type::OTHER_BYTE),
+    (ITEM,                   "Item", "Item", vr_type::DELIMITER),
+    (ITEM_DELIMITER,         "Item Delimitation Item", "ItemDelimitationItem", vr_type::DELIMITER),
+    (SEQUENCE_DELIMITER,     "Sequence Delimitation Item", "SequenceDelimitationItem", vr_type::DELIMITER)
 ];
 
 pub fn tag_vr_type (group: u16, element: u16) -> u16 {
-    for item in TAGS.iter() {
-        if item.0 == (group, element) {
-            return item.2;
-        }
-    }
-    vr_type::UNKNOWN
+    find(group, element).map_or(vr_type::UNKNOWN, |item| item.3)
 }
 
 pub fn try_tag_name (group: u16, element: u16) -> Option<&'static str> {
-    for item in TAGS.iter() {
-        if item.0 == (group, element) {
-            return Some(item.1);
-        }
-    }
-    None
-}
\ No newline at end of file
+    find(group, element).map(|item| item.1)
+}
+
+fn find(group: u16, element: u16) -> Option<&'static ((u16, u16), &'static str, &'static str, u16)> {
+    TAGS.binary_search_by_key(&(group, element), |item| item.0).ok().map(|index| &TAGS[index])
+}
+
+// Keyword of a tag as given by the standard, Patient's Name is PatientName. Group lengths have none,
+// but for those of the command and File Meta Information groups.
+pub fn try_tag_keyword (group: u16, element: u16) -> Option<&'static str> {
+    find(group, element).map(|item| item.2).filter(|keyword| !keyword.is_empty())
+}
+
+pub fn try_keyword_tag (keyword: &str) -> Option<(u16, u16)> {
+    let keywords = KEYWORDS.get_or_init(|| {
+        let mut keywords: Vec<(&str, (u16, u16))> = TAGS.iter().filter(|item| !item.2.is_empty()).map(|item| (item.2, item.0)).collect();
+        keywords.sort();
+        keywords
+    });
+
+    keywords.binary_search_by_key(&keyword, |item| item.0).ok().map(|index| keywords[index].1)
+}
+
+// Keywords sorted for binary search, built on first use.
+static KEYWORDS: OnceLock<Vec<(&str, (u16, u16))>> = OnceLock::new();
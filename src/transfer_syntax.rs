@@ -1,207 +1,174 @@
+use crate::utils;
+
 // Transfer syntax types and catalogue.
 pub const EXPLICIT_LE: &str = "1.2.840.10008.1.2.1";
 pub const IMPLICIT_LE: &str = "1.2.840.10008.1.2";
 pub const EXPLICIT_BE: &str = "1.2.840.10008.1.2.2";
+pub const DEFLATED_EXPLICIT_LE: &str = "1.2.840.10008.1.2.1.99";
 
-const TRANSFER_SYNTAXES:[(&str, &str); 140] = [
-    ("1.2.840.10008.5.1.4.1.1.9.1.3", "Ambulatory Electrocardiogram Waveform"),
-    ("1.2.840.10008.5.1.1.15", "Basic Annotation Box"),
-    ("1.2.840.10008.5.1.1.4.1", "Basic Color Image Box"),
-    ("1.2.840.10008.5.1.1.2", "Basic Film Box"),
-    ("1.2.840.10008.5.1.1.1", "Basic Film Session"),
-    ("1.2.840.10008.5.1.1.4", "Basic Grayscale Image Box"),
-    ("1.2.840.10008.5.1.1.24.1", "Basic Print Image Overlay Box (Retired)"),
-    ("1.2.840.10008.1.9", "Basic Study Content Notification (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.88.11", "Basic Text Structure Report"),
-    ("1.2.840.10008.5.1.4.1.1.9.4.1", "Basic Voice Audio Waveform"),
-    ("1.2.840.10008.5.1.4.1.1.11.4", "Blending Softcopy Presentation State"),
-    ("1.2.840.10008.5.1.4.37.2", "Breast Imaging Relevant Patient"),
-    ("1.2.840.10008.5.1.4.1.1.9.3.1", "Cardiac Electrophysiology Waveform"),
-    ("1.2.840.10008.5.1.4.37.3", "Cardiac Relevant Patient"),
-    ("1.2.840.10008.5.1.4.1.1.88.65", "Chest Computer-Aid Diagnosis Structured Report"),
-    ("1.2.840.10008.5.1.4.1.1.11.2", "Color Softcopy Presentation State"),
-    ("1.2.840.10008.5.1.4.1.1.88.33", "Comprehensive Structured Report"),
-    ("1.2.840.10008.5.1.4.1.1.1", "Computed Radiography Image"),
-    ("1.2.840.10008.5.1.4.1.1.2", "Computed Tomography Image"),
-    ("1.2.840.10008.5.1.4.1.1.66.3", "Deformable Spatial Registration"),
-    ("1.2.840.10008.3.1.2.6.1", "Detached Interpretation Management (Retired)"),
-    ("1.2.840.10008.3.1.2.1.1", "Detached Patient Management (Retired)"),
-    ("1.2.840.10008.3.1.2.5.1", "Detached Results Management (Retired)"),
-    ("1.2.840.10008.3.1.2.3.1", "Detached Study Management (Retired)"),
-    ("1.2.840.10008.3.1.2.2.1", "Detached Visit Management (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.1.3", "Digital Intra-Oral X-Ray Image - for Presentation"),
-    ("1.2.840.10008.5.1.4.1.1.1.3.1", "Digital Intra-Oral XRay Image - for Processing"),
-    ("1.2.840.10008.5.1.4.1.1.1.2", "Digital Mammography X-Ray Image - for Presentation"),
-    ("1.2.840.10008.5.1.4.1.1.1.2.1", "Digital Mammograph X-Ray Image - for Processing"),
-    ("1.2.840.10008.5.1.4.1.1.1.1", "Digital X-Ray Image - Presentation"),
-    ("1.2.840.10008.5.1.4.1.1.1.1.1", "Digital X-Ray Image for Processing"),
-    ("1.2.840.10008.5.1.4.1.1.104.1", "Encapsulated Portable Document Format"),
-    ("1.2.840.10008.5.1.4.1.1.2.1", "Enhanced Computed Tomography Image"),
-    ("1.2.840.10008.5.1.4.1.1.4.1", "Enhanced Magnetic Resonance Image"),
-    ("1.2.840.10008.5.1.4.1.1.88.22", "Enhanced Structure Report"),
-    ("1.2.840.10008.5.1.4.1.1.12.1.1", "Enhanced X-Ray Angiographic Image"),
-    ("1.2.840.10008.5.1.4.1.1.12.2.1", "Enhanced X-Ray Radiofluoroscopic Image"),
-    ("1.2.840.10008.5.1.4.1.1.9.1.2", "General Electrocardiogram Waveform"),
-    ("1.2.840.10008.5.1.4.32.3", "General Purpose Performed Procedure Step"),
-    ("1.2.840.10008.5.1.4.32.2", "General Purpose Scheduled Procedure Step"),
-    ("1.2.840.10008.5.1.4.32.1", "General Purpose Worklist"),
-    ("1.2.840.10008.5.1.4.37.1", "General Relevant Patient"),
-    ("1.2.840.10008.5.1.4.1.1.11.1", "Grayscale Softcopy Presentation State"),
-    ("1.2.840.10008.5.1.4.38.1", "Hanging Protocol"),
-    ("1.2.840.10008.5.1.4.38.3", "Hanging Protocol - Move"),
-    ("1.2.840.10008.5.1.4.38.2", "Hanging Protocol - Query"),
-    ("1.2.840.10008.5.1.1.30", "Hardcopy Color Image (Retired)"),
-    ("1.2.840.10008.5.1.1.29", "Hardcopy Grayscale Image (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.9.2.1", "Hemodynamic Waveform"),
-    ("1.2.840.10008.5.1.1.24", "Image Overlay Box (Retired)"),
-    ("1.2.840.10008.5.1.4.33", "Instance Availability"),
-    ("1.2.840.10008.5.1.4.1.1.88.59", "Key Object Selection Document"),
-    ("1.2.840.10008.5.1.4.1.1.4", "Magnetic Resonance Image"),
-    ("1.2.840.10008.5.1.4.1.1.4.2", "Magnetic Resonance Spectroscopy"),
-    ("1.2.840.10008.5.1.4.1.1.88.50", "Mammography Computer-Aided Diagnosis Structured Report"),
-    ("1.2.840.10008.5.1.1.33", "Media Creation Management"),
-    ("1.2.840.10008.1.3.10", "Media Storage Directory"),
-    ("1.2.840.10008.3.1.2.3.3", "Modality Performed Procedure Step"),
-    ("1.2.840.10008.3.1.2.3.5", "Modality Performed Procedure Step - Notification"),
-    ("1.2.840.10008.3.1.2.3.4", "Modality Performed Procedure Step - Retrieve"),
-    ("1.2.840.10008.5.1.4.31", "Modality Worklist"),
-    ("1.2.840.10008.5.1.4.1.1.7.2", "Multi-Frame Grayscale Byte Secondary Capture Image"),
-    ("1.2.840.10008.5.1.4.1.1.7.3", "Multi-Frame Grayscale Word Secondary Capture Image"),
-    ("1.2.840.10008.5.1.4.1.1.7.1", "Multi-Frame Single Bit Secondary Capture Image"),
-    ("1.2.840.10008.5.1.4.1.1.7.4", "Multi-Frame True Color Secondary Capture Image"),
-    ("1.2.840.10008.5.1.4.1.1.20", "Nuclear Medicine Image"),
-    ("1.2.840.10008.5.1.4.1.1.5", "Nuclear Medicine Image (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.77.1.5.2", "Ophthalmic Photography 16 Bit Image"),
-    ("1.2.840.10008.5.1.4.1.1.77.1.5.1", "Ophthalmic Photography 8 Bit Image"),
-    ("1.2.840.10008.5.1.4.1.2.1.2", "Patient Root - Move"),
-    ("1.2.840.10008.5.1.4.1.2.1.1", "Patient Root - Query"),
-    ("1.2.840.10008.5.1.4.1.2.1.3", "Patient Root - Retrieve"),
-    ("1.2.840.10008.5.1.4.1.2.3.2", "Patient/Study Only - Move (Retired)"),
-    ("1.2.840.10008.5.1.4.1.2.3.1", "Patient/Study Only - Query (Retired)"),
-    ("1.2.840.10008.5.1.4.1.2.3.3", "Patient/Study Only - Retrieve (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.128", "Positron Emission Tomography Image"),
-    ("1.2.840.10008.5.1.1.23", "Presentation Lookup Table"),
-    ("1.2.840.10008.5.1.1.16", "Printer"),
-    ("1.2.840.10008.5.1.1.16.376", "Printer Configuration"),
-    ("1.2.840.10008.5.1.1.14", "Print Job"),
-    ("1.2.840.10008.5.1.1.26", "Print Queue Management (Retired)"),
-    ("1.2.840.10008.1.40", "Procedural Event Logging"),
-    ("1.2.840.10008.5.1.4.1.1.88.40", "Procedure Log"),
-    ("1.2.840.10008.5.1.4.1.1.11.3", "Pseudo-Color Softcopy Presentation State"),
-    ("1.2.840.10008.5.1.1.31", "Pull Print Request (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.481.4", "Radio Therapy Beams Treatment Record"),
-    ("1.2.840.10008.5.1.4.1.1.481.6", "Radio Therapy Brachy Treatment Record"),
-    ("1.2.840.10008.5.1.4.1.1.481.2", "Radio Therapy Dose"),
-    ("1.2.840.10008.5.1.4.1.1.481.1", "Radio Therapy Image"),
-    ("1.2.840.10008.5.1.4.1.1.481.9", "Radio Therapy Ion Beams Treatment Record"),
-    ("1.2.840.10008.5.1.4.1.1.481.8", "Radio Therapy Ion Plan"),
-    ("1.2.840.10008.5.1.4.1.1.481.5", "Radio Therapy Plan"),
-    ("1.2.840.10008.5.1.4.1.1.481.3", "Radio Therapy Structure Set"),
-    ("1.2.840.10008.5.1.4.1.1.481.7", "Radio Therapy Treatment Summary Record"),
-    ("1.2.840.10008.5.1.4.1.1.66", "Raw Data"),
-    ("1.2.840.10008.5.1.4.1.1.67", "Real World Value Mapping"),
-    ("1.2.840.10008.5.1.1.4.2", "Referenced Image Box (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.7", "Secondary Capture Image"),
-    ("1.2.840.10008.5.1.4.1.1.66.4", "Segmentation"),
-    ("1.3.12.2.1107.5.9.1", "Siemens CSA Non-Image"),
-    ("1.3.12.2.1107.5.99.3.11", "Siemens syngo Frame Set"),
-    ("1.3.12.2.1107.5.99.3.10", "Siemens syngo Volume Set"),
-    ("1.2.840.10008.5.1.4.1.1.66.2", "Spatial Fiducials"),
-    ("1.2.840.10008.5.1.4.1.1.66.1", "Spatial Registration"),
-    ("1.2.840.10008.5.1.4.1.1.9", "Standalone Curve (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.10", "Standalone Modality Lookup Table (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.8", "Standalone Overlay (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.129", "Standalone Positron Emission Tomography Curve (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.11", "Standalone Volume of Interest Lookup Table (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.77.1.5.3", "Stereometric Relationship"),
-    ("1.2.840.10008.1.20.2", "Storage Commitment Pull Model (Retired)"),
-    ("1.2.840.10008.1.20.1", "Storage Commitment Push Model"),
-    ("1.2.840.10008.5.1.1.27", "Stored Print (Retired)"),
-    ("1.2.840.10008.3.1.2.3.2", "Study Component (Retired)"),
-    ("1.2.840.10008.5.1.4.1.2.2.2", "Study Root - Move"),
-    ("1.2.840.10008.5.1.4.1.2.2.1", "Study Root - Query"),
-    ("1.2.840.10008.5.1.4.1.2.2.3", "Study Root - Retrieve"),
-    ("1.2.392.200036.9116.7.8.1.1.1", "Toshiba MDW Non- Image"),
-    ("1.2.840.10008.5.1.4.1.1.6.1", "Ultrasound Image"),
-    ("1.2.840.10008.5.1.4.1.1.6", "Ultrasound Image (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.3.1", "Ultrasound Multi-Frame Image"),
-    ("1.2.840.10008.5.1.4.1.1.3", "Ultrasound Multi-Frame Image (Retired)"),
-    ("1.2.840.10008.1.1", "Verification"),
-    ("1.2.840.10008.5.1.4.1.1.77.1.1.1", "Video Endoscopic Image"),
-    ("1.2.840.10008.5.1.4.1.1.77.1.2.1", "Video Microscopic Image"),
-    ("1.2.840.10008.5.1.4.1.1.77.1.4.1", "Video Photograph Image"),
-    ("1.2.840.10008.5.1.4.1.1.77.1.1", "Visible Light Endoscopic Image"),
-    ("1.2.840.10008.5.1.4.1.1.77.1", "Visible Light Image (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.77.1.2", "Visible Light Microscopic Image"),
-    ("1.2.840.10008.5.1.4.1.1.77.2", "Visible Light Multi-Frame Image (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.77.1.4", "Visible Light Photographic Image"),
-    ("1.2.840.10008.5.1.4.1.1.77.1.3", "Visible Light Slide-Coordinates Microscopic Image"),
-    ("1.2.840.10008.5.1.1.22", "Volume of Interest Lookup Table Box (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.9.1.1", "X12-Lead Electrocardiogram Waveform"),
-    ("1.2.840.10008.5.1.4.1.1.13.1.1", "X-Ray 3D Angiographic Image Storage"),
-    ("1.2.840.10008.5.1.4.1.1.13.1.2", "X-Ray 3D Craniofacial Image Storage"),
-    ("1.2.840.10008.5.1.4.1.1.12.3", "X-Ray Angiographic BiPlane Image (Retired)"),
-    ("1.2.840.10008.5.1.4.1.1.12.1", "X-Ray Angiographic Image"),
-    ("1.2.840.10008.5.1.4.1.1.88.67", "X-Ray Radiation Dose Structured Report"),
-    ("1.2.840.10008.5.1.4.1.1.12.2", "X-Ray Radiofluoroscopic")
+// Catalogue columns: UID, name, VR encoding, endianness, compression, encapsulated pixel data, retired.
+pub static TRANSFER_SYNTAXES: [(&str, &str, VrEncoding, EndianEncoding, Compression, bool, bool); 59] = [
+    (IMPLICIT_LE, "Implicit VR Little Endian", VrEncoding::Implicit, EndianEncoding::LittleEndian, Compression::None, false, false),
+    (EXPLICIT_LE, "Explicit VR Little Endian", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::None, false, false),
+    ("1.2.840.10008.1.2.1.98", "Encapsulated Uncompressed Explicit VR Little Endian", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::None, true, false),
+    (DEFLATED_EXPLICIT_LE, "Deflated Explicit VR Little Endian", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Deflate, false, false),
+    (EXPLICIT_BE, "Explicit VR Big Endian (Retired)", VrEncoding::Explicit, EndianEncoding::BigEndian, Compression::None, false, true),
+    ("1.2.840.10008.1.2.4.50", "JPEG Baseline (Process 1)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, false),
+    ("1.2.840.10008.1.2.4.51", "JPEG Extended (Process 2 & 4)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, false),
+    ("1.2.840.10008.1.2.4.52", "JPEG Extended (Process 3 & 5) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.53", "JPEG Spectral Selection, Non-Hierarchical (Process 6 & 8) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.54", "JPEG Spectral Selection, Non-Hierarchical (Process 7 & 9) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.55", "JPEG Full Progression, Non-Hierarchical (Process 10 & 12) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.56", "JPEG Full Progression, Non-Hierarchical (Process 11 & 13) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.57", "JPEG Lossless, Non-Hierarchical (Process 14)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, false),
+    ("1.2.840.10008.1.2.4.58", "JPEG Lossless, Non-Hierarchical (Process 15) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.59", "JPEG Extended, Hierarchical (Process 16 & 18) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.60", "JPEG Extended, Hierarchical (Process 17 & 19) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.61", "JPEG Spectral Selection, Hierarchical (Process 20 & 22) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.62", "JPEG Spectral Selection, Hierarchical (Process 21 & 23) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.63", "JPEG Full Progression, Hierarchical (Process 24 & 26) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.64", "JPEG Full Progression, Hierarchical (Process 25 & 27) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.65", "JPEG Lossless, Hierarchical (Process 28) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.66", "JPEG Lossless, Hierarchical (Process 29) (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, true),
+    ("1.2.840.10008.1.2.4.70", "JPEG Lossless, Non-Hierarchical, First-Order Prediction (Process 14 [Selection Value 1])", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg, true, false),
+    ("1.2.840.10008.1.2.4.80", "JPEG-LS Lossless Image Compression", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::JpegLs, true, false),
+    ("1.2.840.10008.1.2.4.81", "JPEG-LS Lossy (Near-Lossless) Image Compression", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::JpegLs, true, false),
+    ("1.2.840.10008.1.2.4.90", "JPEG 2000 Image Compression (Lossless Only)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg2000, true, false),
+    ("1.2.840.10008.1.2.4.91", "JPEG 2000 Image Compression", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg2000, true, false),
+    ("1.2.840.10008.1.2.4.92", "JPEG 2000 Part 2 Multi-component Image Compression (Lossless Only)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg2000, true, false),
+    ("1.2.840.10008.1.2.4.93", "JPEG 2000 Part 2 Multi-component Image Compression", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg2000, true, false),
+    ("1.2.840.10008.1.2.4.94", "JPIP Referenced", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Jpeg2000, false, false),
+    ("1.2.840.10008.1.2.4.95", "JPIP Referenced Deflate", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Deflate, false, false),
+    ("1.2.840.10008.1.2.4.100", "MPEG2 Main Profile / Main Level", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.100.1", "Fragmentable MPEG2 Main Profile / Main Level", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.101", "MPEG2 Main Profile / High Level", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.101.1", "Fragmentable MPEG2 Main Profile / High Level", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.102", "MPEG-4 AVC/H.264 High Profile / Level 4.1", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.102.1", "Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.1", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.103", "MPEG-4 AVC/H.264 BD-compatible High Profile / Level 4.1", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.103.1", "Fragmentable MPEG-4 AVC/H.264 BD-compatible High Profile / Level 4.1", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.104", "MPEG-4 AVC/H.264 High Profile / Level 4.2 For 2D Video", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.104.1", "Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.2 For 2D Video", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.105", "MPEG-4 AVC/H.264 High Profile / Level 4.2 For 3D Video", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.105.1", "Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.2 For 3D Video", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.106", "MPEG-4 AVC/H.264 Stereo High Profile / Level 4.2", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.106.1", "Fragmentable MPEG-4 AVC/H.264 Stereo High Profile / Level 4.2", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Mpeg, true, false),
+    ("1.2.840.10008.1.2.4.107", "HEVC/H.265 Main Profile / Level 5.1", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Hevc, true, false),
+    ("1.2.840.10008.1.2.4.108", "HEVC/H.265 Main 10 Profile / Level 5.1", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Hevc, true, false),
+    ("1.2.840.10008.1.2.4.201", "High-Throughput JPEG 2000 Image Compression (Lossless Only)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::HtJpeg2000, true, false),
+    ("1.2.840.10008.1.2.4.202", "High-Throughput JPEG 2000 with RPCL Options Image Compression (Lossless Only)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::HtJpeg2000, true, false),
+    ("1.2.840.10008.1.2.4.203", "High-Throughput JPEG 2000 Image Compression", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::HtJpeg2000, true, false),
+    ("1.2.840.10008.1.2.4.204", "JPIP HTJ2K Referenced", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::HtJpeg2000, false, false),
+    ("1.2.840.10008.1.2.4.205", "JPIP HTJ2K Referenced Deflate", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Deflate, false, false),
+    ("1.2.840.10008.1.2.5", "RLE Lossless", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::Rle, true, false),
+    ("1.2.840.10008.1.2.6.1", "RFC 2557 MIME encapsulation (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::None, false, true),
+    ("1.2.840.10008.1.2.6.2", "XML Encoding (Retired)", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::None, false, true),
+    ("1.2.840.10008.1.2.7.1", "SMPTE ST 2110-20 Uncompressed Progressive Active Video", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::None, false, false),
+    ("1.2.840.10008.1.2.7.2", "SMPTE ST 2110-20 Uncompressed Interlaced Active Video", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::None, false, false),
+    ("1.2.840.10008.1.2.7.3", "SMPTE ST 2110-30 PCM Digital Audio", VrEncoding::Explicit, EndianEncoding::LittleEndian, Compression::None, false, false),
+    ("1.2.840.10008.1.20", "Papyrus 3 Implicit VR Little Endian (Retired)", VrEncoding::Implicit, EndianEncoding::LittleEndian, Compression::None, false, true)
 ];
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub enum VrEncoding {
     Explicit,
     Implicit
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub enum EndianEncoding {
     LittleEndian,
     BigEndian
 }
 
-#[derive(Copy, Clone)]
+impl EndianEncoding {
+    // Picks the byte order conversion matching this encoding.
+    pub fn convert<T>(self, little_endian: T, big_endian: T) -> T {
+        match self {
+            EndianEncoding::LittleEndian => little_endian,
+            EndianEncoding::BigEndian    => big_endian
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Compression {
+    None,
+    Deflate,
+    Rle,
+    Jpeg,
+    JpegLs,
+    Jpeg2000,
+    HtJpeg2000,
+    Mpeg,
+    Hevc,
+    Unknown
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub struct TransferSyntax {
+    pub uid: &'static str,
+    pub name: &'static str,
     pub vr_encoding: VrEncoding,
     pub endian_encoding: EndianEncoding,
+    pub compression: Compression,
+    pub encapsulated: bool,
+    pub retired: bool
+}
+
+impl Default for TransferSyntax {
+    fn default() -> TransferSyntax {
+        // Safe to unwrap, Explicit VR Little Endian is in the catalogue.
+        TransferSyntax::from_uid(EXPLICIT_LE).unwrap()
+    }
 }
 
 impl TransferSyntax {
-    pub fn default() -> TransferSyntax {
-        TransferSyntax { 
-            vr_encoding: VrEncoding::Explicit, 
-            endian_encoding: EndianEncoding::LittleEndian 
-        }
-    }    
+    pub fn from_uid(uid: &str) -> Option<TransferSyntax> {
+        let uid = utils::trim_padding(uid);
 
+        TRANSFER_SYNTAXES.iter()
+            .find(|item| item.0 == uid)
+            .map(|item| TransferSyntax {
+                uid: item.0,
+                name: item.1,
+                vr_encoding: item.2,
+                endian_encoding: item.3,
+                compression: item.4,
+                encapsulated: item.5,
+                retired: item.6
+            })
+    }
+
+    // Unknown syntaxes are assumed to be Explicit VR Little Endian with encapsulated pixel data,
+    // which is what the standard mandates for every syntax other than the native ones.
     pub fn parse_str(syntax: &str) -> TransferSyntax {
-        if syntax.eq_ignore_ascii_case(EXPLICIT_LE) {
-            TransferSyntax { vr_encoding: VrEncoding::Explicit, endian_encoding: EndianEncoding::LittleEndian }
-        }
-        else if syntax.eq_ignore_ascii_case(EXPLICIT_BE) {
-            TransferSyntax { vr_encoding: VrEncoding::Explicit, endian_encoding: EndianEncoding::BigEndian }
-        }
-        else if syntax.eq_ignore_ascii_case(IMPLICIT_LE) {
-            TransferSyntax { vr_encoding: VrEncoding::Implicit, endian_encoding: EndianEncoding::LittleEndian }
+        match TransferSyntax::from_uid(syntax) {
+            Some(s) => s,
+            None    => TransferSyntax {
+                uid: "",
+                name: "Unknown",
+                vr_encoding: VrEncoding::Explicit,
+                endian_encoding: EndianEncoding::LittleEndian,
+                compression: Compression::Unknown,
+                encapsulated: true,
+                retired: false
+            }
         }
-        else {
-            TransferSyntax { vr_encoding: VrEncoding::Explicit, endian_encoding: EndianEncoding::LittleEndian }
-        }
-    }    
-}    
-
-pub fn not_compressed(syntax: Option<&str>) -> bool {
-    match syntax {
-        Some(EXPLICIT_LE) | Some(EXPLICIT_BE) | Some(IMPLICIT_LE) => true,
-        _ => false
     }
-}    
 
-pub fn try_name(transfer_syntax_id: &str) -> Option<&str> {
-    for item in TRANSFER_SYNTAXES.iter() {
-        if item.0 == transfer_syntax_id {
-            return Some(item.1);
-        }
+    pub fn is_native(&self) -> bool {
+        !self.encapsulated && self.compression == Compression::None
     }
+}
 
-    None
+pub fn not_compressed(syntax: Option<&str>) -> bool {
+    match syntax.and_then(TransferSyntax::from_uid) {
+        Some(s) => s.is_native(),
+        None    => false
+    }
 }
 
+pub fn try_name(transfer_syntax_id: &str) -> Option<&'static str> {
+    TransferSyntax::from_uid(transfer_syntax_id).map(|s| s.name)
+}
@@ -1,3 +1,8 @@
 pub fn even(v: u16) -> bool {
-    v % 2 == 0
-}
\ No newline at end of file
+    v.is_multiple_of(2)
+}
+
+// Strips the trailing space or NUL padding values get to reach an even length.
+pub fn trim_padding(v: &str) -> &str {
+    v.trim_end_matches(['\0', ' '])
+}
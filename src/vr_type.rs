@@ -1,7 +1,8 @@
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, Result};
 
 use crate::binary_reader::*;
 use crate::dicom_reader::DicomReader;
+use crate::transfer_syntax::EndianEncoding;
 
 // Structural types.
 pub const DELIMITER:u16 = 19524;          // Code: "DL".
@@ -17,6 +18,8 @@ pub const SIGNED_LONG:u16 = 19539;        // Code: "SL".
 pub const SIGNED_SHORT:u16 = 21331;       // Code: "SS".  
 pub const FLOAT:u16 = 19526;              // Code: "FL".
 pub const DOUBLE:u16 = 17478;             // Code: "FD".
+pub const SIGNED_VERY_LONG:u16 = 22099;   // Code: "SV".
+pub const UNSIGNED_VERY_LONG:u16 = 22101; // Code: "UV".
 
 // String types.
 pub const AGE_STRING:u16 = 21313;         // Code: "AS".
@@ -26,6 +29,8 @@ pub const PERSON_NAME:u16 = 20048;        // Code: "PN".
 pub const SHORT_STRING:u16 = 18515;       // Code: "SH".  
 pub const SHORT_TEXT:u16 = 21587;         // Code: "ST".
 pub const UNLIMITED_TEXT:u16 = 21589;     // Code: "UT".
+pub const UNLIMITED_CHARACTERS:u16 = 17237; // Code: "UC".
+pub const UNIVERSAL_RESOURCE:u16 = 21077; // Code: "UR".
 
 // Time types.
 pub const TIME:u16 = 19796;               // Code: "TM".
@@ -41,40 +46,79 @@ pub const LONG_STRING:u16 = 20300;        // Code: "LO".
 pub const OTHER_BYTE:u16 = 16975;         // Code: "OB".
 pub const OTHER_FLOAT:u16 = 17999;        // Code: "OF".
 pub const OTHER_WORD:u16 = 22351;         // Code: "OW".
+pub const OTHER_DOUBLE:u16 = 17487;       // Code: "OD".
+pub const OTHER_LONG:u16 = 19535;         // Code: "OL".
+pub const OTHER_VERY_LONG:u16 = 22095;    // Code: "OV".
 
 // Unknown type.
 pub const UNKNOWN:u16 = 20053;            // Code: "UN".
 
-pub fn get_explicit_vr<T: Read+Seek>(vr_code: u16, reader: &mut T) -> i32 {
+// Explicit VR codes are always two upper case ASCII characters.
+pub fn is_valid_code(vr_code: u16) -> bool {
+    vr_code.to_le_bytes().iter().all(u8::is_ascii_uppercase)
+}
+
+// Codes defined by the standard, a stricter check than is_valid_code used to tell explicit from implicit VR data.
+pub fn is_known_code(vr_code: u16) -> bool {
+    matches!(vr_code,
+        SEQUENCE_OF_ITEMS | ATTRIBUTE | UID | APPLICATION_ENTITY |
+        UNSIGNED_LONG | UNSIGNED_SHORT | SIGNED_LONG | SIGNED_SHORT | FLOAT | DOUBLE | SIGNED_VERY_LONG | UNSIGNED_VERY_LONG |
+        AGE_STRING | CODE_STRING | LONG_TEXT | PERSON_NAME | SHORT_STRING | SHORT_TEXT | UNLIMITED_TEXT | UNLIMITED_CHARACTERS | UNIVERSAL_RESOURCE |
+        TIME | DATE | DATE_TIME | DECIMAL_STRING | INTEGER_STRING | LONG_STRING |
+        OTHER_BYTE | OTHER_FLOAT | OTHER_WORD | OTHER_DOUBLE | OTHER_LONG | OTHER_VERY_LONG | UNKNOWN)
+}
+
+// Codes whose values are character strings, multiple values separated by backslash.
+pub fn is_string(vr_code: u16) -> bool {
+    matches!(vr_code,
+        UID | APPLICATION_ENTITY | AGE_STRING | CODE_STRING | LONG_TEXT | PERSON_NAME | SHORT_STRING | SHORT_TEXT |
+        UNLIMITED_TEXT | UNLIMITED_CHARACTERS | UNIVERSAL_RESOURCE | TIME | DATE | DATE_TIME | DECIMAL_STRING | INTEGER_STRING | LONG_STRING)
+}
+
+// Codes encoded in explicit VR with 2 reserved bytes and a 4 bytes length, the others have a 2 bytes length.
+pub fn has_long_length(vr_code: u16) -> bool {
+    matches!(vr_code,
+        SEQUENCE_OF_ITEMS | SIGNED_VERY_LONG | UNSIGNED_VERY_LONG | UNLIMITED_TEXT | UNLIMITED_CHARACTERS | UNIVERSAL_RESOURCE |
+        OTHER_BYTE | OTHER_FLOAT | OTHER_WORD | OTHER_DOUBLE | OTHER_LONG | OTHER_VERY_LONG | UNKNOWN)
+}
+
+pub fn get_explicit_vr<T: Read+Seek>(vr_code: u16, reader: &mut T, endian: EndianEncoding) -> Result<u32> {
     match vr_code {
-        DELIMITER           => reader.read_i32(),
-        SEQUENCE_OF_ITEMS   => reader.read_reserved_i32(),
-        UID                 => i32::from(reader.read_i16()),
-        ATTRIBUTE           => i32::from(reader.read_i16()),
-        APPLICATION_ENTITY  => i32::from(reader.read_i16()),
-        UNSIGNED_LONG       => i32::from(reader.read_i16()),
-        UNSIGNED_SHORT      => i32::from(reader.read_i16()),
-        SIGNED_LONG         => i32::from(reader.read_i16()),
-        SIGNED_SHORT        => i32::from(reader.read_i16()),
-        FLOAT               => i32::from(reader.read_i16()),
-        DOUBLE              => i32::from(reader.read_i16()),
-        AGE_STRING          => i32::from(reader.read_i16()),
-        CODE_STRING         => i32::from(reader.read_i16()),
-        LONG_TEXT           => i32::from(reader.read_i16()),
-        PERSON_NAME         => i32::from(reader.read_i16()),
-        SHORT_STRING        => i32::from(reader.read_i16()),
-        SHORT_TEXT          => i32::from(reader.read_i16()),
-        UNLIMITED_TEXT      => reader.read_reserved_i32(),
-        DATE                => i32::from(reader.read_i16()),
-        DATE_TIME           => i32::from(reader.read_i16()),
-        TIME                => i32::from(reader.read_i16()),
-        DECIMAL_STRING      => i32::from(reader.read_i16()),
-        INTEGER_STRING      => i32::from(reader.read_i16()),
-        LONG_STRING         => i32::from(reader.read_i16()),
-        OTHER_BYTE          => reader.read_reserved_i32(),
-        OTHER_FLOAT         => reader.read_reserved_i32(),
-        OTHER_WORD          => reader.read_reserved_i32(),
-        UNKNOWN             => reader.read_reserved_i32(),
-        _                   => reader.read_reserved_i32()
+        DELIMITER           => reader.read_u32(endian),
+        SEQUENCE_OF_ITEMS   => reader.read_reserved_u32(endian),
+        UID                 => reader.read_u16(endian).map(u32::from),
+        ATTRIBUTE           => reader.read_u16(endian).map(u32::from),
+        APPLICATION_ENTITY  => reader.read_u16(endian).map(u32::from),
+        UNSIGNED_LONG       => reader.read_u16(endian).map(u32::from),
+        UNSIGNED_SHORT      => reader.read_u16(endian).map(u32::from),
+        SIGNED_LONG         => reader.read_u16(endian).map(u32::from),
+        SIGNED_SHORT        => reader.read_u16(endian).map(u32::from),
+        FLOAT               => reader.read_u16(endian).map(u32::from),
+        DOUBLE              => reader.read_u16(endian).map(u32::from),
+        SIGNED_VERY_LONG    => reader.read_reserved_u32(endian),
+        UNSIGNED_VERY_LONG  => reader.read_reserved_u32(endian),
+        AGE_STRING          => reader.read_u16(endian).map(u32::from),
+        CODE_STRING         => reader.read_u16(endian).map(u32::from),
+        LONG_TEXT           => reader.read_u16(endian).map(u32::from),
+        PERSON_NAME         => reader.read_u16(endian).map(u32::from),
+        SHORT_STRING        => reader.read_u16(endian).map(u32::from),
+        SHORT_TEXT          => reader.read_u16(endian).map(u32::from),
+        UNLIMITED_TEXT      => reader.read_reserved_u32(endian),
+        UNLIMITED_CHARACTERS => reader.read_reserved_u32(endian),
+        UNIVERSAL_RESOURCE  => reader.read_reserved_u32(endian),
+        DATE                => reader.read_u16(endian).map(u32::from),
+        DATE_TIME           => reader.read_u16(endian).map(u32::from),
+        TIME                => reader.read_u16(endian).map(u32::from),
+        DECIMAL_STRING      => reader.read_u16(endian).map(u32::from),
+        INTEGER_STRING      => reader.read_u16(endian).map(u32::from),
+        LONG_STRING         => reader.read_u16(endian).map(u32::from),
+        OTHER_BYTE          => reader.read_reserved_u32(endian),
+        OTHER_FLOAT         => reader.read_reserved_u32(endian),
+        OTHER_WORD          => reader.read_reserved_u32(endian),
+        OTHER_DOUBLE        => reader.read_reserved_u32(endian),
+        OTHER_LONG          => reader.read_reserved_u32(endian),
+        OTHER_VERY_LONG     => reader.read_reserved_u32(endian),
+        UNKNOWN             => reader.read_reserved_u32(endian),
+        _                   => reader.read_reserved_u32(endian)
     }
 }
\ No newline at end of file
@@ -0,0 +1,130 @@
+#![cfg(feature = "tokio")]
+
+mod common;
+
+use std::io::{self, Cursor, Write};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use flate2::{Compression as Level, write::DeflateEncoder};
+use tokio::io::{AsyncRead, ReadBuf};
+
+use dicomize::{dicom, dicom_async, tags, transfer_syntax, DicomContainer, DicomError, DicomHandler, DicomTag, ParseAction, TagHeader, TransferSyntax, VrValue};
+use common::*;
+
+// An async reader handing out at most 3 bytes per read.
+struct Trickle<'a>(&'a [u8]);
+
+impl<'a> AsyncRead for Trickle<'a> {
+    fn poll_read(mut self: Pin<&mut Self>, _: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let n = buf.remaining().min(3).min(self.0.len());
+        buf.put_slice(&self.0[..n]);
+        self.0 = &self.0[n..];
+        Poll::Ready(Ok(()))
+    }
+}
+
+async fn parse_async(bytes: &[u8]) -> DicomContainer {
+    let mut container = DicomContainer::new();
+    dicom_async::parse(Trickle(bytes), &mut container).await.unwrap();
+    container
+}
+
+#[tokio::test]
+async fn raises_the_same_events_as_the_sync_parser() {
+    let bytes = explicit_le(&study(transfer_syntax::EXPLICIT_LE));
+
+    assert_eq!(render(&parse_async(&bytes).await), render(&parse(bytes.clone())));
+
+    let mut seekable = DicomContainer::new();
+    dicom_async::parse_seekable(Cursor::new(bytes.clone()), &mut seekable).await.unwrap();
+    assert_eq!(render(&seekable), render(&parse(bytes)));
+}
+
+#[tokio::test]
+async fn parses_implicit_deflated_and_raw_datasets() {
+    let bytes = part10(transfer_syntax::IMPLICIT_LE, &study(transfer_syntax::IMPLICIT_LE));
+    assert_eq!(render(&parse_async(&bytes).await), render(&parse(bytes.clone())));
+
+    let dataset = study(transfer_syntax::EXPLICIT_LE);
+    let mut encoder = DeflateEncoder::new(Vec::new(), Level::best());
+    encoder.write_all(&dataset).unwrap();
+    let bytes = part10(transfer_syntax::DEFLATED_EXPLICIT_LE, &encoder.finish().unwrap());
+    assert_eq!(render(&parse_async(&bytes).await), render(&parse(bytes.clone())));
+
+    let mut raw = DicomContainer::new();
+    dicom_async::parse_dataset(Trickle(&dataset), TransferSyntax::default(), &mut raw).await.unwrap();
+    assert_eq!(render(&raw), render(&parse(explicit_le(&dataset)))[7..]);
+}
+
+#[tokio::test]
+async fn keeps_fragment_offsets_of_encapsulated_pixel_data() {
+    let items = [item(&[], LE), item(b"\xFF\xD8ab", LE), item(b"cd", LE)].concat();
+    let dataset = [
+        header(tags::PIXEL_DATA, 0, LE)[..4].to_vec(), b"OB\0\0".to_vec(), u32_bytes(0xFFFF_FFFF, LE),
+        items, header(tags::SEQUENCE_DELIMITER, 0, LE)
+    ].concat();
+    let bytes = part10("1.2.840.10008.1.2.4.50", &dataset);
+
+    let offsets = |container: &DicomContainer| match &dataset_tags(container)[0].value {
+        VrValue::Encapsulated(pixel_data) => pixel_data.fragments.iter().map(|f| f.offset).collect::<Vec<_>>(),
+        _                                 => panic!("pixel data not encapsulated")
+    };
+
+    assert_eq!(offsets(&parse_async(&bytes).await), offsets(&parse(bytes.clone())));
+}
+
+// Skips the first sequence and defers pixel data.
+#[derive(Default)]
+struct Selective(Vec<DicomTag>);
+
+impl DicomHandler for Selective {
+    fn tag_header(&mut self, header: &TagHeader) -> ParseAction {
+        match header.tag() {
+            (0x0008, 0x1115) => ParseAction::SkipValue,
+            (0x0040, 0x0009) => ParseAction::SkipSequence,
+            tags::PIXEL_DATA => ParseAction::DeferValue,
+            _                => ParseAction::Continue
+        }
+    }
+
+    fn element(&mut self, tag: DicomTag) {
+        if tag.group != 0x0002 {
+            self.0.push(tag);
+        }
+    }
+}
+
+#[tokio::test]
+async fn skips_and_defers_values_with_or_without_seeking() {
+    let bytes = explicit_le(&study(transfer_syntax::EXPLICIT_LE));
+
+    let mut streamed = Selective::default();
+    dicom_async::parse(Trickle(&bytes), &mut streamed).await.unwrap();
+
+    let mut seeked = Selective::default();
+    dicom_async::parse_seekable(Cursor::new(bytes.clone()), &mut seeked).await.unwrap();
+
+    for handler in [&streamed, &seeked] {
+        assert_eq!(handler.0.iter().map(id).collect::<Vec<_>>(), vec![(0x0008, 0x0060), (0x0010, 0x0010), (0x0028, 0x0010), tags::PIXEL_DATA]);
+    }
+
+    // Values of a stream that cannot seek could not be loaded back, they are read instead of deferred.
+    assert!(matches!(&streamed.0.last().unwrap().value, VrValue::OtherWord(v) if *v == vec![7; 64]));
+
+    let pixel_data = seeked.0.last().unwrap();
+    assert!(matches!(pixel_data.value, VrValue::Deferred { length: 64, .. }));
+    assert!(matches!(dicom::load_value(&mut Cursor::new(bytes), pixel_data), Ok(Some(VrValue::OtherWord(v))) if v == vec![7; 64]));
+}
+
+#[tokio::test]
+async fn reports_the_same_errors_as_the_sync_parser() {
+    let bytes = explicit_le(&study(transfer_syntax::EXPLICIT_LE));
+    let truncated = bytes[..bytes.len() - 10].to_vec();
+
+    let error = dicom_async::parse(Trickle(&truncated), &mut DicomContainer::new()).await.unwrap_err();
+    let sync_error = dicom::parse(&mut Cursor::new(truncated), &mut DicomContainer::new()).unwrap_err();
+
+    assert!(matches!(error, DicomError::TruncatedValue { tag, .. } if tag == tags::PIXEL_DATA));
+    assert_eq!(error.to_string(), sync_error.to_string());
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use dicomize::{transfer_syntax, EndianEncoding, VrValue};
+use common::*;
+
+#[test]
+fn big_endian_values_round_trip() {
+    let words: Vec<u8> = [1u16, 2, 0x1234].iter().flat_map(|w| w.to_be_bytes().to_vec()).collect();
+    let dataset = [
+        explicit((0x0008, 0x0060), "CS", b"MR", BE),
+        explicit((0x0018, 0x9087), "FD", &1000.5f64.to_be_bytes(), BE),
+        explicit((0x0020, 0x9165), "AT", &[u16_bytes(0x0010, BE), u16_bytes(0x0020, BE)].concat(), BE),
+        explicit((0x0028, 0x0010), "US", &[u16_bytes(512, BE), u16_bytes(1, BE)].concat(), BE),
+        explicit((0x0028, 0x0106), "SS", &(-3i16).to_be_bytes(), BE),
+        explicit((0x0028, 0x9099), "UL", &u32_bytes(0x0102_0304, BE), BE),
+        explicit((0x0040, 0x9224), "FL", &2.25f32.to_be_bytes(), BE),
+        sequence((0x0008, 0x1115), &[item(&explicit((0x0020, 0x000E), "UI", b"1.2.3", BE), BE)], BE),
+        explicit((0x7FE0, 0x0010), "OW", &words, BE)
+    ].concat();
+
+    let container = parse(part10(transfer_syntax::EXPLICIT_BE, &dataset));
+    let tags = dataset_tags(&container);
+
+    assert!(matches!(&tags[0].value, VrValue::CodeString(v) if v == &["MR"]));
+    assert!(matches!(&tags[1].value, VrValue::Double(v) if v == &[1000.5]));
+    assert!(matches!(&tags[2].value, VrValue::Attribute(v) if v == &[(0x0010, 0x0020)]));
+    assert!(matches!(&tags[3].value, VrValue::UnsignedShort(v) if v == &[512, 1]));
+    assert!(matches!(&tags[4].value, VrValue::SignedShort(v) if v == &[-3]));
+    assert!(matches!(&tags[5].value, VrValue::UnsignedLong(v) if v == &[0x0102_0304]));
+    assert!(matches!(&tags[6].value, VrValue::Float(v) if v == &[2.25]));
+    assert!(matches!(&tags[7].value, VrValue::SequenceOfItems));
+
+    // OW data is normalized to little endian words.
+    let expected: Vec<u8> = [1u16, 2, 0x1234].iter().flat_map(|w| w.to_le_bytes().to_vec()).collect();
+    assert!(matches!(&tags[8].value, VrValue::OtherWord(v) if v == &expected));
+    assert_eq!(tags[8].syntax.endian_encoding, EndianEncoding::BigEndian);
+}
+
+#[test]
+fn reads_item_headers_and_delimiters_in_big_endian() {
+    let dataset = [
+        undefined_sequence((0x0008, 0x1140), &[
+            undefined_item(&explicit((0x0008, 0x1150), "UI", b"1.2", BE), BE),
+            item(&explicit((0x0008, 0x1155), "UI", b"1.3", BE), BE)
+        ], BE),
+        explicit((0x0028, 0x0011), "US", &u16_bytes(256, BE), BE)
+    ].concat();
+
+    let container = parse(part10(transfer_syntax::EXPLICIT_BE, &dataset));
+    let tags = dataset_tags(&container);
+
+    assert_eq!(tags.iter().map(|t| id(t)).collect::<Vec<_>>(), vec![(0x0008, 0x1140), (0x0028, 0x0011)]);
+    assert!(matches!(&tags[1].value, VrValue::UnsignedShort(v) if v == &[256]));
+    assert_eq!(container.nodes.iter().filter_map(|n| n.tag()).filter(|t| t.group == 0x0008 && t.element >= 0x1150).count(), 2);
+}
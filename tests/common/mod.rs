@@ -0,0 +1,197 @@
+// Builders for hand made Dicom byte streams shared by the integration tests.
+#![allow(dead_code)]
+
+use std::io::Cursor;
+
+use dicomize::{DicomContainer, DicomTag, EndianEncoding, TransferSyntax, VrEncoding};
+use dicomize::transfer_syntax;
+
+pub const LE: EndianEncoding = EndianEncoding::LittleEndian;
+pub const BE: EndianEncoding = EndianEncoding::BigEndian;
+
+const LONG_VRS: [&str; 13] = ["OB", "OD", "OF", "OL", "OV", "OW", "SQ", "SV", "UC", "UN", "UR", "UT", "UV"];
+const UNDEFINED: u32 = 0xFFFF_FFFF;
+
+pub fn u16_bytes(v: u16, endian: EndianEncoding) -> Vec<u8> {
+    endian.convert(v.to_le_bytes(), v.to_be_bytes()).to_vec()
+}
+
+pub fn u32_bytes(v: u32, endian: EndianEncoding) -> Vec<u8> {
+    endian.convert(v.to_le_bytes(), v.to_be_bytes()).to_vec()
+}
+
+fn tag_bytes(tag: (u16, u16), endian: EndianEncoding) -> Vec<u8> {
+    [u16_bytes(tag.0, endian), u16_bytes(tag.1, endian)].concat()
+}
+
+// Values are padded to even length, UIDs with NUL and everything else with a space.
+fn pad(vr: &str, value: &[u8]) -> Vec<u8> {
+    let mut value = value.to_vec();
+    if value.len() % 2 == 1 {
+        value.push(if vr == "UI" { 0 } else { b' ' });
+    }
+    value
+}
+
+pub fn explicit(tag: (u16, u16), vr: &str, value: &[u8], endian: EndianEncoding) -> Vec<u8> {
+    let value = pad(vr, value);
+    let length = match LONG_VRS.contains(&vr) {
+        true  => [vec![0, 0], u32_bytes(value.len() as u32, endian)].concat(),
+        false => u16_bytes(value.len() as u16, endian)
+    };
+    [tag_bytes(tag, endian), vr.as_bytes().to_vec(), length, value].concat()
+}
+
+pub fn implicit(tag: (u16, u16), value: &[u8]) -> Vec<u8> {
+    let value = pad("", value);
+    [tag_bytes(tag, LE), u32_bytes(value.len() as u32, LE), value].concat()
+}
+
+pub fn header(tag: (u16, u16), length: u32, endian: EndianEncoding) -> Vec<u8> {
+    [tag_bytes(tag, endian), u32_bytes(length, endian)].concat()
+}
+
+pub fn item(content: &[u8], endian: EndianEncoding) -> Vec<u8> {
+    [header((0xFFFE, 0xE000), content.len() as u32, endian), content.to_vec()].concat()
+}
+
+pub fn undefined_item(content: &[u8], endian: EndianEncoding) -> Vec<u8> {
+    [header((0xFFFE, 0xE000), UNDEFINED, endian), content.to_vec(), header((0xFFFE, 0xE00D), 0, endian)].concat()
+}
+
+pub fn sequence(tag: (u16, u16), items: &[Vec<u8>], endian: EndianEncoding) -> Vec<u8> {
+    explicit(tag, "SQ", &items.concat(), endian)
+}
+
+pub fn undefined_sequence(tag: (u16, u16), items: &[Vec<u8>], endian: EndianEncoding) -> Vec<u8> {
+    [tag_bytes(tag, endian), b"SQ\0\0".to_vec(), u32_bytes(UNDEFINED, endian), items.concat(), header((0xFFFE, 0xE0DD), 0, endian)].concat()
+}
+
+// UN value of undefined length, a sequence whose items are encoded in Implicit VR Little Endian.
+pub fn undefined_unknown(tag: (u16, u16), items: &[Vec<u8>]) -> Vec<u8> {
+    [tag_bytes(tag, LE), b"UN\0\0".to_vec(), u32_bytes(UNDEFINED, LE), items.concat(), header((0xFFFE, 0xE0DD), 0, LE)].concat()
+}
+
+pub fn encapsulated(offset_table: &[u8], fragments: &[&[u8]]) -> Vec<u8> {
+    let items: Vec<Vec<u8>> = fragments.iter().map(|f| item(f, LE)).collect();
+    [
+        header((0x7FE0, 0x0010), 0, LE)[..4].to_vec(), b"OB\0\0".to_vec(), u32_bytes(0xFFFF_FFFF, LE),
+        item(offset_table, LE), items.concat(), header((0xFFFE, 0xE0DD), 0, LE)
+    ].concat()
+}
+
+// Element encoded in the given syntax, with or without its VR.
+pub fn element(tag: (u16, u16), vr: &str, value: &[u8], syntax: TransferSyntax) -> Vec<u8> {
+    match syntax.vr_encoding {
+        VrEncoding::Explicit => explicit(tag, vr, value, syntax.endian_encoding),
+        VrEncoding::Implicit => {
+            let value = pad(vr, value);
+            [tag_bytes(tag, LE), u32_bytes(value.len() as u32, LE), value].concat()
+        }
+    }
+}
+
+fn study_sequence(tag: (u16, u16), items: &[Vec<u8>], undefined_length: bool, syntax: TransferSyntax) -> Vec<u8> {
+    let endian = syntax.endian_encoding;
+    match (syntax.vr_encoding, undefined_length) {
+        (VrEncoding::Explicit, true)  => undefined_sequence(tag, items, endian),
+        (VrEncoding::Explicit, false) => sequence(tag, items, endian),
+        (VrEncoding::Implicit, true)  => [header(tag, UNDEFINED, LE), items.concat(), header((0xFFFE, 0xE0DD), 0, LE)].concat(),
+        (VrEncoding::Implicit, false) => [header(tag, items.concat().len() as u32, LE), items.concat()].concat()
+    }
+}
+
+// Dataset shared by the tests: modality, a referenced series sequence of undefined length holding an undefined length
+// item with a nested sequence and a defined length item, patient name, rows, a defined length request attributes
+// sequence and 64 bytes of pixel data. Extra elements are merged in tag order, replacing the study elements they share a tag with.
+pub fn study_with(syntax_uid: &str, extra: &[Vec<u8>]) -> Vec<u8> {
+    let syntax = TransferSyntax::parse_str(syntax_uid);
+    let endian = syntax.endian_encoding;
+    let element = |tag, vr, value: &[u8]| element(tag, vr, value, syntax);
+
+    let referenced_images = study_sequence((0x0008, 0x1140), &[undefined_item(&element((0x0008, 0x1150), "UI", b"1.2"), endian)], true, syntax);
+    let referenced_series = study_sequence((0x0008, 0x1115), &[
+        undefined_item(&[referenced_images, element((0x0020, 0x000E), "UI", b"1.2.3")].concat(), endian),
+        item(&element((0x0020, 0x000E), "UI", b"1.2.4"), endian)
+    ], true, syntax);
+    let request_attributes = study_sequence((0x0040, 0x0275), &[item(&element((0x0040, 0x0009), "SH", b"SPS1"), endian)], false, syntax);
+
+    let mut elements = vec![
+        element((0x0008, 0x0060), "CS", b"CT"),
+        referenced_series,
+        element((0x0010, 0x0010), "PN", b"DOE^JOHN"),
+        element((0x0028, 0x0010), "US", &u16_bytes(512, endian)),
+        request_attributes,
+        element((0x7FE0, 0x0010), "OW", &[7; 64])
+    ];
+
+    let tag_of = |element: &Vec<u8>| {
+        let convert: fn([u8; 2]) -> u16 = endian.convert(u16::from_le_bytes, u16::from_be_bytes);
+        (convert([element[0], element[1]]), convert([element[2], element[3]]))
+    };
+
+    elements.retain(|element| extra.iter().all(|e| tag_of(e) != tag_of(element)));
+    elements.extend(extra.iter().cloned());
+    elements.sort_by_key(tag_of);
+    elements.concat()
+}
+
+pub fn study(syntax_uid: &str) -> Vec<u8> {
+    study_with(syntax_uid, &[])
+}
+
+pub fn meta_elements(syntax_uid: &str) -> Vec<u8> {
+    [
+        explicit((0x0002, 0x0001), "OB", &[0, 1], LE),
+        explicit((0x0002, 0x0002), "UI", b"1.2.840.10008.5.1.4.1.1.2", LE),
+        explicit((0x0002, 0x0003), "UI", b"1.2.3.4", LE),
+        explicit((0x0002, 0x0010), "UI", syntax_uid.as_bytes(), LE),
+        explicit((0x0002, 0x0012), "UI", b"1.2.3.5", LE),
+        explicit((0x0002, 0x0013), "SH", b"DICOMIZE", LE)
+    ].concat()
+}
+
+pub fn with_meta(meta: &[u8], dataset: &[u8]) -> Vec<u8> {
+    let group_length = explicit((0x0002, 0x0000), "UL", &(meta.len() as u32).to_le_bytes(), LE);
+    [vec![0xAB; 128], b"DICM".to_vec(), group_length, meta.to_vec(), dataset.to_vec()].concat()
+}
+
+pub fn part10(syntax_uid: &str, dataset: &[u8]) -> Vec<u8> {
+    with_meta(&meta_elements(syntax_uid), dataset)
+}
+
+pub fn explicit_le(dataset: &[u8]) -> Vec<u8> {
+    part10(transfer_syntax::EXPLICIT_LE, dataset)
+}
+
+pub fn parse(bytes: Vec<u8>) -> DicomContainer {
+    let mut container = DicomContainer::new();
+    dicomize::dicom::parse(&mut Cursor::new(bytes), &mut container).expect("parse failed");
+    container
+}
+
+// Tags below the root, skipping the File Meta Information group.
+pub fn dataset_tags(container: &DicomContainer) -> Vec<&DicomTag> {
+    container.nodes[0].children().iter()
+        .filter_map(|i| container.nodes[*i].tag())
+        .filter(|t| t.group != 0x0002)
+        .collect()
+}
+
+// Renders the whole tree, tags and values, to compare containers filled by different parsers.
+pub fn render(container: &DicomContainer) -> Vec<String> {
+    container.nodes.iter()
+        .filter_map(|node| node.tag())
+        .map(|tag| format!("{:04X},{:04X} {}", tag.group, tag.element, tag.value))
+        .collect()
+}
+
+pub fn children(container: &DicomContainer, index: usize) -> Vec<(usize, &DicomTag)> {
+    container.nodes[index].children().iter()
+        .map(|i| (*i, container.nodes[*i].tag().unwrap()))
+        .collect()
+}
+
+pub fn id(tag: &DicomTag) -> (u16, u16) {
+    (tag.group, tag.element)
+}
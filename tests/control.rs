@@ -0,0 +1,132 @@
+mod common;
+
+use std::io::Cursor;
+
+use dicomize::{dicom, tags, transfer_syntax, DicomHandler, DicomTag, ParseAction, TagHeader};
+use common::*;
+
+// Records the dataset events and answers the header of each tag through a closure.
+struct Controlled<F: FnMut(&TagHeader) -> ParseAction> {
+    decide: F,
+    events: Vec<String>
+}
+
+impl<F: FnMut(&TagHeader) -> ParseAction> DicomHandler for Controlled<F> {
+    fn tag_header(&mut self, header: &TagHeader) -> ParseAction {
+        (self.decide)(header)
+    }
+
+    fn start_sequence(&mut self, tag: DicomTag) {
+        self.events.push(format!("start sequence {:04X},{:04X}", tag.group, tag.element));
+    }
+
+    fn end_sequence(&mut self) {
+        self.events.push("end sequence".to_string());
+    }
+
+    fn start_item(&mut self, _: DicomTag) {
+        self.events.push("start item".to_string());
+    }
+
+    fn end_item(&mut self) {
+        self.events.push("end item".to_string());
+    }
+
+    fn element(&mut self, tag: DicomTag) {
+        if tag.group != 0x0002 {
+            self.events.push(format!("element {:04X},{:04X}", tag.group, tag.element));
+        }
+    }
+
+    fn end_of_dataset(&mut self) {
+        self.events.push("end of dataset".to_string());
+    }
+}
+
+fn events(bytes: Vec<u8>, decide: impl FnMut(&TagHeader) -> ParseAction) -> Vec<String> {
+    let mut handler = Controlled { decide, events: Vec::new() };
+    dicom::parse(&mut Cursor::new(bytes), &mut handler).unwrap();
+    handler.events
+}
+
+#[test]
+fn stops_before_pixel_data() {
+    let events = events(explicit_le(&study(transfer_syntax::EXPLICIT_LE)), |header| match header.tag() {
+        tags::PIXEL_DATA => ParseAction::Stop,
+        _                => ParseAction::Continue
+    });
+
+    assert_eq!(&events[events.len() - 3..], ["element 0040,0009", "end item", "end sequence"]);
+}
+
+#[test]
+fn skips_values_and_whole_sequences() {
+    let events = events(explicit_le(&study(transfer_syntax::EXPLICIT_LE)), |header| match header.tag() {
+        (0x0008, 0x1115) | (0x0040, 0x0275) | tags::PIXEL_DATA => ParseAction::SkipValue,
+        _                                                      => ParseAction::Continue
+    });
+
+    assert_eq!(events, vec!["element 0008,0060", "element 0010,0010", "element 0028,0010", "end of dataset"]);
+}
+
+#[test]
+fn skips_the_rest_of_a_sequence() {
+    let events = events(explicit_le(&study(transfer_syntax::EXPLICIT_LE)), |header| match header.tag() {
+        (0x0008, 0x1140) => ParseAction::SkipSequence,
+        _                => ParseAction::Continue
+    });
+
+    assert_eq!(events, vec![
+        "element 0008,0060",
+        "start sequence 0008,1115", "start item", "end item", "end sequence",
+        "element 0010,0010", "element 0028,0010",
+        "start sequence 0040,0275", "start item", "element 0040,0009", "end item", "end sequence",
+        "element 7FE0,0010", "end of dataset"
+    ]);
+}
+
+#[test]
+fn skipped_values_are_not_read() {
+    // The declared length runs far past the end of the data, reading it would fail.
+    let dataset = [
+        explicit((0x0010, 0x0010), "PN", b"DOE^JOHN", LE),
+        header(tags::PIXEL_DATA, 0, LE)[..4].to_vec(), b"OW\0\0".to_vec(), u32_bytes(0x7FFF_FFF0, LE)
+    ].concat();
+
+    let events = events(explicit_le(&dataset), |header| match header.tag() {
+        tags::PIXEL_DATA => ParseAction::SkipValue,
+        _                => ParseAction::Continue
+    });
+
+    assert_eq!(events, vec!["element 0010,0010", "end of dataset"]);
+}
+
+#[test]
+fn stops_after_a_number_of_tags() {
+    let mut count = 0;
+    let events = events(explicit_le(&study(transfer_syntax::EXPLICIT_LE)), |_| {
+        count += 1;
+        match count > 3 {
+            true  => ParseAction::Stop,
+            false => ParseAction::Continue
+        }
+    });
+
+    assert_eq!(events, vec!["element 0008,0060", "start sequence 0008,1115", "start item"]);
+}
+
+#[test]
+fn skips_encapsulated_pixel_data() {
+    let dataset = [
+        header(tags::PIXEL_DATA, 0, LE)[..4].to_vec(), b"OB\0\0".to_vec(), u32_bytes(0xFFFF_FFFF, LE),
+        item(&[], LE), item(b"\xFF\xD8ab\xFF\xD9", LE), header(tags::SEQUENCE_DELIMITER, 0, LE),
+        explicit((0xFFFA, 0xFFFA), "OB", b"xx", LE)
+    ].concat();
+
+    let events = events(part10("1.2.840.10008.1.2.4.50", &dataset), |header| match header.tag() {
+        tags::PIXEL_DATA => ParseAction::SkipValue,
+        _                => ParseAction::Continue
+    });
+
+    assert_eq!(events, vec!["element FFFA,FFFA", "end of dataset"]);
+}
@@ -0,0 +1,172 @@
+mod common;
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use dicomize::{DicomDate, DicomDateTime, DicomError, DicomRange, DicomTime, VrValue};
+use common::*;
+
+const STUDY_DATE: (u16, u16) = (0x0008, 0x0020);
+const STUDY_TIME: (u16, u16) = (0x0008, 0x0030);
+const ACQUISITION_DATE_TIME: (u16, u16) = (0x0008, 0x002A);
+
+fn date(value: &str) -> DicomDate {
+    DicomDate::parse(value).unwrap()
+}
+
+fn time(value: &str) -> DicomTime {
+    DicomTime::parse(value).unwrap()
+}
+
+fn date_time(value: &str) -> DicomDateTime {
+    DicomDateTime::parse(value).unwrap()
+}
+
+#[test]
+fn parses_values_of_partial_precision() {
+    assert_eq!(date("2020"), DicomDate { year: 2020, month: None, day: None });
+    assert_eq!(date("202002"), DicomDate { year: 2020, month: Some(2), day: None });
+    assert_eq!(date("20200229"), DicomDate { year: 2020, month: Some(2), day: Some(29) });
+
+    assert_eq!(time("07"), DicomTime { hour: 7, minute: None, second: None, micros: None, fraction_digits: 0 });
+    assert_eq!(time("0705"), DicomTime { hour: 7, minute: Some(5), second: None, micros: None, fraction_digits: 0 });
+    assert_eq!(time("070503.25"), DicomTime { hour: 7, minute: Some(5), second: Some(3), micros: Some(250_000), fraction_digits: 2 });
+
+    let value = date_time("20200229070503.123456-0530");
+    assert_eq!(value.date, date("20200229"));
+    assert_eq!(value.time, Some(time("070503.123456")));
+    assert_eq!(value.offset, Some(-330));
+
+    assert_eq!(date_time("2020+0100"), DicomDateTime { date: date("2020"), time: None, offset: Some(60) });
+    assert_eq!(date_time("2020022907").time, Some(time("07")));
+}
+
+#[test]
+fn refuses_malformed_values() {
+    for value in &["", "20", "202", "2020022", "20201301", "20200230", "20210229", "2020-02-29", "2020022 ", "٢٠٢٠"] {
+        assert!(DicomDate::parse(value).is_none(), "date {}", value);
+    }
+
+    for value in &["", "7", "075", "2400", "0760", "070561", "07.5", "0705.5", "070503.", "070503.1234567", "07:05:03", "+70503"] {
+        assert!(DicomTime::parse(value).is_none(), "time {}", value);
+    }
+
+    // Dates and hours are complete, offsets range from -1200 to +1400.
+    for value in &["2020022", "202002290", "20200229+1500", "20200229-1300", "20200229+0160", "20200229+01", "20200229070503Z"] {
+        assert!(DicomDateTime::parse(value).is_none(), "date time {}", value);
+    }
+}
+
+#[test]
+fn formats_values_back_to_dicom() {
+    for value in &["2020", "202002", "20200229"] {
+        assert_eq!(date(value).to_string(), *value);
+    }
+
+    for value in &["07", "0705", "070503", "070503.2", "070503.000250", "235960"] {
+        assert_eq!(time(value).to_string(), *value);
+    }
+
+    for value in &["2020", "20200229070503.25", "20200229-0530", "2020022907+1400", "20200229235959.999999+0000"] {
+        assert_eq!(date_time(value).to_string(), *value);
+    }
+
+    for value in &["20200101-20201231", "-20201231", "2020-", "20200101"] {
+        assert_eq!(DicomRange::<DicomDate>::parse(value).unwrap().to_string(), *value);
+    }
+    assert_eq!(DicomRange::<DicomTime>::parse("0700-1200").unwrap().to_string(), "0700-1200");
+}
+
+#[test]
+fn matches_values_against_ranges() {
+    let year: DicomRange<DicomDate> = DicomRange::parse("20200101-20201231").unwrap();
+    assert_eq!(year.start, Some(date("20200101")));
+    assert_eq!(year.end, Some(date("20201231")));
+    assert!(year.contains(&date("20200229")));
+    assert!(year.contains(&date("202012")));
+    assert!(year.contains(&date("2020")));
+    assert!(!year.contains(&date("2021")));
+    assert!(!year.contains(&date("20191231")));
+
+    let until: DicomRange<DicomDate> = DicomRange::parse("-2019").unwrap();
+    assert_eq!(until.start, None);
+    assert!(until.contains(&date("19000101")));
+    assert!(until.contains(&date("20191231")));
+    assert!(!until.contains(&date("20200101")));
+
+    let since: DicomRange<DicomTime> = DicomRange::parse("0730-").unwrap();
+    assert!(since.contains(&time("0730")));
+    assert!(since.contains(&time("235959.999999")));
+    assert!(!since.contains(&time("072959.999999")));
+    assert!(!since.contains(&time("07")));
+
+    // A single value matches what it covers, the hyphen of a UTC offset is not a range.
+    let morning: DicomRange<DicomDateTime> = DicomRange::parse("2020022907-0500").unwrap();
+    assert_eq!(morning.start, morning.end);
+    assert!(morning.contains(&date_time("20200229125959+0000")));
+    assert!(!morning.contains(&date_time("20200229075959")));
+
+    // Date times compare in UTC.
+    let day: DicomRange<DicomDateTime> = DicomRange::parse("20200229000000+0100-20200229235959.999999+0100").unwrap();
+    assert!(day.contains(&date_time("20200228230000")));
+    assert!(!day.contains(&date_time("20200229230000")));
+
+    for value in &["-", "2020-2021-2022", "20201301-", "abc"] {
+        assert!(DicomRange::<DicomDate>::parse(value).is_none(), "range {}", value);
+    }
+}
+
+#[test]
+fn converts_values_to_std_time() {
+    assert_eq!(date("19700102").to_system_time(), Some(UNIX_EPOCH + Duration::from_secs(86_400)));
+    assert_eq!(date("1969").to_system_time(), Some(UNIX_EPOCH - Duration::from_secs(365 * 86_400)));
+    assert_eq!(time("010203.5").to_duration(), Duration::from_millis(3_723_500));
+
+    let value = date_time("20000301120000+0200");
+    assert_eq!(value.to_system_time(), Some(UNIX_EPOCH + Duration::from_secs(951_904_800)));
+    assert_eq!(date_time("20000301100000").to_system_time(), value.to_system_time());
+}
+
+#[test]
+fn gets_typed_values_of_tags() {
+    let container = parse(explicit_le(&[
+        explicit(STUDY_DATE, "DA", b"20200229\\2021", LE),
+        explicit(ACQUISITION_DATE_TIME, "DT", b"20200229070503.25+0100", LE),
+        explicit(STUDY_TIME, "TM", b"0705", LE),
+        explicit((0x0010, 0x0030), "DA", b"2020022", LE)
+    ].concat()));
+
+    assert_eq!(container.get_date(STUDY_DATE).unwrap(), Some(date("20200229")));
+    assert_eq!(container.get(STUDY_DATE).unwrap().to_dates().unwrap(), [date("20200229"), date("2021")]);
+    assert_eq!(container.get_time(STUDY_TIME).unwrap(), Some(time("0705")));
+    assert_eq!(container.get_date_time(ACQUISITION_DATE_TIME).unwrap(), Some(date_time("20200229070503.25+0100")));
+    assert_eq!(container.get_date((0x0018, 0x0012)).unwrap(), None);
+
+    let error = container.get_date((0x0010, 0x0030)).unwrap_err();
+    assert!(matches!(error, DicomError::InvalidValue { tag: (0x0010, 0x0030), .. }));
+
+    // Values of other VRs are not dates, even when they parse as one.
+    assert!(container.get_time(STUDY_DATE).is_err());
+    assert!(VrValue::LongString(vec!["20200229".into()]).dates().is_none());
+
+    let ranges = container.get(STUDY_DATE).unwrap().to_ranges::<DicomDate>().unwrap();
+    assert!(ranges[1].contains(&date("20210615")));
+    assert!(container.get(STUDY_DATE).unwrap().to_ranges::<DicomTime>().is_err());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn converts_values_to_chrono() {
+    use chrono::{FixedOffset, NaiveDate, NaiveTime, TimeZone};
+
+    assert_eq!(date("202002").to_naive_date(), NaiveDate::from_ymd_opt(2020, 2, 1));
+    assert_eq!(time("070503.25").to_naive_time(), NaiveTime::from_hms_milli_opt(7, 5, 3, 250));
+    assert_eq!(time("235960").to_naive_time(), NaiveTime::from_hms_micro_opt(23, 59, 59, 1_000_000));
+
+    let offset = FixedOffset::west_opt(5 * 3600 + 1800).unwrap();
+    let expected = offset.with_ymd_and_hms(2020, 2, 29, 7, 5, 3).unwrap();
+    assert_eq!(date_time("20200229070503-0530").to_chrono(), Some(expected));
+    assert_eq!(DicomDateTime::from(expected).to_string(), "20200229070503-0530");
+
+    assert_eq!(DicomDate::from(NaiveDate::from_ymd_opt(2020, 2, 29).unwrap()), date("20200229"));
+    assert_eq!(DicomTime::from(NaiveTime::from_hms_micro_opt(7, 5, 3, 250).unwrap()).to_string(), "070503.000250");
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use std::io::Cursor;
+
+use dicomize::{dicom, tags, transfer_syntax, DicomContainer, VrValue};
+use common::*;
+
+fn find(container: &DicomContainer, tag: (u16, u16)) -> usize {
+    container.nodes.iter().position(|n| n.tag().map(id) == Some(tag)).expect("tag not found")
+}
+
+#[test]
+fn defers_binary_values_above_the_threshold() {
+    let pixels: Vec<u8> = (0..64u8).collect();
+    let dataset = [
+        explicit((0x0008, 0x0060), "CS", b"CT", LE),
+        explicit((0x0029, 0x1010), "OB", &[1, 2, 3, 4], LE),
+        explicit(tags::PIXEL_DATA, "OW", &pixels, LE)
+    ].concat();
+    let bytes = explicit_le(&dataset);
+
+    let mut container = DicomContainer::with_deferred_threshold(16);
+    dicom::parse(&mut Cursor::new(bytes.clone()), &mut container).unwrap();
+
+    let small = find(&container, (0x0029, 0x1010));
+    assert!(matches!(&container.nodes[small].tag().unwrap().value, VrValue::OtherByte(v) if v == &[1, 2, 3, 4]));
+
+    let pixel_data = find(&container, tags::PIXEL_DATA);
+    let offset = (bytes.len() - pixels.len()) as u64;
+    assert!(matches!(container.nodes[pixel_data].tag().unwrap().value, VrValue::Deferred { offset: o, length: 64, .. } if o == offset));
+
+    container.load_deferred(pixel_data, &mut Cursor::new(bytes)).unwrap();
+    assert!(matches!(&container.nodes[pixel_data].tag().unwrap().value, VrValue::OtherWord(v) if v == &pixels));
+}
+
+#[test]
+fn loads_deferred_values_with_the_syntax_they_were_parsed_with() {
+    let words: Vec<u8> = (0..16u16).flat_map(|w| w.to_be_bytes().to_vec()).collect();
+    let bytes = part10(transfer_syntax::EXPLICIT_BE, &explicit(tags::PIXEL_DATA, "OW", &words, BE));
+
+    let mut container = DicomContainer::with_deferred_threshold(0);
+    dicom::parse(&mut Cursor::new(bytes.clone()), &mut container).unwrap();
+
+    let pixel_data = find(&container, tags::PIXEL_DATA);
+    container.load_deferred(pixel_data, &mut Cursor::new(bytes)).unwrap();
+
+    let expected: Vec<u8> = (0..16u16).flat_map(|w| w.to_le_bytes().to_vec()).collect();
+    assert!(matches!(&container.nodes[pixel_data].tag().unwrap().value, VrValue::OtherWord(v) if v == &expected));
+}
+
+#[test]
+fn keeps_values_without_a_threshold() {
+    let container = parse(explicit_le(&explicit(tags::PIXEL_DATA, "OW", &[0; 64], LE)));
+    let pixel_data = find(&container, tags::PIXEL_DATA);
+
+    assert!(matches!(container.nodes[pixel_data].tag().unwrap().value, VrValue::OtherWord(_)));
+}
+
+#[test]
+fn defers_encapsulated_pixel_data_up_to_the_sequence_delimiter() {
+    let dataset = [
+        encapsulated(&[], &[b"\xFF\xD8ab\xFF\xD9", b"cd"]),
+        explicit((0xFFFA, 0xFFFA), "OB", b"xx", LE)
+    ].concat();
+    let bytes = part10("1.2.840.10008.1.2.4.50", &dataset);
+
+    let mut container = DicomContainer::with_deferred_threshold(16);
+    dicom::parse(&mut Cursor::new(bytes.clone()), &mut container).unwrap();
+
+    // Header of the pixel data, then the offset table item, two fragments and the delimiter.
+    let pixel_data = find(&container, tags::PIXEL_DATA);
+    let offset = (bytes.len() - dataset.len() + 12) as u64;
+    assert!(matches!(container.nodes[pixel_data].tag().unwrap().value, VrValue::Deferred { offset: o, length: 40, .. } if o == offset));
+    assert!(container.nodes[pixel_data].tag().unwrap().has_undefined_length());
+    assert!(matches!(&container.nodes[find(&container, (0xFFFA, 0xFFFA))].tag().unwrap().value, VrValue::OtherByte(v) if v == b"xx"));
+
+    container.load_deferred(pixel_data, &mut Cursor::new(bytes)).unwrap();
+
+    let pixel_data = match &container.nodes[pixel_data].tag().unwrap().value {
+        VrValue::Encapsulated(pixel_data) => pixel_data,
+        _                                 => panic!("pixel data not encapsulated")
+    };
+    assert_eq!(pixel_data.fragments.iter().map(|f| f.data.clone()).collect::<Vec<_>>(), vec![b"\xFF\xD8ab\xFF\xD9".to_vec(), b"cd".to_vec()]);
+    assert_eq!(pixel_data.fragments[0].offset, offset + 16);
+}
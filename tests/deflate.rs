@@ -0,0 +1,38 @@
+mod common;
+
+use std::io::Write;
+
+use flate2::{Compression as Level, write::DeflateEncoder};
+
+use dicomize::{transfer_syntax, VrValue};
+use common::*;
+
+fn deflate(dataset: &[u8]) -> Vec<u8> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Level::best());
+    encoder.write_all(dataset).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn inflates_deflated_datasets() {
+    let dataset = [
+        explicit((0x0008, 0x0060), "CS", b"CT", LE),
+        undefined_sequence((0x0008, 0x1115), &[undefined_item(&explicit((0x0020, 0x000E), "UI", b"1.2.3", LE), LE)], LE),
+        explicit((0x0028, 0x0010), "US", &64u16.to_le_bytes(), LE)
+    ].concat();
+
+    let container = parse(part10(transfer_syntax::DEFLATED_EXPLICIT_LE, &deflate(&dataset)));
+    let tags = dataset_tags(&container);
+
+    assert_eq!(tags.iter().map(|t| id(t)).collect::<Vec<_>>(), vec![(0x0008, 0x0060), (0x0008, 0x1115), (0x0028, 0x0010)]);
+    assert!(matches!(&tags[2].value, VrValue::UnsignedShort(v) if v == &[64]));
+}
+
+#[test]
+fn keeps_the_file_meta_information_uncompressed() {
+    let container = parse(part10(transfer_syntax::DEFLATED_EXPLICIT_LE, &deflate(&explicit((0x0008, 0x0060), "CS", b"CT", LE))));
+    let meta: Vec<_> = children(&container, 0).into_iter().filter(|(_, t)| t.group == 0x0002).collect();
+
+    assert_eq!(meta.len(), 7);
+    assert_eq!(dataset_tags(&container)[0].offset, 0);
+}
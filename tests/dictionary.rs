@@ -0,0 +1,23 @@
+use dicomize::{tags, vr_type};
+
+#[test]
+fn tags_are_sorted_for_binary_search() {
+    assert!(tags::TAGS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn finds_every_tag_of_the_dictionary() {
+    for (tag, name, keyword, vr) in tags::TAGS.iter() {
+        assert_eq!(tags::tag_vr_type(tag.0, tag.1), *vr);
+        assert_eq!(tags::try_tag_name(tag.0, tag.1), Some(*name));
+
+        if !keyword.is_empty() {
+            assert_eq!(tags::try_keyword_tag(keyword), Some(*tag));
+        }
+    }
+
+    assert_eq!(tags::try_tag_name(tags::PATIENT_NAME.0, tags::PATIENT_NAME.1), Some("Patient's Name"));
+    assert_eq!(tags::tag_vr_type(tags::ITEM.0, tags::ITEM.1), vr_type::DELIMITER);
+    assert_eq!(tags::tag_vr_type(0x0009, 0x0010), vr_type::UNKNOWN);
+    assert_eq!(tags::try_tag_name(0xFFFF, 0xFFFF), None);
+}
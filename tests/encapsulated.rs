@@ -0,0 +1,57 @@
+mod common;
+
+use dicomize::{tags, vr_type, VrValue};
+use common::*;
+
+#[test]
+fn splits_encapsulated_pixel_data_into_fragments() {
+    let offset_table = [0u32.to_le_bytes(), 24u32.to_le_bytes()].concat();
+    let dataset = encapsulated(&offset_table, &[b"\xFF\xD8ab\xFF\xD9", b"cd", b"\xFF\xD8ef"]);
+
+    let container = parse(part10("1.2.840.10008.1.2.4.50", &dataset));
+    let tags = dataset_tags(&container);
+
+    let pixel_data = match &tags[0].value {
+        VrValue::Encapsulated(pixel_data) => pixel_data,
+        _                                 => panic!("pixel data not encapsulated")
+    };
+
+    assert_eq!(pixel_data.basic_offset_table, vec![0, 24]);
+    assert_eq!(pixel_data.fragments.iter().map(|f| f.data.len()).collect::<Vec<_>>(), vec![6, 2, 4]);
+    assert_eq!(pixel_data.frame_count(), Some(2));
+    assert_eq!(pixel_data.frame_fragments(0), Some(0..2));
+    assert_eq!(pixel_data.frame_fragments(1), Some(2..3));
+    assert_eq!(pixel_data.frame_fragments(2), None);
+}
+
+#[test]
+fn locates_frames_with_the_extended_offset_table() {
+    let table = [0u64.to_le_bytes(), 28u64.to_le_bytes()].concat();
+    let lengths = [12u64.to_le_bytes(), 4u64.to_le_bytes()].concat();
+    let dataset = [
+        explicit(tags::EXTENDED_OFFSET_TABLE, "OV", &table, LE),
+        explicit(tags::EXTENDED_OFFSET_TABLE_LENGTHS, "OV", &lengths, LE),
+        encapsulated(&[], &[b"\xFF\xD8ab\xFF\xD9", b"cdef\xFF\xD9", b"\xFF\xD8ef"])
+    ].concat();
+
+    let container = parse(part10("1.2.840.10008.1.2.4.50", &dataset));
+    let tags = dataset_tags(&container);
+
+    let pixel_data = match &tags[2].value {
+        VrValue::Encapsulated(pixel_data) => pixel_data,
+        _                                 => panic!("pixel data not encapsulated")
+    };
+
+    assert!(pixel_data.basic_offset_table.is_empty());
+    assert_eq!(pixel_data.frame_count(), Some(2));
+    assert_eq!(pixel_data.frame_fragments(0), Some(0..2));
+    assert_eq!(pixel_data.frame_fragments(1), Some(2..3));
+}
+
+#[test]
+fn types_the_pixel_data_group_from_the_dictionary() {
+    assert_eq!(tags::tag_vr_type(0x7FE0, 0x0001), vr_type::OTHER_VERY_LONG);
+    assert_eq!(tags::tag_vr_type(0x7FE0, 0x0002), vr_type::OTHER_VERY_LONG);
+    assert_eq!(tags::tag_vr_type(0x7FE0, 0x0008), vr_type::OTHER_FLOAT);
+    assert_eq!(tags::tag_vr_type(0x7FE0, 0x0009), vr_type::OTHER_DOUBLE);
+}
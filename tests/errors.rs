@@ -0,0 +1,77 @@
+mod common;
+
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+
+use dicomize::{dicom, tags, DicomContainer, DicomError};
+use common::*;
+
+fn parse_error(bytes: Vec<u8>) -> DicomError {
+    let mut container = DicomContainer::new();
+    dicom::parse(&mut Cursor::new(bytes), &mut container).expect_err("parse should fail")
+}
+
+#[test]
+fn reports_truncated_values() {
+    let mut bytes = explicit_le(&explicit((0x0010, 0x0010), "PN", b"DOE^JOHN", LE));
+    let offset = (bytes.len() - 16) as u64;
+    bytes.truncate(bytes.len() - 3);
+
+    match parse_error(bytes) {
+        DicomError::TruncatedValue { offset: o, tag, length } => assert_eq!((o, tag, length), (offset, (0x0010, 0x0010), 8)),
+        err                                                   => panic!("unexpected error {}", err)
+    }
+}
+
+#[test]
+fn reports_invalid_vr_codes() {
+    let err = parse_error(explicit_le(&explicit((0x0010, 0x0010), "p?", b"XY", LE)));
+    assert!(matches!(err, DicomError::InvalidVr { tag: (0x0010, 0x0010), .. }));
+}
+
+#[test]
+fn reports_delimiters_outside_their_container() {
+    let err = parse_error(explicit_le(&header(tags::SEQUENCE_DELIMITER, 0, LE)));
+    assert!(matches!(err, DicomError::UnexpectedDelimiter { tag: tags::SEQUENCE_DELIMITER, .. }));
+}
+
+// Reader that can no longer tell its position once past limit.
+struct LostPosition {
+    cursor: Cursor<Vec<u8>>,
+    limit: u64
+}
+
+impl Read for LostPosition {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.cursor.read(buf)
+    }
+}
+
+impl Seek for LostPosition {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match pos {
+            SeekFrom::Current(0) if self.cursor.position() > self.limit => Err(io::Error::other("position lost")),
+            _                                                          => self.cursor.seek(pos)
+        }
+    }
+}
+
+#[test]
+fn reports_lost_positions_at_the_last_tag_read() {
+    let name = explicit((0x0010, 0x0010), "PN", b"DOE^JOHN", LE);
+    let bytes = explicit_le(&[name.clone(), explicit((0x0010, 0x0020), "LO", b"ID", LE)].concat());
+    let offset = (bytes.len() - name.len() - 10) as u64;
+
+    let mut reader = LostPosition { cursor: Cursor::new(bytes), limit: offset + name.len() as u64 - 1 };
+    match dicom::parse(&mut reader, &mut DicomContainer::new()).expect_err("parse should fail") {
+        DicomError::Io { offset: o, tag, .. } => assert_eq!((o, tag), (offset, Some((0x0010, 0x0010)))),
+        err                                   => panic!("unexpected error {}", err)
+    }
+}
+
+#[test]
+fn reports_missing_files_from_the_command_line() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_dicomize")).arg("missing.dcm").output().unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("ERROR: COULD NOT LOAD missing.dcm"));
+}
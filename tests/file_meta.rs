@@ -0,0 +1,66 @@
+mod common;
+
+use std::io::{Cursor, Seek};
+
+use dicomize::{read_file_meta, tags, transfer_syntax, DicomError};
+use common::*;
+
+#[test]
+fn reads_the_file_meta_information() {
+    let dataset = explicit((0x0008, 0x0060), "CS", b"CT", LE);
+    let bytes = explicit_le(&dataset);
+    let dataset_offset = (bytes.len() - dataset.len()) as u64;
+
+    let mut reader = Cursor::new(bytes);
+    let meta = read_file_meta(&mut reader).unwrap();
+
+    assert_eq!(meta.preamble.to_vec(), vec![0xAB; 128]);
+    assert_eq!(meta.group_length as usize, meta_elements(transfer_syntax::EXPLICIT_LE).len());
+    assert_eq!(meta.version, vec![0, 1]);
+    assert_eq!(meta.media_storage_sop_class_uid, "1.2.840.10008.5.1.4.1.1.2");
+    assert_eq!(meta.media_storage_sop_instance_uid, "1.2.3.4");
+    assert_eq!(meta.transfer_syntax_uid, transfer_syntax::EXPLICIT_LE);
+    assert_eq!(meta.implementation_class_uid, "1.2.3.5");
+    assert_eq!(meta.implementation_version_name.as_deref(), Some("DICOMIZE"));
+    assert_eq!(meta.source_application_entity_title, None);
+    assert_eq!(meta.transfer_syntax().uid, transfer_syntax::EXPLICIT_LE);
+    assert_eq!(reader.stream_position().unwrap(), dataset_offset);
+}
+
+#[test]
+fn rejects_missing_mandatory_elements() {
+    let meta = [
+        explicit((0x0002, 0x0001), "OB", &[0, 1], LE),
+        explicit((0x0002, 0x0002), "UI", b"1.2.840.10008.5.1.4.1.1.2", LE),
+        explicit((0x0002, 0x0003), "UI", b"1.2.3.4", LE),
+        explicit((0x0002, 0x0010), "UI", transfer_syntax::EXPLICIT_LE.as_bytes(), LE)
+    ].concat();
+
+    match read_file_meta(&mut Cursor::new(with_meta(&meta, &[]))) {
+        Err(DicomError::MissingElement { tag, .. }) => assert_eq!(tag, tags::IMPLEMENTATION_CLASS_UID),
+        _                                           => panic!("missing element not reported")
+    }
+}
+
+#[test]
+fn rejects_files_without_preamble() {
+    let bytes = meta_elements(transfer_syntax::EXPLICIT_LE);
+    assert!(matches!(read_file_meta(&mut Cursor::new(bytes)), Err(DicomError::MissingPreamble { .. })));
+}
+
+#[test]
+fn reads_the_optional_elements() {
+    let meta = [
+        meta_elements(transfer_syntax::IMPLICIT_LE),
+        explicit((0x0002, 0x0016), "AE", b" STORESCU", LE),
+        explicit((0x0002, 0x0100), "UI", b"1.2.3.6", LE),
+        explicit((0x0002, 0x0102), "OB", &[1, 2, 3, 4], LE)
+    ].concat();
+
+    let meta = read_file_meta(&mut Cursor::new(with_meta(&meta, &[]))).unwrap();
+
+    assert_eq!(meta.source_application_entity_title.as_deref(), Some("STORESCU"));
+    assert_eq!(meta.private_information_creator_uid.as_deref(), Some("1.2.3.6"));
+    assert_eq!(meta.private_information, Some(vec![1, 2, 3, 4]));
+    assert_eq!(meta.transfer_syntax().uid, transfer_syntax::IMPLICIT_LE);
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use std::io::Cursor;
+
+use dicomize::{dicom, DicomHandler, DicomTag};
+use common::*;
+
+// Counts tags and tracks the nesting depth without keeping the tags.
+#[derive(Default)]
+struct Counter {
+    tags: usize,
+    depth: usize,
+    max_depth: usize
+}
+
+impl Counter {
+    fn open(&mut self) {
+        self.tags += 1;
+        self.depth += 1;
+        self.max_depth = self.max_depth.max(self.depth);
+    }
+}
+
+impl DicomHandler for Counter {
+    fn start_sequence(&mut self, _: DicomTag) {
+        self.open();
+    }
+
+    fn end_sequence(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn start_item(&mut self, _: DicomTag) {
+        self.open();
+    }
+
+    fn end_item(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn element(&mut self, _: DicomTag) {
+        self.tags += 1;
+    }
+}
+
+fn count(bytes: Vec<u8>) -> Counter {
+    let mut counter = Counter::default();
+    dicom::parse(&mut Cursor::new(bytes), &mut counter).unwrap();
+    counter
+}
+
+#[test]
+fn parses_hundreds_of_thousands_of_elements() {
+    // A DICOMDIR like record sequence, 100000 items holding two elements each.
+    let record = item(&[
+        explicit((0x0004, 0x1430), "CS", b"IMAGE", LE),
+        explicit((0x0004, 0x1511), "UI", b"1.2.3.4", LE)
+    ].concat(), LE);
+    let records: Vec<Vec<u8>> = (0..100_000).map(|_| record.clone()).collect();
+
+    let counter = count(explicit_le(&undefined_sequence((0x0004, 0x1220), &records, LE)));
+
+    // Meta group, the sequence, its items and their elements.
+    assert_eq!(counter.tags, 7 + 1 + 300_000);
+    assert_eq!(counter.max_depth, 2);
+    assert_eq!(counter.depth, 0);
+}
+
+#[test]
+fn parses_deeply_nested_sequences() {
+    let depth = 20_000;
+    let mut nested = explicit((0x0008, 0x0060), "CS", b"CT", LE);
+
+    for _ in 0..depth {
+        nested = undefined_sequence((0x0008, 0x1115), &[undefined_item(&nested, LE)], LE);
+    }
+
+    let counter = count(explicit_le(&nested));
+
+    assert_eq!(counter.tags, 7 + 2 * depth + 1);
+    assert_eq!(counter.max_depth, 2 * depth);
+    assert_eq!(counter.depth, 0);
+}
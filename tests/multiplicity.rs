@@ -0,0 +1,45 @@
+mod common;
+
+use dicomize::{vr_type, VrValue};
+use common::*;
+
+fn doubles(values: &[f64]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_le_bytes().to_vec()).collect()
+}
+
+#[test]
+fn decodes_every_value_of_multi_valued_elements() {
+    let dataset = [
+        explicit((0x0018, 0x9087), "FD", &doubles(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.5]), LE),
+        explicit((0x0028, 0x0010), "US", &[512u16.to_le_bytes(), 256u16.to_le_bytes()].concat(), LE),
+        explicit((0x0020, 0x0037), "DS", b"1\\0\\0\\0\\1\\0", LE),
+        explicit((0x0008, 0x0008), "CS", b"ORIGINAL\\PRIMARY\\AXIAL", LE),
+        explicit((0x0010, 0x4000), "LT", b"one\\line", LE)
+    ].concat();
+
+    let container = parse(explicit_le(&dataset));
+    let tags = dataset_tags(&container);
+
+    assert_eq!(tags.iter().map(|t| t.vm()).collect::<Vec<_>>(), vec![6, 2, 6, 3, 1]);
+    assert!(matches!(&tags[0].value, VrValue::Double(v) if v[5] == 0.5));
+    assert!(matches!(&tags[1].value, VrValue::UnsignedShort(v) if v == &[512, 256]));
+    assert!(matches!(&tags[3].value, VrValue::CodeString(v) if v == &["ORIGINAL", "PRIMARY", "AXIAL"]));
+
+    // Texts are single valued, backslashes are part of the value.
+    assert!(matches!(&tags[4].value, VrValue::LongText(v) if v == "one\\line"));
+}
+
+#[test]
+fn keeps_values_of_malformed_length_as_raw_bytes() {
+    let malformed = [b"\x28\x00\x10\x00US\x03\x00".to_vec(), vec![1, 2, 3]].concat();
+    let dataset = [
+        malformed,
+        explicit((0x0028, 0x0011), "US", &512u16.to_le_bytes(), LE)
+    ].concat();
+
+    let container = parse(explicit_le(&dataset));
+    let tags = dataset_tags(&container);
+
+    assert!(matches!(&tags[0].value, VrValue::Raw { vr_code, bytes } if *vr_code == vr_type::UNSIGNED_SHORT && bytes == &[1, 2, 3]));
+    assert!(matches!(&tags[1].value, VrValue::UnsignedShort(v) if v == &[512]));
+}
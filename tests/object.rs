@@ -0,0 +1,136 @@
+mod common;
+
+use std::io::Cursor;
+
+use dicomize::{dicom_object, tags, transfer_syntax, vr_type, DicomError, DicomObject, VrValue, VrEncoding, UNDEFINED_LENGTH};
+use common::*;
+
+// The shared study followed by an accession number out of tag order.
+fn unsorted_study() -> Vec<u8> {
+    explicit_le(&[study(transfer_syntax::EXPLICIT_LE), explicit((0x0008, 0x0050), "SH", b"A1", LE)].concat())
+}
+
+fn ids(object: &DicomObject) -> Vec<(u16, u16)> {
+    object.elements().iter().map(|element| element.id()).collect()
+}
+
+fn uid(object: &DicomObject) -> &str {
+    object.get(tags::SERIES_INSTANCE_UID).unwrap().tag().to_str().unwrap()
+}
+
+#[test]
+fn builds_the_same_object_from_the_parser_and_a_container() {
+    let parsed = dicom_object::parse(&mut Cursor::new(unsorted_study())).unwrap();
+    let converted = DicomObject::from(parse(unsorted_study()));
+
+    for object in &[parsed, converted] {
+        // Elements are sorted, the meta group first.
+        assert_eq!(ids(object)[7..], [(0x0008, 0x0050), (0x0008, 0x0060), (0x0008, 0x1115), tags::PATIENT_NAME, tags::ROWS, (0x0040, 0x0275), tags::PIXEL_DATA]);
+
+        let items = object.items((0x0008, 0x1115)).unwrap();
+        assert_eq!(items.iter().map(uid).collect::<Vec<_>>(), ["1.2.3", "1.2.4"]);
+        assert!(items[0].has_undefined_length());
+        assert!(!items[1].has_undefined_length());
+    }
+}
+
+#[test]
+fn inserts_replaces_and_removes_elements_in_tag_order() {
+    let mut object = DicomObject::new();
+
+    assert!(object.insert(tags::ROWS, VrValue::UnsignedShort(vec![512])).unwrap().is_none());
+    assert!(object.insert(tags::PATIENT_NAME, VrValue::PersonName(vec!["DOE^JOHN".into()])).unwrap().is_none());
+    assert!(object.insert(tags::COLUMNS, VrValue::UnsignedShort(vec![256])).unwrap().is_none());
+    assert_eq!(ids(&object), [tags::PATIENT_NAME, tags::ROWS, tags::COLUMNS]);
+
+    let previous = object.insert(tags::ROWS, VrValue::UnsignedShort(vec![1024])).unwrap().unwrap();
+    assert!(matches!(previous.value(), VrValue::UnsignedShort(v) if v == &[512]));
+    assert!(matches!(object.get(tags::ROWS).unwrap().value(), VrValue::UnsignedShort(v) if v == &[1024]));
+
+    // Replacing leaves missing tags out.
+    assert!(object.replace(tags::PIXEL_SPACING, VrValue::DecimalString(vec!["0.5".into()])).unwrap().is_none());
+    assert!(object.get(tags::PIXEL_SPACING).is_none());
+    assert!(object.replace(tags::PATIENT_NAME, VrValue::PersonName(vec!["ROE^JANE".into()])).unwrap().is_some());
+    assert_eq!(object.get(tags::PATIENT_NAME).unwrap().tag().to_str().unwrap(), "ROE^JANE");
+
+    assert_eq!(object.remove(tags::COLUMNS).map(|element| element.id()), Some(tags::COLUMNS));
+    assert!(object.remove(tags::COLUMNS).is_none());
+    assert_eq!(ids(&object), [tags::PATIENT_NAME, tags::ROWS]);
+}
+
+#[test]
+fn updates_value_lengths() {
+    let mut object = dicom_object::parse(&mut Cursor::new(unsorted_study())).unwrap();
+    assert_eq!(object.get(tags::PATIENT_NAME).unwrap().tag().value_length, 8);
+
+    // Odd lengths are padded to even.
+    object.insert(tags::PATIENT_NAME, VrValue::PersonName(vec!["ROE".into(), "JANE".into()])).unwrap();
+    assert_eq!(object.get(tags::PATIENT_NAME).unwrap().tag().value_length, 8);
+    object.insert(tags::PATIENT_NAME, VrValue::PersonName(vec!["ROE^JANE^X".into()])).unwrap();
+    assert_eq!(object.get(tags::PATIENT_NAME).unwrap().tag().value_length, 10);
+    object.insert(tags::ROWS, VrValue::UnsignedShort(vec![1, 2, 3])).unwrap();
+    assert_eq!(object.get(tags::ROWS).unwrap().tag().value_length, 6);
+
+    // Sequences of defined length add up their items: 8 + 12 for the item holding SPS1.
+    let sequence = (0x0040, 0x0275);
+    assert_eq!(object.get(sequence).unwrap().value_length(VrEncoding::Explicit), 20);
+    assert_eq!(object.get((0x0008, 0x1115)).unwrap().value_length(VrEncoding::Explicit), UNDEFINED_LENGTH);
+
+    object.item_mut(sequence, 0).unwrap().insert((0x0040, 0x0009), VrValue::ShortString(vec!["SPS12".into()])).unwrap();
+    assert_eq!(object.get(sequence).unwrap().value_length(VrEncoding::Explicit), 22);
+    assert_eq!(object.get(sequence).unwrap().encoded_length(VrEncoding::Explicit), 34);
+    assert_eq!(object.get(sequence).unwrap().encoded_length(VrEncoding::Implicit), 30);
+}
+
+#[test]
+fn inserts_and_removes_sequence_items() {
+    let mut object = dicom_object::parse(&mut Cursor::new(unsorted_study())).unwrap();
+    let sequence = (0x0008, 0x1115);
+
+    let mut item = DicomObject::new();
+    item.insert(tags::SERIES_INSTANCE_UID, VrValue::Uid(vec!["1.2.5".into()])).unwrap();
+    object.insert_item(sequence, 1, item).unwrap();
+
+    let mut last = DicomObject::new();
+    last.insert(tags::SERIES_INSTANCE_UID, VrValue::Uid(vec!["1.2.6".into()])).unwrap();
+    object.insert_item(sequence, 10, last).unwrap();
+
+    let uids: Vec<&str> = object.items(sequence).unwrap().iter().map(uid).collect();
+    assert_eq!(uids, ["1.2.3", "1.2.5", "1.2.4", "1.2.6"]);
+
+    let removed = object.remove_item(sequence, 0).unwrap();
+    assert_eq!(uid(&removed), "1.2.3");
+    assert!(object.remove_item(sequence, 3).is_none());
+    assert_eq!(object.items(sequence).unwrap().len(), 3);
+
+    // Items of missing sequences come with a new sequence of undefined length.
+    let referenced = (0x0008, 0x1140);
+    object.insert_item(referenced, 0, DicomObject::new()).unwrap();
+    assert_eq!(object.items(referenced).unwrap().len(), 1);
+    assert_eq!(object.get(referenced).unwrap().value_length(VrEncoding::Explicit), UNDEFINED_LENGTH);
+
+    object.insert_sequence(referenced, Vec::new()).unwrap();
+    assert!(object.items(referenced).unwrap().is_empty());
+}
+
+#[test]
+fn rejects_values_not_fitting_the_vr_of_the_tag() {
+    let mut object = DicomObject::new();
+
+    let error = object.insert(tags::ROWS, VrValue::LongString(vec!["512".into()]));
+    assert!(matches!(error, Err(DicomError::IncompatibleValue { tag, vr_code }) if tag == tags::ROWS && vr_code == vr_type::LONG_STRING));
+    assert!(object.is_empty());
+
+    assert!(object.insert(tags::PATIENT_NAME, VrValue::SequenceOfItems).is_err());
+    assert!(object.insert_sequence(tags::PATIENT_NAME, Vec::new()).is_err());
+    assert!(object.insert(tags::ITEM, VrValue::Item).is_err());
+
+    object.insert(tags::ROWS, VrValue::UnsignedShort(vec![512])).unwrap();
+    assert!(object.insert_item(tags::ROWS, 0, DicomObject::new()).is_err());
+
+    // Ambiguous VRs, unknown values and private tags.
+    assert!(object.insert(tags::PIXEL_DATA, VrValue::OtherWord(vec![0, 0])).is_ok());
+    assert!(object.insert((0x0028, 0x0106), VrValue::SignedShort(vec![-1])).is_ok());
+    assert!(object.insert(tags::PATIENT_NAME, VrValue::Unknown(b"DOE".to_vec())).is_ok());
+    assert!(object.insert((0x0009, 0x0010), VrValue::LongString(vec!["PRIVATE".into()])).is_ok());
+}
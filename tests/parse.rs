@@ -0,0 +1,113 @@
+mod common;
+
+use std::fs;
+use std::io::Cursor;
+use std::process::Command;
+
+use dicomize::{dicom, transfer_syntax, DicomHandler, DicomTag, TransferSyntax, VrValue, VrEncoding};
+use common::*;
+
+#[test]
+fn parses_explicit_little_endian_values() {
+    let dataset = [
+        explicit((0x0008, 0x0060), "CS", b"CT", LE),
+        explicit((0x0010, 0x0010), "PN", b"DOE^JOHN", LE),
+        explicit((0x0020, 0x0037), "DS", b"1\\0\\0\\0\\1\\0", LE),
+        explicit((0x0028, 0x0010), "US", &512u16.to_le_bytes(), LE)
+    ].concat();
+
+    let container = parse(explicit_le(&dataset));
+    let tags = dataset_tags(&container);
+
+    assert_eq!(tags.len(), 4);
+    assert!(matches!(&tags[0].value, VrValue::CodeString(v) if v == &["CT"]));
+    assert!(matches!(&tags[1].value, VrValue::PersonName(v) if v == &["DOE^JOHN"]));
+    assert_eq!(tags[2].vm(), 6);
+    assert!(matches!(&tags[3].value, VrValue::UnsignedShort(v) if v == &[512]));
+    assert_eq!(tags[3].syntax.uid, transfer_syntax::EXPLICIT_LE);
+}
+
+#[test]
+fn implicit_vr_takes_types_from_the_dictionary() {
+    let dataset = [
+        implicit((0x0008, 0x0060), b"CT"),
+        implicit((0x0028, 0x0010), &64u16.to_le_bytes())
+    ].concat();
+
+    let container = parse(part10(transfer_syntax::IMPLICIT_LE, &dataset));
+    let tags = dataset_tags(&container);
+
+    assert!(matches!(&tags[0].value, VrValue::CodeString(v) if v == &["CT"]));
+    assert!(matches!(&tags[1].value, VrValue::UnsignedShort(v) if v == &[64]));
+    assert_eq!(tags[1].syntax.vr_encoding, VrEncoding::Implicit);
+}
+
+#[test]
+fn loads_files_from_the_command_line_without_printing_their_tags() {
+    let path = std::env::temp_dir().join(format!("dicomize-load-{}.dcm", std::process::id()));
+    fs::write(&path, explicit_le(&explicit((0x0010, 0x0010), "PN", b"DOE^JOHN", LE))).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_dicomize")).arg("load").arg(&path).output().unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(stdout.contains("Found 9 dicom nodes"));
+    assert!(!stdout.contains("TAG |"));
+}
+
+// Records the event stream as text, one line per event.
+#[derive(Default)]
+struct Events(Vec<String>);
+
+impl DicomHandler for Events {
+    fn start_sequence(&mut self, tag: DicomTag) {
+        self.0.push(format!("start sequence {:04X},{:04X}", tag.group, tag.element));
+    }
+
+    fn end_sequence(&mut self) {
+        self.0.push("end sequence".to_string());
+    }
+
+    fn start_item(&mut self, _: DicomTag) {
+        self.0.push("start item".to_string());
+    }
+
+    fn end_item(&mut self) {
+        self.0.push("end item".to_string());
+    }
+
+    fn element(&mut self, tag: DicomTag) {
+        if tag.group != 0x0002 {
+            self.0.push(format!("element {:04X},{:04X}", tag.group, tag.element));
+        }
+    }
+
+    fn file_meta_done(&mut self, syntax: TransferSyntax) {
+        self.0.push(format!("file meta {}", syntax.uid));
+    }
+
+    fn end_of_dataset(&mut self) {
+        self.0.push("end of dataset".to_string());
+    }
+}
+
+#[test]
+fn closes_defined_and_undefined_length_nodes_alike() {
+    let dataset = [
+        sequence((0x0008, 0x1115), &[item(&explicit((0x0020, 0x000E), "UI", b"1.2.3", LE), LE)], LE),
+        undefined_sequence((0x0008, 0x1140), &[undefined_item(&explicit((0x0008, 0x1150), "UI", b"1.2", LE), LE)], LE),
+        explicit((0x0010, 0x0010), "PN", b"X", LE)
+    ].concat();
+
+    let mut events = Events::default();
+    dicom::parse(&mut Cursor::new(explicit_le(&dataset)), &mut events).unwrap();
+
+    assert_eq!(events.0, vec![
+        "file meta 1.2.840.10008.1.2.1",
+        "start sequence 0008,1115", "start item", "element 0020,000E", "end item", "end sequence",
+        "start sequence 0008,1140", "start item", "element 0008,1150", "end item", "end sequence",
+        "element 0010,0010",
+        "end of dataset"
+    ]);
+}
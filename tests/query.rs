@@ -0,0 +1,106 @@
+mod common;
+
+use dicomize::{tags, transfer_syntax, DicomError, VrValue};
+use common::*;
+
+fn study() -> Vec<u8> {
+    explicit_le(&study_with(transfer_syntax::EXPLICIT_LE, &[
+        explicit((0x0018, 0x0050), "DS", b" 2.5", LE),
+        explicit((0x0020, 0x0013), "IS", b"7", LE),
+        explicit((0x0020, 0x0037), "DS", b"1\\0\\0\\0\\1\\0", LE),
+        explicit((0x0028, 0x0030), "DS", b"0.5\\0.25", LE)
+    ]))
+}
+
+#[test]
+fn looks_keywords_up_in_the_dictionary() {
+    assert_eq!(tags::try_tag_keyword(0x0010, 0x0010), Some("PatientName"));
+    assert_eq!(tags::try_tag_keyword(0x0008, 0x1115), Some("ReferencedSeriesSequence"));
+    assert_eq!(tags::try_tag_keyword(0x0018, 0x1153), Some("ExposureInuAs"));
+    assert_eq!(tags::try_tag_keyword(0x0010, 0x0000), None);
+    assert_eq!(tags::try_keyword_tag("PatientName"), Some(tags::PATIENT_NAME));
+    assert_eq!(tags::try_keyword_tag("SeriesInstanceUID"), Some(tags::SERIES_INSTANCE_UID));
+    assert_eq!(tags::try_keyword_tag("FileMetaInformationGroupLength"), Some(tags::FILE_META_GROUP_LENGTH));
+    assert_eq!(tags::try_keyword_tag("seriesinstanceuid"), None);
+    assert_eq!(tags::try_keyword_tag("NoSuchKeyword"), None);
+}
+
+#[test]
+fn gets_dataset_tags_by_tag_and_keyword() {
+    let container = parse(study());
+
+    assert!(matches!(&container.get(tags::PATIENT_NAME).unwrap().value, VrValue::PersonName(v) if v == &["DOE^JOHN"]));
+    assert_eq!(container.get_by_keyword("Modality").map(id), Some((0x0008, 0x0060)));
+
+    // Nested tags are only reached through their path.
+    assert!(container.get(tags::SERIES_INSTANCE_UID).is_none());
+    assert!(container.get_by_keyword("Rows").is_some());
+    assert!(container.get_by_keyword("Columns").is_none());
+}
+
+#[test]
+fn gets_nested_tags_by_path() {
+    let container = parse(study());
+
+    let first = container.get_path("ReferencedSeriesSequence[0].SeriesInstanceUID").unwrap();
+    assert_eq!(first.to_str().unwrap(), "1.2.3");
+
+    let second = container.get_path("(0008,1115)[1].SeriesInstanceUID").unwrap();
+    assert_eq!(second.to_str().unwrap(), "1.2.4");
+
+    let nested = container.get_path("ReferencedSeriesSequence[0].ReferencedImageSequence[0].ReferencedSOPClassUID").unwrap();
+    assert_eq!(nested.to_str().unwrap(), "1.2");
+
+    assert!(matches!(container.get_path("ReferencedSeriesSequence[1]").unwrap().value, VrValue::Item));
+
+    assert!(container.get_path("ReferencedSeriesSequence[2].SeriesInstanceUID").is_none());
+    assert!(container.get_path("ReferencedSeriesSequence.SeriesInstanceUID").is_none());
+    assert!(container.get_path("ReferencedSeriesSequence[x]").is_none());
+    assert!(container.get_path("Unknown[0].Rows").is_none());
+}
+
+#[test]
+fn iterates_over_items_and_their_tags() {
+    let container = parse(study());
+    let sequence = container.find(0, (0x0008, 0x1115)).unwrap();
+
+    let items: Vec<usize> = container.children(sequence).map(|(index, _)| index).collect();
+    assert_eq!(items.len(), 2);
+
+    let uids: Vec<&str> = items.iter()
+        .flat_map(|item| container.children(*item))
+        .filter(|(_, tag)| id(tag) == tags::SERIES_INSTANCE_UID)
+        .map(|(_, tag)| tag.to_str().unwrap())
+        .collect();
+    assert_eq!(uids, vec!["1.2.3", "1.2.4"]);
+
+    assert_eq!(container.children(0).count(), 17);
+    assert_eq!(container.children(container.nodes.len()).count(), 0);
+}
+
+#[test]
+fn converts_values_to_rust_types() {
+    let container = parse(study());
+
+    assert_eq!(container.get_str(tags::PATIENT_NAME).unwrap(), Some("DOE^JOHN"));
+    assert_eq!(container.get_strs(tags::PIXEL_SPACING).unwrap(), Some(vec!["0.5", "0.25"]));
+    assert_eq!(container.get_u16(tags::ROWS).unwrap(), Some(512));
+    assert_eq!(container.get_u16((0x0020, 0x0013)).unwrap(), Some(7));
+    assert_eq!(container.get_f64((0x0018, 0x0050)).unwrap(), Some(2.5));
+    assert_eq!(container.get_f64s(tags::PIXEL_SPACING).unwrap(), Some(vec![0.5, 0.25]));
+    assert_eq!(container.get_f64s((0x0020, 0x0037)).unwrap(), Some(vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0]));
+    assert_eq!(container.get_f64s(tags::ROWS).unwrap(), Some(vec![512.0]));
+
+    assert_eq!(container.get_u16(tags::COLUMNS).unwrap(), None);
+}
+
+#[test]
+fn reports_values_of_another_type() {
+    let container = parse(study());
+
+    let error = container.get_u16(tags::PATIENT_NAME).unwrap_err();
+    assert!(matches!(error, DicomError::InvalidValue { tag, .. } if tag == tags::PATIENT_NAME));
+
+    assert!(container.get_str(tags::ROWS).is_err());
+    assert!(container.get_f64s((0x0008, 0x0060)).is_err());
+}
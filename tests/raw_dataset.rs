@@ -0,0 +1,47 @@
+mod common;
+
+use std::io::Cursor;
+
+use dicomize::{dicom, transfer_syntax, DicomContainer, TransferSyntax, VrValue};
+use common::*;
+
+#[test]
+fn parses_raw_datasets_with_a_given_syntax() {
+    let dataset = [implicit((0x0008, 0x0060), b"CT"), implicit((0x0028, 0x0010), &64u16.to_le_bytes())].concat();
+
+    let mut container = DicomContainer::new();
+    dicom::parse_dataset(&mut Cursor::new(dataset), TransferSyntax::parse_str(transfer_syntax::IMPLICIT_LE), &mut container).unwrap();
+
+    assert_eq!(dataset_tags(&container).len(), 2);
+}
+
+#[test]
+fn detects_the_syntax_of_raw_datasets() {
+    let implicit_le = implicit((0x0008, 0x0060), b"CT");
+    let explicit_le = explicit((0x0008, 0x0060), "CS", b"CT", LE);
+    let explicit_be = explicit((0x0008, 0x0060), "CS", b"CT", BE);
+
+    let detect = |bytes: &Vec<u8>| dicom::detect_syntax(&mut Cursor::new(bytes.clone())).unwrap().uid;
+
+    assert_eq!(detect(&implicit_le), transfer_syntax::IMPLICIT_LE);
+    assert_eq!(detect(&explicit_le), transfer_syntax::EXPLICIT_LE);
+    assert_eq!(detect(&explicit_be), transfer_syntax::EXPLICIT_BE);
+
+    let mut container = DicomContainer::new();
+    dicom::parse_dataset_detect(&mut Cursor::new(explicit_be), &mut container).unwrap();
+    assert!(matches!(&dataset_tags(&container)[0].value, VrValue::CodeString(v) if v == &["CT"]));
+}
+
+#[test]
+fn parses_raw_datasets_from_the_current_position() {
+    let dataset = explicit((0x0008, 0x0060), "CS", b"CT", LE);
+    let mut reader = Cursor::new([b"HEADER".to_vec(), dataset].concat());
+    reader.set_position(6);
+
+    let mut container = DicomContainer::new();
+    dicom::parse_dataset_detect(&mut reader, &mut container).unwrap();
+
+    let tags = dataset_tags(&container);
+    assert_eq!(tags.len(), 1);
+    assert_eq!(tags[0].offset, 6);
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use std::io::Cursor;
+
+use dicomize::{dicom, tags, transfer_syntax, ParseAction, VrValue};
+use common::*;
+
+const PRIVATE_SEQUENCE: (u16, u16) = (0x0009, 0x1010);
+const UNDEFINED: u32 = 0xFFFF_FFFF;
+
+#[test]
+fn nests_undefined_length_sequences_inside_defined_length_ones() {
+    let inner = undefined_sequence((0x0008, 0x1140), &[
+        undefined_item(&explicit((0x0008, 0x1150), "UI", b"1.2", LE), LE),
+        item(&explicit((0x0008, 0x1155), "UI", b"1.3", LE), LE)
+    ], LE);
+    let outer = sequence((0x0008, 0x1115), &[
+        item(&[explicit((0x0020, 0x000E), "UI", b"1.2.3", LE), inner].concat(), LE),
+        item(&[], LE)
+    ], LE);
+    let dataset = [outer, explicit((0x0010, 0x0010), "PN", b"X", LE)].concat();
+
+    let container = parse(explicit_le(&dataset));
+    let root = children(&container, 0);
+    let root: Vec<_> = root.iter().filter(|(_, t)| t.group != 0x0002).collect();
+
+    assert_eq!(root.len(), 2);
+    assert_eq!(id(root[0].1), (0x0008, 0x1115));
+    assert_eq!(id(root[1].1), (0x0010, 0x0010));
+
+    let outer_items = children(&container, root[0].0);
+    assert_eq!(outer_items.len(), 2);
+    assert!(outer_items.iter().all(|(_, t)| id(t) == tags::ITEM));
+    assert!(children(&container, outer_items[1].0).is_empty());
+
+    let first_item = children(&container, outer_items[0].0);
+    assert_eq!(first_item.iter().map(|(_, t)| id(t)).collect::<Vec<_>>(), vec![(0x0020, 0x000E), (0x0008, 0x1140)]);
+    assert!(first_item[1].1.has_undefined_length());
+
+    let inner_items = children(&container, first_item[1].0);
+    assert_eq!(inner_items.iter().map(|(_, t)| id(t)).collect::<Vec<_>>(), vec![tags::ITEM, tags::ITEM]);
+
+    let undefined_item = children(&container, inner_items[0].0);
+    assert_eq!(undefined_item.iter().map(|(_, t)| id(t)).collect::<Vec<_>>(), vec![(0x0008, 0x1150)]);
+
+    let defined_item = children(&container, inner_items[1].0);
+    assert_eq!(defined_item.iter().map(|(_, t)| id(t)).collect::<Vec<_>>(), vec![(0x0008, 0x1155)]);
+}
+
+#[test]
+fn parses_private_sequences_of_undefined_length_in_implicit_vr() {
+    let dataset = [
+        header(PRIVATE_SEQUENCE, UNDEFINED, LE),
+        undefined_item(&implicit((0x0008, 0x0060), b"CT"), LE),
+        item(&implicit((0x0009, 0x1011), b"PRIVATE"), LE),
+        header(tags::SEQUENCE_DELIMITER, 0, LE),
+        implicit((0x0010, 0x0010), b"DOE^JOHN")
+    ].concat();
+
+    let container = parse(part10(transfer_syntax::IMPLICIT_LE, &dataset));
+    let root = children(&container, 0);
+    let root: Vec<_> = root.iter().filter(|(_, t)| t.group != 0x0002).collect();
+
+    assert_eq!(root.iter().map(|(_, t)| id(t)).collect::<Vec<_>>(), vec![PRIVATE_SEQUENCE, (0x0010, 0x0010)]);
+    assert!(matches!(root[0].1.value, VrValue::SequenceOfItems));
+
+    let items = children(&container, root[0].0);
+    assert_eq!(items.len(), 2);
+    assert!(matches!(&children(&container, items[0].0)[0].1.value, VrValue::CodeString(v) if v == &["CT"]));
+    assert!(matches!(&children(&container, items[1].0)[0].1.value, VrValue::Unknown(v) if v == b"PRIVATE "));
+}
+
+// PS3.5 6.2.2, the items of an explicit UN of undefined length are encoded in Implicit VR Little Endian.
+fn explicit_unknown_sequence() -> Vec<u8> {
+    let nested = [
+        header((0x0008, 0x1140), UNDEFINED, LE),
+        undefined_item(&implicit((0x0008, 0x1150), b"1.2"), LE),
+        header(tags::SEQUENCE_DELIMITER, 0, LE)
+    ].concat();
+
+    explicit_le(&[
+        undefined_unknown(PRIVATE_SEQUENCE, &[
+            item(&implicit((0x0008, 0x0060), b"CT"), LE),
+            undefined_item(&nested, LE)
+        ]),
+        explicit((0x0010, 0x0010), "PN", b"DOE^JOHN", LE)
+    ].concat())
+}
+
+#[test]
+fn parses_explicit_unknown_sequences_of_undefined_length_as_implicit_vr() {
+    let container = parse(explicit_unknown_sequence());
+    let tags = dataset_tags(&container);
+
+    assert_eq!(tags.iter().map(|t| id(t)).collect::<Vec<_>>(), vec![PRIVATE_SEQUENCE, (0x0010, 0x0010)]);
+    assert!(matches!(&tags[1].value, VrValue::PersonName(v) if v == &["DOE^JOHN"]));
+
+    let lines = render(&container);
+    assert!(lines.iter().any(|line| line.starts_with("0008,0060") && line.ends_with("CT")));
+    assert!(lines.iter().any(|line| line.starts_with("0008,1150")));
+
+    let items = children(&container, children(&container, 0).iter().find(|(_, t)| id(t) == PRIVATE_SEQUENCE).unwrap().0);
+    assert_eq!(children(&container, items[0].0)[0].1.syntax.uid, transfer_syntax::IMPLICIT_LE);
+}
+
+#[test]
+fn skips_explicit_unknown_sequences_of_undefined_length() {
+    let bytes = explicit_unknown_sequence();
+
+    let mut tags = dicom::tags(Cursor::new(bytes.clone()));
+    let mut ids = Vec::new();
+    while let Some(tag) = tags.next_with(|header, _| match header.tag() {
+        PRIVATE_SEQUENCE => ParseAction::SkipValue,
+        _                => ParseAction::Continue
+    }) {
+        ids.push(id(&tag.unwrap()));
+    }
+    ids.retain(|t| t.0 != 0x0002);
+    assert_eq!(ids, vec![(0x0010, 0x0010)]);
+
+    let sliced: Vec<_> = dicomize::parse_slice(&bytes).map(|t| t.unwrap().tag()).filter(|t| t.0 != 0x0002).collect();
+    assert_eq!(sliced, vec![
+        PRIVATE_SEQUENCE, tags::ITEM, (0x0008, 0x0060), tags::ITEM, (0x0008, 0x1140), tags::ITEM, (0x0008, 0x1150),
+        tags::ITEM_DELIMITER, tags::SEQUENCE_DELIMITER, tags::ITEM_DELIMITER, tags::SEQUENCE_DELIMITER, (0x0010, 0x0010)
+    ]);
+}
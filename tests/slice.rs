@@ -0,0 +1,85 @@
+mod common;
+
+use dicomize::{dicom_slice, tags, transfer_syntax, DicomError, TransferSyntax, VrValue};
+use common::*;
+
+#[test]
+fn borrows_values_from_the_slice() {
+    let bytes = explicit_le(&study_with(transfer_syntax::EXPLICIT_LE, &[explicit((0x0010, 0x0010), "PN", b"DOE^JOHN\\DOE^JANE", LE)]));
+    let tags: Vec<_> = dicomize::parse_slice(&bytes).collect::<Result<_, _>>().unwrap();
+    let dataset: Vec<_> = tags.iter().filter(|t| t.group != 0x0002).collect();
+
+    assert_eq!(dataset.iter().map(|t| t.tag()).collect::<Vec<_>>(), vec![
+        (0x0008, 0x0060),
+        (0x0008, 0x1115), tags::ITEM,
+        (0x0008, 0x1140), tags::ITEM, (0x0008, 0x1150), tags::ITEM_DELIMITER, tags::SEQUENCE_DELIMITER,
+        (0x0020, 0x000E), tags::ITEM_DELIMITER, tags::ITEM, (0x0020, 0x000E), tags::SEQUENCE_DELIMITER,
+        (0x0010, 0x0010), (0x0028, 0x0010),
+        (0x0040, 0x0275), tags::ITEM, (0x0040, 0x0009),
+        tags::PIXEL_DATA
+    ]);
+
+    let name = dataset[13].as_str().unwrap();
+    assert_eq!(dataset[13].strings().collect::<Vec<_>>(), vec!["DOE^JOHN", "DOE^JANE "]);
+    assert!(bytes.as_ptr_range().contains(&name.as_ptr()));
+
+    assert_eq!(dataset[14].bytes, &512u16.to_le_bytes());
+    assert_eq!(dataset[14].as_str(), None);
+    assert!(dataset[1].bytes.is_empty());
+}
+
+#[test]
+fn decodes_the_same_values_as_the_streaming_parser() {
+    let bytes = part10(transfer_syntax::EXPLICIT_BE, &study(transfer_syntax::EXPLICIT_BE));
+
+    let streamed = render(&parse(bytes.clone()));
+
+    // Delimiters are reported by the slice parser only.
+    let sliced: Vec<String> = dicomize::parse_slice(&bytes)
+        .map(|t| t.unwrap())
+        .filter(|t| t.tag() != tags::ITEM_DELIMITER && t.tag() != tags::SEQUENCE_DELIMITER)
+        .map(|t| format!("{:04X},{:04X} {}", t.group, t.element, t.to_value().unwrap()))
+        .collect();
+
+    assert_eq!(sliced, streamed);
+}
+
+#[test]
+fn locates_encapsulated_fragments_in_the_slice() {
+    let dataset = [
+        header(tags::PIXEL_DATA, 0, LE)[..4].to_vec(), b"OB\0\0".to_vec(), u32_bytes(0xFFFF_FFFF, LE),
+        item(&[], LE), item(b"\xFF\xD8ab\xFF\xD9", LE), header(tags::SEQUENCE_DELIMITER, 0, LE)
+    ].concat();
+    let bytes = part10("1.2.840.10008.1.2.4.50", &dataset);
+
+    let pixel_data = dicomize::parse_slice(&bytes).map(|t| t.unwrap()).find(|t| t.tag() == tags::PIXEL_DATA).unwrap();
+
+    match pixel_data.to_value().unwrap() {
+        VrValue::Encapsulated(pixel_data) => {
+            let fragment = &pixel_data.fragments[0];
+            let offset = fragment.offset as usize;
+            assert_eq!(&bytes[offset..offset + fragment.data.len()], &fragment.data[..]);
+        },
+        value => panic!("unexpected value {}", value)
+    }
+}
+
+#[test]
+fn parses_raw_datasets_and_stops_at_errors() {
+    let dataset = [implicit((0x0008, 0x0060), b"CT"), implicit((0x0010, 0x0010), b"DOE^JOHN")].concat();
+    let truncated = &dataset[..dataset.len() - 2];
+
+    let tags: Vec<_> = dicom_slice::parse_dataset_slice(truncated, TransferSyntax::parse_str(transfer_syntax::IMPLICIT_LE)).collect();
+
+    assert_eq!(tags.len(), 2);
+    assert_eq!(tags[0].as_ref().unwrap().as_str(), Some("CT"));
+    assert!(matches!(tags[1], Err(DicomError::TruncatedValue { tag: (0x0010, 0x0010), .. })));
+}
+
+#[test]
+fn rejects_deflated_datasets() {
+    let bytes = part10(transfer_syntax::DEFLATED_EXPLICIT_LE, &[1, 2, 3, 4]);
+    let last = dicomize::parse_slice(&bytes).last().unwrap();
+
+    assert!(matches!(last, Err(DicomError::UnsupportedTransferSyntax { .. })));
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use std::io::{self, Read};
+
+use dicomize::{dicom, tags, transfer_syntax, DicomContainer, DicomError, DicomHandler, DicomTag, ParseAction, TagHeader, TransferSyntax, VrValue};
+use common::*;
+
+// A reader that cannot seek and hands out at most 3 bytes per read, like a slow socket.
+struct Trickle<'a>(&'a [u8]);
+
+impl<'a> Read for Trickle<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = buf.len().min(3);
+        self.0.read(&mut buf[..n])
+    }
+}
+
+#[test]
+fn streams_the_same_tree_as_the_seekable_parser() {
+    let bytes = explicit_le(&study(transfer_syntax::EXPLICIT_LE));
+    let mut container = DicomContainer::new();
+
+    dicom::parse_stream(Trickle(&bytes), &mut container).unwrap();
+
+    assert_eq!(render(&container), render(&parse(bytes.clone())));
+}
+
+#[test]
+fn streams_implicit_and_deflated_datasets() {
+    let dataset = [
+        implicit((0x0008, 0x0060), b"CT"),
+        [header((0x0008, 0x1115), 0xFFFF_FFFF, LE), undefined_item(&implicit((0x0020, 0x000E), b"1.2"), LE), header((0xFFFE, 0xE0DD), 0, LE)].concat()
+    ].concat();
+    let bytes = part10(transfer_syntax::IMPLICIT_LE, &dataset);
+    let mut container = DicomContainer::new();
+
+    dicom::parse_stream(Trickle(&bytes), &mut container).unwrap();
+
+    assert_eq!(render(&container), render(&parse(bytes.clone())));
+}
+
+#[test]
+fn streams_raw_datasets() {
+    let mut container = DicomContainer::new();
+    dicom::parse_dataset_stream(&study(transfer_syntax::EXPLICIT_LE)[..], TransferSyntax::default(), &mut container).unwrap();
+
+    let tags = dataset_tags(&container);
+    assert_eq!(tags.iter().map(|t| id(t)).collect::<Vec<_>>(), vec![
+        (0x0008, 0x0060), (0x0008, 0x1115), (0x0010, 0x0010), (0x0028, 0x0010), (0x0040, 0x0275), tags::PIXEL_DATA
+    ]);
+}
+
+#[test]
+fn streams_ending_after_the_file_meta_information() {
+    let mut container = DicomContainer::new();
+    dicom::parse_stream(&explicit_le(&[])[..], &mut container).unwrap();
+
+    assert!(dataset_tags(&container).is_empty());
+}
+
+// Answers every pixel data header with the same action and keeps the elements.
+struct Pixels(ParseAction, Vec<DicomTag>);
+
+impl DicomHandler for Pixels {
+    fn tag_header(&mut self, header: &TagHeader) -> ParseAction {
+        match header.tag() == tags::PIXEL_DATA {
+            true  => self.0,
+            false => ParseAction::Continue
+        }
+    }
+
+    fn element(&mut self, tag: DicomTag) {
+        self.1.push(tag);
+    }
+}
+
+#[test]
+fn discards_skipped_values_and_reads_deferred_ones() {
+    let bytes = explicit_le(&[study(transfer_syntax::EXPLICIT_LE), explicit((0x7FE1, 0x0010), "OB", b"AB", LE)].concat());
+
+    let mut skipped = Pixels(ParseAction::SkipValue, Vec::new());
+    dicom::parse_stream(Trickle(&bytes), &mut skipped).unwrap();
+    assert!(skipped.1.iter().all(|t| id(t) != tags::PIXEL_DATA));
+    assert_eq!(skipped.1.last().map(id), Some((0x7FE1, 0x0010)));
+
+    let mut deferred = Pixels(ParseAction::DeferValue, Vec::new());
+    dicom::parse_stream(Trickle(&bytes), &mut deferred).unwrap();
+    let pixels = deferred.1.iter().find(|t| id(t) == tags::PIXEL_DATA).unwrap();
+    assert!(matches!(&pixels.value, VrValue::OtherWord(v) if *v == vec![7; 64]));
+    assert_eq!(deferred.1.last().map(id), Some((0x7FE1, 0x0010)));
+}
+
+#[test]
+fn reports_truncated_streams() {
+    let bytes = explicit_le(&study(transfer_syntax::EXPLICIT_LE));
+    let truncated = &bytes[..bytes.len() - 10];
+
+    let error = dicom::parse_stream(Trickle(truncated), &mut DicomContainer::new()).unwrap_err();
+    assert!(matches!(error, DicomError::TruncatedValue { tag, .. } if tag == tags::PIXEL_DATA));
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use std::io::Cursor;
+
+use dicomize::{dicom, tags, transfer_syntax, DicomError, DicomTag, PathNode, TransferSyntax, VrValue};
+use common::*;
+
+#[test]
+fn pulls_the_same_tags_as_the_container() {
+    let bytes = explicit_le(&study(transfer_syntax::EXPLICIT_LE));
+
+    let pulled: Vec<DicomTag> = dicom::tags(Cursor::new(bytes.clone())).collect::<Result<_, _>>().unwrap();
+    let container = parse(bytes);
+    let parsed: Vec<&DicomTag> = container.nodes.iter().filter_map(|node| node.tag()).collect();
+
+    assert_eq!(pulled.iter().map(|t| format!("{:?} {}", id(t), t.value)).collect::<Vec<_>>(),
+               parsed.iter().map(|t| format!("{:?} {}", id(t), t.value)).collect::<Vec<_>>());
+}
+
+#[test]
+fn reports_depth_and_path_of_each_tag() {
+    let mut tags = dicom::dataset_tags(Cursor::new(study(transfer_syntax::EXPLICIT_LE)), TransferSyntax::default());
+    let mut seen = Vec::new();
+
+    while let Some(tag) = tags.next() {
+        let tag = tag.unwrap();
+        seen.push((id(&tag), tags.depth(), tags.path().to_vec()));
+    }
+
+    let sequence = PathNode::Sequence((0x0008, 0x1115));
+    let nested = PathNode::Sequence((0x0008, 0x1140));
+
+    assert_eq!(seen, vec![
+        ((0x0008, 0x0060), 0, vec![]),
+        ((0x0008, 0x1115), 0, vec![]),
+        (tags::ITEM, 1, vec![sequence]),
+        ((0x0008, 0x1140), 2, vec![sequence, PathNode::Item(0)]),
+        (tags::ITEM, 3, vec![sequence, PathNode::Item(0), nested]),
+        ((0x0008, 0x1150), 4, vec![sequence, PathNode::Item(0), nested, PathNode::Item(0)]),
+        ((0x0020, 0x000E), 2, vec![sequence, PathNode::Item(0)]),
+        (tags::ITEM, 1, vec![sequence]),
+        ((0x0020, 0x000E), 2, vec![sequence, PathNode::Item(1)]),
+        ((0x0010, 0x0010), 0, vec![]),
+        ((0x0028, 0x0010), 0, vec![]),
+        ((0x0040, 0x0275), 0, vec![]),
+        (tags::ITEM, 1, vec![PathNode::Sequence((0x0040, 0x0275))]),
+        ((0x0040, 0x0009), 2, vec![PathNode::Sequence((0x0040, 0x0275)), PathNode::Item(0)]),
+        (tags::PIXEL_DATA, 0, vec![])
+    ]);
+}
+
+#[test]
+fn takes_tags_while_their_group_matches() {
+    let mut tags = dicom::tags(Cursor::new(explicit_le(&study(transfer_syntax::EXPLICIT_LE))));
+
+    let meta: Vec<(u16, u16)> = tags.take_while_group(|group| group == 0x0002).map(|t| id(&t.unwrap())).collect();
+    assert_eq!(meta.len(), 7);
+
+    let header: Vec<(u16, u16)> = tags.take_while_group(|group| group < 0x0028).map(|t| id(&t.unwrap())).collect();
+    assert_eq!(header.first(), Some(&(0x0008, 0x0060)));
+    assert_eq!(header.last(), Some(&(0x0010, 0x0010)));
+    assert_eq!(header.len(), 10);
+
+    // The iterator carries on from the first tag left out.
+    assert_eq!(tags.next().map(|t| id(&t.unwrap())), Some((0x0028, 0x0010)));
+}
+
+#[test]
+fn finds_tags_at_any_depth() {
+    let mut tags = dicom::tags(Cursor::new(explicit_le(&study(transfer_syntax::EXPLICIT_LE))));
+
+    let nested = tags.find_tag((0x0008, 0x1150)).unwrap().unwrap();
+    assert!(matches!(&nested.value, VrValue::Uid(v) if v == &["1.2\0"]));
+    assert_eq!(tags.depth(), 4);
+
+    let rows = tags.find_tag((0x0028, 0x0010)).unwrap().unwrap();
+    assert!(matches!(&rows.value, VrValue::UnsignedShort(v) if v == &[512]));
+    assert_eq!(tags.path(), &[]);
+
+    assert!(tags.find_tag((0x0008, 0x0060)).is_none());
+}
+
+#[test]
+fn ends_at_the_first_error() {
+    let bytes = explicit_le(&study(transfer_syntax::EXPLICIT_LE));
+    let truncated = bytes[..bytes.len() - 10].to_vec();
+
+    let results: Vec<_> = dicom::tags(Cursor::new(truncated)).collect();
+
+    assert!(matches!(results.last(), Some(Err(DicomError::TruncatedValue { tag, .. })) if *tag == tags::PIXEL_DATA));
+    assert_eq!(results.iter().filter(|r| r.is_err()).count(), 1);
+}
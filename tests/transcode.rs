@@ -0,0 +1,87 @@
+mod common;
+
+use std::io::Cursor;
+
+use dicomize::{dicom_writer, read_file_meta, transfer_syntax, DicomError, TransferSyntax};
+use common::*;
+
+const JPEG_BASELINE: &str = "1.2.840.10008.1.2.4.50";
+
+// The shared study with a rescale intercept and pixel data words that change when swapped.
+fn transcoded_study(syntax_uid: &str) -> Vec<u8> {
+    let syntax = TransferSyntax::parse_str(syntax_uid);
+    let endian = syntax.endian_encoding;
+
+    study_with(syntax_uid, &[
+        element((0x0028, 0x1052), "DS", b"-1024", syntax),
+        element((0x7FE0, 0x0010), "OW", &[u16_bytes(1, endian), u16_bytes(0x1234, endian)].concat(), syntax)
+    ])
+}
+
+fn transcode(file: Vec<u8>, syntax_uid: &str) -> Result<Vec<u8>, DicomError> {
+    let mut output = Vec::new();
+    dicom_writer::transcode(&mut Cursor::new(file), &mut output, TransferSyntax::parse_str(syntax_uid))?;
+    Ok(output)
+}
+
+#[test]
+fn converts_between_the_uncompressed_syntaxes() {
+    let implicit = part10(transfer_syntax::IMPLICIT_LE, &transcoded_study(transfer_syntax::IMPLICIT_LE));
+    let explicit_le = part10(transfer_syntax::EXPLICIT_LE, &transcoded_study(transfer_syntax::EXPLICIT_LE));
+    let explicit_be = part10(transfer_syntax::EXPLICIT_BE, &transcoded_study(transfer_syntax::EXPLICIT_BE));
+
+    // VRs come from the dictionary, pixel data becomes OW and words are swapped in big endian.
+    assert_eq!(transcode(implicit.clone(), transfer_syntax::EXPLICIT_LE).unwrap(), explicit_le);
+    assert_eq!(transcode(implicit.clone(), transfer_syntax::EXPLICIT_BE).unwrap(), explicit_be);
+    assert_eq!(transcode(explicit_le.clone(), transfer_syntax::EXPLICIT_BE).unwrap(), explicit_be);
+    assert_eq!(transcode(explicit_be.clone(), transfer_syntax::EXPLICIT_LE).unwrap(), explicit_le);
+    assert_eq!(transcode(explicit_be, transfer_syntax::IMPLICIT_LE).unwrap(), implicit);
+    assert_eq!(transcode(implicit.clone(), transfer_syntax::IMPLICIT_LE).unwrap(), implicit);
+
+    let deflated = transcode(implicit, transfer_syntax::DEFLATED_EXPLICIT_LE).unwrap();
+    assert_eq!(read_file_meta(&mut Cursor::new(&deflated)).unwrap().transfer_syntax_uid, transfer_syntax::DEFLATED_EXPLICIT_LE);
+    assert_eq!(transcode(deflated, transfer_syntax::EXPLICIT_LE).unwrap(), explicit_le);
+
+    // Pixel data of 8 bits samples stays OB, its bytes are not swapped.
+    let bytes_study = |syntax_uid| {
+        let syntax = TransferSyntax::parse_str(syntax_uid);
+        part10(syntax_uid, &study_with(syntax_uid, &[
+            element((0x0028, 0x0100), "US", &u16_bytes(8, syntax.endian_encoding), syntax),
+            element((0x7FE0, 0x0010), "OB", &[1, 2, 3, 4], syntax)
+        ]))
+    };
+    assert_eq!(transcode(bytes_study(transfer_syntax::IMPLICIT_LE), transfer_syntax::EXPLICIT_BE).unwrap(), bytes_study(transfer_syntax::EXPLICIT_BE));
+}
+
+#[test]
+fn resolves_unknown_values_of_tags_in_the_dictionary() {
+    let file = explicit_le(&[
+        explicit((0x0028, 0x0010), "UN", &512u16.to_le_bytes(), LE),
+        explicit((0x0029, 0x1010), "UN", b"PRIVATE", LE)
+    ].concat());
+
+    let expected = part10(transfer_syntax::EXPLICIT_BE, &[
+        explicit((0x0028, 0x0010), "US", &512u16.to_be_bytes(), BE),
+        explicit((0x0029, 0x1010), "UN", b"PRIVATE", BE)
+    ].concat());
+
+    assert_eq!(transcode(file, transfer_syntax::EXPLICIT_BE).unwrap(), expected);
+
+    let file = part10(transfer_syntax::EXPLICIT_BE, &explicit((0x0028, 0x0010), "UN", &512u16.to_be_bytes(), BE));
+    let expected = explicit_le(&explicit((0x0028, 0x0010), "US", &512u16.to_le_bytes(), LE));
+
+    assert_eq!(transcode(file, transfer_syntax::EXPLICIT_LE).unwrap(), expected);
+}
+
+#[test]
+fn refuses_encapsulated_syntaxes() {
+    let error = transcode(explicit_le(&transcoded_study(transfer_syntax::EXPLICIT_LE)), JPEG_BASELINE).unwrap_err();
+    assert!(matches!(error, DicomError::UnsupportedTransferSyntax { uid, .. } if uid == JPEG_BASELINE));
+
+    let file = part10(JPEG_BASELINE, &[
+        explicit((0x0028, 0x0010), "US", &512u16.to_le_bytes(), LE),
+        encapsulated(&[], &[b"\xFF\xD8ab\xFF\xD9"])
+    ].concat());
+    let error = transcode(file, transfer_syntax::EXPLICIT_LE).unwrap_err();
+    assert!(matches!(error, DicomError::UnsupportedTransferSyntax { uid, .. } if uid == JPEG_BASELINE));
+}
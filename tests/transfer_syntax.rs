@@ -0,0 +1,51 @@
+use dicomize::transfer_syntax::{self, TRANSFER_SYNTAXES};
+use dicomize::{Compression, EndianEncoding, TransferSyntax, VrEncoding};
+
+#[test]
+fn describes_the_native_syntaxes() {
+    let implicit = TransferSyntax::from_uid(transfer_syntax::IMPLICIT_LE).unwrap();
+    assert_eq!((implicit.vr_encoding, implicit.endian_encoding), (VrEncoding::Implicit, EndianEncoding::LittleEndian));
+    assert!(implicit.is_native());
+
+    let big_endian = TransferSyntax::from_uid(transfer_syntax::EXPLICIT_BE).unwrap();
+    assert_eq!(big_endian.endian_encoding, EndianEncoding::BigEndian);
+    assert!(big_endian.retired);
+
+    let deflated = TransferSyntax::from_uid(transfer_syntax::DEFLATED_EXPLICIT_LE).unwrap();
+    assert_eq!(deflated.compression, Compression::Deflate);
+    assert!(!deflated.is_native());
+
+    assert_eq!(TransferSyntax::default().uid, transfer_syntax::EXPLICIT_LE);
+}
+
+#[test]
+fn looks_up_compressed_syntaxes_by_uid() {
+    let jpeg = TransferSyntax::from_uid("1.2.840.10008.1.2.4.50").unwrap();
+    assert_eq!(jpeg.name, "JPEG Baseline (Process 1)");
+    assert_eq!(jpeg.compression, Compression::Jpeg);
+    assert!(jpeg.encapsulated);
+
+    // UIDs read from files are padded with NUL to an even length.
+    assert_eq!(TransferSyntax::from_uid("1.2.840.10008.1.2.5\0").unwrap().compression, Compression::Rle);
+    assert_eq!(transfer_syntax::try_name("1.2.840.10008.1.2.1.98"), Some("Encapsulated Uncompressed Explicit VR Little Endian"));
+    assert!(!transfer_syntax::not_compressed(Some("1.2.840.10008.1.2.4.50")));
+    assert!(transfer_syntax::not_compressed(Some(transfer_syntax::EXPLICIT_LE)));
+}
+
+#[test]
+fn assumes_encapsulated_explicit_little_endian_for_unknown_uids() {
+    assert!(TransferSyntax::from_uid("1.2.3.4").is_none());
+
+    let unknown = TransferSyntax::parse_str("1.2.3.4");
+    assert_eq!((unknown.vr_encoding, unknown.endian_encoding), (VrEncoding::Explicit, EndianEncoding::LittleEndian));
+    assert_eq!(unknown.compression, Compression::Unknown);
+    assert!(unknown.encapsulated);
+}
+
+#[test]
+fn catalogue_uids_are_unique() {
+    let mut uids: Vec<_> = TRANSFER_SYNTAXES.iter().map(|syntax| syntax.0).collect();
+    uids.sort_unstable();
+    uids.dedup();
+    assert_eq!(uids.len(), TRANSFER_SYNTAXES.len());
+}
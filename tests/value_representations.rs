@@ -0,0 +1,56 @@
+mod common;
+
+use dicomize::{transfer_syntax, VrValue};
+use common::*;
+
+fn le_bytes<const N: usize, T: Copy>(values: &[T], to_bytes: fn(T) -> [u8; N]) -> Vec<u8> {
+    values.iter().flat_map(|v| to_bytes(*v).to_vec()).collect()
+}
+
+#[test]
+fn decodes_the_long_binary_and_text_vrs() {
+    let dataset = [
+        explicit((0x0008, 0x0119), "UC", b"LONG\\CODES", LE),
+        explicit((0x0008, 0x0120), "UR", b"urn:oid:1.23", LE),
+        explicit((0x0066, 0x0040), "OL", &le_bytes(&[1u32, 70_000], u32::to_le_bytes), LE),
+        explicit((0x0072, 0x0073), "OD", &le_bytes(&[0.5f64], f64::to_le_bytes), LE),
+        explicit((0x0072, 0x0081), "OV", &le_bytes(&[u64::MAX], u64::to_le_bytes), LE),
+        explicit((0x0072, 0x0082), "SV", &le_bytes(&[-1i64, 1 << 40], i64::to_le_bytes), LE),
+        explicit((0x0072, 0x0083), "UV", &le_bytes(&[1u64 << 63], u64::to_le_bytes), LE)
+    ].concat();
+
+    let container = parse(explicit_le(&dataset));
+    let tags = dataset_tags(&container);
+
+    assert!(matches!(&tags[0].value, VrValue::UnlimitedCharacters(v) if v == &["LONG", "CODES"]));
+    assert!(matches!(&tags[1].value, VrValue::UniversalResource(v) if v == "urn:oid:1.23"));
+    assert!(matches!(&tags[2].value, VrValue::OtherLong(v) if v == &[1, 70_000]));
+    assert!(matches!(&tags[3].value, VrValue::OtherDouble(v) if v == &[0.5]));
+    assert!(matches!(&tags[4].value, VrValue::OtherVeryLong(v) if v == &[u64::MAX]));
+    assert!(matches!(&tags[5].value, VrValue::SignedVeryLong(v) if v == &[-1, 1 << 40]));
+    assert!(matches!(&tags[6].value, VrValue::UnsignedVeryLong(v) if v == &[1 << 63]));
+}
+
+#[test]
+fn reads_the_new_vrs_in_big_endian_and_implicit_syntaxes() {
+    let dataset = [
+        explicit((0x0066, 0x0040), "OL", &le_bytes(&[70_000u32], u32::to_be_bytes), BE),
+        explicit((0x0072, 0x0082), "SV", &le_bytes(&[-2i64], i64::to_be_bytes), BE)
+    ].concat();
+
+    let tags = parse(part10(transfer_syntax::EXPLICIT_BE, &dataset));
+    let tags = dataset_tags(&tags);
+    assert!(matches!(&tags[0].value, VrValue::OtherLong(v) if v == &[70_000]));
+    assert!(matches!(&tags[1].value, VrValue::SignedVeryLong(v) if v == &[-2]));
+
+    // Implicit VR takes them from the dictionary.
+    let dataset = [
+        implicit((0x0008, 0x0120), b"urn:xy"),
+        implicit((0x0072, 0x0083), &7u64.to_le_bytes())
+    ].concat();
+
+    let container = parse(part10(transfer_syntax::IMPLICIT_LE, &dataset));
+    let tags = dataset_tags(&container);
+    assert!(matches!(&tags[0].value, VrValue::UniversalResource(v) if v == "urn:xy"));
+    assert!(matches!(&tags[1].value, VrValue::UnsignedVeryLong(v) if v == &[7]));
+}
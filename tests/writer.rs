@@ -0,0 +1,165 @@
+mod common;
+
+use std::io::Cursor;
+
+use dicomize::{dicom_object, dicom_writer, read_file_meta, tags, transfer_syntax};
+use dicomize::{DicomContainer, DicomError, DicomObject, SequenceLength, TransferSyntax, VrValue, WriteOptions};
+use common::*;
+
+// The shared study with odd length strings, a private tag and pixel data words that change when swapped.
+fn written_study(syntax_uid: &str) -> Vec<u8> {
+    let syntax = TransferSyntax::parse_str(syntax_uid);
+    let endian = syntax.endian_encoding;
+
+    study_with(syntax_uid, &[
+        element((0x0010, 0x0010), "PN", b"DOE^JOHN^X", syntax),
+        element((0x0018, 0x0050), "DS", b"2.5", syntax),
+        element((0x0028, 0x0030), "DS", b"0.5\\0.25", syntax),
+        element((0x0029, 0x1010), "UN", b"PRIVATE", syntax),
+        element((0x7FE0, 0x0010), "OW", &[u16_bytes(1, endian), u16_bytes(0x1234, endian)].concat(), syntax)
+    ])
+}
+
+fn render_dataset(container: &DicomContainer) -> Vec<String> {
+    render(container).into_iter().filter(|tag| !tag.starts_with("0002,")).collect()
+}
+
+fn options(syntax_uid: &str, preamble: u8) -> WriteOptions {
+    WriteOptions { preamble: [preamble; 128], ..WriteOptions::new(TransferSyntax::parse_str(syntax_uid)) }
+}
+
+fn write_object(object: &DicomObject, options: &WriteOptions) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    dicom_writer::write(&mut bytes, object, options).unwrap();
+    bytes
+}
+
+fn write_container(container: &DicomContainer, options: &WriteOptions) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    dicom_writer::write_container(&mut bytes, container, options).unwrap();
+    bytes
+}
+
+#[test]
+fn writes_parsed_files_back_byte_for_byte() {
+    let files = [
+        (transfer_syntax::EXPLICIT_LE, part10(transfer_syntax::EXPLICIT_LE, &written_study(transfer_syntax::EXPLICIT_LE))),
+        (transfer_syntax::EXPLICIT_BE, part10(transfer_syntax::EXPLICIT_BE, &written_study(transfer_syntax::EXPLICIT_BE))),
+        (transfer_syntax::IMPLICIT_LE, part10(transfer_syntax::IMPLICIT_LE, &written_study(transfer_syntax::IMPLICIT_LE)))
+    ];
+
+    for (syntax_uid, file) in files.iter() {
+        let options = options(syntax_uid, 0xAB);
+
+        assert_eq!(&write_container(&parse(file.clone()), &options), file, "container in {}", syntax_uid);
+
+        let object = dicom_object::parse(&mut Cursor::new(file.clone())).unwrap();
+        assert_eq!(&write_object(&object, &options), file, "object in {}", syntax_uid);
+    }
+}
+
+#[test]
+fn writes_values_of_malformed_length_back_with_their_vr() {
+    let file = explicit_le(&[b"\x18\x00\x88\x00FL\x06\x00".to_vec(), vec![1, 2, 3, 4, 5, 6]].concat());
+
+    assert_eq!(write_container(&parse(file.clone()), &options(transfer_syntax::EXPLICIT_LE, 0xAB)), file);
+}
+
+#[test]
+fn writes_encapsulated_pixel_data_back() {
+    let offset_table: Vec<u8> = [0u32, 14].iter().flat_map(|o| o.to_le_bytes().to_vec()).collect();
+    let file = explicit_le(&[
+        explicit((0x0028, 0x0010), "US", &512u16.to_le_bytes(), LE),
+        encapsulated(&offset_table, &[b"\xFF\xD8ab\xFF\xD9", b"\xFF\xD8ef"])
+    ].concat());
+
+    assert_eq!(write_container(&parse(file.clone()), &options(transfer_syntax::EXPLICIT_LE, 0xAB)), file);
+}
+
+#[test]
+fn pads_encapsulated_fragments_of_odd_length() {
+    let offset_table = [0u8; 4];
+    let file = |fragment: &[u8]| explicit_le(&encapsulated(&offset_table, &[fragment]));
+
+    assert_eq!(write_container(&parse(file(b"\xFF\xD8e")), &options(transfer_syntax::EXPLICIT_LE, 0xAB)), file(b"\xFF\xD8e\0"));
+}
+
+#[test]
+fn writes_datasets_in_another_syntax() {
+    let file = part10(transfer_syntax::EXPLICIT_LE, &written_study(transfer_syntax::EXPLICIT_LE));
+    let object = dicom_object::parse(&mut Cursor::new(file.clone())).unwrap();
+
+    let big_endian = write_object(&object, &options(transfer_syntax::EXPLICIT_BE, 0xAB));
+    assert_eq!(big_endian, part10(transfer_syntax::EXPLICIT_BE, &written_study(transfer_syntax::EXPLICIT_BE)));
+
+    // The Transfer Syntax UID follows the syntax written, the group length follows the UID.
+    // Read back in implicit VR, pixel data takes the VR of the dictionary.
+    let expected = render_dataset(&parse(file));
+    let implicit: Vec<String> = expected.iter().map(|tag| tag.replace("OtherWord", "OtherByte")).collect();
+
+    for (syntax_uid, expected) in [(transfer_syntax::IMPLICIT_LE, implicit), (transfer_syntax::DEFLATED_EXPLICIT_LE, expected)].iter() {
+        let written = write_object(&object, &options(syntax_uid, 0));
+        let meta = read_file_meta(&mut Cursor::new(&written)).unwrap();
+        assert_eq!(meta.transfer_syntax_uid, *syntax_uid);
+
+        assert_eq!(&render_dataset(&parse(written)), expected, "dataset in {}", syntax_uid);
+    }
+}
+
+#[test]
+fn writes_sequences_with_defined_or_undefined_lengths() {
+    let file = part10(transfer_syntax::EXPLICIT_LE, &written_study(transfer_syntax::EXPLICIT_LE));
+    let container = parse(file.clone());
+
+    let defined = write_container(&container, &WriteOptions { sequence_length: SequenceLength::Defined, ..options(transfer_syntax::EXPLICIT_LE, 0) });
+    let undefined = write_container(&container, &WriteOptions { sequence_length: SequenceLength::Undefined, ..options(transfer_syntax::EXPLICIT_LE, 0) });
+
+    for written in [defined, undefined].iter() {
+        assert_eq!(render(&parse(written.clone())), render(&container));
+    }
+
+    let defined = parse(write_container(&container, &WriteOptions { sequence_length: SequenceLength::Defined, ..WriteOptions::default() }));
+    let lengths: Vec<usize> = defined.nodes.iter().filter_map(|node| node.tag())
+        .filter(|tag| matches!(tag.value, VrValue::SequenceOfItems | VrValue::Item))
+        .map(|tag| tag.value_length)
+        .collect();
+    // Outer sequence, first item, nested sequence, its item and the second item, then the request attributes sequence and its item.
+    assert_eq!(lengths, [76, 46, 20, 12, 14, 20, 12]);
+
+    let undefined = parse(write_container(&container, &WriteOptions { sequence_length: SequenceLength::Undefined, ..WriteOptions::default() }));
+    assert!(undefined.nodes.iter().filter_map(|node| node.tag())
+        .filter(|tag| matches!(tag.value, VrValue::SequenceOfItems | VrValue::Item))
+        .all(|tag| tag.has_undefined_length()));
+}
+
+#[test]
+fn writes_edited_objects_with_a_minimal_file_meta() {
+    let mut object = DicomObject::new();
+    object.insert(tags::PATIENT_NAME, VrValue::PersonName(vec!["DOE^JOHN^X".into()])).unwrap();
+    object.insert(tags::ROWS, VrValue::UnsignedShort(vec![512])).unwrap();
+
+    let mut series = DicomObject::new();
+    series.insert(tags::SERIES_INSTANCE_UID, VrValue::Uid(vec!["1.2.3".into()])).unwrap();
+    object.insert_item((0x0008, 0x1115), 0, series).unwrap();
+
+    let written = write_object(&object, &WriteOptions::default());
+
+    let expected = with_meta(&explicit((0x0002, 0x0010), "UI", transfer_syntax::EXPLICIT_LE.as_bytes(), LE), &[
+        undefined_sequence((0x0008, 0x1115), &[item(&explicit((0x0020, 0x000E), "UI", b"1.2.3", LE), LE)], LE),
+        explicit((0x0010, 0x0010), "PN", b"DOE^JOHN^X", LE),
+        explicit((0x0028, 0x0010), "US", &512u16.to_le_bytes(), LE)
+    ].concat());
+
+    assert_eq!(written[128..], expected[128..]);
+    assert!(written[..128].iter().all(|b| *b == 0));
+}
+
+#[test]
+fn refuses_values_left_in_the_source() {
+    let file = explicit_le(&explicit((0x7FE0, 0x0010), "OW", &[0; 64], LE));
+    let mut container = DicomContainer::with_deferred_threshold(16);
+    dicomize::dicom::parse(&mut Cursor::new(file), &mut container).unwrap();
+
+    let error = dicom_writer::write_container(&mut Vec::new(), &container, &WriteOptions::default()).unwrap_err();
+    assert!(matches!(error, DicomError::InvalidValue { tag, .. } if tag == tags::PIXEL_DATA));
+}